@@ -0,0 +1,298 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{ffi::OsString, path::PathBuf, str::FromStr};
+
+use lexopt::{
+    Arg::{Long, Short, Value},
+    ValueExt as _,
+};
+
+use syn_serde::cfg::Cfg;
+
+use crate::format::Format;
+
+pub(crate) const USAGE: &str = "\
+Usage: syn-serde <COMMAND> [OPTIONS]
+
+Commands:
+  parse [INPUT]               Parse a Rust source file and serialize its syntax tree
+  print [INPUT]               Deserialize a syntax tree and print it as Rust code
+  convert [INPUT]             Convert a serialized syntax tree to another format
+  query <SELECTOR> [INPUT]    Print the locations of the nodes that match a selector
+  diff <OLD> <NEW>            Print the differences between two syntax trees
+  validate [INPUT]...         Check that syntax trees can be converted to Rust code
+  batch <DIR>                 Parse and serialize all Rust source files in a directory
+
+If INPUT is omitted or `-`, the standard input is read.
+
+Options:
+  -f, --from <FORMAT>    Format of the input [default: inferred from the extension of INPUT]
+  -t, --to <FORMAT>      Format of the output [default: json]
+  -o, --output <PATH>    Write the output to PATH instead of the standard output
+      --no-spans         Do not include spans in the output
+      --comments         Attach the comments of Rust inputs to the syntax tree
+      --trivia           Record the whitespace and comments of the tokens of Rust inputs
+      --crate            Parse INPUT as the root module of a crate, and inline its modules
+      --cfg <SPEC>       Enable a cfg option, e.g., `unix` or `feature=\"x\"`, and remove the code disabled by `#[cfg]` (can be repeated)
+      --pretty           Pretty-print JSON outputs
+      --json             Print the matches of `query` as JSON
+  -h, --help             Print help
+  -V, --version          Print version
+
+Options of `batch`:
+      --jsonl               Write all files as JSON Lines to the output [default: one JSON file per input]
+      --exclude <PATTERN>   Skip the paths that match a `.gitignore`-style pattern (can be repeated)
+  -j, --jobs <N>            Number of threads [default: number of CPUs]
+      --report <PATH>       Write the files that failed to parse to PATH as JSON
+
+Formats:
+  rust, json, tagged, explicit, lossless, ids, arena, protobuf
+
+  tagged, explicit, and lossless can be combined with `+`, e.g.,
+  `tagged+lossless`. Inputs in all JSON formats can be read with `--from json`. Inputs with the
+  `.rs`, `.json`, and `.pb` extensions are read as `rust`, `json`, and
+  `protobuf` respectively.
+";
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Subcommand {
+    Parse,
+    Print,
+    Convert,
+    Query,
+    Diff,
+    Validate,
+    Batch,
+}
+
+impl Subcommand {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Parse => "parse",
+            Self::Print => "print",
+            Self::Convert => "convert",
+            Self::Query => "query",
+            Self::Diff => "diff",
+            Self::Validate => "validate",
+            Self::Batch => "batch",
+        }
+    }
+}
+
+impl FromStr for Subcommand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "parse" => Ok(Self::Parse),
+            "print" => Ok(Self::Print),
+            "convert" => Ok(Self::Convert),
+            "query" => Ok(Self::Query),
+            "diff" => Ok(Self::Diff),
+            "validate" => Ok(Self::Validate),
+            "batch" => Ok(Self::Batch),
+            _ => Err(format!("unrecognized command '{s}'")),
+        }
+    }
+}
+
+pub(crate) struct Args {
+    pub(crate) subcommand: Subcommand,
+    /// The selector of `query`.
+    pub(crate) selector: Option<String>,
+    /// The input files. `None` is the standard input.
+    pub(crate) inputs: Vec<Option<PathBuf>>,
+    pub(crate) output: Option<PathBuf>,
+    pub(crate) from: Option<Format>,
+    pub(crate) to: Option<Format>,
+    pub(crate) spans: bool,
+    pub(crate) comments: bool,
+    pub(crate) trivia: bool,
+    /// Whether `parse` loads the module tree of a crate.
+    pub(crate) krate: bool,
+    /// The options enabled with `--cfg`. `None` if `--cfg` is not used.
+    pub(crate) cfg: Option<Cfg>,
+    pub(crate) pretty: bool,
+    pub(crate) json: bool,
+    /// Whether `batch` writes JSON Lines.
+    pub(crate) jsonl: bool,
+    pub(crate) excludes: Vec<String>,
+    /// The number of threads of `batch`. `0` is the number of CPUs.
+    pub(crate) jobs: usize,
+    /// The path of the report of `batch`.
+    pub(crate) report: Option<PathBuf>,
+}
+
+pub(crate) enum Command {
+    Run(Args),
+    Help,
+    Version,
+}
+
+impl Args {
+    pub(crate) fn parse() -> Result<Command, lexopt::Error> {
+        Self::parse_from(std::env::args_os())
+    }
+
+    pub(crate) fn parse_from<I>(args: I) -> Result<Command, lexopt::Error>
+    where
+        I: IntoIterator,
+        I::Item: Into<OsString>,
+    {
+        let mut parser = lexopt::Parser::from_iter(args);
+
+        let mut subcommand = None;
+        let mut positional = vec![];
+        let mut output = None;
+        let mut from = None;
+        let mut to = None;
+        let mut spans = true;
+        let mut comments = false;
+        let mut trivia = false;
+        let mut krate = false;
+        let mut cfg: Option<Cfg> = None;
+        let mut pretty = false;
+        let mut json = false;
+        let mut jsonl = false;
+        let mut excludes = vec![];
+        let mut jobs = None;
+        let mut report = None;
+
+        while let Some(arg) = parser.next()? {
+            match arg {
+                Short('f') | Long("from") => from = Some(parser.value()?.parse()?),
+                Short('t') | Long("to") => to = Some(parser.value()?.parse()?),
+                Short('o') | Long("output") => output = Some(parser.value()?.into()),
+                Long("no-spans") => spans = false,
+                Long("comments") => comments = true,
+                Long("trivia") => trivia = true,
+                Long("crate") => krate = true,
+                Long("cfg") => {
+                    let option = parser.value()?.string()?;
+                    cfg.get_or_insert_with(Cfg::new).enable(&option).map_err(|e| e.to_string())?;
+                }
+                Long("pretty") => pretty = true,
+                Long("json") => json = true,
+                Long("jsonl") => jsonl = true,
+                Long("exclude") => excludes.push(parser.value()?.string()?),
+                Short('j') | Long("jobs") => jobs = Some(parser.value()?.parse()?),
+                Long("report") => report = Some(parser.value()?.into()),
+                Short('h') | Long("help") => return Ok(Command::Help),
+                Short('V') | Long("version") => return Ok(Command::Version),
+                Value(value) if subcommand.is_none() => subcommand = Some(value.parse()?),
+                Value(value) => positional.push(value),
+                _ => return Err(arg.unexpected()),
+            }
+        }
+
+        let subcommand: Subcommand = match subcommand {
+            Some(subcommand) => subcommand,
+            None => return Err("no command specified".into()),
+        };
+        let name = subcommand.name();
+        if from.is_some() && subcommand == Subcommand::Parse {
+            return Err(
+                format!("--from cannot be used with '{name}': the input is Rust code").into()
+            );
+        }
+        if to.is_some() && !matches!(subcommand, Subcommand::Parse | Subcommand::Convert) {
+            return Err(format!("--to cannot be used with '{name}'").into());
+        }
+        if json && subcommand != Subcommand::Query {
+            return Err(format!("--json cannot be used with '{name}'").into());
+        }
+        if krate && subcommand != Subcommand::Parse {
+            return Err(format!("--crate cannot be used with '{name}'").into());
+        }
+        if krate && trivia {
+            return Err("--crate cannot be used with --trivia".into());
+        }
+        if cfg.is_some()
+            && !matches!(subcommand, Subcommand::Parse | Subcommand::Print | Subcommand::Convert)
+        {
+            return Err(format!("--cfg cannot be used with '{name}'").into());
+        }
+        if cfg.is_some() && trivia {
+            return Err("--cfg cannot be used with --trivia".into());
+        }
+        if krate && to == Some(Format::Protobuf) {
+            return Err("--crate cannot be used with protobuf output".into());
+        }
+        let invalid: &[_] = if subcommand == Subcommand::Batch {
+            &[(from.is_some(), "--from"), (!spans, "--no-spans"), (trivia, "--trivia")]
+        } else {
+            &[
+                (jsonl, "--jsonl"),
+                (!excludes.is_empty(), "--exclude"),
+                (jobs.is_some(), "--jobs"),
+                (report.is_some(), "--report"),
+            ]
+        };
+        if let Some((_, option)) = invalid.iter().find(|(used, _)| *used) {
+            return Err(format!("{option} cannot be used with '{name}'").into());
+        }
+        if subcommand == Subcommand::Batch {
+            if pretty && jsonl {
+                return Err("--pretty cannot be used with --jsonl".into());
+            }
+            if output.is_none() && !jsonl {
+                return Err("'batch' writes one file per input: --output is required".into());
+            }
+        }
+        if output.is_some() && subcommand == Subcommand::Validate {
+            return Err(format!("--output cannot be used with '{name}'").into());
+        }
+
+        let mut positional = positional.into_iter();
+        let selector = if subcommand == Subcommand::Query {
+            match positional.next() {
+                Some(selector) => Some(selector.string()?),
+                None => return Err("the selector of 'query' is missing".into()),
+            }
+        } else {
+            None
+        };
+        let mut inputs: Vec<_> = positional
+            .map(|path| if path == "-" { None } else { Some(PathBuf::from(path)) })
+            .collect();
+        if subcommand == Subcommand::Diff {
+            if inputs.len() != 2 {
+                return Err("'diff' takes exactly two inputs".into());
+            }
+        } else if subcommand != Subcommand::Validate && inputs.len() > 1 {
+            return Err(format!("'{name}' takes at most one input").into());
+        }
+        if inputs.is_empty() {
+            inputs.push(None);
+        }
+        if inputs.iter().filter(|input| input.is_none()).count() > 1 {
+            return Err("the standard input can only be read once".into());
+        }
+        if subcommand == Subcommand::Batch && inputs[0].is_none() {
+            return Err("the directory of 'batch' is missing".into());
+        }
+        if krate && inputs[0].is_none() {
+            return Err("--crate cannot be used with the standard input".into());
+        }
+
+        Ok(Command::Run(Self {
+            subcommand,
+            selector,
+            inputs,
+            output,
+            from,
+            to,
+            spans,
+            comments,
+            trivia,
+            krate,
+            cfg,
+            pretty,
+            json,
+            jsonl,
+            excludes,
+            jobs: jobs.unwrap_or(0),
+            report,
+        }))
+    }
+}
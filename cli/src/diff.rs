@@ -0,0 +1,88 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::fmt;
+
+use serde_json::Value;
+
+/// A difference between two serialized syntax trees.
+pub(crate) enum Change<'a> {
+    /// A value that is only in the old tree.
+    Removed(String, &'a Value),
+    /// A value that is only in the new tree.
+    Added(String, &'a Value),
+    /// A value that differs between the trees.
+    Changed(String, &'a Value, &'a Value),
+}
+
+impl fmt::Display for Change<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Removed(path, old) => write!(f, "- {path}: {old}"),
+            Self::Added(path, new) => write!(f, "+ {path}: {new}"),
+            Self::Changed(path, old, new) => write!(f, "~ {path}: {old} -> {new}"),
+        }
+    }
+}
+
+/// Compares two serialized syntax trees.
+///
+/// Changes are returned in the order of the trees, with the JSON Pointers
+/// (RFC 6901) of the values, as in `syn_serde::query::Match::path`.
+pub(crate) fn diff<'a>(old: &'a Value, new: &'a Value) -> Vec<Change<'a>> {
+    let mut changes = vec![];
+    diff_value(&mut String::new(), old, new, &mut changes);
+    changes
+}
+
+fn diff_value<'a>(
+    path: &mut String,
+    old: &'a Value,
+    new: &'a Value,
+    changes: &mut Vec<Change<'a>>,
+) {
+    match (old, new) {
+        (Value::Object(old_map), Value::Object(new_map)) => {
+            for (key, old) in old_map {
+                let len = push(path, key);
+                match new_map.get(key) {
+                    Some(new) => diff_value(path, old, new, changes),
+                    None => changes.push(Change::Removed(path.clone(), old)),
+                }
+                path.truncate(len);
+            }
+            for (key, new) in new_map {
+                if !old_map.contains_key(key) {
+                    let len = push(path, key);
+                    changes.push(Change::Added(path.clone(), new));
+                    path.truncate(len);
+                }
+            }
+        }
+        (Value::Array(old_values), Value::Array(new_values)) => {
+            for i in 0..old_values.len().max(new_values.len()) {
+                let len = push(path, &i.to_string());
+                match (old_values.get(i), new_values.get(i)) {
+                    (Some(old), Some(new)) => diff_value(path, old, new, changes),
+                    (Some(old), None) => changes.push(Change::Removed(path.clone(), old)),
+                    (None, Some(new)) => changes.push(Change::Added(path.clone(), new)),
+                    (None, None) => unreachable!(),
+                }
+                path.truncate(len);
+            }
+        }
+        _ => {
+            if old != new {
+                changes.push(Change::Changed(path.clone(), old, new));
+            }
+        }
+    }
+}
+
+/// Appends a reference token to a JSON Pointer, and returns the previous
+/// length of the pointer.
+fn push(path: &mut String, token: &str) -> usize {
+    let len = path.len();
+    path.push('/');
+    path.push_str(&token.replace('~', "~0").replace('/', "~1"));
+    len
+}
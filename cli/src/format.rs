@@ -0,0 +1,253 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{fmt, path::Path, str::FromStr};
+
+use serde_json::Value;
+use syn_serde::{
+    File, Syn as _,
+    arena::Arena,
+    ids::WithIds,
+    options::{Options, Tree},
+    protobuf,
+    tagged::Tagged,
+    trivia,
+};
+
+type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
+
+/// The formats of syntax trees.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Format {
+    /// Rust source code.
+    Rust,
+    /// JSON, in the default form or with any combination of internally
+    /// tagged enums (`tagged`, [`syn_serde::tagged`]), every field
+    /// (`explicit`, [`syn_serde::explicit`]), and punctuation and optional
+    /// tokens (`lossless`, [`syn_serde::lossless`]), e.g., `tagged+lossless`.
+    Json(Options),
+    /// JSON with node ids ([`syn_serde::ids`]).
+    Ids,
+    /// A table of nodes ([`syn_serde::arena`]).
+    Arena,
+    /// Protocol Buffers ([`syn_serde::protobuf`]).
+    Protobuf,
+}
+
+impl Format {
+    /// Infers the format of a file from its extension.
+    pub(crate) fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()? {
+            "rs" => Some(Self::Rust),
+            "json" => Some(Self::Json(Options::new())),
+            "pb" | "binpb" => Some(Self::Protobuf),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "rust" => return Ok(Self::Rust),
+            "json" => return Ok(Self::Json(Options::new())),
+            "ids" => return Ok(Self::Ids),
+            "arena" => return Ok(Self::Arena),
+            "protobuf" => return Ok(Self::Protobuf),
+            _ => {}
+        }
+        let mut options = Options::new();
+        for mode in s.split('+') {
+            options = match mode {
+                "tagged" => options.tagged(true),
+                "explicit" => options.explicit(true),
+                "lossless" => options.lossless(true),
+                _ if s.contains('+') => {
+                    return Err(format!(
+                        "'{mode}' cannot be combined: only tagged, explicit, and lossless can"
+                    ));
+                }
+                _ => return Err(format!("unrecognized format '{s}'")),
+            };
+        }
+        Ok(Self::Json(options))
+    }
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Rust => "rust",
+            // All JSON forms are read in the same way.
+            Self::Json(_) => "json",
+            Self::Ids => "ids",
+            Self::Arena => "arena",
+            Self::Protobuf => "protobuf",
+        })
+    }
+}
+
+/// Options of reading Rust inputs.
+#[derive(Clone, Copy)]
+pub(crate) struct ParseOptions {
+    pub(crate) comments: bool,
+    pub(crate) trivia: bool,
+}
+
+/// Reads a syntax tree in the given format.
+pub(crate) fn read(input: &[u8], format: Format, options: ParseOptions) -> Result<File> {
+    match format {
+        Format::Rust => {
+            let source = std::str::from_utf8(input)?;
+            if options.trivia && !options.comments {
+                return Ok(trivia::parse_file(source)?);
+            }
+            let syn_file = syn::parse_file(source)?;
+            let file = if options.comments {
+                File::from_syn_with_comments(&syn_file, source)
+            } else {
+                syn_file.to_adapter()
+            };
+            if !options.trivia {
+                return Ok(file);
+            }
+            let mut json = serde_json::to_value(lossless(&file))?;
+            copy_trivia(&mut json, serde_json::to_value(lossless(&trivia::parse_file(source)?))?);
+            Ok(serde_json::from_value(json)?)
+        }
+        Format::Protobuf => Ok(protobuf::decode(input)?),
+        Format::Arena => {
+            let arena: Arena = serde_json::from_slice(input)?;
+            Ok(arena.to_adapter()?)
+        }
+        _ => {
+            let mut json: Value = serde_json::from_slice(input)?;
+            // Crates contain the tree of their root module.
+            if json.get("files").is_some() && json.get("root").is_some() {
+                json = json["root"].take();
+            }
+            // Arenas are the only JSON form with a different root.
+            if json.get("nodes").is_some() {
+                let arena: Arena = serde_json::from_value(json)?;
+                return Ok(arena.to_adapter()?);
+            }
+            let tree: Tagged = serde_json::from_value(json)?;
+            Ok(tree.to_adapter()?)
+        }
+    }
+}
+
+/// Options of writing outputs.
+#[derive(Clone, Copy)]
+pub(crate) struct WriteOptions {
+    pub(crate) pretty: bool,
+    pub(crate) spans: bool,
+}
+
+/// Writes a syntax tree in the given format.
+pub(crate) fn write(file: &File, format: Format, options: WriteOptions) -> Result<Vec<u8>> {
+    Ok(match format {
+        Format::Rust => print(file).into_bytes(),
+        Format::Protobuf if options.spans => protobuf::encode(file),
+        Format::Protobuf => protobuf::encode(&strip_spans(file)?),
+        _ => to_vec(&to_json(file, format, options.spans)?, options.pretty)?,
+    })
+}
+
+/// Writes the module tree of a crate, with the given files and root module,
+/// as `{"files": [...], "root": ...}` in JSON formats.
+pub(crate) fn write_crate(
+    files: &[String],
+    root: &File,
+    format: Format,
+    options: WriteOptions,
+) -> Result<Vec<u8>> {
+    match format {
+        Format::Rust => Ok(print(root).into_bytes()),
+        Format::Protobuf => Err("crates cannot be written as protobuf".into()),
+        _ => {
+            let root = to_json(root, format, options.spans)?;
+            to_vec(&serde_json::json!({ "files": files, "root": root }), options.pretty)
+        }
+    }
+}
+
+/// Serializes a syntax tree in the given JSON format.
+fn to_json(file: &File, format: Format, spans: bool) -> Result<Value> {
+    let mut json = match format {
+        Format::Json(options) if options == Options::new() => serde_json::to_value(file)?,
+        Format::Json(options) => serde_json::to_value(Tree::new(file, options))?,
+        Format::Ids => serde_json::to_value(WithIds::new(file))?,
+        Format::Arena => serde_json::to_value(Arena::new(file))?,
+        Format::Rust | Format::Protobuf => unreachable!(),
+    };
+    // Spans are removed from the output, which has the fields of the format
+    // (e.g., the trailing punctuation of `lossless`).
+    if !spans {
+        strip_spans_from_value(&mut json);
+    }
+    Ok(json)
+}
+
+/// Returns a syntax tree that is serialized with all of its tokens.
+fn lossless(file: &File) -> Tree {
+    Tree::new(file, Options::new().lossless(true))
+}
+
+fn to_vec(value: &Value, pretty: bool) -> Result<Vec<u8>> {
+    let mut buf =
+        if pretty { serde_json::to_vec_pretty(value)? } else { serde_json::to_vec(value)? };
+    buf.push(b'\n');
+    Ok(buf)
+}
+
+/// Copies the trivia of the nodes of `from` into the same nodes of `to`,
+/// which is the same syntax tree.
+fn copy_trivia(to: &mut Value, from: Value) {
+    match (to, from) {
+        (Value::Object(to), Value::Object(from)) => {
+            for (key, from) in from {
+                if key == "trivia" {
+                    to.insert(key, from);
+                } else if let Some(to) = to.get_mut(&key) {
+                    copy_trivia(to, from);
+                }
+            }
+        }
+        (Value::Array(to), Value::Array(from)) => {
+            to.iter_mut().zip(from).for_each(|(to, from)| copy_trivia(to, from));
+        }
+        _ => {}
+    }
+}
+
+/// Prints a syntax tree as Rust code, with its trivia if it records them.
+pub(crate) fn print(file: &File) -> String {
+    if trivia::has_trivia(file) {
+        trivia::print_file(file)
+    } else {
+        prettyplease::unparse(&syn::File::from_adapter(file))
+    }
+}
+
+/// Removes the spans of the nodes of a syntax tree.
+fn strip_spans(file: &File) -> Result<File> {
+    let mut json = serde_json::to_value(lossless(file))?;
+    strip_spans_from_value(&mut json);
+    Ok(serde_json::from_value(json)?)
+}
+
+pub(crate) fn strip_spans_from_value(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            // Comments always have a span.
+            if !map.contains_key("text") {
+                map.remove("span");
+            }
+            map.values_mut().for_each(strip_spans_from_value);
+        }
+        Value::Array(values) => values.iter_mut().for_each(strip_spans_from_value),
+        _ => {}
+    }
+}
@@ -0,0 +1,243 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod cli;
+mod diff;
+mod format;
+
+use std::{
+    borrow::Cow,
+    fs,
+    io::{self, BufWriter, Read as _, Write as _},
+    path::Path,
+    process::ExitCode,
+};
+
+use syn_serde::{File, batch, krate::Crate, options::Options, query::Query};
+
+use crate::{
+    cli::{Args, Command, Subcommand, USAGE},
+    format::{Format, ParseOptions, WriteOptions},
+};
+
+type Result<T, E = Box<dyn std::error::Error>> = std::result::Result<T, E>;
+
+fn main() -> ExitCode {
+    match try_main() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(e) => {
+            eprintln!("error: {e}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+/// Returns `false` if `diff` found differences, `validate` found invalid
+/// inputs, or `batch` found files that failed to parse.
+fn try_main() -> Result<bool> {
+    let args = match Args::parse()? {
+        Command::Run(args) => args,
+        Command::Help => {
+            print!("{USAGE}");
+            return Ok(true);
+        }
+        Command::Version => {
+            println!("syn-serde {}", env!("CARGO_PKG_VERSION"));
+            return Ok(true);
+        }
+    };
+
+    match args.subcommand {
+        Subcommand::Parse | Subcommand::Print | Subcommand::Convert => convert(&args),
+        Subcommand::Query => query(&args),
+        Subcommand::Diff => diff(&args),
+        Subcommand::Validate => Ok(validate(&args)),
+        Subcommand::Batch => batch(&args),
+    }
+}
+
+fn convert(args: &Args) -> Result<bool> {
+    let input = args.inputs[0].as_deref();
+    let from = match args.subcommand {
+        Subcommand::Parse => Format::Rust,
+        _ => input_format(args, input, Format::Json(Options::new())),
+    };
+    let to = match args.subcommand {
+        Subcommand::Print => Format::Rust,
+        _ => args.to.unwrap_or(Format::Json(Options::new())),
+    };
+    let options = WriteOptions { pretty: args.pretty, spans: args.spans };
+
+    if args.krate {
+        let path = input.unwrap();
+        let krate = if args.comments { Crate::load_with_comments(path) } else { Crate::load(path) };
+        let krate = krate.map_err(|e| format!("{}: {e}", display(input)))?;
+        let configured;
+        let root = match &args.cfg {
+            Some(cfg) => {
+                configured = cfg.strip(krate.root())?;
+                &configured
+            }
+            None => krate.root(),
+        };
+        write_output(args, &format::write_crate(krate.files(), root, to, options)?)?;
+        return Ok(true);
+    }
+
+    let file = load(args, input, from)?;
+    let file = match &args.cfg {
+        Some(cfg) => cfg.strip(&file)?,
+        None => file,
+    };
+    write_output(args, &format::write(&file, to, options)?)?;
+    Ok(true)
+}
+
+fn query(args: &Args) -> Result<bool> {
+    let query = Query::parse(args.selector.as_deref().unwrap())?;
+    let input = args.inputs[0].as_deref();
+    let file = load(args, input, input_format(args, input, Format::Rust))?;
+    let matches = query.find(&file);
+
+    let mut out = vec![];
+    if args.json {
+        serde_json::to_writer_pretty(&mut out, &matches)?;
+        writeln!(out)?;
+    } else {
+        let name = display(input);
+        for m in &matches {
+            let kind = m.kind.as_deref().unwrap_or("_");
+            match &m.span {
+                Some(span) if args.spans => writeln!(
+                    out,
+                    "{}:{}:{}: {} {}",
+                    name,
+                    span.start_line,
+                    span.start_column + 1,
+                    kind,
+                    m.path
+                )?,
+                _ => writeln!(out, "{}: {} {}", name, kind, m.path)?,
+            }
+        }
+    }
+    write_output(args, &out)?;
+    Ok(true)
+}
+
+fn diff(args: &Args) -> Result<bool> {
+    let mut trees = vec![];
+    for input in &args.inputs {
+        let input = input.as_deref();
+        let file = load(args, input, input_format(args, input, Format::Rust))?;
+        let mut json = serde_json::to_value(&file)?;
+        // Spans change whenever code moves, so they are not compared.
+        format::strip_spans_from_value(&mut json);
+        trees.push(json);
+    }
+
+    let changes = diff::diff(&trees[0], &trees[1]);
+    let mut out = vec![];
+    for change in &changes {
+        writeln!(out, "{change}")?;
+    }
+    write_output(args, &out)?;
+    Ok(changes.is_empty())
+}
+
+fn validate(args: &Args) -> bool {
+    let mut valid = true;
+    for input in &args.inputs {
+        let input = input.as_deref();
+        let result = load(args, input, input_format(args, input, Format::Rust))
+            .and_then(|file| check(&file).map_err(|e| format!("{}: {e}", display(input)).into()));
+        if let Err(e) = result {
+            eprintln!("error: {e}");
+            valid = false;
+        }
+    }
+    valid
+}
+
+fn batch(args: &Args) -> Result<bool> {
+    let root = args.inputs[0].as_deref().unwrap();
+    let options = batch::Options {
+        excludes: args.excludes.clone(),
+        jobs: args.jobs,
+        comments: args.comments,
+        pretty: args.pretty,
+    };
+    let report = if args.jsonl {
+        match &args.output {
+            Some(path) => {
+                batch::to_json_lines(root, BufWriter::new(fs::File::create(path)?), &options)?
+            }
+            None => batch::to_json_lines(root, BufWriter::new(io::stdout().lock()), &options)?,
+        }
+    } else {
+        batch::to_dir(root, args.output.as_deref().unwrap(), &options)?
+    };
+
+    for failure in &report.failures {
+        eprintln!("error: {}/{failure}", root.display());
+    }
+    eprintln!("converted {} files, {} failed", report.converted, report.failures.len());
+    if let Some(path) = &args.report {
+        fs::write(path, serde_json::to_vec_pretty(&report)?)?;
+    }
+    Ok(report.failures.is_empty())
+}
+
+/// Checks that a syntax tree can be printed as Rust code that can be parsed
+/// again.
+fn check(file: &File) -> Result<(), String> {
+    // Invalid identifiers, literals and punctuation are rejected when the
+    // tree is deserialized, so printing it cannot fail.
+    let source = format::print(file);
+    syn::parse_file(&source)
+        .map_err(|e| format!("the syntax tree does not print valid Rust code: {e}"))?;
+    Ok(())
+}
+
+/// Returns the format of an input: the format given with `--from`, the format
+/// inferred from the extension of the input, or `default`.
+fn input_format(args: &Args, path: Option<&Path>, default: Format) -> Format {
+    args.from.or_else(|| path.and_then(Format::from_path)).unwrap_or(default)
+}
+
+/// Reads and deserializes an input.
+fn load(args: &Args, path: Option<&Path>, format: Format) -> Result<File> {
+    let options = ParseOptions { comments: args.comments, trivia: args.trivia };
+    let result = read_input(path).and_then(|input| format::read(&input, format, options));
+    result.map_err(|e| format!("{}: failed to read as {}: {}", display(path), format, e).into())
+}
+
+fn read_input(path: Option<&Path>) -> Result<Vec<u8>> {
+    match path {
+        Some(path) => Ok(fs::read(path)?),
+        None => {
+            let mut buf = vec![];
+            io::stdin().lock().read_to_end(&mut buf)?;
+            Ok(buf)
+        }
+    }
+}
+
+fn write_output(args: &Args, out: &[u8]) -> Result<()> {
+    match &args.output {
+        Some(path) => fs::write(path, out)?,
+        None => {
+            let mut stdout = io::stdout().lock(); // Not buffered because it is written at once.
+            stdout.write_all(out)?;
+            stdout.flush()?;
+        }
+    }
+    Ok(())
+}
+
+fn display(path: Option<&Path>) -> Cow<'_, str> {
+    match path {
+        Some(path) => path.to_string_lossy(),
+        None => Cow::Borrowed("<stdin>"),
+    }
+}
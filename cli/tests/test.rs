@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Output},
+};
+
+fn syn_serde(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_syn-serde")).args(args).output().unwrap()
+}
+
+#[track_caller]
+fn stdout(output: &Output) -> String {
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout.clone()).unwrap()
+}
+
+fn tmp_file(name: &str, contents: &str) -> PathBuf {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn parse_and_print() {
+    let rs = tmp_file("parse_and_print.rs", "fn main() {\n    // Comment.\n    f(a,  b)\n}\n");
+    let rs = rs.to_str().unwrap();
+
+    let json = stdout(&syn_serde(&["parse", "--no-spans", rs]));
+    assert_eq!(
+        json,
+        "{\"items\":[{\"fn\":{\"ident\":\"main\",\"inputs\":[],\"output\":null,\"stmts\":\
+         {\"stmts\":[{\"expr\":{\"expr\":{\"call\":{\"args\":[{\"path\":{\"segments\":[{\"ident\":\"a\"}]}},\
+         {\"path\":{\"segments\":[{\"ident\":\"b\"}]}}],\"func\":{\"path\":{\"segments\":\
+         [{\"ident\":\"f\"}]}}}}}}]}}}]}\n"
+    );
+
+    let out = tmp_file("parse_and_print.json", "");
+    for args in [
+        &["--comments"][..],
+        &["--trivia"],
+        &["--trivia", "--comments"],
+        &["-t", "tagged", "--pretty"],
+    ] {
+        let mut parse = vec!["parse", rs, "-o", out.to_str().unwrap()];
+        parse.extend_from_slice(args);
+        stdout(&syn_serde(&parse));
+        let printed = stdout(&syn_serde(&["print", out.to_str().unwrap()]));
+        if args[0] == "--trivia" {
+            assert_eq!(printed, fs::read_to_string(rs).unwrap());
+        } else {
+            assert_eq!(printed, "fn main() {\n    f(a, b)\n}\n");
+        }
+    }
+}
+
+#[test]
+fn convert() {
+    let rs = tmp_file("convert.rs", "struct S<T>(T);\n");
+    let mut prev = rs;
+    for (i, to) in ["tagged", "explicit", "lossless", "ids", "arena", "protobuf", "json", "rust"]
+        .into_iter()
+        .enumerate()
+    {
+        let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!("convert{i}.{to}"));
+        let from = if i == 0 {
+            "rust"
+        } else if to == "json" {
+            "protobuf"
+        } else {
+            "json"
+        };
+        let output = syn_serde(&[
+            "convert",
+            "-f",
+            from,
+            "-t",
+            to,
+            prev.to_str().unwrap(),
+            "-o",
+            path.to_str().unwrap(),
+        ]);
+        stdout(&output);
+        prev = path;
+    }
+    assert_eq!(fs::read_to_string(prev).unwrap(), "struct S<T>(T);\n");
+
+    // The modes of JSON can be combined.
+    let rs = tmp_file("convert_modes.rs", "fn f(a: u8,) {}\n");
+    let json = stdout(&syn_serde(&["parse", "-t", "tagged+lossless", rs.to_str().unwrap()]));
+    assert!(json.contains("\"kind\":\"fn\""), "{json}");
+    assert!(json.contains("\"trailing\":true"), "{json}");
+    let out = tmp_file("convert_modes.json", &json);
+    let printed = stdout(&syn_serde(&["print", out.to_str().unwrap()]));
+    assert_eq!(printed, "fn f(a: u8) {}\n");
+
+    // Spans and trivia are removed and added without losing the other fields.
+    for args in [&["--no-spans"], &["--trivia"]] {
+        let mut parse = vec!["parse", "-t", "lossless", rs.to_str().unwrap()];
+        parse.extend_from_slice(args);
+        let json = stdout(&syn_serde(&parse));
+        assert!(json.contains("\"trailing\":true"), "{json}");
+        assert_eq!(json.contains("\"span\""), args[0] == "--trivia", "{json}");
+    }
+}
+
+#[test]
+fn parse_crate() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("parse_crate");
+    fs::create_dir_all(dir.join("a")).unwrap();
+    fs::write(dir.join("lib.rs"), "mod a;\n").unwrap();
+    fs::write(dir.join("a/mod.rs"), "fn f() {}\n").unwrap();
+    let lib = dir.join("lib.rs");
+    let lib = lib.to_str().unwrap();
+
+    let json = stdout(&syn_serde(&["parse", "--crate", "--no-spans", lib]));
+    assert_eq!(
+        json,
+        "{\"files\":[\"lib.rs\",\"a/mod.rs\"],\"root\":{\"items\":[{\"mod\":{\"content\":\
+         [{\"fn\":{\"ident\":\"f\",\"inputs\":[],\"output\":null,\"stmts\":{\"stmts\":[]}}}],\
+         \"ident\":\"a\"}}]}}\n"
+    );
+    let out = tmp_file("parse_crate.json", &json);
+    let printed = stdout(&syn_serde(&["print", out.to_str().unwrap()]));
+    assert_eq!(printed, "mod a {\n    fn f() {}\n}\n");
+
+    let json = stdout(&syn_serde(&["parse", "--crate", "-t", "tagged", lib]));
+    assert!(json.contains("\"kind\":\"fn\""));
+    assert!(json.contains("\"file\":1"));
+}
+
+#[test]
+fn cfg() {
+    let rs = tmp_file(
+        "cfg.rs",
+        "#[cfg(unix)]\nfn unix() {}\n#[cfg(windows)]\nfn windows() {}\n\
+         #[cfg_attr(feature = \"x\", inline)]\nfn f() {}\n",
+    );
+    let rs = rs.to_str().unwrap();
+
+    let json = stdout(&syn_serde(&["parse", "--cfg", "unix", "--cfg", "feature=\"x\"", rs]));
+    let out = tmp_file("cfg.json", &json);
+    let printed = stdout(&syn_serde(&["print", out.to_str().unwrap()]));
+    assert_eq!(printed, "fn unix() {}\n#[inline]\nfn f() {}\n");
+
+    let printed = stdout(&syn_serde(&["print", "--cfg", "windows", rs, "-f", "rust"]));
+    assert_eq!(printed, "fn windows() {}\nfn f() {}\n");
+}
+
+#[test]
+fn query() {
+    let rs = tmp_file("query.rs", "fn f() {\n    a.unwrap();\n    b.unwrap();\n}\n");
+    let rs = rs.to_str().unwrap();
+    let out = stdout(&syn_serde(&["query", "method_call[method=unwrap]", rs]));
+    assert_eq!(
+        out,
+        format!(
+            "{rs}:2:7: method_call /items/0/fn/stmts/stmts/0/expr/expr\n\
+             {rs}:3:7: method_call /items/0/fn/stmts/stmts/1/expr/expr\n"
+        )
+    );
+    let out = stdout(&syn_serde(&["query", "--json", "fn", rs]));
+    assert!(out.starts_with("[\n  {\n    \"path\": \"/items/0\",\n    \"kind\": \"fn\",\n"));
+}
+
+#[test]
+fn diff() {
+    let old = tmp_file("diff_old.rs", "fn f() -> u8 { 1 }\n");
+    let new = tmp_file("diff_new.rs", "\n\nfn f() -> u16 {\n    1\n}\n");
+    let same = tmp_file("diff_same.rs", "fn  f( ) -> u8 {1}");
+    let (old, new, same) = (old.to_str().unwrap(), new.to_str().unwrap(), same.to_str().unwrap());
+
+    let output = syn_serde(&["diff", old, new]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "~ /items/0/fn/output/path/segments/0/ident: \"u8\" -> \"u16\"\n"
+    );
+    assert_eq!(stdout(&syn_serde(&["diff", old, same])), "");
+}
+
+#[test]
+fn validate() {
+    let valid = tmp_file("valid.json", r#"{"items":[{"struct":{"ident":"S","fields":"unit"}}]}"#);
+    let invalid =
+        tmp_file("invalid.json", r#"{"items":[{"struct":{"ident":"1x","fields":"unit"}}]}"#);
+    let (valid, invalid) = (valid.to_str().unwrap(), invalid.to_str().unwrap());
+
+    assert_eq!(stdout(&syn_serde(&["validate", valid])), "");
+    let output = syn_serde(&["validate", valid, invalid]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        format!("error: {invalid}: failed to read as json: `1x` is not a valid identifier\n")
+    );
+}
+
+#[test]
+fn batch() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("batch");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("src/gen")).unwrap();
+    fs::write(dir.join("src/a.rs"), "struct A;\n").unwrap();
+    fs::write(dir.join("src/b.rs"), "fn b() {\n    1 +\n}\n").unwrap();
+    fs::write(dir.join("src/gen/c.rs"), "struct C;\n").unwrap();
+    let src = dir.join("src");
+    let src = src.to_str().unwrap();
+
+    let report = dir.join("report.json");
+    let output = syn_serde(&[
+        "batch",
+        "--jsonl",
+        "--exclude",
+        "gen/",
+        "--report",
+        report.to_str().unwrap(),
+        src,
+    ]);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(
+        String::from_utf8(output.stdout).unwrap(),
+        "{\"path\":\"a.rs\",\"file\":{\"items\":[{\"struct\":{\"ident\":\"A\",\"fields\":\"unit\",\
+         \"span\":{\"start_offset\":0,\"end_offset\":0,\"start_line\":1,\"start_column\":7,\
+         \"end_line\":1,\"end_column\":8}}}],\"span\":{\"start_offset\":0,\"end_offset\":0,\
+         \"start_line\":1,\"start_column\":0,\"end_line\":1,\"end_column\":9}}}\n"
+    );
+    assert_eq!(
+        String::from_utf8(output.stderr).unwrap(),
+        format!(
+            "error: {src}/b.rs:3:1: unexpected end of input, expected an expression\n\
+             converted 1 files, 1 failed\n"
+        )
+    );
+    assert_eq!(
+        fs::read_to_string(report).unwrap(),
+        "{\n  \"converted\": 1,\n  \"failures\": [\n    {\n      \"path\": \"b.rs\",\n      \
+         \"line\": 3,\n      \"column\": 1,\n      \"message\": \"unexpected end of input, \
+         expected an expression\"\n    }\n  ]\n}"
+    );
+
+    let out = dir.join("out");
+    let output = syn_serde(&["batch", "-j", "2", src, "-o", out.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    let printed = stdout(&syn_serde(&["print", out.join("gen/c.json").to_str().unwrap()]));
+    assert_eq!(printed, "struct C;\n");
+    assert!(out.join("a.json").is_file());
+    assert!(!out.join("b.json").exists());
+}
+
+#[test]
+fn errors() {
+    for (args, msg) in [
+        (&[][..], "no command specified"),
+        (&["foo"], "cannot parse argument \"foo\": unrecognized command 'foo'"),
+        (&["parse", "-t", "xml"], "cannot parse argument \"xml\": unrecognized format 'xml'"),
+        (
+            &["parse", "-t", "ids+tagged"],
+            "cannot parse argument \"ids+tagged\": 'ids' cannot be combined: only tagged, \
+             explicit, and lossless can",
+        ),
+        (&["parse", "-f", "json"], "--from cannot be used with 'parse': the input is Rust code"),
+        (&["diff", "a.rs"], "'diff' takes exactly two inputs"),
+        (&["query"], "the selector of 'query' is missing"),
+        (&["print", "--crate"], "--crate cannot be used with 'print'"),
+        (&["parse", "--crate"], "--crate cannot be used with the standard input"),
+        (&["parse", "--jsonl"], "--jsonl cannot be used with 'parse'"),
+        (&["parse", "--cfg", "all(unix)"], "invalid cfg option `all(unix)`"),
+        (&["query", "--cfg", "unix", "fn"], "--cfg cannot be used with 'query'"),
+        (&["parse", "--cfg", "unix", "--trivia"], "--cfg cannot be used with --trivia"),
+        (&["batch", "--jsonl"], "the directory of 'batch' is missing"),
+        (&["batch", "src"], "'batch' writes one file per input: --output is required"),
+    ] {
+        let output = syn_serde(args);
+        assert_eq!(output.status.code(), Some(2));
+        assert_eq!(String::from_utf8(output.stderr).unwrap(), format!("error: {msg}\n"));
+    }
+}
@@ -13,7 +13,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         [input] => (input, None),
         [input, output] => (input, Some(output)),
         _ => {
-            println!("Usage: rust2pickle <input_path> [output_path]");
+            println!("Usage: json2rust <input_path> [output_path]");
             std::process::exit(1);
         }
     };
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use std::{
+    env, fs,
+    io::{self, BufWriter, Write as _},
+    path::Path,
+};
+
+use syn_serde::query::Query;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut args: Vec<_> = env::args_os().skip(1).collect();
+    let json_output = if let Some(pos) = args.iter().position(|arg| arg == "--json") {
+        args.remove(pos);
+        true
+    } else {
+        false
+    };
+    let (selector, input_path) = match &*args {
+        [selector, input] => (selector.to_string_lossy(), Path::new(input)),
+        _ => {
+            println!("Usage: rust-query [--json] <selector> <input_path>");
+            std::process::exit(1);
+        }
+    };
+
+    let query = Query::parse(&selector)?;
+    let input = fs::read_to_string(input_path)?;
+    let matches = if input_path.extension().is_some_and(|ext| ext == "json") {
+        let file: syn_serde::File = serde_json::from_str(&input)?;
+        query.find(&file)
+    } else {
+        let syn_file = syn::parse_file(&input)?;
+        query.find(&syn_serde::File::from_syn_with_comments(&syn_file, &input))
+    };
+
+    let mut stdout = BufWriter::new(io::stdout().lock()); // Buffered because it is written with newline many times.
+    if json_output {
+        serde_json::to_writer_pretty(&mut stdout, &matches)?;
+        writeln!(stdout)?;
+    } else {
+        for m in &matches {
+            let kind = m.kind.as_deref().unwrap_or("_");
+            match &m.span {
+                Some(span) => writeln!(
+                    stdout,
+                    "{}:{}:{}: {kind} {}",
+                    input_path.display(),
+                    span.start_line,
+                    span.start_column + 1,
+                    m.path
+                )?,
+                None => writeln!(stdout, "{}: {kind} {}", input_path.display(), m.path)?,
+            }
+        }
+    }
+    stdout.flush()?;
+    Ok(())
+}
@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A flat, table-like encoding of syntax trees.
+//!
+//! An [`Arena`] stores each node of a syntax tree as a row of a table, instead
+//! of nesting nodes in each other. This is easier to load into databases and
+//! columnar tools, and the serialized arena does not hit the recursion limits
+//! of JSON parsers on deeply nested code. Note that building an arena from an
+//! adapter and converting it back still recurse through the adapter, like the
+//! other encodings; see [`json::from_str_deep`](crate::json::from_str_deep) for
+//! deeply nested trees.
+//!
+//! An arena is serialized as `{"nodes": [...]}`, with one row per node:
+//!
+//! | Field      | Description                                                  |
+//! | ---------- | ------------------------------------------------------------ |
+//! | `id`       | the id of the node, same as in [`ids`](crate::ids)           |
+//! | `parent`   | the id of the parent node, omitted for the root              |
+//! | `role`     | the position of the node in the `fields` of its parent, as a relative JSON Pointer |
+//! | `kind`     | the kind of the node, e.g., `"method_call"`                  |
+//! | `variant`  | the enum variant of the node, omitted for structs            |
+//! | `fields`   | the content of the node, with child nodes replaced by `null` |
+//! | `children` | the ids of the child nodes                                   |
+//!
+//! Rows are sorted by id, and the parent of a node always precedes it.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn dox() {
+//! use syn_serde::arena::Arena;
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     fn main() {}
+//! };
+//! let arena = Arena::from_syn(&syn_file);
+//! let json = serde_json::to_string(&arena).unwrap();
+//!
+//! let arena: Arena = serde_json::from_str(&json).unwrap();
+//! let syn_file2: syn::File = arena.to_syn().unwrap();
+//! assert_eq!(syn_file, syn_file2);
+//! # }
+//! ```
+
+use std::collections::HashMap;
+
+use serde::{de::DeserializeOwned, ser::Serialize};
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    Error, Syn, lossless,
+    node::{self, Map, Value, Variant},
+};
+
+/// A syntax tree encoded as a flat table of nodes.
+///
+/// See the [module level documentation](self) for more.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Arena {
+    // Written in lossless mode, so that converting the arena back keeps
+    // trailing punctuation and optional tokens.
+    #[serde(serialize_with = "lossless::serialize_enabled")]
+    nodes: Vec<Row>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Row {
+    id: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parent: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    role: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    variant: Option<String>,
+    fields: Value,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    children: Vec<usize>,
+}
+
+impl Arena {
+    /// Encodes the given adapter.
+    pub fn new<T>(adapter: &T) -> Self
+    where
+        T: ?Sized + Serialize,
+    {
+        let root = node::to_value(adapter);
+        let entries = node::index(&root);
+        let infos = crate::ids::node_table(&root);
+        let mut nodes: Vec<_> = entries
+            .iter()
+            .zip(infos)
+            .map(|(entry, info)| {
+                let (variant, content) = match entry.value {
+                    Value::Variant(Variant { name, value: Some(inner) }) => {
+                        (Some((*name).to_owned()), &**inner)
+                    }
+                    value => (None, value),
+                };
+                Row {
+                    id: info.id,
+                    parent: info.parent,
+                    role: info.role,
+                    kind: info.kind,
+                    variant,
+                    fields: skeleton(content),
+                    children: vec![],
+                }
+            })
+            .collect();
+        for i in 0..nodes.len() {
+            if let Some(parent) = nodes[i].parent {
+                nodes[parent].children.push(i);
+            }
+        }
+        Self { nodes }
+    }
+
+    /// Encodes the given [`Syn`] type.
+    ///
+    /// This function is equivalent to the following code:
+    ///
+    /// ```
+    /// # fn from_syn(syn_file: &syn::File) -> syn_serde::arena::Arena {
+    /// use syn_serde::{Syn, arena::Arena};
+    ///
+    /// let adapter = syn_file.to_adapter();
+    /// Arena::new(&adapter)
+    /// # }
+    /// ```
+    pub fn from_syn<S>(syn: &S) -> Self
+    where
+        S: Syn,
+    {
+        Self::new(&syn.to_adapter())
+    }
+
+    /// Returns the number of nodes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if this arena contains no nodes.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Rebuilds the adapter that this arena encodes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the rows of this arena are inconsistent, or if they
+    /// do not encode a `T`.
+    pub fn to_adapter<T>(&self) -> Result<T, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let root = self.rebuild()?;
+        node::from_value(root).map_err(|e| Error::new(e.to_string()))
+    }
+
+    /// Rebuilds the [`Syn`] type that this arena encodes.
+    ///
+    /// This function is equivalent to the following code:
+    ///
+    /// ```
+    /// # fn to_syn(arena: &syn_serde::arena::Arena) -> Result<syn::File, syn_serde::Error> {
+    /// use syn_serde::Syn;
+    ///
+    /// let adapter = arena.to_adapter()?;
+    /// Ok(syn::File::from_adapter(&adapter))
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`to_adapter`](Self::to_adapter).
+    pub fn to_syn<S>(&self) -> Result<S, Error>
+    where
+        S: Syn,
+    {
+        let adapter = self.to_adapter()?;
+        Ok(S::from_adapter(&adapter))
+    }
+
+    /// Rebuilds the tree without recursion: since the parent of a node always
+    /// precedes it, visiting the rows in reverse order completes each node
+    /// before it is moved into its parent.
+    fn rebuild(&self) -> Result<Value, Error> {
+        if self.nodes.is_empty() {
+            return Err(Error::new("arena has no nodes"));
+        }
+        let mut values: Vec<Option<Value>> = Vec::with_capacity(self.nodes.len());
+        for (i, row) in self.nodes.iter().enumerate() {
+            if row.id != i {
+                return Err(Error::new(format!("expected node {}, found node {}", i, row.id)));
+            }
+            match row.parent {
+                Some(parent) if parent >= i => {
+                    return Err(Error::new(format!("node {} precedes its parent {}", i, parent)));
+                }
+                None if i != 0 => return Err(Error::new(format!("node {} has no parent", i))),
+                _ => {}
+            }
+            values.push(Some(row.fields.clone()));
+        }
+
+        // The positions that have been filled, and the nodes that fill them.
+        let mut filled = HashMap::new();
+        for (i, row) in self.nodes.iter().enumerate().rev() {
+            let content = values[i].take().unwrap();
+            let value = match &row.variant {
+                // `{"name": content}`, the same as the externally tagged
+                // representation.
+                Some(name) => Value::Map(Map { ty: None, fields: vec![(name.clone(), content)] }),
+                None => content,
+            };
+            let parent = match row.parent {
+                Some(parent) => parent,
+                None => return Ok(value),
+            };
+            let role = row.role.as_deref().unwrap_or_default();
+            if let Some(other) = filled.insert((parent, role), i) {
+                return Err(Error::new(format!(
+                    "nodes {} and {} have the same position `{}` in node {}",
+                    i, other, role, parent
+                )));
+            }
+            let slot = values[parent].as_mut().and_then(|parent| node::pointer_mut(parent, role));
+            match slot {
+                Some(slot) => *slot = value,
+                None => {
+                    return Err(Error::new(format!(
+                        "node {} refers to a nonexistent position `{}` in node {}",
+                        i, role, parent
+                    )));
+                }
+            }
+        }
+        unreachable!()
+    }
+}
+
+/// Copies the content of a node, replacing its child nodes with `null`.
+fn skeleton(content: &Value) -> Value {
+    if !matches!(content, Value::Map(_) | Value::Seq(_)) && content.is_node() {
+        return Value::Null;
+    }
+    let mut root = Value::Null;
+    // The values to copy, and the places to copy them to.
+    let mut stack = vec![(content, &mut root)];
+    while let Some((value, out)) = stack.pop() {
+        *out = match value {
+            Value::Map(map) => Value::Map(Map {
+                ty: map.ty,
+                fields: map.fields.iter().map(|(key, _)| (key.clone(), Value::Null)).collect(),
+            }),
+            Value::Seq(elems) => Value::Seq(vec![Value::Null; elems.len()]),
+            value => value.clone(),
+        };
+        match (value, out) {
+            (Value::Map(map), Value::Map(out)) => {
+                let fields = map.fields.iter().map(|(_, value)| value);
+                let out = out.fields.iter_mut().map(|(_, value)| value);
+                stack.extend(fields.zip(out).filter(|(value, _)| !value.is_node()));
+            }
+            (Value::Seq(elems), Value::Seq(out)) => {
+                stack.extend(elems.iter().zip(out).filter(|(value, _)| !value.is_node()));
+            }
+            _ => {}
+        }
+    }
+    root
+}
@@ -0,0 +1,432 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Conversion of all Rust source files in a directory tree.
+//!
+//! The functions in this module walk a directory, skip the files excluded by
+//! `.gitignore` files and by [`Options::excludes`], and parse and serialize
+//! the `.rs` files in parallel. Files that fail to parse do not stop the
+//! conversion: they are collected into the [`Report`] with their position.
+//!
+//! The outputs are written either as one JSON file per input
+//! ([`to_dir`]), or as one [JSON Lines](https://jsonlines.org) stream
+//! ([`to_json_lines`]) whose lines look like this:
+//!
+//! ```json
+//! {"path":"src/lib.rs","file":{"items":[...]}}
+//! ```
+//!
+//! Outputs are always written in the order of the paths, regardless of the
+//! order in which the files are converted.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # fn dox() -> std::io::Result<()> {
+//! use std::{fs, path::Path};
+//!
+//! use syn_serde::batch::{self, Options};
+//!
+//! let options = Options { excludes: vec!["/target".to_owned()], ..Options::default() };
+//! let out = fs::File::create("crates.jsonl")?;
+//! let report = batch::to_json_lines(Path::new("crates"), out, &options)?;
+//! for failure in &report.failures {
+//!     eprintln!("{}", failure);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Excludes
+//!
+//! Excludes have the syntax of `.gitignore` files: `*`, `?`, `[...]`, and
+//! `**` wildcards, `/` to anchor a pattern to the directory of the file, a
+//! trailing `/` to only match directories, and `!` to re-include a path.
+//! Patterns in [`Options::excludes`] are relative to the root directory, and
+//! take precedence over `.gitignore` files. `.git` directories are always
+//! skipped.
+
+use core::{
+    fmt,
+    panic::AssertUnwindSafe,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use std::{
+    collections::BTreeMap,
+    fs, io, panic,
+    path::{Path, PathBuf},
+    sync::{Arc, mpsc},
+    thread,
+};
+
+use serde_derive::Serialize;
+
+use crate::{File, Syn as _};
+
+/// Options of the conversion.
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Additional `.gitignore`-style patterns of the paths to skip, relative
+    /// to the root directory.
+    pub excludes: Vec<String>,
+    /// The number of threads to use. `0` uses the available parallelism of
+    /// the system.
+    pub jobs: usize,
+    /// Whether to distribute the comments of each file to its nodes, as
+    /// [`File::from_syn_with_comments`] does.
+    pub comments: bool,
+    /// Whether to pretty-print the JSON files written by [`to_dir`].
+    pub pretty: bool,
+}
+
+/// The result of converting a directory tree.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Report {
+    /// The number of files that were converted.
+    pub converted: usize,
+    /// The files that could not be converted.
+    pub failures: Vec<Failure>,
+}
+
+/// A file that could not be converted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Failure {
+    /// The path of the file, relative to the root directory, with `/` as the
+    /// separator.
+    pub path: String,
+    /// The line (1-based) of the error, if it is a syntax error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    /// The column (1-based) of the error, if it is a syntax error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub column: Option<usize>,
+    /// The error message.
+    pub message: String,
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => {
+                write!(f, "{}:{}:{}: {}", self.path, line, column, self.message)
+            }
+            _ => write!(f, "{}: {}", self.path, self.message),
+        }
+    }
+}
+
+/// Returns the paths of the `.rs` files under `root` that are not excluded,
+/// relative to `root`, in sorted order.
+pub fn files(root: &Path, options: &Options) -> io::Result<Vec<PathBuf>> {
+    let mut excludes = vec![];
+    for pattern in &options.excludes {
+        excludes.extend(Pattern::parse(pattern, ""));
+    }
+    let mut files = vec![];
+    walk(root, Path::new(""), &mut vec![], &excludes, &mut files)?;
+    Ok(files)
+}
+
+/// Converts the `.rs` files under `root`, and writes each of them into a JSON
+/// file at the same relative path under `out_dir`, with the `.json`
+/// extension.
+pub fn to_dir(root: &Path, out_dir: &Path, options: &Options) -> io::Result<Report> {
+    convert(root, options, options.pretty, |path, json| {
+        let path = out_dir.join(path).with_extension("json");
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, json)
+    })
+}
+
+/// Converts the `.rs` files under `root`, and writes them into `out` as JSON
+/// Lines of the form `{"path": ..., "file": ...}`.
+pub fn to_json_lines<W>(root: &Path, mut out: W, options: &Options) -> io::Result<Report>
+where
+    W: io::Write,
+{
+    let report = convert(root, options, false, |path, json| {
+        let path = serde_json::to_string(&display(path))?;
+        writeln!(out, "{{\"path\":{},\"file\":{}}}", path, json)
+    })?;
+    out.flush()?;
+    Ok(report)
+}
+
+/// Converts the files under `root` on worker threads, and passes the JSON
+/// of each file to `write` in the order of the paths.
+fn convert<F>(root: &Path, options: &Options, pretty: bool, mut write: F) -> io::Result<Report>
+where
+    F: FnMut(&Path, &str) -> io::Result<()>,
+{
+    let files = Arc::new(files(root, options)?);
+    let jobs = match options.jobs {
+        0 => thread::available_parallelism().map_or(1, usize::from),
+        jobs => jobs,
+    };
+    let next = Arc::new(AtomicUsize::new(0));
+    let (tx, rx) = mpsc::channel();
+    let workers: Vec<_> = (0..jobs.min(files.len()))
+        .map(|_| {
+            let (files, next, tx) = (files.clone(), next.clone(), tx.clone());
+            let root = root.to_owned();
+            let comments = options.comments;
+            thread::spawn(move || {
+                loop {
+                    let i = next.fetch_add(1, Ordering::Relaxed);
+                    let path = match files.get(i) {
+                        Some(path) => path,
+                        None => break,
+                    };
+                    let result = convert_file(&root.join(path), comments, pretty);
+                    if tx.send((i, result)).is_err() {
+                        break;
+                    }
+                }
+            })
+        })
+        .collect();
+    drop(tx);
+
+    let mut report = Report::default();
+    let mut pending = BTreeMap::new();
+    let mut result = Ok(());
+    for (i, converted) in rx {
+        pending.insert(i, converted);
+        // Results arrive in any order; they are written in the order of the paths.
+        while let Some(converted) = pending.remove(&(report.converted + report.failures.len())) {
+            let path = &files[report.converted + report.failures.len()];
+            match converted {
+                Ok(json) => {
+                    if let Err(e) = write(path, &json) {
+                        result = Err(e);
+                        break;
+                    }
+                    report.converted += 1;
+                }
+                Err((line_column, message)) => report.failures.push(Failure {
+                    path: display(path),
+                    line: line_column.map(|(line, _)| line),
+                    column: line_column.map(|(_, column)| column),
+                    message,
+                }),
+            }
+        }
+        if result.is_err() {
+            // Stop the workers.
+            next.store(files.len(), Ordering::Relaxed);
+            break;
+        }
+    }
+    for worker in workers {
+        // Panics during conversion are caught by `convert_file`.
+        worker.join().unwrap();
+    }
+    result.map(|()| report)
+}
+
+/// Parses and serializes a file. Errors are returned with the line and the
+/// (1-based) column of syntax errors.
+fn convert_file(
+    path: &Path,
+    comments: bool,
+    pretty: bool,
+) -> Result<String, (Option<(usize, usize)>, String)> {
+    let source = fs::read_to_string(path).map_err(|e| (None, e.to_string()))?;
+    let syn_file = syn::parse_file(&source).map_err(|e| {
+        let start = e.span().start();
+        (Some((start.line, start.column + 1)), e.to_string())
+    })?;
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        let file = if comments {
+            File::from_syn_with_comments(&syn_file, &source)
+        } else {
+            syn_file.to_adapter()
+        };
+        if pretty { serde_json::to_string_pretty(&file) } else { serde_json::to_string(&file) }
+    }))
+    .map_err(|_| (None, "panicked while converting the syntax tree".to_owned()))?
+    .map_err(|e| (None, e.to_string()))
+}
+
+/// Collects the `.rs` files in `dir` (relative to `root`) into `files`.
+/// `ignores` are the patterns of the `.gitignore` files of `dir` and its
+/// ancestors.
+fn walk(
+    root: &Path,
+    dir: &Path,
+    ignores: &mut Vec<Pattern>,
+    excludes: &[Pattern],
+    files: &mut Vec<PathBuf>,
+) -> io::Result<()> {
+    let full_dir = root.join(dir);
+    let len = ignores.len();
+    match fs::read_to_string(full_dir.join(".gitignore")) {
+        Ok(gitignore) => {
+            let base = display(dir);
+            ignores.extend(gitignore.lines().filter_map(|line| Pattern::parse(line, &base)));
+        }
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e),
+    }
+
+    let mut entries = fs::read_dir(&full_dir)?.collect::<io::Result<Vec<_>>>()?;
+    entries.sort_by_key(fs::DirEntry::file_name);
+    for entry in entries {
+        let path = dir.join(entry.file_name());
+        let is_dir = entry.file_type()?.is_dir();
+        if is_dir && entry.file_name() == ".git" {
+            continue;
+        }
+        let name = display(&path);
+        // Later patterns take precedence, and excludes take precedence over
+        // `.gitignore` files.
+        let ignored = ignores
+            .iter()
+            .chain(excludes)
+            .rev()
+            .find(|pattern| pattern.matches(&name, is_dir))
+            .map_or(false, |pattern| !pattern.negated);
+        if ignored {
+            continue;
+        }
+        if is_dir {
+            walk(root, &path, ignores, excludes, files)?;
+        } else if path.extension().map_or(false, |ext| ext == "rs") {
+            files.push(path);
+        }
+    }
+    ignores.truncate(len);
+    Ok(())
+}
+
+/// A pattern of a `.gitignore` file.
+struct Pattern {
+    /// The directory of the `.gitignore` file, relative to the root, with a
+    /// trailing `/` unless it is the root.
+    base: String,
+    glob: String,
+    /// Whether the pattern matches the whole path relative to `base`, rather
+    /// than the file name.
+    anchored: bool,
+    dir_only: bool,
+    negated: bool,
+}
+
+impl Pattern {
+    /// Parses a line of a `.gitignore` file in `base`. Returns `None` for
+    /// blank lines and comments.
+    fn parse(line: &str, base: &str) -> Option<Self> {
+        let mut line = line.trim_end();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let negated = line.starts_with('!');
+        if negated {
+            line = &line[1..];
+        }
+        let dir_only = line.ends_with('/');
+        if dir_only {
+            line = &line[..line.len() - 1];
+        }
+        let anchored = line.contains('/');
+        let glob = line.strip_prefix('/').unwrap_or(line);
+        if glob.is_empty() {
+            return None;
+        }
+        let base = if base.is_empty() { String::new() } else { format!("{}/", base) };
+        Some(Self { base, glob: glob.to_owned(), anchored, dir_only, negated })
+    }
+
+    /// Returns `true` if the pattern matches `path`, relative to the root.
+    fn matches(&self, path: &str, is_dir: bool) -> bool {
+        if self.dir_only && !is_dir {
+            return false;
+        }
+        let path = match path.strip_prefix(&self.base) {
+            Some(path) => path,
+            None => return false,
+        };
+        if self.anchored {
+            glob(self.glob.as_bytes(), path.as_bytes())
+        } else {
+            let name = path.rsplit('/').next().unwrap_or(path);
+            glob(self.glob.as_bytes(), name.as_bytes())
+        }
+    }
+}
+
+/// Matches `s` against a glob pattern with the wildcards of `.gitignore`.
+fn glob(p: &[u8], s: &[u8]) -> bool {
+    match p.first() {
+        None => s.is_empty(),
+        Some(b'*') if p.get(1) == Some(&b'*') => {
+            let rest = &p[2..];
+            match rest.first() {
+                // Trailing `**` matches everything.
+                None => true,
+                // `**/` matches zero or more directories.
+                Some(b'/') => {
+                    let rest = &rest[1..];
+                    glob(rest, s)
+                        || s.iter().enumerate().any(|(i, &c)| c == b'/' && glob(rest, &s[i + 1..]))
+                }
+                // Other `**` are regular `*`.
+                Some(_) => glob(&p[1..], s),
+            }
+        }
+        Some(b'*') => {
+            let rest = &p[1..];
+            let len = s.iter().position(|&c| c == b'/').unwrap_or(s.len());
+            (0..=len).any(|i| glob(rest, &s[i..]))
+        }
+        Some(b'?') => matches!(s.first(), Some(&c) if c != b'/') && glob(&p[1..], &s[1..]),
+        Some(b'[') => {
+            let c = match s.first() {
+                Some(&c) if c != b'/' => c,
+                _ => return false,
+            };
+            match class(&p[1..], c) {
+                Some((true, rest)) => glob(rest, &s[1..]),
+                Some((false, _)) => false,
+                // An unclosed `[` is a literal.
+                None => s.first() == Some(&b'[') && glob(&p[1..], &s[1..]),
+            }
+        }
+        Some(b'\\') if p.len() > 1 => s.first() == Some(&p[1]) && glob(&p[2..], &s[1..]),
+        Some(&c) => s.first() == Some(&c) && glob(&p[1..], &s[1..]),
+    }
+}
+
+/// Matches `c` against a character class whose `[` has been consumed, and
+/// returns whether it matched along with the rest of the pattern, or `None`
+/// if the class is not closed.
+fn class(p: &[u8], c: u8) -> Option<(bool, &[u8])> {
+    let (negated, mut p) = match p.first() {
+        Some(b'!' | b'^') => (true, &p[1..]),
+        _ => (false, p),
+    };
+    let mut matched = false;
+    let mut first = true;
+    loop {
+        match p {
+            [] => return None,
+            [b']', rest @ ..] if !first => return Some((matched != negated, rest)),
+            [lo, b'-', hi, rest @ ..] if *hi != b']' => {
+                matched |= (*lo..=*hi).contains(&c);
+                p = rest;
+            }
+            [x, rest @ ..] => {
+                matched |= *x == c;
+                p = rest;
+            }
+        }
+        first = false;
+    }
+}
+
+/// Formats a relative path with `/` as the separator on all platforms.
+fn display(path: &Path) -> String {
+    let components: Vec<_> = path.components().map(|c| c.as_os_str().to_string_lossy()).collect();
+    components.join("/")
+}
@@ -0,0 +1,381 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Conditional compilation.
+//!
+//! A [`Cfg`] is a set of enabled configuration options, such as `unix` or
+//! `feature = "json"`, as passed to rustc with `--cfg`.
+//! [`strip`](Cfg::strip) rewrites a syntax tree as it is compiled with these
+//! options:
+//!
+//! - `#[cfg_attr(predicate, attr1, attr2)]` is replaced with `#[attr1]` and
+//!   `#[attr2]` if the predicate holds, and removed otherwise.
+//! - Nodes with a `#[cfg(predicate)]` that does not hold are removed: items,
+//!   fields, variants, statements, match arms, elements of arrays, tuples,
+//!   and calls, and any other node in a list. `#[cfg]` attributes of the
+//!   remaining nodes are removed.
+//! - If a `#![cfg]` of the file does not hold, all of its items are removed.
+//!
+//! Predicates are evaluated as rustc does: `name` and `name = "value"` hold if
+//! they are enabled, and `all(..)`, `any(..)`, and `not(..)` combine other
+//! predicates. `true` and `false` are also accepted.
+//!
+//! # Examples
+//!
+//! ```
+//! use quote::ToTokens as _;
+//! use syn_serde::cfg::Cfg;
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     #[cfg(unix)]
+//!     fn unix() {}
+//!     #[cfg(not(unix))]
+//!     fn other() {}
+//!     #[cfg_attr(feature = "serde", derive(Serialize))]
+//!     struct S;
+//! };
+//!
+//! let mut cfg = Cfg::new();
+//! cfg.enable("unix").unwrap();
+//! cfg.enable("feature = \"serde\"").unwrap();
+//! let syn_file = cfg.strip_syn(&syn_file).unwrap();
+//!
+//! let expected: syn::File = syn::parse_quote! {
+//!     fn unix() {}
+//!     #[derive(Serialize)]
+//!     struct S;
+//! };
+//! assert_eq!(syn_file.into_token_stream().to_string(), expected.into_token_stream().to_string());
+//! ```
+
+use std::collections::BTreeSet;
+
+use serde::{Serialize, de::DeserializeOwned};
+use syn::{
+    Token,
+    ext::IdentExt as _,
+    parse::{ParseStream, Parser as _},
+    punctuated::Punctuated,
+};
+
+use crate::{
+    Attribute, Error, Syn, default,
+    node::{self, Value},
+    span,
+};
+
+/// A set of enabled configuration options.
+///
+/// See the [module level documentation](self) for more.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Cfg {
+    /// The enabled options: names, or names with values.
+    options: BTreeSet<(String, Option<String>)>,
+}
+
+impl Cfg {
+    /// Creates a set without enabled options.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables an option written in the syntax of `--cfg` of rustc: `name` or
+    /// `name = "value"`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `option` is not in either form.
+    pub fn enable(&mut self, option: &str) -> Result<(), Error> {
+        let invalid = || Error::new(format!("invalid cfg option `{}`", option));
+        let meta: syn::Meta = syn::parse_str(option).map_err(|_| invalid())?;
+        let option = match &meta {
+            syn::Meta::Path(path) => (ident(path).ok_or_else(invalid)?, None),
+            syn::Meta::NameValue(meta) => {
+                (ident(&meta.path).ok_or_else(invalid)?, Some(str_value(&meta.value, invalid)?))
+            }
+            syn::Meta::List(_) => return Err(invalid()),
+        };
+        self.options.insert(option);
+        Ok(())
+    }
+
+    /// Evaluates a configuration predicate, e.g., the content of `#[cfg(..)]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `predicate` is malformed.
+    pub fn eval(&self, predicate: &syn::Meta) -> Result<bool, Error> {
+        match predicate {
+            syn::Meta::Path(path) => match ident(path).as_deref() {
+                Some("true") => Ok(true),
+                Some("false") => Ok(false),
+                Some(name) => Ok(self.options.contains(&(name.to_owned(), None))),
+                None => Err(Error::new("cfg predicate key must be an identifier")),
+            },
+            syn::Meta::NameValue(meta) => {
+                let name = ident(&meta.path)
+                    .ok_or_else(|| Error::new("cfg predicate key must be an identifier"))?;
+                let value =
+                    str_value(&meta.value, || Error::new("cfg predicate value must be a string"))?;
+                Ok(self.options.contains(&(name, Some(value))))
+            }
+            syn::Meta::List(list) => {
+                let predicates = list
+                    .parse_args_with(|input: ParseStream<'_>| {
+                        Punctuated::<_, Token![,]>::parse_terminated_with(input, parse_predicate)
+                    })
+                    .map_err(|e| Error::new(format!("invalid cfg predicate: {}", e)))?;
+                match ident(&list.path).as_deref() {
+                    Some("all") => {
+                        for predicate in &predicates {
+                            if !self.eval(predicate)? {
+                                return Ok(false);
+                            }
+                        }
+                        Ok(true)
+                    }
+                    Some("any") => {
+                        for predicate in &predicates {
+                            if self.eval(predicate)? {
+                                return Ok(true);
+                            }
+                        }
+                        Ok(false)
+                    }
+                    Some("not") if predicates.len() == 1 => Ok(!self.eval(&predicates[0])?),
+                    Some("not") => Err(Error::new("expected 1 cfg predicate in `not(..)`")),
+                    _ => {
+                        let path = &list.path;
+                        let path = quote::quote!(#path).to_string().replace(' ', "");
+                        Err(Error::new(format!("invalid cfg predicate `{}`", path)))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Removes the nodes of the given adapter that are disabled by `#[cfg]`,
+    /// and expands `#[cfg_attr]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a predicate is malformed, or if a disabled node is
+    /// not in a list, e.g., the condition of an `if` expression.
+    pub fn strip<T>(&self, adapter: &T) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let mut root = node::to_value(adapter);
+        if !self.configure(&mut root)? {
+            match root
+                .fields_mut()
+                .and_then(|map| map.fields.iter_mut().find(|(k, _)| k == "items"))
+            {
+                Some((_, items)) => *items = Value::Seq(vec![]),
+                None => return Err(Error::new("the root node is disabled by `#[cfg]`")),
+            }
+        }
+        self.strip_value(&mut root)?;
+        node::from_value(root)
+            .map_err(|e| Error::new(format!("configured syntax tree is invalid: {}", e)))
+    }
+
+    /// Removes the nodes of the given [`Syn`] type that are disabled by
+    /// `#[cfg]`, and expands `#[cfg_attr]`.
+    ///
+    /// This function is equivalent to the following code:
+    ///
+    /// ```
+    /// # fn strip_syn(cfg: &syn_serde::cfg::Cfg, syn_file: &syn::File) -> Result<syn::File, syn_serde::Error> {
+    /// use syn_serde::Syn;
+    ///
+    /// let adapter = syn_file.to_adapter();
+    /// let adapter = cfg.strip(&adapter)?;
+    /// Ok(syn::File::from_adapter(&adapter))
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`strip`](Self::strip).
+    pub fn strip_syn<S>(&self, syn: &S) -> Result<S, Error>
+    where
+        S: Syn,
+    {
+        let adapter = self.strip(&syn.to_adapter())?;
+        Ok(S::from_adapter(&adapter))
+    }
+
+    /// Configures the descendants of `value`.
+    fn strip_value(&self, value: &mut Value) -> Result<(), Error> {
+        let mut stack = vec![value];
+        while let Some(value) = stack.pop() {
+            match value {
+                Value::Seq(elems) => {
+                    let mut i = 0;
+                    while i < elems.len() {
+                        if self.configure(&mut elems[i])? {
+                            i += 1;
+                        } else {
+                            elems.remove(i);
+                        }
+                    }
+                    stack.extend(elems);
+                }
+                Value::Map(map) => {
+                    for (_, value) in &mut map.fields {
+                        if !matches!(value, Value::Seq(_)) && !self.configure(value)? {
+                            return Err(Error::new(
+                                "removing a node with `#[cfg]` is not supported in this position",
+                            ));
+                        }
+                        stack.push(value);
+                    }
+                }
+                Value::Variant(variant) => stack.extend(variant.value.as_deref_mut()),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// Expands the `#[cfg_attr]` of a node, and removes its `#[cfg]`. Returns
+    /// `false` if the node is disabled.
+    fn configure(&self, node: &mut Value) -> Result<bool, Error> {
+        let attrs = match attrs_mut(node) {
+            Some(attrs) => attrs,
+            None => return Ok(true),
+        };
+        let mut enabled = true;
+        let mut i = 0;
+        while i < attrs.len() {
+            let attr: Attribute = node::from_value(attrs[i].clone())
+                .map_err(|e| Error::new(format!("invalid attribute: {}", e)))?;
+            let attr = syn::Attribute::from(&attr);
+            if attr.path().is_ident("cfg_attr") {
+                let expanded = self.expand_cfg_attr(&attr)?;
+                let span = path_span(&attrs[i]);
+                let expanded: Vec<_> = expanded
+                    .iter()
+                    .map(|attr| {
+                        let mut value = node::to_value(&Attribute::from(attr));
+                        span::set_spans(&mut value, span.as_ref());
+                        value
+                    })
+                    .collect();
+                // The expanded attributes may also be `#[cfg]` or `#[cfg_attr]`.
+                attrs.splice(i..=i, expanded);
+            } else if attr.path().is_ident("cfg") {
+                let predicate = match &attr.meta {
+                    syn::Meta::List(list) => list
+                        .parse_args_with(parse_predicate)
+                        .map_err(|e| Error::new(format!("invalid cfg predicate: {}", e)))?,
+                    _ => {
+                        return Err(Error::new("malformed `cfg` attribute: expected `#[cfg(..)]`"));
+                    }
+                };
+                enabled &= self.eval(&predicate)?;
+                attrs.remove(i);
+            } else {
+                i += 1;
+            }
+        }
+        Ok(enabled)
+    }
+
+    /// Returns the attributes that a `#[cfg_attr]` expands to.
+    fn expand_cfg_attr(&self, attr: &syn::Attribute) -> Result<Vec<syn::Attribute>, Error> {
+        let malformed = || {
+            Error::new(
+                "malformed `cfg_attr` attribute: expected `#[cfg_attr(predicate, attr, ..)]`",
+            )
+        };
+        let list = match &attr.meta {
+            syn::Meta::List(list) => list,
+            _ => return Err(malformed()),
+        };
+        let (predicate, metas) = (|input: ParseStream<'_>| {
+            let predicate = parse_predicate(input)?;
+            if input.is_empty() {
+                return Ok((predicate, Punctuated::new()));
+            }
+            input.parse::<Token![,]>()?;
+            Ok((predicate, Punctuated::<syn::Meta, Token![,]>::parse_terminated(input)?))
+        })
+        .parse2(list.tokens.clone())
+        .map_err(|_| malformed())?;
+        if !self.eval(&predicate)? {
+            return Ok(vec![]);
+        }
+        let inner = matches!(attr.style, syn::AttrStyle::Inner(_));
+        Ok(metas
+            .into_iter()
+            .map(|meta| syn::Attribute {
+                pound_token: default(),
+                style: if inner { syn::AttrStyle::Inner(default()) } else { syn::AttrStyle::Outer },
+                bracket_token: default(),
+                meta,
+            })
+            .collect())
+    }
+}
+
+/// Returns the attributes of a node. The attributes of an expression
+/// statement are the attributes of the expression.
+fn attrs_mut(node: &mut Value) -> Option<&mut Vec<Value>> {
+    let map = match node {
+        Value::Variant(variant) => {
+            let is_stmt_expr = variant.name == "expr";
+            let value = variant.value.as_deref_mut()?;
+            if is_stmt_expr && value.as_map().map_or(false, |map| map.get("expr").is_some()) {
+                let map = value.fields_mut()?;
+                let expr = &mut map.fields.iter_mut().find(|(k, _)| k == "expr")?.1;
+                return attrs_mut(expr);
+            }
+            return attrs_mut(value);
+        }
+        Value::Map(map) => map,
+        _ => return None,
+    };
+    match map.fields.iter_mut().find(|(k, _)| k == "attrs") {
+        Some((_, Value::Seq(attrs))) => Some(attrs),
+        _ => None,
+    }
+}
+
+/// Parses a configuration predicate. Unlike [`syn::Meta`], this accepts the
+/// `true` and `false` keywords.
+fn parse_predicate(input: ParseStream<'_>) -> syn::Result<syn::Meta> {
+    if input.peek(syn::LitBool) {
+        let ident = syn::Ident::parse_any(input)?;
+        return Ok(syn::Meta::Path(ident.into()));
+    }
+    input.parse()
+}
+
+/// Returns the span of the path of an attribute.
+fn path_span(attr: &Value) -> Option<Value> {
+    attr.as_map()?.get("meta")?.fields()?.get("path")?.as_map()?.get("span").cloned()
+}
+
+/// Returns the name of a single-segment path.
+fn ident(path: &syn::Path) -> Option<String> {
+    if path.leading_colon.is_some() || path.segments.len() != 1 {
+        return None;
+    }
+    let segment = &path.segments[0];
+    if !segment.arguments.is_none() {
+        return None;
+    }
+    Some(segment.ident.unraw().to_string())
+}
+
+fn str_value<F>(expr: &syn::Expr, error: F) -> Result<String, Error>
+where
+    F: FnOnce() -> Error,
+{
+    match expr {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }) => Ok(lit.value()),
+        _ => Err(error()),
+    }
+}
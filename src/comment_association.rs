@@ -11,12 +11,13 @@ use std::collections::HashMap;
 /// Associates comments with AST nodes based on their position.
 /// 
 /// This function takes a list of comments and a list of AST node spans
-/// and returns a mapping of AST node identifiers to their associated comments.
+/// and returns a mapping of AST node ids (see [`crate::ids`]) to their
+/// associated comments.
 pub(crate) fn associate_comments_with_nodes(
     comments: &[Comment],
-    node_spans: &[(String, SpanInfo)],
-) -> HashMap<String, Vec<Comment>> {
-    let mut associations: HashMap<String, Vec<Comment>> = HashMap::new();
+    node_spans: &[(usize, SpanInfo)],
+) -> HashMap<usize, Vec<Comment>> {
+    let mut associations: HashMap<usize, Vec<Comment>> = HashMap::new();
     
     for comment in comments {
         let best_node = find_best_node_for_comment(comment, node_spans);
@@ -35,7 +36,7 @@ pub(crate) fn associate_comments_with_nodes(
 /// 2. If the comment is immediately before a node (line before), associate it with that node
 /// 3. If the comment is inside a block or immediately after a node, associate it with that node
 /// 4. Otherwise, find the nearest enclosing node
-fn find_best_node_for_comment(comment: &Comment, node_spans: &[(String, SpanInfo)]) -> Option<String> {
+fn find_best_node_for_comment(comment: &Comment, node_spans: &[(usize, SpanInfo)]) -> Option<usize> {
     let comment_line = comment.span.start_line;
     let comment_column = comment.span.start_column;
     
@@ -45,14 +46,14 @@ fn find_best_node_for_comment(comment: &Comment, node_spans: &[(String, SpanInfo
             // Comment is on the same line as the node start
             // Only associate if comment is after the node start (not before)
             if comment_column >= node_span.start_column {
-                return Some(node_id.clone());
+                return Some(*node_id);
             }
         }
         if comment_line == node_span.end_line {
             // Comment is on the same line as the node end
             // Only associate if comment is before the node end (inside the node)
             if comment_column < node_span.end_column {
-                return Some(node_id.clone());
+                return Some(*node_id);
             }
         }
     }
@@ -61,14 +62,14 @@ fn find_best_node_for_comment(comment: &Comment, node_spans: &[(String, SpanInfo
     for (node_id, node_span) in node_spans {
         if comment_line + 1 == node_span.start_line {
             // Comment is immediately before this node
-            return Some(node_id.clone());
+            return Some(*node_id);
         }
     }
     
     // Third pass: look for nodes that contain the comment
     for (node_id, node_span) in node_spans {
         if is_comment_inside_node(comment, node_span) {
-            return Some(node_id.clone());
+            return Some(*node_id);
         }
     }
     
@@ -100,17 +101,17 @@ fn is_comment_inside_node(comment: &Comment, node_span: &SpanInfo) -> bool {
 }
 
 /// Find the nearest enclosing node for a comment.
-fn find_nearest_enclosing_node(comment: &Comment, node_spans: &[(String, SpanInfo)]) -> Option<String> {
-    let mut best_node: Option<String> = None;
+fn find_nearest_enclosing_node(comment: &Comment, node_spans: &[(usize, SpanInfo)]) -> Option<usize> {
+    let mut best_node: Option<usize> = None;
     let mut best_span_size = usize::MAX;
     
     for (node_id, node_span) in node_spans {
         if is_comment_inside_node(comment, node_span) {
-            let span_size = ((node_span.end_line - node_span.start_line) as usize) * 1000 + 
-                           ((node_span.end_column - node_span.start_column) as usize);
+            let span_size = (node_span.end_line - node_span.start_line) * 1000
+                + (node_span.end_column - node_span.start_column);
             if span_size < best_span_size {
                 best_span_size = span_size;
-                best_node = Some(node_id.clone());
+                best_node = Some(*node_id);
             }
         }
     }
@@ -123,6 +124,9 @@ mod tests {
     use super::*;
     use crate::{CommentKind, SpanInfo};
 
+    const FN_FOO: usize = 1;
+    const BLOCK_BODY: usize = 2;
+
     #[test]
     fn test_comment_on_same_line_as_node() {
         let comment = Comment {
@@ -134,26 +138,28 @@ mod tests {
                 start_column: 9,
                 end_line: 2,
                 end_column: 18,
+                file: None,
             },
             kind: CommentKind::Line,
         };
         
         let node_spans = vec![
-            ("fn_foo".to_string(), SpanInfo {
+            (FN_FOO, SpanInfo {
                 start_offset: 0,
                 end_offset: 0,
                 start_line: 2,
                 start_column: 3,
                 end_line: 2,
                 end_column: 6,
+                file: None,
             }),
         ];
         
         let associations = associate_comments_with_nodes(&[comment], &node_spans);
         assert_eq!(associations.len(), 1);
-        assert!(associations.contains_key("fn_foo"));
-        assert_eq!(associations["fn_foo"].len(), 1);
-        assert_eq!(associations["fn_foo"][0].text, "Line 2");
+        assert!(associations.contains_key(&FN_FOO));
+        assert_eq!(associations[&FN_FOO].len(), 1);
+        assert_eq!(associations[&FN_FOO][0].text, "Line 2");
     }
     
     #[test]
@@ -167,26 +173,28 @@ mod tests {
                 start_column: 0,
                 end_line: 1,
                 end_column: 14,
+                file: None,
             },
             kind: CommentKind::Line,
         };
         
         let node_spans = vec![
-            ("fn_foo".to_string(), SpanInfo {
+            (FN_FOO, SpanInfo {
                 start_offset: 0,
                 end_offset: 0,
                 start_line: 2,
                 start_column: 3,
                 end_line: 2,
                 end_column: 6,
+                file: None,
             }),
         ];
         
         let associations = associate_comments_with_nodes(&[comment], &node_spans);
         assert_eq!(associations.len(), 1);
-        assert!(associations.contains_key("fn_foo"));
-        assert_eq!(associations["fn_foo"].len(), 1);
-        assert_eq!(associations["fn_foo"][0].text, "white space");
+        assert!(associations.contains_key(&FN_FOO));
+        assert_eq!(associations[&FN_FOO].len(), 1);
+        assert_eq!(associations[&FN_FOO][0].text, "white space");
     }
     
     #[test]
@@ -200,26 +208,28 @@ mod tests {
                 start_column: 11,
                 end_line: 4,
                 end_column: 31,
+                file: None,
             },
             kind: CommentKind::Line,
         };
         
         let node_spans = vec![
-            ("block_body".to_string(), SpanInfo {
+            (BLOCK_BODY, SpanInfo {
                 start_offset: 0,
                 end_offset: 0,
                 start_line: 4,
                 start_column: 9,
                 end_line: 10,
                 end_column: 10,
+                file: None,
             }),
         ];
         
         let associations = associate_comments_with_nodes(&[comment], &node_spans);
         assert_eq!(associations.len(), 1);
-        assert!(associations.contains_key("block_body"));
-        assert_eq!(associations["block_body"].len(), 1);
-        assert_eq!(associations["block_body"][0].text, "Line 4, Column 10");
+        assert!(associations.contains_key(&BLOCK_BODY));
+        assert_eq!(associations[&BLOCK_BODY].len(), 1);
+        assert_eq!(associations[&BLOCK_BODY][0].text, "Line 4, Column 10");
     }
     
     #[test]
@@ -233,26 +243,29 @@ mod tests {
                 start_column: 11, // Comment starts after the closing brace
                 end_line: 10,
                 end_column: 50,
+                file: None,
             },
             kind: CommentKind::Line,
         };
         
         let node_spans = vec![
-            ("fn_foo".to_string(), SpanInfo {
+            (FN_FOO, SpanInfo {
                 start_offset: 0,
                 end_offset: 0,
                 start_line: 2,
                 start_column: 3,
                 end_line: 2,
                 end_column: 6,
+                file: None,
             }),
-            ("block_body".to_string(), SpanInfo {
+            (BLOCK_BODY, SpanInfo {
                 start_offset: 0,
                 end_offset: 0,
                 start_line: 4,
                 start_column: 9,
                 end_line: 10,
                 end_column: 10, // Block ends at column 10
+                file: None,
             }),
         ];
         
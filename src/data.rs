@@ -37,18 +37,20 @@ pub(crate) fn assert_struct_semi(fields: &Fields, semi_token: bool) {
 ast_struct! {
     /// An adapter for [`struct@syn::Field`].
     pub struct Field {
-        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) attrs: Vec<Attribute>,
-        #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) vis: Visibility,
         #[serde(rename = "mut")]
-        #[serde(default, skip_serializing_if = "FieldMutability::is_none")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) mutability: FieldMutability,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) ident: Option<Ident>,
         // TODO: can remove?
-        #[serde(default, skip_serializing_if = "not")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) colon_token: bool,
         pub(crate) ty: Type,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub(crate) trivia: Vec<crate::trivia::TokenTrivia>,
     }
 }
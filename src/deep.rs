@@ -0,0 +1,183 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Deserialization of arbitrarily deeply nested syntax trees.
+//!
+//! serde_json, the `Deserialize` implementations of the adapters, and the
+//! conversions into syn types are all recursive, so deeply nested expressions
+//! (e.g., a sum of 100000 terms) overflow the stack. This module avoids the
+//! recursion on expressions:
+//!
+//! 1. The JSON text is parsed into a [`Value`] by serde_json, growing the
+//!    stack as needed with serde_stacker.
+//! 2. The tree is deserialized in chunks: every expression below the root of a
+//!    chunk is replaced with a placeholder, and becomes a chunk of its own.
+//! 3. Each chunk, which is shallow, is converted into a syn type.
+//! 4. The chunks are put back together from the innermost one, by replacing
+//!    the placeholders.
+
+use core::{cell::RefCell, mem};
+
+use proc_macro2::TokenTree;
+use serde::de::{self, Deserialize as _, DeserializeOwned};
+use syn::visit_mut::{self, VisitMut};
+
+use crate::{
+    Syn as _,
+    node::{self, Map, Value, Variant},
+    options::{self, Options},
+};
+
+const PLACEHOLDER_PREFIX: &str = "__syn_serde_chunk_";
+
+pub(crate) fn from_slice(v: &[u8]) -> serde_json::Result<syn::File> {
+    let root = parse(v)?;
+    // Enums can also be in the tagged form.
+    let _enable = options::Enable::add(Options::new().tagged(true));
+    let splitter = Splitter { chunks: RefCell::new(vec![]) };
+    let file: crate::File = deserialize(root, &splitter)?;
+    let mut file = syn::File::from_adapter(&file);
+
+    let mut exprs = vec![];
+    loop {
+        let chunk = match splitter.chunks.borrow_mut().get_mut(exprs.len()) {
+            Some(chunk) => mem::replace(chunk, Value::Null),
+            None => break,
+        };
+        let expr: crate::Expr = deserialize(chunk, &splitter)?;
+        exprs.push(Some(syn::Expr::from_adapter(&expr)));
+    }
+
+    // A chunk only contains placeholders for chunks that come after it.
+    let mut stitcher = Stitcher { exprs };
+    for i in (0..stitcher.exprs.len()).rev() {
+        let mut expr = stitcher.exprs[i].take().unwrap();
+        visit_mut::visit_expr_mut(&mut stitcher, &mut expr);
+        stitcher.exprs[i] = Some(expr);
+    }
+    stitcher.visit_file_mut(&mut file);
+    Ok(file)
+}
+
+fn deserialize<T>(value: Value, splitter: &Splitter) -> serde_json::Result<T>
+where
+    T: DeserializeOwned,
+{
+    T::deserialize(node::Deserializer::new(value, Some(splitter)))
+        .map_err(<serde_json::Error as de::Error>::custom)
+}
+
+// -----------------------------------------------------------------------------
+// Splitting
+
+struct Splitter {
+    chunks: RefCell<Vec<Value>>,
+}
+
+impl node::Split for Splitter {
+    fn split(&self, name: &'static str, value: Value) -> Result<Value, Value> {
+        if name != "Expr" {
+            return Err(value);
+        }
+        let mut chunks = self.chunks.borrow_mut();
+        let placeholder = placeholder(chunks.len(), requires_terminator(&value));
+        chunks.push(value);
+        Ok(placeholder)
+    }
+}
+
+impl Drop for Splitter {
+    fn drop(&mut self) {
+        self.chunks.get_mut().drain(..).for_each(node::drop_deep);
+    }
+}
+
+/// Returns the placeholder for the chunk at `index`.
+///
+/// Whether a match arm needs a comma depends on its body, so the placeholder
+/// of an expression that does not require a terminator is a block:
+/// `{ __syn_serde_chunk_N }`. Otherwise, it is a verbatim expression:
+/// `__syn_serde_chunk_N`.
+fn placeholder(index: usize, requires_terminator: bool) -> Value {
+    fn tagged(name: &str, value: Value) -> Value {
+        Value::Map(Map { ty: None, fields: vec![(name.to_owned(), value)] })
+    }
+    let marker = tagged("ident", Value::Str(format!("{}{}", PLACEHOLDER_PREFIX, index)));
+    let tokens = Value::Seq(vec![marker]);
+    if requires_terminator {
+        tagged("verbatim", tokens)
+    } else {
+        let stmts = Value::Seq(vec![tagged("item", tagged("verbatim", tokens))]);
+        let block = Value::Map(Map { ty: None, fields: vec![("stmts".to_owned(), stmts)] });
+        tagged("block", Value::Map(Map { ty: None, fields: vec![("stmts".to_owned(), block)] }))
+    }
+}
+
+/// Same as `requires_terminator` in `src/stmt.rs`, but on the serialized
+/// expression.
+fn requires_terminator(value: &Value) -> bool {
+    let kind = match value {
+        Value::Map(map) if map.fields.len() == 1 => &*map.fields[0].0,
+        Value::Variant(Variant { name, .. }) => name,
+        Value::Str(name) => name,
+        _ => return true,
+    };
+    !matches!(
+        kind,
+        "if" | "match" | "block" | "unsafe" | "while" | "loop" | "for_loop" | "try_block" | "const"
+    )
+}
+
+// -----------------------------------------------------------------------------
+// Stitching
+
+struct Stitcher {
+    exprs: Vec<Option<syn::Expr>>,
+}
+
+impl VisitMut for Stitcher {
+    fn visit_expr_mut(&mut self, expr: &mut syn::Expr) {
+        // Chunks are already complete, so they are not visited again.
+        match chunk_index(expr).and_then(|i| self.exprs.get_mut(i)).and_then(Option::take) {
+            Some(chunk) => *expr = chunk,
+            None => visit_mut::visit_expr_mut(self, expr),
+        }
+    }
+}
+
+fn chunk_index(expr: &syn::Expr) -> Option<usize> {
+    let tokens = match expr {
+        syn::Expr::Verbatim(tokens) => tokens,
+        syn::Expr::Block(expr) if expr.label.is_none() && expr.block.stmts.len() == 1 => {
+            match &expr.block.stmts[0] {
+                syn::Stmt::Item(syn::Item::Verbatim(tokens)) => tokens,
+                _ => return None,
+            }
+        }
+        _ => return None,
+    };
+    let mut iter = tokens.clone().into_iter();
+    match (iter.next(), iter.next()) {
+        (Some(TokenTree::Ident(ident)), None) => {
+            ident.to_string().strip_prefix(PLACEHOLDER_PREFIX)?.parse().ok()
+        }
+        _ => None,
+    }
+}
+
+// -----------------------------------------------------------------------------
+// JSON parser
+
+/// Parses JSON text into a [`Value`].
+///
+/// serde_json's recursion limit is disabled, and serde_stacker grows the
+/// stack as needed while the nested arrays and objects are visited.
+fn parse(input: &[u8]) -> serde_json::Result<Value> {
+    let mut de = serde_json::Deserializer::from_slice(input);
+    de.disable_recursion_limit();
+    let value = Value::deserialize(serde_stacker::Deserializer::new(&mut de))?;
+    if let Err(e) = de.end() {
+        node::drop_deep(value);
+        return Err(e);
+    }
+    Ok(value)
+}
@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Doc comments as Markdown strings.
+//!
+//! Doc comments are serialized as the attributes that they desugar to, one
+//! `#[doc = "..."]` attribute per line. [`WithDocs`] serializes the doc
+//! comments of each node (items, fields, variants, impl items, etc.) as a
+//! single `"docs"` field that contains the decoded Markdown text and the span
+//! of the comments:
+//!
+//! ```json
+//! {
+//!   "struct": {
+//!     "attrs": [{ "style": "outer", "meta": { "list": { "path": "derive", ... } } }],
+//!     "ident": "S",
+//!     "docs": { "text": "Line 1.\n\nLine 2.", "span": { "start_line": 1, ... } }
+//!   }
+//! }
+//! ```
+//!
+//! The consecutive `#[doc]` attributes at the start of the outer attributes of
+//! a node are collected into `"docs"`, and those at the start of the inner
+//! attributes (`//!`) into `"inner_docs"`. The space after `///` and `//!` is
+//! removed from each line, as rustdoc does.
+//!
+//! Doc attributes that cannot be restored exactly from the text stay in
+//! `"attrs"`: block doc comments that span multiple lines, lines without a
+//! space after `///`, and `#[doc]` attributes that are not written as plain
+//! string literals.
+//!
+//! [`WithDocs`] can be deserialized from trees with or without `"docs"`
+//! fields, which are turned back into doc attributes, and converted to
+//! adapters and syn types with [`to_adapter`](WithDocs::to_adapter) and
+//! [`to_syn`](WithDocs::to_syn).
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn dox() {
+//! use syn_serde::docs::WithDocs;
+//!
+//! let syn_file: syn::File = syn::parse_str(
+//!     "
+//!     /// Line 1.
+//!     ///
+//!     /// Line 2.
+//!     #[derive(Debug)]
+//!     struct S;
+//!     ",
+//! )
+//! .unwrap();
+//! let tree = WithDocs::from_syn(&syn_file);
+//!
+//! let json = serde_json::to_value(&tree).unwrap();
+//! let docs = &json["items"][0]["struct"]["docs"];
+//! assert_eq!(docs["text"], "Line 1.\n\nLine 2.");
+//! assert_eq!(docs["span"]["start_line"], 2);
+//! assert_eq!(docs["span"]["end_line"], 4);
+//!
+//! let tree: WithDocs = serde_json::from_value(json).unwrap();
+//! let syn_file2: syn::File = tree.to_syn().unwrap();
+//! assert_eq!(syn_file, syn_file2);
+//! # }
+//! ```
+
+use serde::de::{Deserialize, Deserializer};
+
+use crate::{
+    Attribute, SpanInfo,
+    node::{self, Map, Value},
+    span,
+};
+
+value_tree! {
+    /// A syntax tree that is serialized with doc comments as Markdown strings.
+    ///
+    /// See the [module level documentation](self) for more.
+    pub struct WithDocs;
+    new: "Collects the doc comments of",
+    transform: |_, value| collect_docs(value),
+}
+
+impl<'de> Deserialize<'de> for WithDocs {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut value = Value::deserialize(deserializer)?;
+        restore_docs(&mut value);
+        Ok(Self { value })
+    }
+}
+
+/// Moves the doc attributes of the nodes in `value` into `"docs"` and
+/// `"inner_docs"` fields.
+fn collect_docs(value: &mut Value) {
+    let mut stack = vec![value];
+    while let Some(value) = stack.pop() {
+        match value {
+            Value::Map(map) => {
+                let i =
+                    map.fields.iter().position(|(k, v)| k == "attrs" && matches!(v, Value::Seq(_)));
+                if let Some(i) = i {
+                    let attrs = match &mut map.fields[i].1 {
+                        Value::Seq(attrs) => attrs,
+                        _ => unreachable!(),
+                    };
+                    let outer = take_docs(attrs, false);
+                    let inner = take_docs(attrs, true);
+                    if attrs.is_empty() {
+                        map.fields.remove(i);
+                    }
+                    map.fields.extend(outer.map(|docs| ("docs".to_owned(), docs)));
+                    map.fields.extend(inner.map(|docs| ("inner_docs".to_owned(), docs)));
+                }
+                stack.extend(map.fields.iter_mut().map(|(_, v)| v));
+            }
+            Value::Seq(elems) => stack.extend(elems),
+            Value::Variant(variant) => stack.extend(variant.value.as_deref_mut()),
+            _ => {}
+        }
+    }
+}
+
+/// Removes the consecutive doc attributes at the start of the outer or inner
+/// attributes in `attrs`, and returns them as a `{"text", "span"}` map.
+fn take_docs(attrs: &mut Vec<Value>, inner: bool) -> Option<Value> {
+    let start = if inner { attrs.iter().position(is_inner)? } else { 0 };
+    let mut lines = vec![];
+    while let Some(line) = attrs.get(start + lines.len()).and_then(|attr| doc_line(attr, inner)) {
+        lines.push(line);
+    }
+    // Lines that are empty or only a space would both be empty in the text.
+    if lines.is_empty()
+        || !lines.iter().all(|line| line.is_empty() || line.len() > 1 && line.starts_with(' '))
+    {
+        return None;
+    }
+    let text: Vec<_> = lines.iter().map(|line| line.get(1..).unwrap_or_default()).collect();
+    let mut fields = vec![("text".to_owned(), Value::Str(text.join("\n")))];
+    let end = start + lines.len();
+    if let (Some(first), Some(last)) = (path_span(&attrs[start]), path_span(&attrs[end - 1])) {
+        let span = SpanInfo {
+            end_offset: last.end_offset,
+            end_line: last.end_line,
+            end_column: last.end_column,
+            ..first
+        };
+        fields.push(("span".to_owned(), node::to_value(&span)));
+    }
+    attrs.drain(start..end);
+    Some(Value::Map(Map { ty: None, fields }))
+}
+
+/// Moves the `"docs"` and `"inner_docs"` fields of the nodes in `value` back
+/// into their attributes.
+fn restore_docs(value: &mut Value) {
+    let mut stack = vec![value];
+    while let Some(value) = stack.pop() {
+        match value {
+            Value::Map(map) => {
+                for (key, inner) in [("docs", false), ("inner_docs", true)] {
+                    let i = map.fields.iter().position(|(k, v)| {
+                        k == key && v.as_map().and_then(|docs| docs.get("text")).is_some()
+                    });
+                    if let Some(i) = i {
+                        let docs = map.fields.remove(i).1;
+                        insert_docs(map, &docs, inner);
+                    }
+                }
+                stack.extend(map.fields.iter_mut().map(|(_, v)| v));
+            }
+            Value::Seq(elems) => stack.extend(elems),
+            Value::Variant(variant) => stack.extend(variant.value.as_deref_mut()),
+            _ => {}
+        }
+    }
+}
+
+/// Inserts the doc attributes of `docs` into the attributes of `map`.
+fn insert_docs(map: &mut Map, docs: &Value, inner: bool) {
+    let docs = match docs {
+        Value::Map(docs) => docs,
+        _ => return,
+    };
+    let text = match docs.get("text") {
+        Some(Value::Str(text)) => text,
+        _ => return,
+    };
+    let span = docs.get("span");
+    let new_attrs = text.split('\n').map(|line| {
+        let line = if line.is_empty() { String::new() } else { format!(" {}", line) };
+        let attr: syn::Attribute = if inner {
+            syn::parse_quote!(#![doc = #line])
+        } else {
+            syn::parse_quote!(#[doc = #line])
+        };
+        let mut value = node::to_value(&Attribute::from(&attr));
+        span::set_spans(&mut value, span);
+        value
+    });
+
+    if !map.fields.iter().any(|(k, _)| k == "attrs") {
+        map.fields.push(("attrs".to_owned(), Value::Seq(vec![])));
+    }
+    let attrs = match map.fields.iter_mut().find(|(k, _)| k == "attrs") {
+        Some((_, Value::Seq(attrs))) => attrs,
+        _ => return,
+    };
+    let i = if inner { attrs.iter().position(is_inner).unwrap_or(attrs.len()) } else { 0 };
+    attrs.splice(i..i, new_attrs.collect::<Vec<_>>());
+}
+
+fn parse_attr(attr: &Value) -> Option<syn::Attribute> {
+    let attr: Attribute = node::from_value(attr.clone()).ok()?;
+    Some(syn::Attribute::from(&attr))
+}
+
+fn is_inner(attr: &Value) -> bool {
+    parse_attr(attr).map_or(false, |attr| matches!(attr.style, syn::AttrStyle::Inner(_)))
+}
+
+/// Returns the line of a doc attribute, if it can be restored exactly from
+/// the line.
+fn doc_line(attr: &Value, inner: bool) -> Option<String> {
+    let attr = parse_attr(attr)?;
+    if matches!(attr.style, syn::AttrStyle::Inner(_)) != inner || !attr.path().is_ident("doc") {
+        return None;
+    }
+    let lit = match &attr.meta {
+        syn::Meta::NameValue(syn::MetaNameValue {
+            value: syn::Expr::Lit(syn::ExprLit { attrs, lit: syn::Lit::Str(lit) }),
+            ..
+        }) if attrs.is_empty() => lit,
+        _ => return None,
+    };
+    let line = lit.value();
+    let token = proc_macro2::Literal::string(&line).to_string();
+    if line.contains('\n') || !lit.suffix().is_empty() || lit.token().to_string() != token {
+        return None;
+    }
+    Some(line)
+}
+
+/// Returns the span of the path of an attribute, which is the span of the
+/// comment for doc comments.
+fn path_span(attr: &Value) -> Option<SpanInfo> {
+    attr.as_map()?.get("meta")?.fields()?.get("path")?.as_map()?.span()
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use core::fmt;
+
+/// An error that occurred while parsing, converting, or analyzing a syntax
+/// tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    msg: String,
+    pos: Option<usize>,
+}
+
+impl Error {
+    pub(crate) fn new<M: Into<String>>(msg: M) -> Self {
+        Self { msg: msg.into(), pos: None }
+    }
+
+    pub(crate) fn at<M: Into<String>>(msg: M, pos: usize) -> Self {
+        Self { msg: msg.into(), pos: Some(pos) }
+    }
+
+    /// Returns the byte offset in the input at which the error occurred, if
+    /// the error is about a string such as a [`Query`](crate::query::Query)
+    /// selector.
+    #[must_use]
+    pub fn position(&self) -> Option<usize> {
+        self.pos
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.pos {
+            Some(pos) => write!(f, "{} at position {}", self.msg, pos),
+            None => f.write_str(&self.msg),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,138 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Serialization without omitted defaults.
+//!
+//! By default, fields that have their default value are omitted, so the set
+//! of fields of a node depends on its content. [`Explicit`] serializes a
+//! syntax tree with every field, including the defaults:
+//!
+//! | Field                            | Default                                |
+//! | -------------------------------- | -------------------------------------- |
+//! | Lists (e.g., `attrs`)            | `[]`                                   |
+//! | Optional values                  | `null`                                 |
+//! | Optional tokens (e.g., `unsafe`) | `false`                                |
+//! | [`Visibility`]                   | `"inherited"`                          |
+//! | [`Generics`]                     | `{"params": [], "where_clause": null}` |
+//! | Other enums (e.g., `arguments`)  | `"none"`                               |
+//!
+//! Spans, comments, and trivia are metadata, not fields of the syntax, so they
+//! are still omitted if they are not recorded. The tokens that are only
+//! serialized by [`Lossless`](crate::lossless::Lossless) are not written
+//! either, unless the modes are combined with [`options`].
+//!
+//! The compact form and the explicit form can both be deserialized as usual.
+//!
+//! [`Generics`]: crate::Generics
+//! [`Visibility`]: crate::Visibility
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn dox() {
+//! use syn_serde::explicit::Explicit;
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     fn f() {}
+//! };
+//! let tree = Explicit::from_syn(&syn_file);
+//!
+//! let json = serde_json::to_value(&tree).unwrap();
+//! let f = &json["items"][0]["fn"];
+//! assert_eq!(f["attrs"], serde_json::json!([]));
+//! assert_eq!(f["vis"], "inherited");
+//! assert_eq!(f["unsafe"], false);
+//!
+//! let syn_file2: syn::File = syn_serde::json::from_str(&json.to_string()).unwrap();
+//! assert_eq!(syn_file, syn_file2);
+//! # }
+//! ```
+
+use crate::{
+    FieldMutability, Generics, PathArguments, StaticMutability, TraitBoundModifier, Visibility,
+    lossless::Punctuated,
+    options::{self, Options},
+};
+
+/// Skips the fields that have their default value, unless the adapters are
+/// being serialized by [`Explicit`].
+pub(crate) fn skip<T>(x: &T) -> bool
+where
+    T: ?Sized + Omit,
+{
+    !options::current().explicit && x.is_default()
+}
+
+/// The fields that are omitted if they have their default value.
+pub(crate) trait Omit {
+    fn is_default(&self) -> bool;
+}
+
+impl Omit for bool {
+    fn is_default(&self) -> bool {
+        !*self
+    }
+}
+
+impl<T> Omit for Option<T> {
+    fn is_default(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl<T> Omit for Vec<T> {
+    fn is_default(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<T> Omit for Punctuated<T> {
+    fn is_default(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl Omit for Visibility {
+    fn is_default(&self) -> bool {
+        self.is_inherited()
+    }
+}
+
+impl Omit for Generics {
+    fn is_default(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl Omit for PathArguments {
+    fn is_default(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl Omit for FieldMutability {
+    fn is_default(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl Omit for StaticMutability {
+    fn is_default(&self) -> bool {
+        self.is_none()
+    }
+}
+
+impl Omit for TraitBoundModifier {
+    fn is_default(&self) -> bool {
+        self.is_none()
+    }
+}
+
+value_tree! {
+    /// A syntax tree that is serialized with every field.
+    ///
+    /// See the [module level documentation](self) for more.
+    pub struct Explicit;
+    new: "Records every field of",
+    options: Options::new().explicit(true),
+}
@@ -16,14 +16,18 @@ pub use crate::{
 ast_struct! {
     /// An adapter for [`struct@syn::Arm`].
     pub struct Arm {
-        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) attrs: Vec<Attribute>,
         pub(crate) pat: Pat,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) guard: Option<Box<Expr>>,
         pub(crate) body: Box<Expr>,
-        // #[serde(default, skip_serializing_if = "not")]
-        // pub(crate) comma: bool,
+        // Whether the arm is followed by `,`, if it differs from the default.
+        #[serde(default, skip_serializing_if = "lossless::skip")]
+        #[serde(serialize_with = "lossless::serialize_field")]
+        pub(crate) comma: Option<bool>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub(crate) trivia: Vec<crate::trivia::TokenTrivia>,
     }
 }
 
@@ -92,7 +96,9 @@ mod convert {
                     attrs: other.attrs.map_into(),
                     pat: other.pat.ref_into(),
                     guard: other.guard.ref_map(|(_, x)| x.map_into()),
+                    comma: arm_comma(&body, other.comma.is_some()),
                     body,
+                    trivia: crate::trivia::take(other),
                 }
             })
             .collect()
@@ -104,6 +110,7 @@ mod convert {
                 expr: other.expr.map_into(),
                 arms: from_syn_arms(&other.arms),
                 comments: vec![],
+                trivia: crate::trivia::take(other),
             }
         }
     }
@@ -120,6 +127,11 @@ mod convert {
     }
 
     // Arm
+    fn arm_comma(body: &Expr, comma: bool) -> Option<bool> {
+        // By default, `,` follows the arms that require it.
+        if comma == requires_terminator(body) { None } else { Some(comma) }
+    }
+
     syn_trait_impl!(syn::Arm);
     impl From<&syn::Arm> for Arm {
         fn from(other: &syn::Arm) -> Self {
@@ -132,7 +144,9 @@ mod convert {
                 attrs: other.attrs.map_into(),
                 pat: other.pat.ref_into(),
                 guard: other.guard.ref_map(|(_, x)| x.map_into()),
+                comma: arm_comma(&body, other.comma.is_some()),
                 body,
+                trivia: crate::trivia::take(other),
             }
         }
     }
@@ -144,7 +158,9 @@ mod convert {
                 guard: other.guard.ref_map(|x| (default(), x.map_into())),
                 fat_arrow_token: default(),
                 body: other.body.map_into(),
-                comma: default_or_none(requires_terminator(&other.body)),
+                comma: default_or_none(
+                    other.comma.unwrap_or_else(|| requires_terminator(&other.body)),
+                ),
             }
         }
     }
@@ -4,16 +4,63 @@
 // It is not intended for manual editing.
 
 #![cfg_attr(rustfmt, rustfmt::skip)]
+#![allow(clippy::large_enum_variant)]
 use crate::*;
+/// Returns the name of the struct in the given variant of the given
+/// enum if the struct contains `#[serde(flatten)]` fields.
+pub(crate) fn flattened_struct(ident: &str, variant: &str) -> Option<&'static str> {
+    match (ident, variant) {
+        ("Expr", "field") => Some("ExprField"),
+        ("Expr" | "Pat", "lit") => Some("ExprLit"),
+        ("Expr" | "Pat", "macro") => Some("ExprMacro"),
+        ("Expr" | "Pat", "path") => Some("ExprPath"),
+        ("ForeignItem", "fn") => Some("ForeignItemFn"),
+        ("ForeignItem", "macro") => Some("ForeignItemMacro"),
+        ("ImplItem", "fn") => Some("ImplItemFn"),
+        ("ImplItem", "macro") => Some("ImplItemMacro"),
+        ("Item", "fn") => Some("ItemFn"),
+        ("Item", "macro") => Some("ItemMacro"),
+        ("Stmt", "macro") => Some("StmtMacro"),
+        ("TraitItem", "fn") => Some("TraitItemFn"),
+        ("TraitItem", "macro") => Some("TraitItemMacro"),
+        ("Type", "macro") => Some("TypeMacro"),
+        ("Type", "path") => Some("TypePath"),
+        _ => None,
+    }
+}
 /// An adapter for [`enum@syn::AttrStyle`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum AttrStyle {
     Outer,
     Inner,
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "AttrStyle", rename_all = "snake_case")]
+    enum External {
+        Outer,
+        Inner,
+    }
+    impl From<External> for AttrStyle {
+        fn from(external: External) -> Self {
+            match external {
+                External::Outer => Self::Outer,
+                External::Inner => Self::Inner,
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for AttrStyle {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::BinOp`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum BinOp {
@@ -74,8 +121,112 @@ pub enum BinOp {
     #[serde(rename = ">>=")]
     ShrAssign,
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "BinOp", rename_all = "snake_case")]
+    enum External {
+        #[serde(rename = "+")]
+        Add,
+        #[serde(rename = "-")]
+        Sub,
+        #[serde(rename = "*")]
+        Mul,
+        #[serde(rename = "/")]
+        Div,
+        #[serde(rename = "%")]
+        Rem,
+        #[serde(rename = "&&")]
+        And,
+        #[serde(rename = "||")]
+        Or,
+        #[serde(rename = "^")]
+        BitXor,
+        #[serde(rename = "&")]
+        BitAnd,
+        #[serde(rename = "|")]
+        BitOr,
+        #[serde(rename = "<<")]
+        Shl,
+        #[serde(rename = ">>")]
+        Shr,
+        #[serde(rename = "==")]
+        Eq,
+        #[serde(rename = "<")]
+        Lt,
+        #[serde(rename = "<=")]
+        Le,
+        #[serde(rename = "!=")]
+        Ne,
+        #[serde(rename = ">=")]
+        Ge,
+        #[serde(rename = ">")]
+        Gt,
+        #[serde(rename = "+=")]
+        AddAssign,
+        #[serde(rename = "-=")]
+        SubAssign,
+        #[serde(rename = "*=")]
+        MulAssign,
+        #[serde(rename = "/=")]
+        DivAssign,
+        #[serde(rename = "%=")]
+        RemAssign,
+        #[serde(rename = "^=")]
+        BitXorAssign,
+        #[serde(rename = "&=")]
+        BitAndAssign,
+        #[serde(rename = "|=")]
+        BitOrAssign,
+        #[serde(rename = "<<=")]
+        ShlAssign,
+        #[serde(rename = ">>=")]
+        ShrAssign,
+    }
+    impl From<External> for BinOp {
+        fn from(external: External) -> Self {
+            match external {
+                External::Add => Self::Add,
+                External::Sub => Self::Sub,
+                External::Mul => Self::Mul,
+                External::Div => Self::Div,
+                External::Rem => Self::Rem,
+                External::And => Self::And,
+                External::Or => Self::Or,
+                External::BitXor => Self::BitXor,
+                External::BitAnd => Self::BitAnd,
+                External::BitOr => Self::BitOr,
+                External::Shl => Self::Shl,
+                External::Shr => Self::Shr,
+                External::Eq => Self::Eq,
+                External::Lt => Self::Lt,
+                External::Le => Self::Le,
+                External::Ne => Self::Ne,
+                External::Ge => Self::Ge,
+                External::Gt => Self::Gt,
+                External::AddAssign => Self::AddAssign,
+                External::SubAssign => Self::SubAssign,
+                External::MulAssign => Self::MulAssign,
+                External::DivAssign => Self::DivAssign,
+                External::RemAssign => Self::RemAssign,
+                External::BitXorAssign => Self::BitXorAssign,
+                External::BitAndAssign => Self::BitAndAssign,
+                External::BitOrAssign => Self::BitOrAssign,
+                External::ShlAssign => Self::ShlAssign,
+                External::ShrAssign => Self::ShrAssign,
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for BinOp {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::Expr`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum Expr {
@@ -119,30 +270,200 @@ pub enum Expr {
     While(ExprWhile),
     Yield(ExprYield),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "Expr", rename_all = "snake_case")]
+    enum External {
+        Array(ExprArray),
+        Assign(ExprAssign),
+        Async(ExprAsync),
+        Await(ExprAwait),
+        Binary(ExprBinary),
+        Block(ExprBlock),
+        Break(ExprBreak),
+        Call(ExprCall),
+        Cast(ExprCast),
+        Closure(ExprClosure),
+        Const(ExprConst),
+        Continue(ExprContinue),
+        Field(ExprField),
+        ForLoop(ExprForLoop),
+        Group(ExprGroup),
+        If(ExprIf),
+        Index(ExprIndex),
+        Infer(ExprInfer),
+        Let(ExprLet),
+        Lit(ExprLit),
+        Loop(ExprLoop),
+        Macro(ExprMacro),
+        Match(ExprMatch),
+        MethodCall(ExprMethodCall),
+        Paren(ExprParen),
+        Path(ExprPath),
+        Range(ExprRange),
+        Reference(ExprReference),
+        Repeat(ExprRepeat),
+        Return(ExprReturn),
+        Struct(ExprStruct),
+        Try(ExprTry),
+        TryBlock(ExprTryBlock),
+        Tuple(ExprTuple),
+        Unary(ExprUnary),
+        Unsafe(ExprUnsafe),
+        Verbatim(TokenStream),
+        While(ExprWhile),
+        Yield(ExprYield),
+    }
+    impl From<External> for Expr {
+        fn from(external: External) -> Self {
+            match external {
+                External::Array(v) => Self::Array(v),
+                External::Assign(v) => Self::Assign(v),
+                External::Async(v) => Self::Async(v),
+                External::Await(v) => Self::Await(v),
+                External::Binary(v) => Self::Binary(v),
+                External::Block(v) => Self::Block(v),
+                External::Break(v) => Self::Break(v),
+                External::Call(v) => Self::Call(v),
+                External::Cast(v) => Self::Cast(v),
+                External::Closure(v) => Self::Closure(v),
+                External::Const(v) => Self::Const(v),
+                External::Continue(v) => Self::Continue(v),
+                External::Field(v) => Self::Field(v),
+                External::ForLoop(v) => Self::ForLoop(v),
+                External::Group(v) => Self::Group(v),
+                External::If(v) => Self::If(v),
+                External::Index(v) => Self::Index(v),
+                External::Infer(v) => Self::Infer(v),
+                External::Let(v) => Self::Let(v),
+                External::Lit(v) => Self::Lit(v),
+                External::Loop(v) => Self::Loop(v),
+                External::Macro(v) => Self::Macro(v),
+                External::Match(v) => Self::Match(v),
+                External::MethodCall(v) => Self::MethodCall(v),
+                External::Paren(v) => Self::Paren(v),
+                External::Path(v) => Self::Path(v),
+                External::Range(v) => Self::Range(v),
+                External::Reference(v) => Self::Reference(v),
+                External::Repeat(v) => Self::Repeat(v),
+                External::Return(v) => Self::Return(v),
+                External::Struct(v) => Self::Struct(v),
+                External::Try(v) => Self::Try(v),
+                External::TryBlock(v) => Self::TryBlock(v),
+                External::Tuple(v) => Self::Tuple(v),
+                External::Unary(v) => Self::Unary(v),
+                External::Unsafe(v) => Self::Unsafe(v),
+                External::Verbatim(v) => Self::Verbatim(v),
+                External::While(v) => Self::While(v),
+                External::Yield(v) => Self::Yield(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for Expr {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::FieldMutability`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum FieldMutability {
     None,
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "FieldMutability", rename_all = "snake_case")]
+    enum External {
+        None,
+    }
+    impl From<External> for FieldMutability {
+        fn from(external: External) -> Self {
+            match external {
+                External::None => Self::None,
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for FieldMutability {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::Fields`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Fields {
     Named(FieldsNamed),
     Unnamed(FieldsUnnamed),
     Unit,
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "Fields", rename_all = "snake_case")]
+    enum External {
+        Named(FieldsNamed),
+        Unnamed(FieldsUnnamed),
+        Unit,
+    }
+    impl From<External> for Fields {
+        fn from(external: External) -> Self {
+            match external {
+                External::Named(v) => Self::Named(v),
+                External::Unnamed(v) => Self::Unnamed(v),
+                External::Unit => Self::Unit,
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for Fields {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::FnArg`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum FnArg {
     Receiver(Receiver),
     Typed(PatType),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "FnArg", rename_all = "snake_case")]
+    enum External {
+        Receiver(Receiver),
+        Typed(PatType),
+    }
+    impl From<External> for FnArg {
+        fn from(external: External) -> Self {
+            match external {
+                External::Receiver(v) => Self::Receiver(v),
+                External::Typed(v) => Self::Typed(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for FnArg {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::ForeignItem`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum ForeignItem {
@@ -152,8 +473,38 @@ pub enum ForeignItem {
     Macro(ForeignItemMacro),
     Verbatim(TokenStream),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "ForeignItem", rename_all = "snake_case")]
+    enum External {
+        Fn(ForeignItemFn),
+        Static(ForeignItemStatic),
+        Type(ForeignItemType),
+        Macro(ForeignItemMacro),
+        Verbatim(TokenStream),
+    }
+    impl From<External> for ForeignItem {
+        fn from(external: External) -> Self {
+            match external {
+                External::Fn(v) => Self::Fn(v),
+                External::Static(v) => Self::Static(v),
+                External::Type(v) => Self::Type(v),
+                External::Macro(v) => Self::Macro(v),
+                External::Verbatim(v) => Self::Verbatim(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for ForeignItem {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::GenericArgument`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum GenericArgument {
@@ -164,16 +515,74 @@ pub enum GenericArgument {
     AssocConst(AssocConst),
     Constraint(Constraint),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "GenericArgument", rename_all = "snake_case")]
+    enum External {
+        Lifetime(Lifetime),
+        Type(Type),
+        Const(Expr),
+        AssocType(AssocType),
+        AssocConst(AssocConst),
+        Constraint(Constraint),
+    }
+    impl From<External> for GenericArgument {
+        fn from(external: External) -> Self {
+            match external {
+                External::Lifetime(v) => Self::Lifetime(v),
+                External::Type(v) => Self::Type(v),
+                External::Const(v) => Self::Const(v),
+                External::AssocType(v) => Self::AssocType(v),
+                External::AssocConst(v) => Self::AssocConst(v),
+                External::Constraint(v) => Self::Constraint(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for GenericArgument {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::GenericParam`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum GenericParam {
     Lifetime(LifetimeParam),
     Type(TypeParam),
     Const(ConstParam),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "GenericParam", rename_all = "snake_case")]
+    enum External {
+        Lifetime(LifetimeParam),
+        Type(TypeParam),
+        Const(ConstParam),
+    }
+    impl From<External> for GenericParam {
+        fn from(external: External) -> Self {
+            match external {
+                External::Lifetime(v) => Self::Lifetime(v),
+                External::Type(v) => Self::Type(v),
+                External::Const(v) => Self::Const(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for GenericParam {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::ImplItem`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum ImplItem {
@@ -183,13 +592,61 @@ pub enum ImplItem {
     Macro(ImplItemMacro),
     Verbatim(TokenStream),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "ImplItem", rename_all = "snake_case")]
+    enum External {
+        Const(ImplItemConst),
+        Fn(ImplItemFn),
+        Type(ImplItemType),
+        Macro(ImplItemMacro),
+        Verbatim(TokenStream),
+    }
+    impl From<External> for ImplItem {
+        fn from(external: External) -> Self {
+            match external {
+                External::Const(v) => Self::Const(v),
+                External::Fn(v) => Self::Fn(v),
+                External::Type(v) => Self::Type(v),
+                External::Macro(v) => Self::Macro(v),
+                External::Verbatim(v) => Self::Verbatim(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for ImplItem {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::ImplRestriction`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum ImplRestriction {}
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "ImplRestriction", rename_all = "snake_case")]
+    enum External {}
+    impl From<External> for ImplRestriction {
+        fn from(external: External) -> Self {
+            match external {}
+        }
+    }
+    impl<'de> Deserialize<'de> for ImplRestriction {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::Item`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum Item {
@@ -210,6 +667,58 @@ pub enum Item {
     Use(ItemUse),
     Verbatim(TokenStream),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "Item", rename_all = "snake_case")]
+    enum External {
+        Const(ItemConst),
+        Enum(ItemEnum),
+        ExternCrate(ItemExternCrate),
+        Fn(ItemFn),
+        ForeignMod(ItemForeignMod),
+        Impl(ItemImpl),
+        Macro(ItemMacro),
+        Mod(ItemMod),
+        Static(ItemStatic),
+        Struct(ItemStruct),
+        Trait(ItemTrait),
+        TraitAlias(ItemTraitAlias),
+        Type(ItemType),
+        Union(ItemUnion),
+        Use(ItemUse),
+        Verbatim(TokenStream),
+    }
+    impl From<External> for Item {
+        fn from(external: External) -> Self {
+            match external {
+                External::Const(v) => Self::Const(v),
+                External::Enum(v) => Self::Enum(v),
+                External::ExternCrate(v) => Self::ExternCrate(v),
+                External::Fn(v) => Self::Fn(v),
+                External::ForeignMod(v) => Self::ForeignMod(v),
+                External::Impl(v) => Self::Impl(v),
+                External::Macro(v) => Self::Macro(v),
+                External::Mod(v) => Self::Mod(v),
+                External::Static(v) => Self::Static(v),
+                External::Struct(v) => Self::Struct(v),
+                External::Trait(v) => Self::Trait(v),
+                External::TraitAlias(v) => Self::TraitAlias(v),
+                External::Type(v) => Self::Type(v),
+                External::Union(v) => Self::Union(v),
+                External::Use(v) => Self::Use(v),
+                External::Verbatim(v) => Self::Verbatim(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for Item {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::Lit`].
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -217,6 +726,7 @@ pub enum Item {
 pub enum Lit {
     Str(LitStr),
     ByteStr(LitByteStr),
+    CStr(LitCStr),
     Byte(LitByte),
     Char(LitChar),
     Int(LitInt),
@@ -225,13 +735,39 @@ pub enum Lit {
     Verbatim(Literal),
 }
 /// An adapter for [`enum@syn::MacroDelimiter`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum MacroDelimiter {
     Paren,
     Brace,
     Bracket,
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "MacroDelimiter", rename_all = "snake_case")]
+    enum External {
+        Paren,
+        Brace,
+        Bracket,
+    }
+    impl From<External> for MacroDelimiter {
+        fn from(external: External) -> Self {
+            match external {
+                External::Paren => Self::Paren,
+                External::Brace => Self::Brace,
+                External::Bracket => Self::Bracket,
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for MacroDelimiter {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::Member`].
 #[derive(Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -242,15 +778,41 @@ pub enum Member {
     Unnamed(Index),
 }
 /// An adapter for [`enum@syn::Meta`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Meta {
     Path(Path),
     List(MetaList),
     NameValue(MetaNameValue),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "Meta", rename_all = "snake_case")]
+    enum External {
+        Path(Path),
+        List(MetaList),
+        NameValue(MetaNameValue),
+    }
+    impl From<External> for Meta {
+        fn from(external: External) -> Self {
+            match external {
+                External::Path(v) => Self::Path(v),
+                External::List(v) => Self::List(v),
+                External::NameValue(v) => Self::NameValue(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for Meta {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::Pat`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum Pat {
@@ -273,16 +835,97 @@ pub enum Pat {
     #[serde(rename = "_")]
     Wild(PatWild),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "Pat", rename_all = "snake_case")]
+    enum External {
+        Const(ExprConst),
+        Ident(PatIdent),
+        Lit(ExprLit),
+        Macro(ExprMacro),
+        Or(PatOr),
+        Paren(PatParen),
+        Path(ExprPath),
+        Range(ExprRange),
+        Reference(PatReference),
+        Rest(PatRest),
+        Slice(PatSlice),
+        Struct(PatStruct),
+        Tuple(PatTuple),
+        TupleStruct(PatTupleStruct),
+        Type(PatType),
+        Verbatim(TokenStream),
+        #[serde(rename = "_")]
+        Wild(PatWild),
+    }
+    impl From<External> for Pat {
+        fn from(external: External) -> Self {
+            match external {
+                External::Const(v) => Self::Const(v),
+                External::Ident(v) => Self::Ident(v),
+                External::Lit(v) => Self::Lit(v),
+                External::Macro(v) => Self::Macro(v),
+                External::Or(v) => Self::Or(v),
+                External::Paren(v) => Self::Paren(v),
+                External::Path(v) => Self::Path(v),
+                External::Range(v) => Self::Range(v),
+                External::Reference(v) => Self::Reference(v),
+                External::Rest(v) => Self::Rest(v),
+                External::Slice(v) => Self::Slice(v),
+                External::Struct(v) => Self::Struct(v),
+                External::Tuple(v) => Self::Tuple(v),
+                External::TupleStruct(v) => Self::TupleStruct(v),
+                External::Type(v) => Self::Type(v),
+                External::Verbatim(v) => Self::Verbatim(v),
+                External::Wild(v) => Self::Wild(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for Pat {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::PathArguments`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum PathArguments {
     None,
     AngleBracketed(AngleBracketedGenericArguments),
     Parenthesized(ParenthesizedGenericArguments),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "PathArguments", rename_all = "snake_case")]
+    enum External {
+        None,
+        AngleBracketed(AngleBracketedGenericArguments),
+        Parenthesized(ParenthesizedGenericArguments),
+    }
+    impl From<External> for PathArguments {
+        fn from(external: External) -> Self {
+            match external {
+                External::None => Self::None,
+                External::AngleBracketed(v) => Self::AngleBracketed(v),
+                External::Parenthesized(v) => Self::Parenthesized(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for PathArguments {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::RangeLimits`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum RangeLimits {
     #[serde(rename = "..")]
@@ -290,8 +933,34 @@ pub enum RangeLimits {
     #[serde(rename = "..=")]
     Closed,
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "RangeLimits", rename_all = "snake_case")]
+    enum External {
+        #[serde(rename = "..")]
+        HalfOpen,
+        #[serde(rename = "..=")]
+        Closed,
+    }
+    impl From<External> for RangeLimits {
+        fn from(external: External) -> Self {
+            match external {
+                External::HalfOpen => Self::HalfOpen,
+                External::Closed => Self::Closed,
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for RangeLimits {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::StaticMutability`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum StaticMutability {
@@ -299,15 +968,64 @@ pub enum StaticMutability {
     Mut,
     None,
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "StaticMutability", rename_all = "snake_case")]
+    enum External {
+        #[serde(rename = "mut")]
+        Mut,
+        None,
+    }
+    impl From<External> for StaticMutability {
+        fn from(external: External) -> Self {
+            match external {
+                External::Mut => Self::Mut,
+                External::None => Self::None,
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for StaticMutability {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::TraitBoundModifier`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum TraitBoundModifier {
     None,
     Maybe,
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "TraitBoundModifier", rename_all = "snake_case")]
+    enum External {
+        None,
+        Maybe,
+    }
+    impl From<External> for TraitBoundModifier {
+        fn from(external: External) -> Self {
+            match external {
+                External::None => Self::None,
+                External::Maybe => Self::Maybe,
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for TraitBoundModifier {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::TraitItem`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum TraitItem {
@@ -317,8 +1035,38 @@ pub enum TraitItem {
     Macro(TraitItemMacro),
     Verbatim(TokenStream),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "TraitItem", rename_all = "snake_case")]
+    enum External {
+        Const(TraitItemConst),
+        Fn(TraitItemFn),
+        Type(TraitItemType),
+        Macro(TraitItemMacro),
+        Verbatim(TokenStream),
+    }
+    impl From<External> for TraitItem {
+        fn from(external: External) -> Self {
+            match external {
+                External::Const(v) => Self::Const(v),
+                External::Fn(v) => Self::Fn(v),
+                External::Type(v) => Self::Type(v),
+                External::Macro(v) => Self::Macro(v),
+                External::Verbatim(v) => Self::Verbatim(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for TraitItem {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::Type`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum Type {
@@ -340,8 +1088,60 @@ pub enum Type {
     Tuple(TypeTuple),
     Verbatim(TokenStream),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "Type", rename_all = "snake_case")]
+    enum External {
+        Array(TypeArray),
+        BareFn(TypeBareFn),
+        Group(TypeGroup),
+        ImplTrait(TypeImplTrait),
+        #[serde(rename = "_")]
+        Infer,
+        Macro(TypeMacro),
+        #[serde(rename = "!")]
+        Never,
+        Paren(TypeParen),
+        Path(TypePath),
+        Ptr(TypePtr),
+        Reference(TypeReference),
+        Slice(TypeSlice),
+        TraitObject(TypeTraitObject),
+        Tuple(TypeTuple),
+        Verbatim(TokenStream),
+    }
+    impl From<External> for Type {
+        fn from(external: External) -> Self {
+            match external {
+                External::Array(v) => Self::Array(v),
+                External::BareFn(v) => Self::BareFn(v),
+                External::Group(v) => Self::Group(v),
+                External::ImplTrait(v) => Self::ImplTrait(v),
+                External::Infer => Self::Infer,
+                External::Macro(v) => Self::Macro(v),
+                External::Never => Self::Never,
+                External::Paren(v) => Self::Paren(v),
+                External::Path(v) => Self::Path(v),
+                External::Ptr(v) => Self::Ptr(v),
+                External::Reference(v) => Self::Reference(v),
+                External::Slice(v) => Self::Slice(v),
+                External::TraitObject(v) => Self::TraitObject(v),
+                External::Tuple(v) => Self::Tuple(v),
+                External::Verbatim(v) => Self::Verbatim(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for Type {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::TypeParamBound`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum TypeParamBound {
@@ -349,8 +1149,34 @@ pub enum TypeParamBound {
     Lifetime(Lifetime),
     Verbatim(TokenStream),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "TypeParamBound", rename_all = "snake_case")]
+    enum External {
+        Trait(TraitBound),
+        Lifetime(Lifetime),
+        Verbatim(TokenStream),
+    }
+    impl From<External> for TypeParamBound {
+        fn from(external: External) -> Self {
+            match external {
+                External::Trait(v) => Self::Trait(v),
+                External::Lifetime(v) => Self::Lifetime(v),
+                External::Verbatim(v) => Self::Verbatim(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for TypeParamBound {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::UnOp`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum UnOp {
@@ -361,8 +1187,37 @@ pub enum UnOp {
     #[serde(rename = "-")]
     Neg,
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "UnOp", rename_all = "snake_case")]
+    enum External {
+        #[serde(rename = "*")]
+        Deref,
+        #[serde(rename = "!")]
+        Not,
+        #[serde(rename = "-")]
+        Neg,
+    }
+    impl From<External> for UnOp {
+        fn from(external: External) -> Self {
+            match external {
+                External::Deref => Self::Deref,
+                External::Not => Self::Not,
+                External::Neg => Self::Neg,
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for UnOp {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::UseTree`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum UseTree {
     Path(UsePath),
@@ -373,8 +1228,40 @@ pub enum UseTree {
     Glob,
     Group(UseGroup),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "UseTree", rename_all = "snake_case")]
+    enum External {
+        Path(UsePath),
+        #[serde(rename = "ident")]
+        Name(UseName),
+        Rename(UseRename),
+        #[serde(rename = "*")]
+        Glob,
+        Group(UseGroup),
+    }
+    impl From<External> for UseTree {
+        fn from(external: External) -> Self {
+            match external {
+                External::Path(v) => Self::Path(v),
+                External::Name(v) => Self::Name(v),
+                External::Rename(v) => Self::Rename(v),
+                External::Glob => Self::Glob,
+                External::Group(v) => Self::Group(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for UseTree {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::Visibility`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 pub enum Visibility {
     #[serde(rename = "pub")]
@@ -382,11 +1269,62 @@ pub enum Visibility {
     Restricted(VisRestricted),
     Inherited,
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "Visibility", rename_all = "snake_case")]
+    enum External {
+        #[serde(rename = "pub")]
+        Public,
+        Restricted(VisRestricted),
+        Inherited,
+    }
+    impl From<External> for Visibility {
+        fn from(external: External) -> Self {
+            match external {
+                External::Public => Self::Public,
+                External::Restricted(v) => Self::Restricted(v),
+                External::Inherited => Self::Inherited,
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for Visibility {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
 /// An adapter for [`enum@syn::WherePredicate`].
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
 pub enum WherePredicate {
     Lifetime(PredicateLifetime),
     Type(PredicateType),
 }
+const _: () = {
+    #[derive(Deserialize)]
+    #[serde(rename = "WherePredicate", rename_all = "snake_case")]
+    enum External {
+        Lifetime(PredicateLifetime),
+        Type(PredicateType),
+    }
+    impl From<External> for WherePredicate {
+        fn from(external: External) -> Self {
+            match external {
+                External::Lifetime(v) => Self::Lifetime(v),
+                External::Type(v) => Self::Type(v),
+            }
+        }
+    }
+    impl<'de> Deserialize<'de> for WherePredicate {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            tagged::deserialize::<External, D>(deserializer).map(Self::from)
+        }
+    }
+};
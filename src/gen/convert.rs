@@ -11,11 +11,14 @@
     clippy::match_single_binding,
 )]
 use crate::*;
-use syn::spanned::Spanned;
+use syn::spanned::Spanned as _;
 syn_trait_impl!(syn::Abi);
 impl From<&syn::Abi> for Abi {
     fn from(node: &syn::Abi) -> Self {
-        Self { name: node.name.map_into() }
+        Self {
+            name: node.name.map_into(),
+            trivia: crate::trivia::take(node),
+        }
     }
 }
 impl From<&Abi> for syn::Abi {
@@ -32,6 +35,7 @@ impl From<&syn::AngleBracketedGenericArguments> for AngleBracketedGenericArgumen
         Self {
             colon2_token: node.colon2_token.is_some(),
             args: node.args.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -52,6 +56,7 @@ impl From<&syn::AssocConst> for AssocConst {
             ident: node.ident.ref_into(),
             generics: node.generics.map_into(),
             value: node.value.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -72,6 +77,7 @@ impl From<&syn::AssocType> for AssocType {
             ident: node.ident.ref_into(),
             generics: node.generics.map_into(),
             ty: node.ty.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -108,6 +114,7 @@ impl From<&syn::Attribute> for Attribute {
         Self {
             style: node.style.ref_into(),
             meta: node.meta.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -128,6 +135,7 @@ impl From<&syn::BareFnArg> for BareFnArg {
             attrs: node.attrs.map_into(),
             name: node.name.ref_map(|(_0, _1)| (*_0).ref_into()),
             ty: node.ty.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -147,6 +155,7 @@ impl From<&syn::BareVariadic> for BareVariadic {
             attrs: node.attrs.map_into(),
             name: node.name.ref_map(|(_0, _1)| (*_0).ref_into()),
             comma: node.comma.is_some(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -237,6 +246,7 @@ impl From<&syn::Block> for Block {
             stmts: node.stmts.map_into(),
             span: Some(crate::SpanInfo::from_span(node.brace_token.span.join())),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -275,6 +285,7 @@ impl From<&syn::ConstParam> for ConstParam {
             ty: node.ty.ref_into(),
             eq_token: node.eq_token.is_some(),
             default: node.default.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -298,6 +309,7 @@ impl From<&syn::Constraint> for Constraint {
             ident: node.ident.ref_into(),
             generics: node.generics.map_into(),
             bounds: node.bounds.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -410,6 +422,7 @@ impl From<&syn::ExprArray> for ExprArray {
             attrs: node.attrs.map_into(),
             elems: node.elems.map_into(),
             span: Some(crate::SpanInfo::from_span(node.bracket_token.span.join())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -430,6 +443,7 @@ impl From<&syn::ExprAssign> for ExprAssign {
             left: node.left.map_into(),
             right: node.right.map_into(),
             span: Some(crate::SpanInfo::from_span(node.left.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -451,6 +465,7 @@ impl From<&syn::ExprAsync> for ExprAsync {
             capture: node.capture.is_some(),
             block: node.block.ref_into(),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -470,6 +485,7 @@ impl From<&syn::ExprAwait> for ExprAwait {
         Self {
             attrs: node.attrs.map_into(),
             base: node.base.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -492,6 +508,7 @@ impl From<&syn::ExprBinary> for ExprBinary {
             op: node.op.ref_into(),
             right: node.right.map_into(),
             span: Some(crate::SpanInfo::from_span(node.op.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -514,6 +531,7 @@ impl From<&syn::ExprBlock> for ExprBlock {
             block: node.block.ref_into(),
             span: Some(crate::SpanInfo::from_span(node.block.brace_token.span.join())),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -533,6 +551,7 @@ impl From<&syn::ExprBreak> for ExprBreak {
             attrs: node.attrs.map_into(),
             label: node.label.map_into(),
             expr: node.expr.ref_map(MapInto::map_into),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -554,6 +573,7 @@ impl From<&syn::ExprCall> for ExprCall {
             func: node.func.map_into(),
             args: node.args.map_into(),
             span: Some(crate::SpanInfo::from_span(node.func.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -574,6 +594,7 @@ impl From<&syn::ExprCast> for ExprCast {
             attrs: node.attrs.map_into(),
             expr: node.expr.map_into(),
             ty: node.ty.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -600,6 +621,7 @@ impl From<&syn::ExprClosure> for ExprClosure {
             inputs: node.inputs.map_into(),
             output: node.output.ref_into(),
             body: node.body.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -627,6 +649,7 @@ impl From<&syn::ExprConst> for ExprConst {
             attrs: node.attrs.map_into(),
             block: node.block.ref_into(),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -645,6 +668,7 @@ impl From<&syn::ExprContinue> for ExprContinue {
         Self {
             attrs: node.attrs.map_into(),
             label: node.label.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -665,6 +689,7 @@ impl From<&syn::ExprField> for ExprField {
             base: node.base.map_into(),
             member: node.member.ref_into(),
             span: Some(crate::SpanInfo::from_span(node.member.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -688,6 +713,7 @@ impl From<&syn::ExprForLoop> for ExprForLoop {
             expr: node.expr.map_into(),
             body: node.body.ref_into(),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -710,6 +736,7 @@ impl From<&syn::ExprGroup> for ExprGroup {
         Self {
             attrs: node.attrs.map_into(),
             expr: node.expr.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -732,6 +759,7 @@ impl From<&syn::ExprIf> for ExprIf {
             else_branch: node.else_branch.ref_map(|(_0, _1)| (*_1).map_into()),
             span: Some(crate::SpanInfo::from_span(node.if_token.span())),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -753,6 +781,7 @@ impl From<&syn::ExprIndex> for ExprIndex {
             attrs: node.attrs.map_into(),
             expr: node.expr.map_into(),
             index: node.index.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -771,6 +800,7 @@ impl From<&syn::ExprInfer> for ExprInfer {
     fn from(node: &syn::ExprInfer) -> Self {
         Self {
             attrs: node.attrs.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -789,6 +819,7 @@ impl From<&syn::ExprLet> for ExprLet {
             attrs: node.attrs.map_into(),
             pat: node.pat.map_into(),
             expr: node.expr.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -809,6 +840,7 @@ impl From<&syn::ExprLit> for ExprLit {
         Self {
             attrs: node.attrs.map_into(),
             lit: node.lit.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -828,6 +860,7 @@ impl From<&syn::ExprLoop> for ExprLoop {
             label: node.label.map_into(),
             body: node.body.ref_into(),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -847,6 +880,7 @@ impl From<&syn::ExprMacro> for ExprMacro {
         Self {
             attrs: node.attrs.map_into(),
             mac: node.mac.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -868,6 +902,7 @@ impl From<&syn::ExprMethodCall> for ExprMethodCall {
             turbofish: node.turbofish.map_into(),
             args: node.args.map_into(),
             span: Some(crate::SpanInfo::from_span(node.method.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -890,6 +925,7 @@ impl From<&syn::ExprParen> for ExprParen {
         Self {
             attrs: node.attrs.map_into(),
             expr: node.expr.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -909,6 +945,7 @@ impl From<&syn::ExprPath> for ExprPath {
             attrs: node.attrs.map_into(),
             qself: node.qself.map_into(),
             path: node.path.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -929,6 +966,7 @@ impl From<&syn::ExprRange> for ExprRange {
             start: node.start.ref_map(MapInto::map_into),
             limits: node.limits.ref_into(),
             end: node.end.ref_map(MapInto::map_into),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -949,6 +987,7 @@ impl From<&syn::ExprReference> for ExprReference {
             attrs: node.attrs.map_into(),
             mutability: node.mutability.is_some(),
             expr: node.expr.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -969,6 +1008,7 @@ impl From<&syn::ExprRepeat> for ExprRepeat {
             attrs: node.attrs.map_into(),
             expr: node.expr.map_into(),
             len: node.len.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -989,6 +1029,7 @@ impl From<&syn::ExprReturn> for ExprReturn {
         Self {
             attrs: node.attrs.map_into(),
             expr: node.expr.ref_map(MapInto::map_into),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1012,6 +1053,7 @@ impl From<&syn::ExprStruct> for ExprStruct {
             dot2_token: node.dot2_token.is_some(),
             rest: node.rest.ref_map(MapInto::map_into),
             span: Some(crate::SpanInfo::from_span(node.path.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1034,6 +1076,7 @@ impl From<&syn::ExprTry> for ExprTry {
         Self {
             attrs: node.attrs.map_into(),
             expr: node.expr.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1053,6 +1096,7 @@ impl From<&syn::ExprTryBlock> for ExprTryBlock {
             attrs: node.attrs.map_into(),
             block: node.block.ref_into(),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1072,6 +1116,7 @@ impl From<&syn::ExprTuple> for ExprTuple {
             attrs: node.attrs.map_into(),
             elems: node.elems.map_into(),
             span: Some(crate::SpanInfo::from_span(node.paren_token.span.join())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1092,6 +1137,7 @@ impl From<&syn::ExprUnary> for ExprUnary {
             op: node.op.ref_into(),
             expr: node.expr.map_into(),
             span: Some(crate::SpanInfo::from_span(node.op.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1111,6 +1157,7 @@ impl From<&syn::ExprUnsafe> for ExprUnsafe {
             attrs: node.attrs.map_into(),
             block: node.block.ref_into(),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1132,6 +1179,7 @@ impl From<&syn::ExprWhile> for ExprWhile {
             cond: node.cond.map_into(),
             body: node.body.ref_into(),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1152,6 +1200,7 @@ impl From<&syn::ExprYield> for ExprYield {
         Self {
             attrs: node.attrs.map_into(),
             expr: node.expr.ref_map(MapInto::map_into),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1174,6 +1223,7 @@ impl From<&syn::Field> for Field {
             ident: node.ident.map_into(),
             colon_token: node.colon_token.is_some(),
             ty: node.ty.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1213,6 +1263,7 @@ impl From<&syn::FieldPat> for FieldPat {
             member: node.member.ref_into(),
             colon_token: node.colon_token.is_some(),
             pat: node.pat.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1234,6 +1285,7 @@ impl From<&syn::FieldValue> for FieldValue {
             member: node.member.ref_into(),
             colon_token: node.colon_token.is_some(),
             expr: node.expr.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1306,6 +1358,7 @@ impl From<&syn::File> for File {
             attrs: node.attrs.map_into(),
             items: node.items.map_into(),
             span: Some(crate::SpanInfo::from_span(node.span())),
+            trivia: None,
         }
     }
 }
@@ -1366,6 +1419,8 @@ impl From<&syn::ForeignItemFn> for ForeignItemFn {
             attrs: node.attrs.map_into(),
             vis: node.vis.ref_into(),
             sig: node.sig.ref_into(),
+            span: Some(crate::SpanInfo::from_span(node.sig.ident.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1386,6 +1441,7 @@ impl From<&syn::ForeignItemMacro> for ForeignItemMacro {
             attrs: node.attrs.map_into(),
             mac: node.mac.ref_into(),
             semi_token: node.semi_token.is_some(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1407,6 +1463,8 @@ impl From<&syn::ForeignItemStatic> for ForeignItemStatic {
             mutability: node.mutability.ref_into(),
             ident: node.ident.ref_into(),
             ty: node.ty.map_into(),
+            span: Some(crate::SpanInfo::from_span(node.ident.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1432,6 +1490,8 @@ impl From<&syn::ForeignItemType> for ForeignItemType {
             vis: node.vis.ref_into(),
             ident: node.ident.ref_into(),
             generics: node.generics.ref_into(),
+            span: Some(crate::SpanInfo::from_span(node.ident.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1543,6 +1603,8 @@ impl From<&syn::ImplItemConst> for ImplItemConst {
             generics: node.generics.ref_into(),
             ty: node.ty.ref_into(),
             expr: node.expr.ref_into(),
+            span: Some(crate::SpanInfo::from_span(node.ident.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1572,6 +1634,8 @@ impl From<&syn::ImplItemFn> for ImplItemFn {
             defaultness: node.defaultness.is_some(),
             sig: node.sig.ref_into(),
             block: node.block.ref_into(),
+            span: Some(crate::SpanInfo::from_span(node.sig.ident.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1593,6 +1657,7 @@ impl From<&syn::ImplItemMacro> for ImplItemMacro {
             attrs: node.attrs.map_into(),
             mac: node.mac.ref_into(),
             semi_token: node.semi_token.is_some(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1615,6 +1680,8 @@ impl From<&syn::ImplItemType> for ImplItemType {
             ident: node.ident.ref_into(),
             generics: node.generics.ref_into(),
             ty: node.ty.ref_into(),
+            span: Some(crate::SpanInfo::from_span(node.ident.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1654,6 +1721,7 @@ impl From<&syn::Index> for Index {
         Self {
             index: node.index,
             span: node.span.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1723,6 +1791,7 @@ impl From<&syn::ItemConst> for ItemConst {
             expr: node.expr.map_into(),
             span: Some(crate::SpanInfo::from_span(node.ident.span())),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1753,6 +1822,7 @@ impl From<&syn::ItemEnum> for ItemEnum {
             variants: node.variants.map_into(),
             span: Some(crate::SpanInfo::from_span(node.ident.span())),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1777,6 +1847,7 @@ impl From<&syn::ItemExternCrate> for ItemExternCrate {
             vis: node.vis.ref_into(),
             ident: node.ident.ref_into(),
             rename: node.rename.ref_map(|(_0, _1)| (*_1).ref_into()),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1803,6 +1874,7 @@ impl From<&syn::ItemFn> for ItemFn {
             block: node.block.map_into(),
             span: Some(crate::SpanInfo::from_span(node.sig.ident.span())),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1825,6 +1897,7 @@ impl From<&syn::ItemForeignMod> for ItemForeignMod {
             abi: node.abi.ref_into(),
             items: node.items.map_into(),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1854,6 +1927,7 @@ impl From<&syn::ItemImpl> for ItemImpl {
             items: node.items.map_into(),
             span: Some(crate::SpanInfo::from_span(node.impl_token.span())),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1886,6 +1960,15 @@ impl From<&syn::ItemMacro> for ItemMacro {
             ident: node.ident.map_into(),
             mac: node.mac.ref_into(),
             semi_token: node.semi_token.is_some(),
+            span: Some(
+                crate::SpanInfo::from_span(
+                    match &node.ident {
+                        Some(ident) => ident.span(),
+                        None => node.mac.path.span(),
+                    },
+                ),
+            ),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1909,6 +1992,8 @@ impl From<&syn::ItemMod> for ItemMod {
             ident: node.ident.ref_into(),
             content: node.content.ref_map(|(_0, _1)| (*_1).map_into()),
             semi: node.semi.is_some(),
+            span: Some(crate::SpanInfo::from_span(node.ident.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1937,6 +2022,7 @@ impl From<&syn::ItemStatic> for ItemStatic {
             expr: node.expr.map_into(),
             span: Some(crate::SpanInfo::from_span(node.ident.span())),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -1972,6 +2058,7 @@ impl From<&syn::ItemTrait> for ItemTrait {
             items: node.items.map_into(),
             span: Some(crate::SpanInfo::from_span(node.ident.span())),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2002,6 +2089,8 @@ impl From<&syn::ItemTraitAlias> for ItemTraitAlias {
             ident: node.ident.ref_into(),
             generics: node.generics.ref_into(),
             bounds: node.bounds.map_into(),
+            span: Some(crate::SpanInfo::from_span(node.ident.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2030,6 +2119,7 @@ impl From<&syn::ItemType> for ItemType {
             ty: node.ty.map_into(),
             span: Some(crate::SpanInfo::from_span(node.ident.span())),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2056,7 +2146,9 @@ impl From<&syn::ItemUnion> for ItemUnion {
             ident: node.ident.ref_into(),
             generics: node.generics.ref_into(),
             fields: node.fields.ref_into(),
+            span: Some(crate::SpanInfo::from_span(node.ident.span())),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2082,6 +2174,7 @@ impl From<&syn::ItemUse> for ItemUse {
             tree: node.tree.ref_into(),
             span: Some(crate::SpanInfo::from_span(node.use_token.span())),
             comments: vec![],
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2117,6 +2210,7 @@ impl From<&syn::Lifetime> for Lifetime {
         Self {
             apostrophe: node.apostrophe.ref_into(),
             ident: node.ident.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2136,6 +2230,7 @@ impl From<&syn::LifetimeParam> for LifetimeParam {
             lifetime: node.lifetime.ref_into(),
             colon_token: node.colon_token.is_some(),
             bounds: node.bounds.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2155,6 +2250,7 @@ impl From<&syn::Lit> for Lit {
         match node {
             syn::Lit::Str(_0) => Lit::Str((*_0).ref_into()),
             syn::Lit::ByteStr(_0) => Lit::ByteStr((*_0).ref_into()),
+            syn::Lit::CStr(_0) => Lit::CStr((*_0).ref_into()),
             syn::Lit::Byte(_0) => Lit::Byte((*_0).ref_into()),
             syn::Lit::Char(_0) => Lit::Char((*_0).ref_into()),
             syn::Lit::Int(_0) => Lit::Int((*_0).ref_into()),
@@ -2170,6 +2266,7 @@ impl From<&Lit> for syn::Lit {
         match node {
             Lit::Str(_0) => syn::Lit::Str((*_0).ref_into()),
             Lit::ByteStr(_0) => syn::Lit::ByteStr((*_0).ref_into()),
+            Lit::CStr(_0) => syn::Lit::CStr((*_0).ref_into()),
             Lit::Byte(_0) => syn::Lit::Byte((*_0).ref_into()),
             Lit::Char(_0) => syn::Lit::Char((*_0).ref_into()),
             Lit::Int(_0) => syn::Lit::Int((*_0).ref_into()),
@@ -2185,6 +2282,7 @@ impl From<&syn::LitBool> for LitBool {
         Self {
             value: node.value,
             span: node.span.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2204,6 +2302,7 @@ impl From<&syn::Local> for Local {
             pat: node.pat.ref_into(),
             init: node.init.map_into(),
             span: Some(crate::SpanInfo::from_span(node.pat.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2318,6 +2417,7 @@ impl From<&syn::MetaList> for MetaList {
             path: node.path.ref_into(),
             delimiter: node.delimiter.ref_into(),
             tokens: node.tokens.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2336,6 +2436,7 @@ impl From<&syn::MetaNameValue> for MetaNameValue {
         Self {
             path: node.path.ref_into(),
             value: node.value.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2354,6 +2455,7 @@ impl From<&syn::ParenthesizedGenericArguments> for ParenthesizedGenericArguments
         Self {
             inputs: node.inputs.map_into(),
             output: node.output.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2424,6 +2526,7 @@ impl From<&syn::PatIdent> for PatIdent {
             ident: node.ident.ref_into(),
             subpat: node.subpat.ref_map(|(_0, _1)| (*_1).map_into()),
             span: Some(crate::SpanInfo::from_span(node.ident.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2445,6 +2548,7 @@ impl From<&syn::PatOr> for PatOr {
             attrs: node.attrs.map_into(),
             leading_vert: node.leading_vert.is_some(),
             cases: node.cases.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2463,6 +2567,7 @@ impl From<&syn::PatParen> for PatParen {
         Self {
             attrs: node.attrs.map_into(),
             pat: node.pat.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2482,6 +2587,7 @@ impl From<&syn::PatReference> for PatReference {
             attrs: node.attrs.map_into(),
             mutability: node.mutability.is_some(),
             pat: node.pat.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2500,6 +2606,7 @@ impl From<&syn::PatRest> for PatRest {
     fn from(node: &syn::PatRest) -> Self {
         Self {
             attrs: node.attrs.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2517,6 +2624,7 @@ impl From<&syn::PatSlice> for PatSlice {
         Self {
             attrs: node.attrs.map_into(),
             elems: node.elems.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2539,6 +2647,7 @@ impl From<&syn::PatStruct> for PatStruct {
             fields: node.fields.map_into(),
             rest: node.rest.map_into(),
             span: Some(crate::SpanInfo::from_span(proc_macro2::Span::call_site())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2561,6 +2670,7 @@ impl From<&syn::PatTuple> for PatTuple {
             attrs: node.attrs.map_into(),
             elems: node.elems.map_into(),
             span: Some(crate::SpanInfo::from_span(proc_macro2::Span::call_site())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2581,6 +2691,7 @@ impl From<&syn::PatTupleStruct> for PatTupleStruct {
             qself: node.qself.map_into(),
             path: node.path.ref_into(),
             elems: node.elems.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2602,6 +2713,7 @@ impl From<&syn::PatType> for PatType {
             attrs: node.attrs.map_into(),
             pat: node.pat.map_into(),
             ty: node.ty.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2620,6 +2732,7 @@ impl From<&syn::PatWild> for PatWild {
     fn from(node: &syn::PatWild) -> Self {
         Self {
             attrs: node.attrs.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2691,6 +2804,7 @@ impl From<&syn::PathSegment> for PathSegment {
             ident: node.ident.ref_into(),
             arguments: node.arguments.ref_into(),
             span: Some(crate::SpanInfo::from_span(node.ident.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2708,6 +2822,7 @@ impl From<&syn::PredicateLifetime> for PredicateLifetime {
         Self {
             lifetime: node.lifetime.ref_into(),
             bounds: node.bounds.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2727,6 +2842,7 @@ impl From<&syn::PredicateType> for PredicateType {
             lifetimes: node.lifetimes.map_into(),
             bounded_ty: node.bounded_ty.ref_into(),
             bounds: node.bounds.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2835,7 +2951,12 @@ impl From<&syn::Stmt> for Stmt {
         match node {
             syn::Stmt::Local(_0) => Stmt::Local((*_0).ref_into()),
             syn::Stmt::Item(_0) => Stmt::Item((*_0).ref_into()),
-            syn::Stmt::Expr(_0, _1) => Stmt::Expr((*_0).ref_into(), (*_1).is_some()),
+            syn::Stmt::Expr(_0, _1) => {
+                Stmt::Expr {
+                    expr: (*_0).ref_into(),
+                    semi: (*_1).is_some(),
+                }
+            }
             syn::Stmt::Macro(_0) => Stmt::Macro((*_0).ref_into()),
         }
     }
@@ -2845,7 +2966,7 @@ impl From<&Stmt> for syn::Stmt {
         match node {
             Stmt::Local(_0) => syn::Stmt::Local((*_0).ref_into()),
             Stmt::Item(_0) => syn::Stmt::Item((*_0).ref_into()),
-            Stmt::Expr(_0, _1) => {
+            Stmt::Expr { expr: _0, semi: _1 } => {
                 syn::Stmt::Expr((*_0).ref_into(), default_or_none((*_1)))
             }
             Stmt::Macro(_0) => syn::Stmt::Macro((*_0).ref_into()),
@@ -2859,6 +2980,7 @@ impl From<&syn::StmtMacro> for StmtMacro {
             attrs: node.attrs.map_into(),
             mac: node.mac.ref_into(),
             semi_token: node.semi_token.is_some(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2879,6 +3001,7 @@ impl From<&syn::TraitBound> for TraitBound {
             modifier: node.modifier.ref_into(),
             lifetimes: node.lifetimes.map_into(),
             path: node.path.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2942,6 +3065,8 @@ impl From<&syn::TraitItemConst> for TraitItemConst {
             generics: node.generics.ref_into(),
             ty: node.ty.ref_into(),
             default: node.default.ref_map(|(_0, _1)| (*_1).ref_into()),
+            span: Some(crate::SpanInfo::from_span(node.ident.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2966,6 +3091,7 @@ impl From<&syn::TraitItemMacro> for TraitItemMacro {
             attrs: node.attrs.map_into(),
             mac: node.mac.ref_into(),
             semi_token: node.semi_token.is_some(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -2988,6 +3114,8 @@ impl From<&syn::TraitItemType> for TraitItemType {
             colon_token: node.colon_token.is_some(),
             bounds: node.bounds.map_into(),
             default: node.default.ref_map(|(_0, _1)| (*_1).ref_into()),
+            span: Some(crate::SpanInfo::from_span(node.ident.span())),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3063,6 +3191,7 @@ impl From<&syn::TypeArray> for TypeArray {
         Self {
             elem: node.elem.map_into(),
             len: node.len.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3086,6 +3215,7 @@ impl From<&syn::TypeBareFn> for TypeBareFn {
             inputs: node.inputs.map_into(),
             variadic: node.variadic.map_into(),
             output: node.output.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3106,7 +3236,10 @@ impl From<&TypeBareFn> for syn::TypeBareFn {
 syn_trait_impl!(syn::TypeGroup);
 impl From<&syn::TypeGroup> for TypeGroup {
     fn from(node: &syn::TypeGroup) -> Self {
-        Self { elem: node.elem.map_into() }
+        Self {
+            elem: node.elem.map_into(),
+            trivia: crate::trivia::take(node),
+        }
     }
 }
 impl From<&TypeGroup> for syn::TypeGroup {
@@ -3122,6 +3255,7 @@ impl From<&syn::TypeImplTrait> for TypeImplTrait {
     fn from(node: &syn::TypeImplTrait) -> Self {
         Self {
             bounds: node.bounds.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3136,7 +3270,10 @@ impl From<&TypeImplTrait> for syn::TypeImplTrait {
 syn_trait_impl!(syn::TypeMacro);
 impl From<&syn::TypeMacro> for TypeMacro {
     fn from(node: &syn::TypeMacro) -> Self {
-        Self { mac: node.mac.ref_into() }
+        Self {
+            mac: node.mac.ref_into(),
+            trivia: crate::trivia::take(node),
+        }
     }
 }
 impl From<&TypeMacro> for syn::TypeMacro {
@@ -3154,6 +3291,7 @@ impl From<&syn::TypeParam> for TypeParam {
             bounds: node.bounds.map_into(),
             eq_token: node.eq_token.is_some(),
             default: node.default.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3200,7 +3338,10 @@ impl From<&TypeParamBound> for syn::TypeParamBound {
 syn_trait_impl!(syn::TypeParen);
 impl From<&syn::TypeParen> for TypeParen {
     fn from(node: &syn::TypeParen) -> Self {
-        Self { elem: node.elem.map_into() }
+        Self {
+            elem: node.elem.map_into(),
+            trivia: crate::trivia::take(node),
+        }
     }
 }
 impl From<&TypeParen> for syn::TypeParen {
@@ -3217,6 +3358,7 @@ impl From<&syn::TypePath> for TypePath {
         Self {
             qself: node.qself.map_into(),
             path: node.path.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3235,6 +3377,7 @@ impl From<&syn::TypePtr> for TypePtr {
             const_token: node.const_token.is_some(),
             mutability: node.mutability.is_some(),
             elem: node.elem.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3255,6 +3398,7 @@ impl From<&syn::TypeReference> for TypeReference {
             lifetime: node.lifetime.map_into(),
             mutability: node.mutability.is_some(),
             elem: node.elem.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3271,7 +3415,10 @@ impl From<&TypeReference> for syn::TypeReference {
 syn_trait_impl!(syn::TypeSlice);
 impl From<&syn::TypeSlice> for TypeSlice {
     fn from(node: &syn::TypeSlice) -> Self {
-        Self { elem: node.elem.map_into() }
+        Self {
+            elem: node.elem.map_into(),
+            trivia: crate::trivia::take(node),
+        }
     }
 }
 impl From<&TypeSlice> for syn::TypeSlice {
@@ -3288,6 +3435,7 @@ impl From<&syn::TypeTraitObject> for TypeTraitObject {
         Self {
             dyn_token: node.dyn_token.is_some(),
             bounds: node.bounds.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3304,6 +3452,7 @@ impl From<&syn::TypeTuple> for TypeTuple {
     fn from(node: &syn::TypeTuple) -> Self {
         Self {
             elems: node.elems.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3372,6 +3521,7 @@ impl From<&syn::UsePath> for UsePath {
         Self {
             ident: node.ident.ref_into(),
             tree: node.tree.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3390,6 +3540,7 @@ impl From<&syn::UseRename> for UseRename {
         Self {
             ident: node.ident.ref_into(),
             rename: node.rename.ref_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3436,6 +3587,7 @@ impl From<&syn::Variadic> for Variadic {
             attrs: node.attrs.map_into(),
             pat: node.pat.ref_map(|(_0, _1)| (*_0).map_into()),
             comma: node.comma.is_some(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3457,6 +3609,7 @@ impl From<&syn::Variant> for Variant {
             ident: node.ident.ref_into(),
             fields: node.fields.ref_into(),
             discriminant: node.discriminant.ref_map(|(_0, _1)| (*_1).ref_into()),
+            trivia: crate::trivia::take(node),
         }
     }
 }
@@ -3476,6 +3629,7 @@ impl From<&syn::VisRestricted> for VisRestricted {
         Self {
             in_token: node.in_token.is_some(),
             path: node.path.map_into(),
+            trivia: crate::trivia::take(node),
         }
     }
 }
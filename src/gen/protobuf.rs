@@ -0,0 +1,6303 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// This file is @generated by syn-serde-internal-codegen
+// (generate function at tools/codegen/src/protobuf.rs).
+// It is not intended for manual editing.
+
+#![cfg_attr(rustfmt, rustfmt::skip)]
+use super::{Field, Kind, Label, Message, Ty};
+/// The messages of the adapters, sorted by name. The field numbers
+/// are the indices of the fields plus one.
+pub(super) static MESSAGES: &[Message] = &[
+    Message {
+        name: "Abi",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "name",
+                label: Label::Optional,
+                ty: Ty::Message("Literal"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "AngleBracketedGenericArguments",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "colon2_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "args",
+                label: Label::Repeated,
+                ty: Ty::Message("GenericArgument"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Arm",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "pat",
+                label: Label::Singular,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "guard",
+                label: Label::Optional,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "body",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "comma",
+                label: Label::Optional,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "AssocConst",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Optional,
+                ty: Ty::Message("AngleBracketedGenericArguments"),
+                flatten: false,
+            },
+            Field {
+                key: "value",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "AssocType",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Optional,
+                ty: Ty::Message("AngleBracketedGenericArguments"),
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "AttrStyle",
+        kind: Kind::Enum,
+        fields: &[
+            Field {
+                key: "outer",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "inner",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Attribute",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "style",
+                label: Label::Singular,
+                ty: Ty::Enum("AttrStyle"),
+                flatten: false,
+            },
+            Field {
+                key: "meta",
+                label: Label::Singular,
+                ty: Ty::Message("Meta"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "BareFnArg",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "name",
+                label: Label::Optional,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "BareVariadic",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "name",
+                label: Label::Optional,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "comma",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "BinOp",
+        kind: Kind::Enum,
+        fields: &[
+            Field {
+                key: "+",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "-",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "*",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "/",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "%",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "&&",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "||",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "^",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "&",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "|",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "<<",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: ">>",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "==",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "<",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "<=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "!=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: ">=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: ">",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "+=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "-=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "*=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "/=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "%=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "^=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "&=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "|=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "<<=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: ">>=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Block",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "stmts",
+                label: Label::Repeated,
+                ty: Ty::Message("Stmt"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "BoundLifetimes",
+        kind: Kind::Transparent,
+        fields: &[
+            Field {
+                key: "lifetimes",
+                label: Label::Repeated,
+                ty: Ty::Message("GenericParam"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Comment",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "text",
+                label: Label::Singular,
+                ty: Ty::String,
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Singular,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "kind",
+                label: Label::Singular,
+                ty: Ty::Enum("CommentKind"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "CommentKind",
+        kind: Kind::Enum,
+        fields: &[
+            Field {
+                key: "Line",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "Block",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ConstParam",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "eq_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "default",
+                label: Label::Optional,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Constraint",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Optional,
+                ty: Ty::Message("AngleBracketedGenericArguments"),
+                flatten: false,
+            },
+            Field {
+                key: "bounds",
+                label: Label::Repeated,
+                ty: Ty::Message("TypeParamBound"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Delimiter",
+        kind: Kind::Enum,
+        fields: &[
+            Field {
+                key: "parenthesis",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "brace",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "bracket",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "none",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Expr",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "array",
+                label: Label::Singular,
+                ty: Ty::Message("ExprArray"),
+                flatten: false,
+            },
+            Field {
+                key: "assign",
+                label: Label::Singular,
+                ty: Ty::Message("ExprAssign"),
+                flatten: false,
+            },
+            Field {
+                key: "async",
+                label: Label::Singular,
+                ty: Ty::Message("ExprAsync"),
+                flatten: false,
+            },
+            Field {
+                key: "await",
+                label: Label::Singular,
+                ty: Ty::Message("ExprAwait"),
+                flatten: false,
+            },
+            Field {
+                key: "binary",
+                label: Label::Singular,
+                ty: Ty::Message("ExprBinary"),
+                flatten: false,
+            },
+            Field {
+                key: "block",
+                label: Label::Singular,
+                ty: Ty::Message("ExprBlock"),
+                flatten: false,
+            },
+            Field {
+                key: "break",
+                label: Label::Singular,
+                ty: Ty::Message("ExprBreak"),
+                flatten: false,
+            },
+            Field {
+                key: "call",
+                label: Label::Singular,
+                ty: Ty::Message("ExprCall"),
+                flatten: false,
+            },
+            Field {
+                key: "cast",
+                label: Label::Singular,
+                ty: Ty::Message("ExprCast"),
+                flatten: false,
+            },
+            Field {
+                key: "closure",
+                label: Label::Singular,
+                ty: Ty::Message("ExprClosure"),
+                flatten: false,
+            },
+            Field {
+                key: "const",
+                label: Label::Singular,
+                ty: Ty::Message("ExprConst"),
+                flatten: false,
+            },
+            Field {
+                key: "continue",
+                label: Label::Singular,
+                ty: Ty::Message("ExprContinue"),
+                flatten: false,
+            },
+            Field {
+                key: "field",
+                label: Label::Singular,
+                ty: Ty::Message("ExprField"),
+                flatten: false,
+            },
+            Field {
+                key: "for_loop",
+                label: Label::Singular,
+                ty: Ty::Message("ExprForLoop"),
+                flatten: false,
+            },
+            Field {
+                key: "group",
+                label: Label::Singular,
+                ty: Ty::Message("ExprGroup"),
+                flatten: false,
+            },
+            Field {
+                key: "if",
+                label: Label::Singular,
+                ty: Ty::Message("ExprIf"),
+                flatten: false,
+            },
+            Field {
+                key: "index",
+                label: Label::Singular,
+                ty: Ty::Message("ExprIndex"),
+                flatten: false,
+            },
+            Field {
+                key: "infer",
+                label: Label::Singular,
+                ty: Ty::Message("ExprInfer"),
+                flatten: false,
+            },
+            Field {
+                key: "let",
+                label: Label::Singular,
+                ty: Ty::Message("ExprLet"),
+                flatten: false,
+            },
+            Field {
+                key: "lit",
+                label: Label::Singular,
+                ty: Ty::Message("ExprLit"),
+                flatten: false,
+            },
+            Field {
+                key: "loop",
+                label: Label::Singular,
+                ty: Ty::Message("ExprLoop"),
+                flatten: false,
+            },
+            Field {
+                key: "macro",
+                label: Label::Singular,
+                ty: Ty::Message("ExprMacro"),
+                flatten: false,
+            },
+            Field {
+                key: "match",
+                label: Label::Singular,
+                ty: Ty::Message("ExprMatch"),
+                flatten: false,
+            },
+            Field {
+                key: "method_call",
+                label: Label::Singular,
+                ty: Ty::Message("ExprMethodCall"),
+                flatten: false,
+            },
+            Field {
+                key: "paren",
+                label: Label::Singular,
+                ty: Ty::Message("ExprParen"),
+                flatten: false,
+            },
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("ExprPath"),
+                flatten: false,
+            },
+            Field {
+                key: "range",
+                label: Label::Singular,
+                ty: Ty::Message("ExprRange"),
+                flatten: false,
+            },
+            Field {
+                key: "reference",
+                label: Label::Singular,
+                ty: Ty::Message("ExprReference"),
+                flatten: false,
+            },
+            Field {
+                key: "repeat",
+                label: Label::Singular,
+                ty: Ty::Message("ExprRepeat"),
+                flatten: false,
+            },
+            Field {
+                key: "return",
+                label: Label::Singular,
+                ty: Ty::Message("ExprReturn"),
+                flatten: false,
+            },
+            Field {
+                key: "struct",
+                label: Label::Singular,
+                ty: Ty::Message("ExprStruct"),
+                flatten: false,
+            },
+            Field {
+                key: "try",
+                label: Label::Singular,
+                ty: Ty::Message("ExprTry"),
+                flatten: false,
+            },
+            Field {
+                key: "try_block",
+                label: Label::Singular,
+                ty: Ty::Message("ExprTryBlock"),
+                flatten: false,
+            },
+            Field {
+                key: "tuple",
+                label: Label::Singular,
+                ty: Ty::Message("ExprTuple"),
+                flatten: false,
+            },
+            Field {
+                key: "unary",
+                label: Label::Singular,
+                ty: Ty::Message("ExprUnary"),
+                flatten: false,
+            },
+            Field {
+                key: "unsafe",
+                label: Label::Singular,
+                ty: Ty::Message("ExprUnsafe"),
+                flatten: false,
+            },
+            Field {
+                key: "verbatim",
+                label: Label::Singular,
+                ty: Ty::Message("TokenStream"),
+                flatten: false,
+            },
+            Field {
+                key: "while",
+                label: Label::Singular,
+                ty: Ty::Message("ExprWhile"),
+                flatten: false,
+            },
+            Field {
+                key: "yield",
+                label: Label::Singular,
+                ty: Ty::Message("ExprYield"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprArray",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "elems",
+                label: Label::Repeated,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprAssign",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "left",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "right",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprAsync",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "move",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "stmts",
+                label: Label::Singular,
+                ty: Ty::Message("Block"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprAwait",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "base",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprBinary",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "left",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "op",
+                label: Label::Singular,
+                ty: Ty::Enum("BinOp"),
+                flatten: false,
+            },
+            Field {
+                key: "right",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprBlock",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "label",
+                label: Label::Optional,
+                ty: Ty::Message("Label"),
+                flatten: false,
+            },
+            Field {
+                key: "stmts",
+                label: Label::Singular,
+                ty: Ty::Message("Block"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprBreak",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "label",
+                label: Label::Optional,
+                ty: Ty::Message("Lifetime"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Optional,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprCall",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "func",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "args",
+                label: Label::Repeated,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprCast",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprClosure",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "lifetimes",
+                label: Label::Optional,
+                ty: Ty::Message("BoundLifetimes"),
+                flatten: false,
+            },
+            Field {
+                key: "const",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "static",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "async",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "move",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "inputs",
+                label: Label::Repeated,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "output",
+                label: Label::Singular,
+                ty: Ty::Message("ReturnType"),
+                flatten: false,
+            },
+            Field {
+                key: "body",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprConst",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "stmts",
+                label: Label::Singular,
+                ty: Ty::Message("Block"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprContinue",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "label",
+                label: Label::Optional,
+                ty: Ty::Message("Lifetime"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprField",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "base",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "member",
+                label: Label::Singular,
+                ty: Ty::Message("Member"),
+                flatten: true,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprForLoop",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "label",
+                label: Label::Optional,
+                ty: Ty::Message("Label"),
+                flatten: false,
+            },
+            Field {
+                key: "pat",
+                label: Label::Singular,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "body",
+                label: Label::Singular,
+                ty: Ty::Message("Block"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprGroup",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprIf",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "cond",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "then_branch",
+                label: Label::Singular,
+                ty: Ty::Message("Block"),
+                flatten: false,
+            },
+            Field {
+                key: "else_branch",
+                label: Label::Optional,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprIndex",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "index",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprInfer",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprLet",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "pat",
+                label: Label::Singular,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprLit",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "lit",
+                label: Label::Singular,
+                ty: Ty::Message("Lit"),
+                flatten: true,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprLoop",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "label",
+                label: Label::Optional,
+                ty: Ty::Message("Label"),
+                flatten: false,
+            },
+            Field {
+                key: "body",
+                label: Label::Singular,
+                ty: Ty::Message("Block"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprMacro",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "mac",
+                label: Label::Singular,
+                ty: Ty::Message("Macro"),
+                flatten: true,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprMatch",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "arms",
+                label: Label::Repeated,
+                ty: Ty::Message("Arm"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprMethodCall",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "receiver",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "method",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "turbofish",
+                label: Label::Optional,
+                ty: Ty::Message("AngleBracketedGenericArguments"),
+                flatten: false,
+            },
+            Field {
+                key: "args",
+                label: Label::Repeated,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprParen",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprPath",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "qself",
+                label: Label::Optional,
+                ty: Ty::Message("QSelf"),
+                flatten: false,
+            },
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("Path"),
+                flatten: true,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprRange",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "start",
+                label: Label::Optional,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "limits",
+                label: Label::Singular,
+                ty: Ty::Enum("RangeLimits"),
+                flatten: false,
+            },
+            Field {
+                key: "end",
+                label: Label::Optional,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprReference",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "mut",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprRepeat",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "len",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprReturn",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Optional,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprStruct",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "qself",
+                label: Label::Optional,
+                ty: Ty::Message("QSelf"),
+                flatten: false,
+            },
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("Path"),
+                flatten: false,
+            },
+            Field {
+                key: "fields",
+                label: Label::Repeated,
+                ty: Ty::Message("FieldValue"),
+                flatten: false,
+            },
+            Field {
+                key: "dot2_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "rest",
+                label: Label::Optional,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprTry",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprTryBlock",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "stmts",
+                label: Label::Singular,
+                ty: Ty::Message("Block"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprTuple",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "elems",
+                label: Label::Repeated,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprUnary",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "op",
+                label: Label::Singular,
+                ty: Ty::Enum("UnOp"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprUnsafe",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "stmts",
+                label: Label::Singular,
+                ty: Ty::Message("Block"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprWhile",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "label",
+                label: Label::Optional,
+                ty: Ty::Message("Label"),
+                flatten: false,
+            },
+            Field {
+                key: "cond",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "body",
+                label: Label::Singular,
+                ty: Ty::Message("Block"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ExprYield",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Optional,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Field",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "mut",
+                label: Label::Singular,
+                ty: Ty::Enum("FieldMutability"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Optional,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "colon_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "FieldMutability",
+        kind: Kind::Enum,
+        fields: &[
+            Field {
+                key: "none",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "FieldPat",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "member",
+                label: Label::Singular,
+                ty: Ty::Message("Member"),
+                flatten: true,
+            },
+            Field {
+                key: "colon_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "pat",
+                label: Label::Singular,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "FieldValue",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "member",
+                label: Label::Singular,
+                ty: Ty::Message("Member"),
+                flatten: true,
+            },
+            Field {
+                key: "colon_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Fields",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "named",
+                label: Label::Singular,
+                ty: Ty::Message("FieldsNamed"),
+                flatten: false,
+            },
+            Field {
+                key: "unnamed",
+                label: Label::Singular,
+                ty: Ty::Message("FieldsUnnamed"),
+                flatten: false,
+            },
+            Field {
+                key: "unit",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "FieldsNamed",
+        kind: Kind::Transparent,
+        fields: &[
+            Field {
+                key: "named",
+                label: Label::Repeated,
+                ty: Ty::Message("Field"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "FieldsUnnamed",
+        kind: Kind::Transparent,
+        fields: &[
+            Field {
+                key: "unnamed",
+                label: Label::Repeated,
+                ty: Ty::Message("Field"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "File",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "shebang",
+                label: Label::Optional,
+                ty: Ty::String,
+                flatten: false,
+            },
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "items",
+                label: Label::Repeated,
+                ty: Ty::Message("Item"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Optional,
+                ty: Ty::Message("Trivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "FnArg",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "receiver",
+                label: Label::Singular,
+                ty: Ty::Message("Receiver"),
+                flatten: false,
+            },
+            Field {
+                key: "typed",
+                label: Label::Singular,
+                ty: Ty::Message("PatType"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ForeignItem",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "fn",
+                label: Label::Singular,
+                ty: Ty::Message("ForeignItemFn"),
+                flatten: false,
+            },
+            Field {
+                key: "static",
+                label: Label::Singular,
+                ty: Ty::Message("ForeignItemStatic"),
+                flatten: false,
+            },
+            Field {
+                key: "type",
+                label: Label::Singular,
+                ty: Ty::Message("ForeignItemType"),
+                flatten: false,
+            },
+            Field {
+                key: "macro",
+                label: Label::Singular,
+                ty: Ty::Message("ForeignItemMacro"),
+                flatten: false,
+            },
+            Field {
+                key: "verbatim",
+                label: Label::Singular,
+                ty: Ty::Message("TokenStream"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ForeignItemFn",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "sig",
+                label: Label::Singular,
+                ty: Ty::Message("Signature"),
+                flatten: true,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ForeignItemMacro",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "mac",
+                label: Label::Singular,
+                ty: Ty::Message("Macro"),
+                flatten: true,
+            },
+            Field {
+                key: "semi_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ForeignItemStatic",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "mut",
+                label: Label::Singular,
+                ty: Ty::Enum("StaticMutability"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ForeignItemType",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "GenericArgument",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "lifetime",
+                label: Label::Singular,
+                ty: Ty::Message("Lifetime"),
+                flatten: false,
+            },
+            Field {
+                key: "type",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "const",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "assoc_type",
+                label: Label::Singular,
+                ty: Ty::Message("AssocType"),
+                flatten: false,
+            },
+            Field {
+                key: "assoc_const",
+                label: Label::Singular,
+                ty: Ty::Message("AssocConst"),
+                flatten: false,
+            },
+            Field {
+                key: "constraint",
+                label: Label::Singular,
+                ty: Ty::Message("Constraint"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "GenericParam",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "lifetime",
+                label: Label::Singular,
+                ty: Ty::Message("LifetimeParam"),
+                flatten: false,
+            },
+            Field {
+                key: "type",
+                label: Label::Singular,
+                ty: Ty::Message("TypeParam"),
+                flatten: false,
+            },
+            Field {
+                key: "const",
+                label: Label::Singular,
+                ty: Ty::Message("ConstParam"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Generics",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "lt_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "params",
+                label: Label::Repeated,
+                ty: Ty::Message("GenericParam"),
+                flatten: false,
+            },
+            Field {
+                key: "gt_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "where_clause",
+                label: Label::Optional,
+                ty: Ty::Message("WhereClause"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Group",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "delimiter",
+                label: Label::Singular,
+                ty: Ty::Enum("Delimiter"),
+                flatten: false,
+            },
+            Field {
+                key: "stream",
+                label: Label::Singular,
+                ty: Ty::Message("TokenStream"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "span_open",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "span_close",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Ident",
+        kind: Kind::Token,
+        fields: &[
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::String,
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ImplItem",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "const",
+                label: Label::Singular,
+                ty: Ty::Message("ImplItemConst"),
+                flatten: false,
+            },
+            Field {
+                key: "fn",
+                label: Label::Singular,
+                ty: Ty::Message("ImplItemFn"),
+                flatten: false,
+            },
+            Field {
+                key: "type",
+                label: Label::Singular,
+                ty: Ty::Message("ImplItemType"),
+                flatten: false,
+            },
+            Field {
+                key: "macro",
+                label: Label::Singular,
+                ty: Ty::Message("ImplItemMacro"),
+                flatten: false,
+            },
+            Field {
+                key: "verbatim",
+                label: Label::Singular,
+                ty: Ty::Message("TokenStream"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ImplItemConst",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "default",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ImplItemFn",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "default",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "sig",
+                label: Label::Singular,
+                ty: Ty::Message("Signature"),
+                flatten: true,
+            },
+            Field {
+                key: "stmts",
+                label: Label::Singular,
+                ty: Ty::Message("Block"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ImplItemMacro",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "mac",
+                label: Label::Singular,
+                ty: Ty::Message("Macro"),
+                flatten: true,
+            },
+            Field {
+                key: "semi_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ImplItemType",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "default",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ImplRestriction",
+        kind: Kind::Oneof,
+        fields: &[],
+    },
+    Message {
+        name: "Index",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "index",
+                label: Label::Singular,
+                ty: Ty::Uint32,
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Singular,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Item",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "const",
+                label: Label::Singular,
+                ty: Ty::Message("ItemConst"),
+                flatten: false,
+            },
+            Field {
+                key: "enum",
+                label: Label::Singular,
+                ty: Ty::Message("ItemEnum"),
+                flatten: false,
+            },
+            Field {
+                key: "extern_crate",
+                label: Label::Singular,
+                ty: Ty::Message("ItemExternCrate"),
+                flatten: false,
+            },
+            Field {
+                key: "fn",
+                label: Label::Singular,
+                ty: Ty::Message("ItemFn"),
+                flatten: false,
+            },
+            Field {
+                key: "foreign_mod",
+                label: Label::Singular,
+                ty: Ty::Message("ItemForeignMod"),
+                flatten: false,
+            },
+            Field {
+                key: "impl",
+                label: Label::Singular,
+                ty: Ty::Message("ItemImpl"),
+                flatten: false,
+            },
+            Field {
+                key: "macro",
+                label: Label::Singular,
+                ty: Ty::Message("ItemMacro"),
+                flatten: false,
+            },
+            Field {
+                key: "mod",
+                label: Label::Singular,
+                ty: Ty::Message("ItemMod"),
+                flatten: false,
+            },
+            Field {
+                key: "static",
+                label: Label::Singular,
+                ty: Ty::Message("ItemStatic"),
+                flatten: false,
+            },
+            Field {
+                key: "struct",
+                label: Label::Singular,
+                ty: Ty::Message("ItemStruct"),
+                flatten: false,
+            },
+            Field {
+                key: "trait",
+                label: Label::Singular,
+                ty: Ty::Message("ItemTrait"),
+                flatten: false,
+            },
+            Field {
+                key: "trait_alias",
+                label: Label::Singular,
+                ty: Ty::Message("ItemTraitAlias"),
+                flatten: false,
+            },
+            Field {
+                key: "type",
+                label: Label::Singular,
+                ty: Ty::Message("ItemType"),
+                flatten: false,
+            },
+            Field {
+                key: "union",
+                label: Label::Singular,
+                ty: Ty::Message("ItemUnion"),
+                flatten: false,
+            },
+            Field {
+                key: "use",
+                label: Label::Singular,
+                ty: Ty::Message("ItemUse"),
+                flatten: false,
+            },
+            Field {
+                key: "verbatim",
+                label: Label::Singular,
+                ty: Ty::Message("TokenStream"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemConst",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemEnum",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "variants",
+                label: Label::Repeated,
+                ty: Ty::Message("Variant"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemExternCrate",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "rename",
+                label: Label::Optional,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemFn",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "sig",
+                label: Label::Singular,
+                ty: Ty::Message("Signature"),
+                flatten: true,
+            },
+            Field {
+                key: "stmts",
+                label: Label::Singular,
+                ty: Ty::Message("Block"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemForeignMod",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "unsafe",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "abi",
+                label: Label::Singular,
+                ty: Ty::Message("Abi"),
+                flatten: false,
+            },
+            Field {
+                key: "items",
+                label: Label::Repeated,
+                ty: Ty::Message("ForeignItem"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemImpl",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "default",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "unsafe",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "trait",
+                label: Label::Optional,
+                ty: Ty::Message("ItemImplTrait"),
+                flatten: false,
+            },
+            Field {
+                key: "self_ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "items",
+                label: Label::Repeated,
+                ty: Ty::Message("ImplItem"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemImplTrait",
+        kind: Kind::Tuple,
+        fields: &[
+            Field {
+                key: "elem0",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "elem1",
+                label: Label::Singular,
+                ty: Ty::Message("Path"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemMacro",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Optional,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "mac",
+                label: Label::Singular,
+                ty: Ty::Message("Macro"),
+                flatten: true,
+            },
+            Field {
+                key: "semi_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemMod",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "unsafe",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "content",
+                label: Label::Optional,
+                ty: Ty::Message("ItemModContent"),
+                flatten: false,
+            },
+            Field {
+                key: "semi",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemModContent",
+        kind: Kind::Transparent,
+        fields: &[
+            Field {
+                key: "elems",
+                label: Label::Repeated,
+                ty: Ty::Message("Item"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemStatic",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "mut",
+                label: Label::Singular,
+                ty: Ty::Enum("StaticMutability"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemStruct",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "fields",
+                label: Label::Singular,
+                ty: Ty::Message("Fields"),
+                flatten: false,
+            },
+            Field {
+                key: "semi_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemTrait",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "unsafe",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "auto",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "restriction",
+                label: Label::Optional,
+                ty: Ty::Message("ImplRestriction"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "colon_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "supertraits",
+                label: Label::Repeated,
+                ty: Ty::Message("TypeParamBound"),
+                flatten: false,
+            },
+            Field {
+                key: "items",
+                label: Label::Repeated,
+                ty: Ty::Message("TraitItem"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemTraitAlias",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "bounds",
+                label: Label::Repeated,
+                ty: Ty::Message("TypeParamBound"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemType",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemUnion",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "fields",
+                label: Label::Singular,
+                ty: Ty::Message("FieldsNamed"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ItemUse",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "vis",
+                label: Label::Singular,
+                ty: Ty::Message("Visibility"),
+                flatten: false,
+            },
+            Field {
+                key: "leading_colon",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "tree",
+                label: Label::Singular,
+                ty: Ty::Message("UseTree"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "comments",
+                label: Label::Repeated,
+                ty: Ty::Message("Comment"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Label",
+        kind: Kind::Transparent,
+        fields: &[
+            Field {
+                key: "name",
+                label: Label::Singular,
+                ty: Ty::Message("Lifetime"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Lifetime",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "apostrophe",
+                label: Label::Singular,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "LifetimeParam",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "lifetime",
+                label: Label::Singular,
+                ty: Ty::Message("Lifetime"),
+                flatten: false,
+            },
+            Field {
+                key: "colon_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "bounds",
+                label: Label::Repeated,
+                ty: Ty::Message("Lifetime"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Lit",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "str",
+                label: Label::Singular,
+                ty: Ty::Message("Literal"),
+                flatten: false,
+            },
+            Field {
+                key: "byte_str",
+                label: Label::Singular,
+                ty: Ty::Message("Literal"),
+                flatten: false,
+            },
+            Field {
+                key: "c_str",
+                label: Label::Singular,
+                ty: Ty::Message("Literal"),
+                flatten: false,
+            },
+            Field {
+                key: "byte",
+                label: Label::Singular,
+                ty: Ty::Message("Literal"),
+                flatten: false,
+            },
+            Field {
+                key: "char",
+                label: Label::Singular,
+                ty: Ty::Message("Literal"),
+                flatten: false,
+            },
+            Field {
+                key: "int",
+                label: Label::Singular,
+                ty: Ty::Message("Literal"),
+                flatten: false,
+            },
+            Field {
+                key: "float",
+                label: Label::Singular,
+                ty: Ty::Message("Literal"),
+                flatten: false,
+            },
+            Field {
+                key: "bool",
+                label: Label::Singular,
+                ty: Ty::Message("LitBool"),
+                flatten: false,
+            },
+            Field {
+                key: "verbatim",
+                label: Label::Singular,
+                ty: Ty::Message("Literal"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "LitBool",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "value",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Singular,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Literal",
+        kind: Kind::Token,
+        fields: &[
+            Field {
+                key: "lit",
+                label: Label::Singular,
+                ty: Ty::String,
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Local",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "pat",
+                label: Label::Singular,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "init",
+                label: Label::Optional,
+                ty: Ty::Message("LocalInit"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "LocalInit",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "diverge",
+                label: Label::Optional,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Macro",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("Path"),
+                flatten: false,
+            },
+            Field {
+                key: "delimiter",
+                label: Label::Singular,
+                ty: Ty::Enum("MacroDelimiter"),
+                flatten: false,
+            },
+            Field {
+                key: "tokens",
+                label: Label::Singular,
+                ty: Ty::Message("TokenStream"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "MacroDelimiter",
+        kind: Kind::Enum,
+        fields: &[
+            Field {
+                key: "paren",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "brace",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "bracket",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Member",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "index",
+                label: Label::Singular,
+                ty: Ty::Message("Index"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Meta",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("Path"),
+                flatten: false,
+            },
+            Field {
+                key: "list",
+                label: Label::Singular,
+                ty: Ty::Message("MetaList"),
+                flatten: false,
+            },
+            Field {
+                key: "name_value",
+                label: Label::Singular,
+                ty: Ty::Message("MetaNameValue"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "MetaList",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("Path"),
+                flatten: false,
+            },
+            Field {
+                key: "delimiter",
+                label: Label::Singular,
+                ty: Ty::Enum("MacroDelimiter"),
+                flatten: false,
+            },
+            Field {
+                key: "tokens",
+                label: Label::Singular,
+                ty: Ty::Message("TokenStream"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "MetaNameValue",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("Path"),
+                flatten: false,
+            },
+            Field {
+                key: "value",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ParenthesizedGenericArguments",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "inputs",
+                label: Label::Repeated,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "output",
+                label: Label::Singular,
+                ty: Ty::Message("ReturnType"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Pat",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "const",
+                label: Label::Singular,
+                ty: Ty::Message("ExprConst"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("PatIdent"),
+                flatten: false,
+            },
+            Field {
+                key: "lit",
+                label: Label::Singular,
+                ty: Ty::Message("ExprLit"),
+                flatten: false,
+            },
+            Field {
+                key: "macro",
+                label: Label::Singular,
+                ty: Ty::Message("ExprMacro"),
+                flatten: false,
+            },
+            Field {
+                key: "or",
+                label: Label::Singular,
+                ty: Ty::Message("PatOr"),
+                flatten: false,
+            },
+            Field {
+                key: "paren",
+                label: Label::Singular,
+                ty: Ty::Message("PatParen"),
+                flatten: false,
+            },
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("ExprPath"),
+                flatten: false,
+            },
+            Field {
+                key: "range",
+                label: Label::Singular,
+                ty: Ty::Message("ExprRange"),
+                flatten: false,
+            },
+            Field {
+                key: "reference",
+                label: Label::Singular,
+                ty: Ty::Message("PatReference"),
+                flatten: false,
+            },
+            Field {
+                key: "rest",
+                label: Label::Singular,
+                ty: Ty::Message("PatRest"),
+                flatten: false,
+            },
+            Field {
+                key: "slice",
+                label: Label::Singular,
+                ty: Ty::Message("PatSlice"),
+                flatten: false,
+            },
+            Field {
+                key: "struct",
+                label: Label::Singular,
+                ty: Ty::Message("PatStruct"),
+                flatten: false,
+            },
+            Field {
+                key: "tuple",
+                label: Label::Singular,
+                ty: Ty::Message("PatTuple"),
+                flatten: false,
+            },
+            Field {
+                key: "tuple_struct",
+                label: Label::Singular,
+                ty: Ty::Message("PatTupleStruct"),
+                flatten: false,
+            },
+            Field {
+                key: "type",
+                label: Label::Singular,
+                ty: Ty::Message("PatType"),
+                flatten: false,
+            },
+            Field {
+                key: "verbatim",
+                label: Label::Singular,
+                ty: Ty::Message("TokenStream"),
+                flatten: false,
+            },
+            Field {
+                key: "_",
+                label: Label::Singular,
+                ty: Ty::Message("PatWild"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PatIdent",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "ref",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "mut",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "subpat",
+                label: Label::Optional,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PatOr",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "leading_vert",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "cases",
+                label: Label::Repeated,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PatParen",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "pat",
+                label: Label::Singular,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PatReference",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "mut",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "pat",
+                label: Label::Singular,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PatRest",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PatSlice",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "elems",
+                label: Label::Repeated,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PatStruct",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "qself",
+                label: Label::Optional,
+                ty: Ty::Message("QSelf"),
+                flatten: false,
+            },
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("Path"),
+                flatten: false,
+            },
+            Field {
+                key: "fields",
+                label: Label::Repeated,
+                ty: Ty::Message("FieldPat"),
+                flatten: false,
+            },
+            Field {
+                key: "rest",
+                label: Label::Optional,
+                ty: Ty::Message("PatRest"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PatTuple",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "elems",
+                label: Label::Repeated,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PatTupleStruct",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "qself",
+                label: Label::Optional,
+                ty: Ty::Message("QSelf"),
+                flatten: false,
+            },
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("Path"),
+                flatten: false,
+            },
+            Field {
+                key: "elems",
+                label: Label::Repeated,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PatType",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "pat",
+                label: Label::Singular,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PatWild",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Path",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "leading_colon",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "segments",
+                label: Label::Repeated,
+                ty: Ty::Message("PathSegment"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PathArguments",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "none",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "angle_bracketed",
+                label: Label::Singular,
+                ty: Ty::Message("AngleBracketedGenericArguments"),
+                flatten: false,
+            },
+            Field {
+                key: "parenthesized",
+                label: Label::Singular,
+                ty: Ty::Message("ParenthesizedGenericArguments"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PathSegment",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "arguments",
+                label: Label::Singular,
+                ty: Ty::Message("PathArguments"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PredicateLifetime",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "lifetime",
+                label: Label::Singular,
+                ty: Ty::Message("Lifetime"),
+                flatten: false,
+            },
+            Field {
+                key: "bounds",
+                label: Label::Repeated,
+                ty: Ty::Message("Lifetime"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "PredicateType",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "lifetimes",
+                label: Label::Optional,
+                ty: Ty::Message("BoundLifetimes"),
+                flatten: false,
+            },
+            Field {
+                key: "bounded_ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "bounds",
+                label: Label::Repeated,
+                ty: Ty::Message("TypeParamBound"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Punct",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "op",
+                label: Label::Singular,
+                ty: Ty::String,
+                flatten: false,
+            },
+            Field {
+                key: "spacing",
+                label: Label::Singular,
+                ty: Ty::Enum("Spacing"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "QSelf",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "position",
+                label: Label::Singular,
+                ty: Ty::Uint64,
+                flatten: false,
+            },
+            Field {
+                key: "as_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "RangeLimits",
+        kind: Kind::Enum,
+        fields: &[
+            Field {
+                key: "..",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "..=",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Receiver",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "ref",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "lifetime",
+                label: Label::Optional,
+                ty: Ty::Message("Lifetime"),
+                flatten: false,
+            },
+            Field {
+                key: "mut",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "colon_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "ReturnType",
+        kind: Kind::Transparent,
+        fields: &[
+            Field {
+                key: "ty",
+                label: Label::Optional,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Signature",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "const",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "async",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "unsafe",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "abi",
+                label: Label::Optional,
+                ty: Ty::Message("Abi"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "inputs",
+                label: Label::Repeated,
+                ty: Ty::Message("FnArg"),
+                flatten: false,
+            },
+            Field {
+                key: "variadic",
+                label: Label::Optional,
+                ty: Ty::Message("Variadic"),
+                flatten: false,
+            },
+            Field {
+                key: "output",
+                label: Label::Singular,
+                ty: Ty::Message("ReturnType"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Spacing",
+        kind: Kind::Enum,
+        fields: &[
+            Field {
+                key: "alone",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "joint",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "SpanInfo",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "start_offset",
+                label: Label::Singular,
+                ty: Ty::Uint64,
+                flatten: false,
+            },
+            Field {
+                key: "end_offset",
+                label: Label::Singular,
+                ty: Ty::Uint64,
+                flatten: false,
+            },
+            Field {
+                key: "start_line",
+                label: Label::Singular,
+                ty: Ty::Uint64,
+                flatten: false,
+            },
+            Field {
+                key: "start_column",
+                label: Label::Singular,
+                ty: Ty::Uint64,
+                flatten: false,
+            },
+            Field {
+                key: "end_line",
+                label: Label::Singular,
+                ty: Ty::Uint64,
+                flatten: false,
+            },
+            Field {
+                key: "end_column",
+                label: Label::Singular,
+                ty: Ty::Uint64,
+                flatten: false,
+            },
+            Field {
+                key: "file",
+                label: Label::Optional,
+                ty: Ty::Uint64,
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "StaticMutability",
+        kind: Kind::Enum,
+        fields: &[
+            Field {
+                key: "mut",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "none",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Stmt",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "let",
+                label: Label::Singular,
+                ty: Ty::Message("Local"),
+                flatten: false,
+            },
+            Field {
+                key: "item",
+                label: Label::Singular,
+                ty: Ty::Message("Item"),
+                flatten: false,
+            },
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("StmtExpr"),
+                flatten: false,
+            },
+            Field {
+                key: "macro",
+                label: Label::Singular,
+                ty: Ty::Message("StmtMacro"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "StmtExpr",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "expr",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "semi",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "StmtMacro",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "mac",
+                label: Label::Singular,
+                ty: Ty::Message("Macro"),
+                flatten: true,
+            },
+            Field {
+                key: "semi_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TokenStream",
+        kind: Kind::Transparent,
+        fields: &[
+            Field {
+                key: "trees",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTree"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TokenTree",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "group",
+                label: Label::Singular,
+                ty: Ty::Message("Group"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "punct",
+                label: Label::Singular,
+                ty: Ty::Message("Punct"),
+                flatten: false,
+            },
+            Field {
+                key: "lit",
+                label: Label::Singular,
+                ty: Ty::Message("Literal"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TokenTrivia",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "token",
+                label: Label::Singular,
+                ty: Ty::String,
+                flatten: false,
+            },
+            Field {
+                key: "leading",
+                label: Label::Singular,
+                ty: Ty::String,
+                flatten: false,
+            },
+            Field {
+                key: "trailing",
+                label: Label::Singular,
+                ty: Ty::String,
+                flatten: false,
+            },
+            Field {
+                key: "source",
+                label: Label::Optional,
+                ty: Ty::String,
+                flatten: false,
+            },
+            Field {
+                key: "index",
+                label: Label::Singular,
+                ty: Ty::Uint64,
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TraitBound",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "paren_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "modifier",
+                label: Label::Singular,
+                ty: Ty::Enum("TraitBoundModifier"),
+                flatten: false,
+            },
+            Field {
+                key: "lifetimes",
+                label: Label::Optional,
+                ty: Ty::Message("BoundLifetimes"),
+                flatten: false,
+            },
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("Path"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TraitBoundModifier",
+        kind: Kind::Enum,
+        fields: &[
+            Field {
+                key: "none",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "maybe",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TraitItem",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "const",
+                label: Label::Singular,
+                ty: Ty::Message("TraitItemConst"),
+                flatten: false,
+            },
+            Field {
+                key: "fn",
+                label: Label::Singular,
+                ty: Ty::Message("TraitItemFn"),
+                flatten: false,
+            },
+            Field {
+                key: "type",
+                label: Label::Singular,
+                ty: Ty::Message("TraitItemType"),
+                flatten: false,
+            },
+            Field {
+                key: "macro",
+                label: Label::Singular,
+                ty: Ty::Message("TraitItemMacro"),
+                flatten: false,
+            },
+            Field {
+                key: "verbatim",
+                label: Label::Singular,
+                ty: Ty::Message("TokenStream"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TraitItemConst",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "ty",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "default",
+                label: Label::Optional,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TraitItemFn",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "sig",
+                label: Label::Singular,
+                ty: Ty::Message("Signature"),
+                flatten: true,
+            },
+            Field {
+                key: "default",
+                label: Label::Optional,
+                ty: Ty::Message("Block"),
+                flatten: false,
+            },
+            Field {
+                key: "semi_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TraitItemMacro",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "mac",
+                label: Label::Singular,
+                ty: Ty::Message("Macro"),
+                flatten: true,
+            },
+            Field {
+                key: "semi_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TraitItemType",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "generics",
+                label: Label::Singular,
+                ty: Ty::Message("Generics"),
+                flatten: false,
+            },
+            Field {
+                key: "colon_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "bounds",
+                label: Label::Repeated,
+                ty: Ty::Message("TypeParamBound"),
+                flatten: false,
+            },
+            Field {
+                key: "default",
+                label: Label::Optional,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "span",
+                label: Label::Optional,
+                ty: Ty::Message("SpanInfo"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Trivia",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "tokens",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+            Field {
+                key: "eof",
+                label: Label::Singular,
+                ty: Ty::String,
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Type",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "array",
+                label: Label::Singular,
+                ty: Ty::Message("TypeArray"),
+                flatten: false,
+            },
+            Field {
+                key: "bare_fn",
+                label: Label::Singular,
+                ty: Ty::Message("TypeBareFn"),
+                flatten: false,
+            },
+            Field {
+                key: "group",
+                label: Label::Singular,
+                ty: Ty::Message("TypeGroup"),
+                flatten: false,
+            },
+            Field {
+                key: "impl_trait",
+                label: Label::Singular,
+                ty: Ty::Message("TypeImplTrait"),
+                flatten: false,
+            },
+            Field {
+                key: "_",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "macro",
+                label: Label::Singular,
+                ty: Ty::Message("TypeMacro"),
+                flatten: false,
+            },
+            Field {
+                key: "!",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "paren",
+                label: Label::Singular,
+                ty: Ty::Message("TypeParen"),
+                flatten: false,
+            },
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("TypePath"),
+                flatten: false,
+            },
+            Field {
+                key: "ptr",
+                label: Label::Singular,
+                ty: Ty::Message("TypePtr"),
+                flatten: false,
+            },
+            Field {
+                key: "reference",
+                label: Label::Singular,
+                ty: Ty::Message("TypeReference"),
+                flatten: false,
+            },
+            Field {
+                key: "slice",
+                label: Label::Singular,
+                ty: Ty::Message("TypeSlice"),
+                flatten: false,
+            },
+            Field {
+                key: "trait_object",
+                label: Label::Singular,
+                ty: Ty::Message("TypeTraitObject"),
+                flatten: false,
+            },
+            Field {
+                key: "tuple",
+                label: Label::Singular,
+                ty: Ty::Message("TypeTuple"),
+                flatten: false,
+            },
+            Field {
+                key: "verbatim",
+                label: Label::Singular,
+                ty: Ty::Message("TokenStream"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypeArray",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "elem",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "len",
+                label: Label::Singular,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypeBareFn",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "lifetimes",
+                label: Label::Optional,
+                ty: Ty::Message("BoundLifetimes"),
+                flatten: false,
+            },
+            Field {
+                key: "unsafe",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "abi",
+                label: Label::Optional,
+                ty: Ty::Message("Abi"),
+                flatten: false,
+            },
+            Field {
+                key: "inputs",
+                label: Label::Repeated,
+                ty: Ty::Message("BareFnArg"),
+                flatten: false,
+            },
+            Field {
+                key: "variadic",
+                label: Label::Optional,
+                ty: Ty::Message("BareVariadic"),
+                flatten: false,
+            },
+            Field {
+                key: "output",
+                label: Label::Singular,
+                ty: Ty::Message("ReturnType"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypeGroup",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "elem",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypeImplTrait",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "bounds",
+                label: Label::Repeated,
+                ty: Ty::Message("TypeParamBound"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypeMacro",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "mac",
+                label: Label::Singular,
+                ty: Ty::Message("Macro"),
+                flatten: true,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypeParam",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "colon_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "bounds",
+                label: Label::Repeated,
+                ty: Ty::Message("TypeParamBound"),
+                flatten: false,
+            },
+            Field {
+                key: "eq_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "default",
+                label: Label::Optional,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypeParamBound",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "trait",
+                label: Label::Singular,
+                ty: Ty::Message("TraitBound"),
+                flatten: false,
+            },
+            Field {
+                key: "lifetime",
+                label: Label::Singular,
+                ty: Ty::Message("Lifetime"),
+                flatten: false,
+            },
+            Field {
+                key: "verbatim",
+                label: Label::Singular,
+                ty: Ty::Message("TokenStream"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypeParen",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "elem",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypePath",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "qself",
+                label: Label::Optional,
+                ty: Ty::Message("QSelf"),
+                flatten: false,
+            },
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("Path"),
+                flatten: true,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypePtr",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "const",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "mut",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "elem",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypeReference",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "lifetime",
+                label: Label::Optional,
+                ty: Ty::Message("Lifetime"),
+                flatten: false,
+            },
+            Field {
+                key: "mut",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "elem",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypeSlice",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "elem",
+                label: Label::Singular,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypeTraitObject",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "dyn",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "bounds",
+                label: Label::Repeated,
+                ty: Ty::Message("TypeParamBound"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "TypeTuple",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "elems",
+                label: Label::Repeated,
+                ty: Ty::Message("Type"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "UnOp",
+        kind: Kind::Enum,
+        fields: &[
+            Field {
+                key: "*",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "!",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "-",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Unit",
+        kind: Kind::Struct,
+        fields: &[],
+    },
+    Message {
+        name: "UseGroup",
+        kind: Kind::Transparent,
+        fields: &[
+            Field {
+                key: "items",
+                label: Label::Repeated,
+                ty: Ty::Message("UseTree"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "UseName",
+        kind: Kind::Transparent,
+        fields: &[
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "UsePath",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "tree",
+                label: Label::Singular,
+                ty: Ty::Message("UseTree"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "UseRename",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "rename",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "UseTree",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("UsePath"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("UseName"),
+                flatten: false,
+            },
+            Field {
+                key: "rename",
+                label: Label::Singular,
+                ty: Ty::Message("UseRename"),
+                flatten: false,
+            },
+            Field {
+                key: "*",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "group",
+                label: Label::Singular,
+                ty: Ty::Message("UseGroup"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Variadic",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "pat",
+                label: Label::Optional,
+                ty: Ty::Message("Pat"),
+                flatten: false,
+            },
+            Field {
+                key: "comma",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Variant",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "attrs",
+                label: Label::Repeated,
+                ty: Ty::Message("Attribute"),
+                flatten: false,
+            },
+            Field {
+                key: "ident",
+                label: Label::Singular,
+                ty: Ty::Message("Ident"),
+                flatten: false,
+            },
+            Field {
+                key: "fields",
+                label: Label::Singular,
+                ty: Ty::Message("Fields"),
+                flatten: false,
+            },
+            Field {
+                key: "discriminant",
+                label: Label::Optional,
+                ty: Ty::Message("Expr"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "VisRestricted",
+        kind: Kind::Struct,
+        fields: &[
+            Field {
+                key: "in_token",
+                label: Label::Singular,
+                ty: Ty::Bool,
+                flatten: false,
+            },
+            Field {
+                key: "path",
+                label: Label::Singular,
+                ty: Ty::Message("Path"),
+                flatten: false,
+            },
+            Field {
+                key: "trivia",
+                label: Label::Repeated,
+                ty: Ty::Message("TokenTrivia"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "Visibility",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "pub",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+            Field {
+                key: "restricted",
+                label: Label::Singular,
+                ty: Ty::Message("VisRestricted"),
+                flatten: false,
+            },
+            Field {
+                key: "inherited",
+                label: Label::Singular,
+                ty: Ty::Message("Unit"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "WhereClause",
+        kind: Kind::Transparent,
+        fields: &[
+            Field {
+                key: "predicates",
+                label: Label::Repeated,
+                ty: Ty::Message("WherePredicate"),
+                flatten: false,
+            },
+        ],
+    },
+    Message {
+        name: "WherePredicate",
+        kind: Kind::Oneof,
+        fields: &[
+            Field {
+                key: "lifetime",
+                label: Label::Singular,
+                ty: Ty::Message("PredicateLifetime"),
+                flatten: false,
+            },
+            Field {
+                key: "type",
+                label: Label::Singular,
+                ty: Ty::Message("PredicateType"),
+                flatten: false,
+            },
+        ],
+    },
+];
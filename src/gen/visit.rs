@@ -0,0 +1,2940 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// This file is @generated by syn-serde-internal-codegen
+// (generate function at tools/codegen/src/visit.rs).
+// It is not intended for manual editing.
+
+#![cfg_attr(rustfmt, rustfmt::skip)]
+#![allow(
+    clippy::explicit_auto_deref,
+    clippy::explicit_iter_loop,
+    clippy::match_same_arms,
+)]
+use crate::*;
+/// A traversal of the adapters. Each method visits the children of
+/// the node by default, like `syn::visit::Visit`.
+pub(crate) trait Visit<'ast> {
+    fn visit_abi(&mut self, node: &'ast Abi) {
+        visit_abi(self, node);
+    }
+    fn visit_angle_bracketed_generic_arguments(
+        &mut self,
+        node: &'ast AngleBracketedGenericArguments,
+    ) {
+        visit_angle_bracketed_generic_arguments(self, node);
+    }
+    fn visit_arm(&mut self, node: &'ast Arm) {
+        visit_arm(self, node);
+    }
+    fn visit_assoc_const(&mut self, node: &'ast AssocConst) {
+        visit_assoc_const(self, node);
+    }
+    fn visit_assoc_type(&mut self, node: &'ast AssocType) {
+        visit_assoc_type(self, node);
+    }
+    fn visit_attr_style(&mut self, node: &'ast AttrStyle) {
+        visit_attr_style(self, node);
+    }
+    fn visit_attribute(&mut self, node: &'ast Attribute) {
+        visit_attribute(self, node);
+    }
+    fn visit_bare_fn_arg(&mut self, node: &'ast BareFnArg) {
+        visit_bare_fn_arg(self, node);
+    }
+    fn visit_bare_variadic(&mut self, node: &'ast BareVariadic) {
+        visit_bare_variadic(self, node);
+    }
+    fn visit_bin_op(&mut self, node: &'ast BinOp) {
+        visit_bin_op(self, node);
+    }
+    fn visit_block(&mut self, node: &'ast Block) {
+        visit_block(self, node);
+    }
+    fn visit_bound_lifetimes(&mut self, node: &'ast BoundLifetimes) {
+        visit_bound_lifetimes(self, node);
+    }
+    fn visit_const_param(&mut self, node: &'ast ConstParam) {
+        visit_const_param(self, node);
+    }
+    fn visit_constraint(&mut self, node: &'ast Constraint) {
+        visit_constraint(self, node);
+    }
+    fn visit_expr(&mut self, node: &'ast Expr) {
+        visit_expr(self, node);
+    }
+    fn visit_expr_array(&mut self, node: &'ast ExprArray) {
+        visit_expr_array(self, node);
+    }
+    fn visit_expr_assign(&mut self, node: &'ast ExprAssign) {
+        visit_expr_assign(self, node);
+    }
+    fn visit_expr_async(&mut self, node: &'ast ExprAsync) {
+        visit_expr_async(self, node);
+    }
+    fn visit_expr_await(&mut self, node: &'ast ExprAwait) {
+        visit_expr_await(self, node);
+    }
+    fn visit_expr_binary(&mut self, node: &'ast ExprBinary) {
+        visit_expr_binary(self, node);
+    }
+    fn visit_expr_block(&mut self, node: &'ast ExprBlock) {
+        visit_expr_block(self, node);
+    }
+    fn visit_expr_break(&mut self, node: &'ast ExprBreak) {
+        visit_expr_break(self, node);
+    }
+    fn visit_expr_call(&mut self, node: &'ast ExprCall) {
+        visit_expr_call(self, node);
+    }
+    fn visit_expr_cast(&mut self, node: &'ast ExprCast) {
+        visit_expr_cast(self, node);
+    }
+    fn visit_expr_closure(&mut self, node: &'ast ExprClosure) {
+        visit_expr_closure(self, node);
+    }
+    fn visit_expr_const(&mut self, node: &'ast ExprConst) {
+        visit_expr_const(self, node);
+    }
+    fn visit_expr_continue(&mut self, node: &'ast ExprContinue) {
+        visit_expr_continue(self, node);
+    }
+    fn visit_expr_field(&mut self, node: &'ast ExprField) {
+        visit_expr_field(self, node);
+    }
+    fn visit_expr_for_loop(&mut self, node: &'ast ExprForLoop) {
+        visit_expr_for_loop(self, node);
+    }
+    fn visit_expr_group(&mut self, node: &'ast ExprGroup) {
+        visit_expr_group(self, node);
+    }
+    fn visit_expr_if(&mut self, node: &'ast ExprIf) {
+        visit_expr_if(self, node);
+    }
+    fn visit_expr_index(&mut self, node: &'ast ExprIndex) {
+        visit_expr_index(self, node);
+    }
+    fn visit_expr_infer(&mut self, node: &'ast ExprInfer) {
+        visit_expr_infer(self, node);
+    }
+    fn visit_expr_let(&mut self, node: &'ast ExprLet) {
+        visit_expr_let(self, node);
+    }
+    fn visit_expr_lit(&mut self, node: &'ast ExprLit) {
+        visit_expr_lit(self, node);
+    }
+    fn visit_expr_loop(&mut self, node: &'ast ExprLoop) {
+        visit_expr_loop(self, node);
+    }
+    fn visit_expr_macro(&mut self, node: &'ast ExprMacro) {
+        visit_expr_macro(self, node);
+    }
+    fn visit_expr_match(&mut self, node: &'ast ExprMatch) {
+        visit_expr_match(self, node);
+    }
+    fn visit_expr_method_call(&mut self, node: &'ast ExprMethodCall) {
+        visit_expr_method_call(self, node);
+    }
+    fn visit_expr_paren(&mut self, node: &'ast ExprParen) {
+        visit_expr_paren(self, node);
+    }
+    fn visit_expr_path(&mut self, node: &'ast ExprPath) {
+        visit_expr_path(self, node);
+    }
+    fn visit_expr_range(&mut self, node: &'ast ExprRange) {
+        visit_expr_range(self, node);
+    }
+    fn visit_expr_reference(&mut self, node: &'ast ExprReference) {
+        visit_expr_reference(self, node);
+    }
+    fn visit_expr_repeat(&mut self, node: &'ast ExprRepeat) {
+        visit_expr_repeat(self, node);
+    }
+    fn visit_expr_return(&mut self, node: &'ast ExprReturn) {
+        visit_expr_return(self, node);
+    }
+    fn visit_expr_struct(&mut self, node: &'ast ExprStruct) {
+        visit_expr_struct(self, node);
+    }
+    fn visit_expr_try(&mut self, node: &'ast ExprTry) {
+        visit_expr_try(self, node);
+    }
+    fn visit_expr_try_block(&mut self, node: &'ast ExprTryBlock) {
+        visit_expr_try_block(self, node);
+    }
+    fn visit_expr_tuple(&mut self, node: &'ast ExprTuple) {
+        visit_expr_tuple(self, node);
+    }
+    fn visit_expr_unary(&mut self, node: &'ast ExprUnary) {
+        visit_expr_unary(self, node);
+    }
+    fn visit_expr_unsafe(&mut self, node: &'ast ExprUnsafe) {
+        visit_expr_unsafe(self, node);
+    }
+    fn visit_expr_while(&mut self, node: &'ast ExprWhile) {
+        visit_expr_while(self, node);
+    }
+    fn visit_expr_yield(&mut self, node: &'ast ExprYield) {
+        visit_expr_yield(self, node);
+    }
+    fn visit_field(&mut self, node: &'ast Field) {
+        visit_field(self, node);
+    }
+    fn visit_field_mutability(&mut self, node: &'ast FieldMutability) {
+        visit_field_mutability(self, node);
+    }
+    fn visit_field_pat(&mut self, node: &'ast FieldPat) {
+        visit_field_pat(self, node);
+    }
+    fn visit_field_value(&mut self, node: &'ast FieldValue) {
+        visit_field_value(self, node);
+    }
+    fn visit_fields(&mut self, node: &'ast Fields) {
+        visit_fields(self, node);
+    }
+    fn visit_fields_named(&mut self, node: &'ast FieldsNamed) {
+        visit_fields_named(self, node);
+    }
+    fn visit_fields_unnamed(&mut self, node: &'ast FieldsUnnamed) {
+        visit_fields_unnamed(self, node);
+    }
+    fn visit_file(&mut self, node: &'ast File) {
+        visit_file(self, node);
+    }
+    fn visit_fn_arg(&mut self, node: &'ast FnArg) {
+        visit_fn_arg(self, node);
+    }
+    fn visit_foreign_item(&mut self, node: &'ast ForeignItem) {
+        visit_foreign_item(self, node);
+    }
+    fn visit_foreign_item_fn(&mut self, node: &'ast ForeignItemFn) {
+        visit_foreign_item_fn(self, node);
+    }
+    fn visit_foreign_item_macro(&mut self, node: &'ast ForeignItemMacro) {
+        visit_foreign_item_macro(self, node);
+    }
+    fn visit_foreign_item_static(&mut self, node: &'ast ForeignItemStatic) {
+        visit_foreign_item_static(self, node);
+    }
+    fn visit_foreign_item_type(&mut self, node: &'ast ForeignItemType) {
+        visit_foreign_item_type(self, node);
+    }
+    fn visit_generic_argument(&mut self, node: &'ast GenericArgument) {
+        visit_generic_argument(self, node);
+    }
+    fn visit_generic_param(&mut self, node: &'ast GenericParam) {
+        visit_generic_param(self, node);
+    }
+    fn visit_generics(&mut self, node: &'ast Generics) {
+        visit_generics(self, node);
+    }
+    fn visit_impl_item(&mut self, node: &'ast ImplItem) {
+        visit_impl_item(self, node);
+    }
+    fn visit_impl_item_const(&mut self, node: &'ast ImplItemConst) {
+        visit_impl_item_const(self, node);
+    }
+    fn visit_impl_item_fn(&mut self, node: &'ast ImplItemFn) {
+        visit_impl_item_fn(self, node);
+    }
+    fn visit_impl_item_macro(&mut self, node: &'ast ImplItemMacro) {
+        visit_impl_item_macro(self, node);
+    }
+    fn visit_impl_item_type(&mut self, node: &'ast ImplItemType) {
+        visit_impl_item_type(self, node);
+    }
+    fn visit_impl_restriction(&mut self, node: &'ast ImplRestriction) {
+        visit_impl_restriction(self, node);
+    }
+    fn visit_index(&mut self, node: &'ast Index) {
+        visit_index(self, node);
+    }
+    fn visit_item(&mut self, node: &'ast Item) {
+        visit_item(self, node);
+    }
+    fn visit_item_const(&mut self, node: &'ast ItemConst) {
+        visit_item_const(self, node);
+    }
+    fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
+        visit_item_enum(self, node);
+    }
+    fn visit_item_extern_crate(&mut self, node: &'ast ItemExternCrate) {
+        visit_item_extern_crate(self, node);
+    }
+    fn visit_item_fn(&mut self, node: &'ast ItemFn) {
+        visit_item_fn(self, node);
+    }
+    fn visit_item_foreign_mod(&mut self, node: &'ast ItemForeignMod) {
+        visit_item_foreign_mod(self, node);
+    }
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        visit_item_impl(self, node);
+    }
+    fn visit_item_macro(&mut self, node: &'ast ItemMacro) {
+        visit_item_macro(self, node);
+    }
+    fn visit_item_mod(&mut self, node: &'ast ItemMod) {
+        visit_item_mod(self, node);
+    }
+    fn visit_item_static(&mut self, node: &'ast ItemStatic) {
+        visit_item_static(self, node);
+    }
+    fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
+        visit_item_struct(self, node);
+    }
+    fn visit_item_trait(&mut self, node: &'ast ItemTrait) {
+        visit_item_trait(self, node);
+    }
+    fn visit_item_trait_alias(&mut self, node: &'ast ItemTraitAlias) {
+        visit_item_trait_alias(self, node);
+    }
+    fn visit_item_type(&mut self, node: &'ast ItemType) {
+        visit_item_type(self, node);
+    }
+    fn visit_item_union(&mut self, node: &'ast ItemUnion) {
+        visit_item_union(self, node);
+    }
+    fn visit_item_use(&mut self, node: &'ast ItemUse) {
+        visit_item_use(self, node);
+    }
+    fn visit_label(&mut self, node: &'ast Label) {
+        visit_label(self, node);
+    }
+    fn visit_lifetime(&mut self, node: &'ast Lifetime) {
+        visit_lifetime(self, node);
+    }
+    fn visit_lifetime_param(&mut self, node: &'ast LifetimeParam) {
+        visit_lifetime_param(self, node);
+    }
+    fn visit_lit(&mut self, node: &'ast Lit) {
+        visit_lit(self, node);
+    }
+    fn visit_lit_bool(&mut self, node: &'ast LitBool) {
+        visit_lit_bool(self, node);
+    }
+    fn visit_lit_byte(&mut self, node: &'ast LitByte) {
+        visit_lit_byte(self, node);
+    }
+    fn visit_lit_byte_str(&mut self, node: &'ast LitByteStr) {
+        visit_lit_byte_str(self, node);
+    }
+    fn visit_lit_c_str(&mut self, node: &'ast LitCStr) {
+        visit_lit_c_str(self, node);
+    }
+    fn visit_lit_char(&mut self, node: &'ast LitChar) {
+        visit_lit_char(self, node);
+    }
+    fn visit_lit_float(&mut self, node: &'ast LitFloat) {
+        visit_lit_float(self, node);
+    }
+    fn visit_lit_int(&mut self, node: &'ast LitInt) {
+        visit_lit_int(self, node);
+    }
+    fn visit_lit_str(&mut self, node: &'ast LitStr) {
+        visit_lit_str(self, node);
+    }
+    fn visit_local(&mut self, node: &'ast Local) {
+        visit_local(self, node);
+    }
+    fn visit_local_init(&mut self, node: &'ast LocalInit) {
+        visit_local_init(self, node);
+    }
+    fn visit_macro(&mut self, node: &'ast Macro) {
+        visit_macro(self, node);
+    }
+    fn visit_macro_delimiter(&mut self, node: &'ast MacroDelimiter) {
+        visit_macro_delimiter(self, node);
+    }
+    fn visit_member(&mut self, node: &'ast Member) {
+        visit_member(self, node);
+    }
+    fn visit_meta(&mut self, node: &'ast Meta) {
+        visit_meta(self, node);
+    }
+    fn visit_meta_list(&mut self, node: &'ast MetaList) {
+        visit_meta_list(self, node);
+    }
+    fn visit_meta_name_value(&mut self, node: &'ast MetaNameValue) {
+        visit_meta_name_value(self, node);
+    }
+    fn visit_parenthesized_generic_arguments(
+        &mut self,
+        node: &'ast ParenthesizedGenericArguments,
+    ) {
+        visit_parenthesized_generic_arguments(self, node);
+    }
+    fn visit_pat(&mut self, node: &'ast Pat) {
+        visit_pat(self, node);
+    }
+    fn visit_pat_ident(&mut self, node: &'ast PatIdent) {
+        visit_pat_ident(self, node);
+    }
+    fn visit_pat_or(&mut self, node: &'ast PatOr) {
+        visit_pat_or(self, node);
+    }
+    fn visit_pat_paren(&mut self, node: &'ast PatParen) {
+        visit_pat_paren(self, node);
+    }
+    fn visit_pat_reference(&mut self, node: &'ast PatReference) {
+        visit_pat_reference(self, node);
+    }
+    fn visit_pat_rest(&mut self, node: &'ast PatRest) {
+        visit_pat_rest(self, node);
+    }
+    fn visit_pat_slice(&mut self, node: &'ast PatSlice) {
+        visit_pat_slice(self, node);
+    }
+    fn visit_pat_struct(&mut self, node: &'ast PatStruct) {
+        visit_pat_struct(self, node);
+    }
+    fn visit_pat_tuple(&mut self, node: &'ast PatTuple) {
+        visit_pat_tuple(self, node);
+    }
+    fn visit_pat_tuple_struct(&mut self, node: &'ast PatTupleStruct) {
+        visit_pat_tuple_struct(self, node);
+    }
+    fn visit_pat_type(&mut self, node: &'ast PatType) {
+        visit_pat_type(self, node);
+    }
+    fn visit_pat_wild(&mut self, node: &'ast PatWild) {
+        visit_pat_wild(self, node);
+    }
+    fn visit_path(&mut self, node: &'ast Path) {
+        visit_path(self, node);
+    }
+    fn visit_path_arguments(&mut self, node: &'ast PathArguments) {
+        visit_path_arguments(self, node);
+    }
+    fn visit_path_segment(&mut self, node: &'ast PathSegment) {
+        visit_path_segment(self, node);
+    }
+    fn visit_predicate_lifetime(&mut self, node: &'ast PredicateLifetime) {
+        visit_predicate_lifetime(self, node);
+    }
+    fn visit_predicate_type(&mut self, node: &'ast PredicateType) {
+        visit_predicate_type(self, node);
+    }
+    fn visit_q_self(&mut self, node: &'ast QSelf) {
+        visit_q_self(self, node);
+    }
+    fn visit_range_limits(&mut self, node: &'ast RangeLimits) {
+        visit_range_limits(self, node);
+    }
+    fn visit_receiver(&mut self, node: &'ast Receiver) {
+        visit_receiver(self, node);
+    }
+    fn visit_return_type(&mut self, node: &'ast ReturnType) {
+        visit_return_type(self, node);
+    }
+    fn visit_signature(&mut self, node: &'ast Signature) {
+        visit_signature(self, node);
+    }
+    fn visit_static_mutability(&mut self, node: &'ast StaticMutability) {
+        visit_static_mutability(self, node);
+    }
+    fn visit_stmt(&mut self, node: &'ast Stmt) {
+        visit_stmt(self, node);
+    }
+    fn visit_stmt_macro(&mut self, node: &'ast StmtMacro) {
+        visit_stmt_macro(self, node);
+    }
+    fn visit_trait_bound(&mut self, node: &'ast TraitBound) {
+        visit_trait_bound(self, node);
+    }
+    fn visit_trait_bound_modifier(&mut self, node: &'ast TraitBoundModifier) {
+        visit_trait_bound_modifier(self, node);
+    }
+    fn visit_trait_item(&mut self, node: &'ast TraitItem) {
+        visit_trait_item(self, node);
+    }
+    fn visit_trait_item_const(&mut self, node: &'ast TraitItemConst) {
+        visit_trait_item_const(self, node);
+    }
+    fn visit_trait_item_fn(&mut self, node: &'ast TraitItemFn) {
+        visit_trait_item_fn(self, node);
+    }
+    fn visit_trait_item_macro(&mut self, node: &'ast TraitItemMacro) {
+        visit_trait_item_macro(self, node);
+    }
+    fn visit_trait_item_type(&mut self, node: &'ast TraitItemType) {
+        visit_trait_item_type(self, node);
+    }
+    fn visit_type(&mut self, node: &'ast Type) {
+        visit_type(self, node);
+    }
+    fn visit_type_array(&mut self, node: &'ast TypeArray) {
+        visit_type_array(self, node);
+    }
+    fn visit_type_bare_fn(&mut self, node: &'ast TypeBareFn) {
+        visit_type_bare_fn(self, node);
+    }
+    fn visit_type_group(&mut self, node: &'ast TypeGroup) {
+        visit_type_group(self, node);
+    }
+    fn visit_type_impl_trait(&mut self, node: &'ast TypeImplTrait) {
+        visit_type_impl_trait(self, node);
+    }
+    fn visit_type_macro(&mut self, node: &'ast TypeMacro) {
+        visit_type_macro(self, node);
+    }
+    fn visit_type_param(&mut self, node: &'ast TypeParam) {
+        visit_type_param(self, node);
+    }
+    fn visit_type_param_bound(&mut self, node: &'ast TypeParamBound) {
+        visit_type_param_bound(self, node);
+    }
+    fn visit_type_paren(&mut self, node: &'ast TypeParen) {
+        visit_type_paren(self, node);
+    }
+    fn visit_type_path(&mut self, node: &'ast TypePath) {
+        visit_type_path(self, node);
+    }
+    fn visit_type_ptr(&mut self, node: &'ast TypePtr) {
+        visit_type_ptr(self, node);
+    }
+    fn visit_type_reference(&mut self, node: &'ast TypeReference) {
+        visit_type_reference(self, node);
+    }
+    fn visit_type_slice(&mut self, node: &'ast TypeSlice) {
+        visit_type_slice(self, node);
+    }
+    fn visit_type_trait_object(&mut self, node: &'ast TypeTraitObject) {
+        visit_type_trait_object(self, node);
+    }
+    fn visit_type_tuple(&mut self, node: &'ast TypeTuple) {
+        visit_type_tuple(self, node);
+    }
+    fn visit_un_op(&mut self, node: &'ast UnOp) {
+        visit_un_op(self, node);
+    }
+    fn visit_use_group(&mut self, node: &'ast UseGroup) {
+        visit_use_group(self, node);
+    }
+    fn visit_use_name(&mut self, node: &'ast UseName) {
+        visit_use_name(self, node);
+    }
+    fn visit_use_path(&mut self, node: &'ast UsePath) {
+        visit_use_path(self, node);
+    }
+    fn visit_use_rename(&mut self, node: &'ast UseRename) {
+        visit_use_rename(self, node);
+    }
+    fn visit_use_tree(&mut self, node: &'ast UseTree) {
+        visit_use_tree(self, node);
+    }
+    fn visit_variadic(&mut self, node: &'ast Variadic) {
+        visit_variadic(self, node);
+    }
+    fn visit_variant(&mut self, node: &'ast Variant) {
+        visit_variant(self, node);
+    }
+    fn visit_vis_restricted(&mut self, node: &'ast VisRestricted) {
+        visit_vis_restricted(self, node);
+    }
+    fn visit_visibility(&mut self, node: &'ast Visibility) {
+        visit_visibility(self, node);
+    }
+    fn visit_where_clause(&mut self, node: &'ast WhereClause) {
+        visit_where_clause(self, node);
+    }
+    fn visit_where_predicate(&mut self, node: &'ast WherePredicate) {
+        visit_where_predicate(self, node);
+    }
+    fn visit_comment(&mut self, node: &'ast Comment) {
+        visit_comment(self, node);
+    }
+    fn visit_delimiter(&mut self, node: &'ast Delimiter) {
+        visit_delimiter(self, node);
+    }
+    fn visit_group(&mut self, node: &'ast Group) {
+        visit_group(self, node);
+    }
+    fn visit_ident(&mut self, node: &'ast Ident) {
+        visit_ident(self, node);
+    }
+    fn visit_literal(&mut self, node: &'ast Literal) {
+        visit_literal(self, node);
+    }
+    fn visit_punct(&mut self, node: &'ast Punct) {
+        visit_punct(self, node);
+    }
+    fn visit_spacing(&mut self, node: &'ast Spacing) {
+        visit_spacing(self, node);
+    }
+    fn visit_span_info(&mut self, node: &'ast SpanInfo) {
+        visit_span_info(self, node);
+    }
+    fn visit_token_stream(&mut self, node: &'ast TokenStream) {
+        visit_token_stream(self, node);
+    }
+    fn visit_token_tree(&mut self, node: &'ast TokenTree) {
+        visit_token_tree(self, node);
+    }
+}
+pub(crate) fn visit_abi<'ast, V>(v: &mut V, node: &'ast Abi)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.name {
+        v.visit_lit_str(it);
+    }
+}
+pub(crate) fn visit_angle_bracketed_generic_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast AngleBracketedGenericArguments,
+)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.args.iter() {
+        v.visit_generic_argument(it);
+    }
+}
+pub(crate) fn visit_arm<'ast, V>(v: &mut V, node: &'ast Arm)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_pat(&node.pat);
+    if let Some(_0) = &node.guard {
+        v.visit_expr(&**_0);
+    }
+    v.visit_expr(&*node.body);
+}
+pub(crate) fn visit_assoc_const<'ast, V>(v: &mut V, node: &'ast AssocConst)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident);
+    if let Some(it) = &node.generics {
+        v.visit_angle_bracketed_generic_arguments(it);
+    }
+    v.visit_expr(&node.value);
+}
+pub(crate) fn visit_assoc_type<'ast, V>(v: &mut V, node: &'ast AssocType)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident);
+    if let Some(it) = &node.generics {
+        v.visit_angle_bracketed_generic_arguments(it);
+    }
+    v.visit_type(&node.ty);
+}
+pub(crate) fn visit_attr_style<'ast, V>(_v: &mut V, _node: &'ast AttrStyle)
+where
+    V: Visit<'ast> + ?Sized,
+{}
+pub(crate) fn visit_attribute<'ast, V>(v: &mut V, node: &'ast Attribute)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_attr_style(&node.style);
+    v.visit_meta(&node.meta);
+}
+pub(crate) fn visit_bare_fn_arg<'ast, V>(v: &mut V, node: &'ast BareFnArg)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(_0) = &node.name {
+        v.visit_ident(_0);
+    }
+    v.visit_type(&node.ty);
+}
+pub(crate) fn visit_bare_variadic<'ast, V>(v: &mut V, node: &'ast BareVariadic)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(_0) = &node.name {
+        v.visit_ident(_0);
+    }
+}
+pub(crate) fn visit_bin_op<'ast, V>(_v: &mut V, _node: &'ast BinOp)
+where
+    V: Visit<'ast> + ?Sized,
+{}
+pub(crate) fn visit_block<'ast, V>(v: &mut V, node: &'ast Block)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.stmts.iter() {
+        v.visit_stmt(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_bound_lifetimes<'ast, V>(v: &mut V, node: &'ast BoundLifetimes)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.lifetimes.iter() {
+        v.visit_generic_param(it);
+    }
+}
+pub(crate) fn visit_const_param<'ast, V>(v: &mut V, node: &'ast ConstParam)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_ident(&node.ident);
+    v.visit_type(&node.ty);
+    if let Some(it) = &node.default {
+        v.visit_expr(it);
+    }
+}
+pub(crate) fn visit_constraint<'ast, V>(v: &mut V, node: &'ast Constraint)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident);
+    if let Some(it) = &node.generics {
+        v.visit_angle_bracketed_generic_arguments(it);
+    }
+    for it in node.bounds.iter() {
+        v.visit_type_param_bound(it);
+    }
+}
+pub(crate) fn visit_expr<'ast, V>(v: &mut V, node: &'ast Expr)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        Expr::Array(it) => {
+            v.visit_expr_array(it);
+        }
+        Expr::Assign(it) => {
+            v.visit_expr_assign(it);
+        }
+        Expr::Async(it) => {
+            v.visit_expr_async(it);
+        }
+        Expr::Await(it) => {
+            v.visit_expr_await(it);
+        }
+        Expr::Binary(it) => {
+            v.visit_expr_binary(it);
+        }
+        Expr::Block(it) => {
+            v.visit_expr_block(it);
+        }
+        Expr::Break(it) => {
+            v.visit_expr_break(it);
+        }
+        Expr::Call(it) => {
+            v.visit_expr_call(it);
+        }
+        Expr::Cast(it) => {
+            v.visit_expr_cast(it);
+        }
+        Expr::Closure(it) => {
+            v.visit_expr_closure(it);
+        }
+        Expr::Const(it) => {
+            v.visit_expr_const(it);
+        }
+        Expr::Continue(it) => {
+            v.visit_expr_continue(it);
+        }
+        Expr::Field(it) => {
+            v.visit_expr_field(it);
+        }
+        Expr::ForLoop(it) => {
+            v.visit_expr_for_loop(it);
+        }
+        Expr::Group(it) => {
+            v.visit_expr_group(it);
+        }
+        Expr::If(it) => {
+            v.visit_expr_if(it);
+        }
+        Expr::Index(it) => {
+            v.visit_expr_index(it);
+        }
+        Expr::Infer(it) => {
+            v.visit_expr_infer(it);
+        }
+        Expr::Let(it) => {
+            v.visit_expr_let(it);
+        }
+        Expr::Lit(it) => {
+            v.visit_expr_lit(it);
+        }
+        Expr::Loop(it) => {
+            v.visit_expr_loop(it);
+        }
+        Expr::Macro(it) => {
+            v.visit_expr_macro(it);
+        }
+        Expr::Match(it) => {
+            v.visit_expr_match(it);
+        }
+        Expr::MethodCall(it) => {
+            v.visit_expr_method_call(it);
+        }
+        Expr::Paren(it) => {
+            v.visit_expr_paren(it);
+        }
+        Expr::Path(it) => {
+            v.visit_expr_path(it);
+        }
+        Expr::Range(it) => {
+            v.visit_expr_range(it);
+        }
+        Expr::Reference(it) => {
+            v.visit_expr_reference(it);
+        }
+        Expr::Repeat(it) => {
+            v.visit_expr_repeat(it);
+        }
+        Expr::Return(it) => {
+            v.visit_expr_return(it);
+        }
+        Expr::Struct(it) => {
+            v.visit_expr_struct(it);
+        }
+        Expr::Try(it) => {
+            v.visit_expr_try(it);
+        }
+        Expr::TryBlock(it) => {
+            v.visit_expr_try_block(it);
+        }
+        Expr::Tuple(it) => {
+            v.visit_expr_tuple(it);
+        }
+        Expr::Unary(it) => {
+            v.visit_expr_unary(it);
+        }
+        Expr::Unsafe(it) => {
+            v.visit_expr_unsafe(it);
+        }
+        Expr::Verbatim(it) => {
+            v.visit_token_stream(it);
+        }
+        Expr::While(it) => {
+            v.visit_expr_while(it);
+        }
+        Expr::Yield(it) => {
+            v.visit_expr_yield(it);
+        }
+    }
+}
+pub(crate) fn visit_expr_array<'ast, V>(v: &mut V, node: &'ast ExprArray)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    for it in node.elems.iter() {
+        v.visit_expr(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_expr_assign<'ast, V>(v: &mut V, node: &'ast ExprAssign)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.left);
+    v.visit_expr(&*node.right);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_expr_async<'ast, V>(v: &mut V, node: &'ast ExprAsync)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_block(&node.block);
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_expr_await<'ast, V>(v: &mut V, node: &'ast ExprAwait)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.base);
+}
+pub(crate) fn visit_expr_binary<'ast, V>(v: &mut V, node: &'ast ExprBinary)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.left);
+    v.visit_bin_op(&node.op);
+    v.visit_expr(&*node.right);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_expr_block<'ast, V>(v: &mut V, node: &'ast ExprBlock)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it);
+    }
+    v.visit_block(&node.block);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_expr_break<'ast, V>(v: &mut V, node: &'ast ExprBreak)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.label {
+        v.visit_lifetime(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(&**it);
+    }
+}
+pub(crate) fn visit_expr_call<'ast, V>(v: &mut V, node: &'ast ExprCall)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.func);
+    for it in node.args.iter() {
+        v.visit_expr(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_expr_cast<'ast, V>(v: &mut V, node: &'ast ExprCast)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.expr);
+    v.visit_type(&*node.ty);
+}
+pub(crate) fn visit_expr_closure<'ast, V>(v: &mut V, node: &'ast ExprClosure)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it);
+    }
+    for it in node.inputs.iter() {
+        v.visit_pat(it);
+    }
+    v.visit_return_type(&node.output);
+    v.visit_expr(&*node.body);
+}
+pub(crate) fn visit_expr_const<'ast, V>(v: &mut V, node: &'ast ExprConst)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_block(&node.block);
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_expr_continue<'ast, V>(v: &mut V, node: &'ast ExprContinue)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.label {
+        v.visit_lifetime(it);
+    }
+}
+pub(crate) fn visit_expr_field<'ast, V>(v: &mut V, node: &'ast ExprField)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.base);
+    v.visit_member(&node.member);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_expr_for_loop<'ast, V>(v: &mut V, node: &'ast ExprForLoop)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it);
+    }
+    v.visit_pat(&*node.pat);
+    v.visit_expr(&*node.expr);
+    v.visit_block(&node.body);
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_expr_group<'ast, V>(v: &mut V, node: &'ast ExprGroup)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.expr);
+}
+pub(crate) fn visit_expr_if<'ast, V>(v: &mut V, node: &'ast ExprIf)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.cond);
+    v.visit_block(&node.then_branch);
+    if let Some(_0) = &node.else_branch {
+        v.visit_expr(&**_0);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_expr_index<'ast, V>(v: &mut V, node: &'ast ExprIndex)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.expr);
+    v.visit_expr(&*node.index);
+}
+pub(crate) fn visit_expr_infer<'ast, V>(v: &mut V, node: &'ast ExprInfer)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+}
+pub(crate) fn visit_expr_let<'ast, V>(v: &mut V, node: &'ast ExprLet)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_pat(&*node.pat);
+    v.visit_expr(&*node.expr);
+}
+pub(crate) fn visit_expr_lit<'ast, V>(v: &mut V, node: &'ast ExprLit)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_lit(&node.lit);
+}
+pub(crate) fn visit_expr_loop<'ast, V>(v: &mut V, node: &'ast ExprLoop)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it);
+    }
+    v.visit_block(&node.body);
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_expr_macro<'ast, V>(v: &mut V, node: &'ast ExprMacro)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_macro(&node.mac);
+}
+pub(crate) fn visit_expr_match<'ast, V>(v: &mut V, node: &'ast ExprMatch)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.expr);
+    for it in node.arms.iter() {
+        v.visit_arm(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_expr_method_call<'ast, V>(v: &mut V, node: &'ast ExprMethodCall)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.receiver);
+    v.visit_ident(&node.method);
+    if let Some(it) = &node.turbofish {
+        v.visit_angle_bracketed_generic_arguments(it);
+    }
+    for it in node.args.iter() {
+        v.visit_expr(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_expr_paren<'ast, V>(v: &mut V, node: &'ast ExprParen)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.expr);
+}
+pub(crate) fn visit_expr_path<'ast, V>(v: &mut V, node: &'ast ExprPath)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.qself {
+        v.visit_q_self(it);
+    }
+    v.visit_path(&node.path);
+}
+pub(crate) fn visit_expr_range<'ast, V>(v: &mut V, node: &'ast ExprRange)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.start {
+        v.visit_expr(&**it);
+    }
+    v.visit_range_limits(&node.limits);
+    if let Some(it) = &node.end {
+        v.visit_expr(&**it);
+    }
+}
+pub(crate) fn visit_expr_reference<'ast, V>(v: &mut V, node: &'ast ExprReference)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.expr);
+}
+pub(crate) fn visit_expr_repeat<'ast, V>(v: &mut V, node: &'ast ExprRepeat)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.expr);
+    v.visit_expr(&*node.len);
+}
+pub(crate) fn visit_expr_return<'ast, V>(v: &mut V, node: &'ast ExprReturn)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(&**it);
+    }
+}
+pub(crate) fn visit_expr_struct<'ast, V>(v: &mut V, node: &'ast ExprStruct)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.qself {
+        v.visit_q_self(it);
+    }
+    v.visit_path(&node.path);
+    for it in node.fields.iter() {
+        v.visit_field_value(it);
+    }
+    if let Some(it) = &node.rest {
+        v.visit_expr(&**it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_expr_try<'ast, V>(v: &mut V, node: &'ast ExprTry)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_expr(&*node.expr);
+}
+pub(crate) fn visit_expr_try_block<'ast, V>(v: &mut V, node: &'ast ExprTryBlock)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_block(&node.block);
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_expr_tuple<'ast, V>(v: &mut V, node: &'ast ExprTuple)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    for it in node.elems.iter() {
+        v.visit_expr(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_expr_unary<'ast, V>(v: &mut V, node: &'ast ExprUnary)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_un_op(&node.op);
+    v.visit_expr(&*node.expr);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_expr_unsafe<'ast, V>(v: &mut V, node: &'ast ExprUnsafe)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_block(&node.block);
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_expr_while<'ast, V>(v: &mut V, node: &'ast ExprWhile)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.label {
+        v.visit_label(it);
+    }
+    v.visit_expr(&*node.cond);
+    v.visit_block(&node.body);
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_expr_yield<'ast, V>(v: &mut V, node: &'ast ExprYield)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.expr {
+        v.visit_expr(&**it);
+    }
+}
+pub(crate) fn visit_field<'ast, V>(v: &mut V, node: &'ast Field)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_field_mutability(&node.mutability);
+    if let Some(it) = &node.ident {
+        v.visit_ident(it);
+    }
+    v.visit_type(&node.ty);
+}
+pub(crate) fn visit_field_mutability<'ast, V>(_v: &mut V, _node: &'ast FieldMutability)
+where
+    V: Visit<'ast> + ?Sized,
+{}
+pub(crate) fn visit_field_pat<'ast, V>(v: &mut V, node: &'ast FieldPat)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_member(&node.member);
+    v.visit_pat(&*node.pat);
+}
+pub(crate) fn visit_field_value<'ast, V>(v: &mut V, node: &'ast FieldValue)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_member(&node.member);
+    v.visit_expr(&node.expr);
+}
+pub(crate) fn visit_fields<'ast, V>(v: &mut V, node: &'ast Fields)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        Fields::Named(it) => {
+            v.visit_fields_named(it);
+        }
+        Fields::Unnamed(it) => {
+            v.visit_fields_unnamed(it);
+        }
+        Fields::Unit => {}
+    }
+}
+pub(crate) fn visit_fields_named<'ast, V>(v: &mut V, node: &'ast FieldsNamed)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.named.iter() {
+        v.visit_field(it);
+    }
+}
+pub(crate) fn visit_fields_unnamed<'ast, V>(v: &mut V, node: &'ast FieldsUnnamed)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.unnamed.iter() {
+        v.visit_field(it);
+    }
+}
+pub(crate) fn visit_file<'ast, V>(v: &mut V, node: &'ast File)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    for it in node.items.iter() {
+        v.visit_item(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_fn_arg<'ast, V>(v: &mut V, node: &'ast FnArg)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        FnArg::Receiver(it) => {
+            v.visit_receiver(it);
+        }
+        FnArg::Typed(it) => {
+            v.visit_pat_type(it);
+        }
+    }
+}
+pub(crate) fn visit_foreign_item<'ast, V>(v: &mut V, node: &'ast ForeignItem)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        ForeignItem::Fn(it) => {
+            v.visit_foreign_item_fn(it);
+        }
+        ForeignItem::Static(it) => {
+            v.visit_foreign_item_static(it);
+        }
+        ForeignItem::Type(it) => {
+            v.visit_foreign_item_type(it);
+        }
+        ForeignItem::Macro(it) => {
+            v.visit_foreign_item_macro(it);
+        }
+        ForeignItem::Verbatim(it) => {
+            v.visit_token_stream(it);
+        }
+    }
+}
+pub(crate) fn visit_foreign_item_fn<'ast, V>(v: &mut V, node: &'ast ForeignItemFn)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_signature(&node.sig);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_foreign_item_macro<'ast, V>(v: &mut V, node: &'ast ForeignItemMacro)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_macro(&node.mac);
+}
+pub(crate) fn visit_foreign_item_static<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemStatic,
+)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_static_mutability(&node.mutability);
+    v.visit_ident(&node.ident);
+    v.visit_type(&*node.ty);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_foreign_item_type<'ast, V>(v: &mut V, node: &'ast ForeignItemType)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_generic_argument<'ast, V>(v: &mut V, node: &'ast GenericArgument)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        GenericArgument::Lifetime(it) => {
+            v.visit_lifetime(it);
+        }
+        GenericArgument::Type(it) => {
+            v.visit_type(it);
+        }
+        GenericArgument::Const(it) => {
+            v.visit_expr(it);
+        }
+        GenericArgument::AssocType(it) => {
+            v.visit_assoc_type(it);
+        }
+        GenericArgument::AssocConst(it) => {
+            v.visit_assoc_const(it);
+        }
+        GenericArgument::Constraint(it) => {
+            v.visit_constraint(it);
+        }
+    }
+}
+pub(crate) fn visit_generic_param<'ast, V>(v: &mut V, node: &'ast GenericParam)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        GenericParam::Lifetime(it) => {
+            v.visit_lifetime_param(it);
+        }
+        GenericParam::Type(it) => {
+            v.visit_type_param(it);
+        }
+        GenericParam::Const(it) => {
+            v.visit_const_param(it);
+        }
+    }
+}
+pub(crate) fn visit_generics<'ast, V>(v: &mut V, node: &'ast Generics)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.params.iter() {
+        v.visit_generic_param(it);
+    }
+    if let Some(it) = &node.where_clause {
+        v.visit_where_clause(it);
+    }
+}
+pub(crate) fn visit_impl_item<'ast, V>(v: &mut V, node: &'ast ImplItem)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        ImplItem::Const(it) => {
+            v.visit_impl_item_const(it);
+        }
+        ImplItem::Fn(it) => {
+            v.visit_impl_item_fn(it);
+        }
+        ImplItem::Type(it) => {
+            v.visit_impl_item_type(it);
+        }
+        ImplItem::Macro(it) => {
+            v.visit_impl_item_macro(it);
+        }
+        ImplItem::Verbatim(it) => {
+            v.visit_token_stream(it);
+        }
+    }
+}
+pub(crate) fn visit_impl_item_const<'ast, V>(v: &mut V, node: &'ast ImplItemConst)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    v.visit_type(&node.ty);
+    v.visit_expr(&node.expr);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_impl_item_fn<'ast, V>(v: &mut V, node: &'ast ImplItemFn)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_signature(&node.sig);
+    v.visit_block(&node.block);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_impl_item_macro<'ast, V>(v: &mut V, node: &'ast ImplItemMacro)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_macro(&node.mac);
+}
+pub(crate) fn visit_impl_item_type<'ast, V>(v: &mut V, node: &'ast ImplItemType)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    v.visit_type(&node.ty);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_impl_restriction<'ast, V>(_v: &mut V, _node: &'ast ImplRestriction)
+where
+    V: Visit<'ast> + ?Sized,
+{}
+pub(crate) fn visit_index<'ast, V>(v: &mut V, node: &'ast Index)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_span_info(&node.span);
+}
+pub(crate) fn visit_item<'ast, V>(v: &mut V, node: &'ast Item)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        Item::Const(it) => {
+            v.visit_item_const(it);
+        }
+        Item::Enum(it) => {
+            v.visit_item_enum(it);
+        }
+        Item::ExternCrate(it) => {
+            v.visit_item_extern_crate(it);
+        }
+        Item::Fn(it) => {
+            v.visit_item_fn(it);
+        }
+        Item::ForeignMod(it) => {
+            v.visit_item_foreign_mod(it);
+        }
+        Item::Impl(it) => {
+            v.visit_item_impl(it);
+        }
+        Item::Macro(it) => {
+            v.visit_item_macro(it);
+        }
+        Item::Mod(it) => {
+            v.visit_item_mod(it);
+        }
+        Item::Static(it) => {
+            v.visit_item_static(it);
+        }
+        Item::Struct(it) => {
+            v.visit_item_struct(it);
+        }
+        Item::Trait(it) => {
+            v.visit_item_trait(it);
+        }
+        Item::TraitAlias(it) => {
+            v.visit_item_trait_alias(it);
+        }
+        Item::Type(it) => {
+            v.visit_item_type(it);
+        }
+        Item::Union(it) => {
+            v.visit_item_union(it);
+        }
+        Item::Use(it) => {
+            v.visit_item_use(it);
+        }
+        Item::Verbatim(it) => {
+            v.visit_token_stream(it);
+        }
+    }
+}
+pub(crate) fn visit_item_const<'ast, V>(v: &mut V, node: &'ast ItemConst)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    v.visit_type(&*node.ty);
+    v.visit_expr(&*node.expr);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_item_enum<'ast, V>(v: &mut V, node: &'ast ItemEnum)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    for it in node.variants.iter() {
+        v.visit_variant(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_item_extern_crate<'ast, V>(v: &mut V, node: &'ast ItemExternCrate)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_ident(&node.ident);
+    if let Some(_0) = &node.rename {
+        v.visit_ident(_0);
+    }
+}
+pub(crate) fn visit_item_fn<'ast, V>(v: &mut V, node: &'ast ItemFn)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_signature(&node.sig);
+    v.visit_block(&*node.block);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_item_foreign_mod<'ast, V>(v: &mut V, node: &'ast ItemForeignMod)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_abi(&node.abi);
+    for it in node.items.iter() {
+        v.visit_foreign_item(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_item_impl<'ast, V>(v: &mut V, node: &'ast ItemImpl)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_generics(&node.generics);
+    if let Some((_0, _1)) = &node.trait_ {
+        v.visit_path(_1);
+    }
+    v.visit_type(&*node.self_ty);
+    for it in node.items.iter() {
+        v.visit_impl_item(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_item_macro<'ast, V>(v: &mut V, node: &'ast ItemMacro)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.ident {
+        v.visit_ident(it);
+    }
+    v.visit_macro(&node.mac);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_item_mod<'ast, V>(v: &mut V, node: &'ast ItemMod)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_ident(&node.ident);
+    if let Some(_0) = &node.content {
+        for it in _0.iter() {
+            v.visit_item(it);
+        }
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_item_static<'ast, V>(v: &mut V, node: &'ast ItemStatic)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_static_mutability(&node.mutability);
+    v.visit_ident(&node.ident);
+    v.visit_type(&*node.ty);
+    v.visit_expr(&*node.expr);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_item_struct<'ast, V>(v: &mut V, node: &'ast ItemStruct)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    v.visit_fields(&node.fields);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_item_trait<'ast, V>(v: &mut V, node: &'ast ItemTrait)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    if let Some(it) = &node.restriction {
+        v.visit_impl_restriction(it);
+    }
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    for it in node.supertraits.iter() {
+        v.visit_type_param_bound(it);
+    }
+    for it in node.items.iter() {
+        v.visit_trait_item(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_item_trait_alias<'ast, V>(v: &mut V, node: &'ast ItemTraitAlias)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    for it in node.bounds.iter() {
+        v.visit_type_param_bound(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_item_type<'ast, V>(v: &mut V, node: &'ast ItemType)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    v.visit_type(&*node.ty);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_item_union<'ast, V>(v: &mut V, node: &'ast ItemUnion)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    v.visit_fields_named(&node.fields);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_item_use<'ast, V>(v: &mut V, node: &'ast ItemUse)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_visibility(&node.vis);
+    v.visit_use_tree(&node.tree);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    for it in node.comments.iter() {
+        v.visit_comment(it);
+    }
+}
+pub(crate) fn visit_label<'ast, V>(v: &mut V, node: &'ast Label)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_lifetime(&node.name);
+}
+pub(crate) fn visit_lifetime<'ast, V>(v: &mut V, node: &'ast Lifetime)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_span_info(&node.apostrophe);
+    v.visit_ident(&node.ident);
+}
+pub(crate) fn visit_lifetime_param<'ast, V>(v: &mut V, node: &'ast LifetimeParam)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_lifetime(&node.lifetime);
+    for it in node.bounds.iter() {
+        v.visit_lifetime(it);
+    }
+}
+pub(crate) fn visit_lit<'ast, V>(v: &mut V, node: &'ast Lit)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        Lit::Str(it) => {
+            v.visit_lit_str(it);
+        }
+        Lit::ByteStr(it) => {
+            v.visit_lit_byte_str(it);
+        }
+        Lit::CStr(it) => {
+            v.visit_lit_c_str(it);
+        }
+        Lit::Byte(it) => {
+            v.visit_lit_byte(it);
+        }
+        Lit::Char(it) => {
+            v.visit_lit_char(it);
+        }
+        Lit::Int(it) => {
+            v.visit_lit_int(it);
+        }
+        Lit::Float(it) => {
+            v.visit_lit_float(it);
+        }
+        Lit::Bool(it) => {
+            v.visit_lit_bool(it);
+        }
+        Lit::Verbatim(it) => {
+            v.visit_literal(it);
+        }
+    }
+}
+pub(crate) fn visit_lit_bool<'ast, V>(v: &mut V, node: &'ast LitBool)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_span_info(&node.span);
+}
+pub(crate) fn visit_lit_byte<'ast, V>(v: &mut V, node: &'ast LitByte)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_literal(&node.token);
+}
+pub(crate) fn visit_lit_byte_str<'ast, V>(v: &mut V, node: &'ast LitByteStr)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_literal(&node.token);
+}
+pub(crate) fn visit_lit_c_str<'ast, V>(v: &mut V, node: &'ast LitCStr)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_literal(&node.token);
+}
+pub(crate) fn visit_lit_char<'ast, V>(v: &mut V, node: &'ast LitChar)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_literal(&node.token);
+}
+pub(crate) fn visit_lit_float<'ast, V>(v: &mut V, node: &'ast LitFloat)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_literal(&node.token);
+}
+pub(crate) fn visit_lit_int<'ast, V>(v: &mut V, node: &'ast LitInt)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_literal(&node.token);
+}
+pub(crate) fn visit_lit_str<'ast, V>(v: &mut V, node: &'ast LitStr)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_literal(&node.token);
+}
+pub(crate) fn visit_local<'ast, V>(v: &mut V, node: &'ast Local)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_pat(&node.pat);
+    if let Some(it) = &node.init {
+        v.visit_local_init(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_local_init<'ast, V>(v: &mut V, node: &'ast LocalInit)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_expr(&*node.expr);
+    if let Some(_0) = &node.diverge {
+        v.visit_expr(&**_0);
+    }
+}
+pub(crate) fn visit_macro<'ast, V>(v: &mut V, node: &'ast Macro)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_path(&node.path);
+    v.visit_macro_delimiter(&node.delimiter);
+    v.visit_token_stream(&node.tokens);
+}
+pub(crate) fn visit_macro_delimiter<'ast, V>(_v: &mut V, _node: &'ast MacroDelimiter)
+where
+    V: Visit<'ast> + ?Sized,
+{}
+pub(crate) fn visit_member<'ast, V>(v: &mut V, node: &'ast Member)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        Member::Named(it) => {
+            v.visit_ident(it);
+        }
+        Member::Unnamed(it) => {
+            v.visit_index(it);
+        }
+    }
+}
+pub(crate) fn visit_meta<'ast, V>(v: &mut V, node: &'ast Meta)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        Meta::Path(it) => {
+            v.visit_path(it);
+        }
+        Meta::List(it) => {
+            v.visit_meta_list(it);
+        }
+        Meta::NameValue(it) => {
+            v.visit_meta_name_value(it);
+        }
+    }
+}
+pub(crate) fn visit_meta_list<'ast, V>(v: &mut V, node: &'ast MetaList)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_path(&node.path);
+    v.visit_macro_delimiter(&node.delimiter);
+    v.visit_token_stream(&node.tokens);
+}
+pub(crate) fn visit_meta_name_value<'ast, V>(v: &mut V, node: &'ast MetaNameValue)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_path(&node.path);
+    v.visit_expr(&node.value);
+}
+pub(crate) fn visit_parenthesized_generic_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast ParenthesizedGenericArguments,
+)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.inputs.iter() {
+        v.visit_type(it);
+    }
+    v.visit_return_type(&node.output);
+}
+pub(crate) fn visit_pat<'ast, V>(v: &mut V, node: &'ast Pat)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        Pat::Const(it) => {
+            v.visit_expr_const(it);
+        }
+        Pat::Ident(it) => {
+            v.visit_pat_ident(it);
+        }
+        Pat::Lit(it) => {
+            v.visit_expr_lit(it);
+        }
+        Pat::Macro(it) => {
+            v.visit_expr_macro(it);
+        }
+        Pat::Or(it) => {
+            v.visit_pat_or(it);
+        }
+        Pat::Paren(it) => {
+            v.visit_pat_paren(it);
+        }
+        Pat::Path(it) => {
+            v.visit_expr_path(it);
+        }
+        Pat::Range(it) => {
+            v.visit_expr_range(it);
+        }
+        Pat::Reference(it) => {
+            v.visit_pat_reference(it);
+        }
+        Pat::Rest(it) => {
+            v.visit_pat_rest(it);
+        }
+        Pat::Slice(it) => {
+            v.visit_pat_slice(it);
+        }
+        Pat::Struct(it) => {
+            v.visit_pat_struct(it);
+        }
+        Pat::Tuple(it) => {
+            v.visit_pat_tuple(it);
+        }
+        Pat::TupleStruct(it) => {
+            v.visit_pat_tuple_struct(it);
+        }
+        Pat::Type(it) => {
+            v.visit_pat_type(it);
+        }
+        Pat::Verbatim(it) => {
+            v.visit_token_stream(it);
+        }
+        Pat::Wild(it) => {
+            v.visit_pat_wild(it);
+        }
+    }
+}
+pub(crate) fn visit_pat_ident<'ast, V>(v: &mut V, node: &'ast PatIdent)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_ident(&node.ident);
+    if let Some(_0) = &node.subpat {
+        v.visit_pat(&**_0);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_pat_or<'ast, V>(v: &mut V, node: &'ast PatOr)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    for it in node.cases.iter() {
+        v.visit_pat(it);
+    }
+}
+pub(crate) fn visit_pat_paren<'ast, V>(v: &mut V, node: &'ast PatParen)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_pat(&*node.pat);
+}
+pub(crate) fn visit_pat_reference<'ast, V>(v: &mut V, node: &'ast PatReference)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_pat(&*node.pat);
+}
+pub(crate) fn visit_pat_rest<'ast, V>(v: &mut V, node: &'ast PatRest)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+}
+pub(crate) fn visit_pat_slice<'ast, V>(v: &mut V, node: &'ast PatSlice)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    for it in node.elems.iter() {
+        v.visit_pat(it);
+    }
+}
+pub(crate) fn visit_pat_struct<'ast, V>(v: &mut V, node: &'ast PatStruct)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.qself {
+        v.visit_q_self(it);
+    }
+    v.visit_path(&node.path);
+    for it in node.fields.iter() {
+        v.visit_field_pat(it);
+    }
+    if let Some(it) = &node.rest {
+        v.visit_pat_rest(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_pat_tuple<'ast, V>(v: &mut V, node: &'ast PatTuple)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    for it in node.elems.iter() {
+        v.visit_pat(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_pat_tuple_struct<'ast, V>(v: &mut V, node: &'ast PatTupleStruct)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.qself {
+        v.visit_q_self(it);
+    }
+    v.visit_path(&node.path);
+    for it in node.elems.iter() {
+        v.visit_pat(it);
+    }
+}
+pub(crate) fn visit_pat_type<'ast, V>(v: &mut V, node: &'ast PatType)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_pat(&*node.pat);
+    v.visit_type(&*node.ty);
+}
+pub(crate) fn visit_pat_wild<'ast, V>(v: &mut V, node: &'ast PatWild)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+}
+pub(crate) fn visit_path<'ast, V>(v: &mut V, node: &'ast Path)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.segments.iter() {
+        v.visit_path_segment(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_path_arguments<'ast, V>(v: &mut V, node: &'ast PathArguments)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        PathArguments::None => {}
+        PathArguments::AngleBracketed(it) => {
+            v.visit_angle_bracketed_generic_arguments(it);
+        }
+        PathArguments::Parenthesized(it) => {
+            v.visit_parenthesized_generic_arguments(it);
+        }
+    }
+}
+pub(crate) fn visit_path_segment<'ast, V>(v: &mut V, node: &'ast PathSegment)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident);
+    v.visit_path_arguments(&node.arguments);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_predicate_lifetime<'ast, V>(v: &mut V, node: &'ast PredicateLifetime)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_lifetime(&node.lifetime);
+    for it in node.bounds.iter() {
+        v.visit_lifetime(it);
+    }
+}
+pub(crate) fn visit_predicate_type<'ast, V>(v: &mut V, node: &'ast PredicateType)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it);
+    }
+    v.visit_type(&node.bounded_ty);
+    for it in node.bounds.iter() {
+        v.visit_type_param_bound(it);
+    }
+}
+pub(crate) fn visit_q_self<'ast, V>(v: &mut V, node: &'ast QSelf)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_type(&*node.ty);
+}
+pub(crate) fn visit_range_limits<'ast, V>(_v: &mut V, _node: &'ast RangeLimits)
+where
+    V: Visit<'ast> + ?Sized,
+{}
+pub(crate) fn visit_receiver<'ast, V>(v: &mut V, node: &'ast Receiver)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(it) = &node.lifetime {
+        v.visit_lifetime(it);
+    }
+    v.visit_type(&*node.ty);
+}
+pub(crate) fn visit_return_type<'ast, V>(v: &mut V, node: &'ast ReturnType)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.ty {
+        v.visit_type(&**it);
+    }
+}
+pub(crate) fn visit_signature<'ast, V>(v: &mut V, node: &'ast Signature)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.abi {
+        v.visit_abi(it);
+    }
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    for it in node.inputs.iter() {
+        v.visit_fn_arg(it);
+    }
+    if let Some(it) = &node.variadic {
+        v.visit_variadic(it);
+    }
+    v.visit_return_type(&node.output);
+}
+pub(crate) fn visit_static_mutability<'ast, V>(_v: &mut V, _node: &'ast StaticMutability)
+where
+    V: Visit<'ast> + ?Sized,
+{}
+pub(crate) fn visit_stmt<'ast, V>(v: &mut V, node: &'ast Stmt)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        Stmt::Local(it) => {
+            v.visit_local(it);
+        }
+        Stmt::Item(it) => {
+            v.visit_item(it);
+        }
+        Stmt::Expr { expr, .. } => {
+            v.visit_expr(expr);
+        }
+        Stmt::Macro(it) => {
+            v.visit_stmt_macro(it);
+        }
+    }
+}
+pub(crate) fn visit_stmt_macro<'ast, V>(v: &mut V, node: &'ast StmtMacro)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_macro(&node.mac);
+}
+pub(crate) fn visit_trait_bound<'ast, V>(v: &mut V, node: &'ast TraitBound)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_trait_bound_modifier(&node.modifier);
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it);
+    }
+    v.visit_path(&node.path);
+}
+pub(crate) fn visit_trait_bound_modifier<'ast, V>(
+    _v: &mut V,
+    _node: &'ast TraitBoundModifier,
+)
+where
+    V: Visit<'ast> + ?Sized,
+{}
+pub(crate) fn visit_trait_item<'ast, V>(v: &mut V, node: &'ast TraitItem)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        TraitItem::Const(it) => {
+            v.visit_trait_item_const(it);
+        }
+        TraitItem::Fn(it) => {
+            v.visit_trait_item_fn(it);
+        }
+        TraitItem::Type(it) => {
+            v.visit_trait_item_type(it);
+        }
+        TraitItem::Macro(it) => {
+            v.visit_trait_item_macro(it);
+        }
+        TraitItem::Verbatim(it) => {
+            v.visit_token_stream(it);
+        }
+    }
+}
+pub(crate) fn visit_trait_item_const<'ast, V>(v: &mut V, node: &'ast TraitItemConst)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    v.visit_type(&node.ty);
+    if let Some(_0) = &node.default {
+        v.visit_expr(_0);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_trait_item_fn<'ast, V>(v: &mut V, node: &'ast TraitItemFn)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_signature(&node.sig);
+    if let Some(it) = &node.default {
+        v.visit_block(it);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_trait_item_macro<'ast, V>(v: &mut V, node: &'ast TraitItemMacro)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_macro(&node.mac);
+}
+pub(crate) fn visit_trait_item_type<'ast, V>(v: &mut V, node: &'ast TraitItemType)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_ident(&node.ident);
+    v.visit_generics(&node.generics);
+    for it in node.bounds.iter() {
+        v.visit_type_param_bound(it);
+    }
+    if let Some(_0) = &node.default {
+        v.visit_type(_0);
+    }
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_type<'ast, V>(v: &mut V, node: &'ast Type)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        Type::Array(it) => {
+            v.visit_type_array(it);
+        }
+        Type::BareFn(it) => {
+            v.visit_type_bare_fn(it);
+        }
+        Type::Group(it) => {
+            v.visit_type_group(it);
+        }
+        Type::ImplTrait(it) => {
+            v.visit_type_impl_trait(it);
+        }
+        Type::Infer => {}
+        Type::Macro(it) => {
+            v.visit_type_macro(it);
+        }
+        Type::Never => {}
+        Type::Paren(it) => {
+            v.visit_type_paren(it);
+        }
+        Type::Path(it) => {
+            v.visit_type_path(it);
+        }
+        Type::Ptr(it) => {
+            v.visit_type_ptr(it);
+        }
+        Type::Reference(it) => {
+            v.visit_type_reference(it);
+        }
+        Type::Slice(it) => {
+            v.visit_type_slice(it);
+        }
+        Type::TraitObject(it) => {
+            v.visit_type_trait_object(it);
+        }
+        Type::Tuple(it) => {
+            v.visit_type_tuple(it);
+        }
+        Type::Verbatim(it) => {
+            v.visit_token_stream(it);
+        }
+    }
+}
+pub(crate) fn visit_type_array<'ast, V>(v: &mut V, node: &'ast TypeArray)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_type(&*node.elem);
+    v.visit_expr(&node.len);
+}
+pub(crate) fn visit_type_bare_fn<'ast, V>(v: &mut V, node: &'ast TypeBareFn)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.lifetimes {
+        v.visit_bound_lifetimes(it);
+    }
+    if let Some(it) = &node.abi {
+        v.visit_abi(it);
+    }
+    for it in node.inputs.iter() {
+        v.visit_bare_fn_arg(it);
+    }
+    if let Some(it) = &node.variadic {
+        v.visit_bare_variadic(it);
+    }
+    v.visit_return_type(&node.output);
+}
+pub(crate) fn visit_type_group<'ast, V>(v: &mut V, node: &'ast TypeGroup)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_type(&*node.elem);
+}
+pub(crate) fn visit_type_impl_trait<'ast, V>(v: &mut V, node: &'ast TypeImplTrait)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.bounds.iter() {
+        v.visit_type_param_bound(it);
+    }
+}
+pub(crate) fn visit_type_macro<'ast, V>(v: &mut V, node: &'ast TypeMacro)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_macro(&node.mac);
+}
+pub(crate) fn visit_type_param<'ast, V>(v: &mut V, node: &'ast TypeParam)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_ident(&node.ident);
+    for it in node.bounds.iter() {
+        v.visit_type_param_bound(it);
+    }
+    if let Some(it) = &node.default {
+        v.visit_type(it);
+    }
+}
+pub(crate) fn visit_type_param_bound<'ast, V>(v: &mut V, node: &'ast TypeParamBound)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        TypeParamBound::Trait(it) => {
+            v.visit_trait_bound(it);
+        }
+        TypeParamBound::Lifetime(it) => {
+            v.visit_lifetime(it);
+        }
+        TypeParamBound::Verbatim(it) => {
+            v.visit_token_stream(it);
+        }
+    }
+}
+pub(crate) fn visit_type_paren<'ast, V>(v: &mut V, node: &'ast TypeParen)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_type(&*node.elem);
+}
+pub(crate) fn visit_type_path<'ast, V>(v: &mut V, node: &'ast TypePath)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.qself {
+        v.visit_q_self(it);
+    }
+    v.visit_path(&node.path);
+}
+pub(crate) fn visit_type_ptr<'ast, V>(v: &mut V, node: &'ast TypePtr)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_type(&*node.elem);
+}
+pub(crate) fn visit_type_reference<'ast, V>(v: &mut V, node: &'ast TypeReference)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.lifetime {
+        v.visit_lifetime(it);
+    }
+    v.visit_type(&*node.elem);
+}
+pub(crate) fn visit_type_slice<'ast, V>(v: &mut V, node: &'ast TypeSlice)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_type(&*node.elem);
+}
+pub(crate) fn visit_type_trait_object<'ast, V>(v: &mut V, node: &'ast TypeTraitObject)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.bounds.iter() {
+        v.visit_type_param_bound(it);
+    }
+}
+pub(crate) fn visit_type_tuple<'ast, V>(v: &mut V, node: &'ast TypeTuple)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.elems.iter() {
+        v.visit_type(it);
+    }
+}
+pub(crate) fn visit_un_op<'ast, V>(_v: &mut V, _node: &'ast UnOp)
+where
+    V: Visit<'ast> + ?Sized,
+{}
+pub(crate) fn visit_use_group<'ast, V>(v: &mut V, node: &'ast UseGroup)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.items.iter() {
+        v.visit_use_tree(it);
+    }
+}
+pub(crate) fn visit_use_name<'ast, V>(v: &mut V, node: &'ast UseName)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident);
+}
+pub(crate) fn visit_use_path<'ast, V>(v: &mut V, node: &'ast UsePath)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident);
+    v.visit_use_tree(&*node.tree);
+}
+pub(crate) fn visit_use_rename<'ast, V>(v: &mut V, node: &'ast UseRename)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_ident(&node.ident);
+    v.visit_ident(&node.rename);
+}
+pub(crate) fn visit_use_tree<'ast, V>(v: &mut V, node: &'ast UseTree)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        UseTree::Path(it) => {
+            v.visit_use_path(it);
+        }
+        UseTree::Name(it) => {
+            v.visit_use_name(it);
+        }
+        UseTree::Rename(it) => {
+            v.visit_use_rename(it);
+        }
+        UseTree::Glob => {}
+        UseTree::Group(it) => {
+            v.visit_use_group(it);
+        }
+    }
+}
+pub(crate) fn visit_variadic<'ast, V>(v: &mut V, node: &'ast Variadic)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    if let Some(_0) = &node.pat {
+        v.visit_pat(&**_0);
+    }
+}
+pub(crate) fn visit_variant<'ast, V>(v: &mut V, node: &'ast Variant)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.attrs.iter() {
+        v.visit_attribute(it);
+    }
+    v.visit_ident(&node.ident);
+    v.visit_fields(&node.fields);
+    if let Some(_0) = &node.discriminant {
+        v.visit_expr(_0);
+    }
+}
+pub(crate) fn visit_vis_restricted<'ast, V>(v: &mut V, node: &'ast VisRestricted)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_path(&*node.path);
+}
+pub(crate) fn visit_visibility<'ast, V>(v: &mut V, node: &'ast Visibility)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        Visibility::Public => {}
+        Visibility::Restricted(it) => {
+            v.visit_vis_restricted(it);
+        }
+        Visibility::Inherited => {}
+    }
+}
+pub(crate) fn visit_where_clause<'ast, V>(v: &mut V, node: &'ast WhereClause)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.predicates.iter() {
+        v.visit_where_predicate(it);
+    }
+}
+pub(crate) fn visit_where_predicate<'ast, V>(v: &mut V, node: &'ast WherePredicate)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        WherePredicate::Lifetime(it) => {
+            v.visit_predicate_lifetime(it);
+        }
+        WherePredicate::Type(it) => {
+            v.visit_predicate_type(it);
+        }
+    }
+}
+pub(crate) fn visit_comment<'ast, V>(v: &mut V, node: &'ast Comment)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_span_info(&node.span);
+}
+pub(crate) fn visit_delimiter<'ast, V>(_v: &mut V, _node: &'ast Delimiter)
+where
+    V: Visit<'ast> + ?Sized,
+{}
+pub(crate) fn visit_group<'ast, V>(v: &mut V, node: &'ast Group)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_delimiter(&node.delimiter);
+    v.visit_token_stream(&node.stream);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+    if let Some(it) = &node.span_open {
+        v.visit_span_info(it);
+    }
+    if let Some(it) = &node.span_close {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_ident<'ast, V>(v: &mut V, node: &'ast Ident)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_literal<'ast, V>(v: &mut V, node: &'ast Literal)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_punct<'ast, V>(v: &mut V, node: &'ast Punct)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    v.visit_spacing(&node.spacing);
+    if let Some(it) = &node.span {
+        v.visit_span_info(it);
+    }
+}
+pub(crate) fn visit_spacing<'ast, V>(_v: &mut V, _node: &'ast Spacing)
+where
+    V: Visit<'ast> + ?Sized,
+{}
+pub(crate) fn visit_span_info<'ast, V>(_v: &mut V, _node: &'ast SpanInfo)
+where
+    V: Visit<'ast> + ?Sized,
+{}
+pub(crate) fn visit_token_stream<'ast, V>(v: &mut V, node: &'ast TokenStream)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for it in node.inner.iter() {
+        v.visit_token_tree(it);
+    }
+}
+pub(crate) fn visit_token_tree<'ast, V>(v: &mut V, node: &'ast TokenTree)
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match node {
+        TokenTree::Group(it) => v.visit_group(it),
+        TokenTree::Ident(it) => v.visit_ident(it),
+        TokenTree::Punct(it) => v.visit_punct(it),
+        TokenTree::Literal(it) => v.visit_literal(it),
+    }
+}
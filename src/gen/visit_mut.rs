@@ -0,0 +1,4408 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+// This file is @generated by syn-serde-internal-codegen
+// (generate function at tools/codegen/src/visit.rs).
+// It is not intended for manual editing.
+
+#![cfg_attr(rustfmt, rustfmt::skip)]
+#![allow(
+    clippy::explicit_auto_deref,
+    clippy::explicit_iter_loop,
+    clippy::match_same_arms,
+)]
+use crate::*;
+/// A traversal of the adapters that can modify them. Each method
+/// visits the children of the node by default, like
+/// `syn::visit_mut::VisitMut`.
+pub(crate) trait VisitMut {
+    fn visit_abi_mut(&mut self, node: &mut Abi) {
+        visit_abi_mut(self, node);
+    }
+    fn visit_angle_bracketed_generic_arguments_mut(
+        &mut self,
+        node: &mut AngleBracketedGenericArguments,
+    ) {
+        visit_angle_bracketed_generic_arguments_mut(self, node);
+    }
+    fn visit_arm_mut(&mut self, node: &mut Arm) {
+        visit_arm_mut(self, node);
+    }
+    fn visit_assoc_const_mut(&mut self, node: &mut AssocConst) {
+        visit_assoc_const_mut(self, node);
+    }
+    fn visit_assoc_type_mut(&mut self, node: &mut AssocType) {
+        visit_assoc_type_mut(self, node);
+    }
+    fn visit_attr_style_mut(&mut self, node: &mut AttrStyle) {
+        visit_attr_style_mut(self, node);
+    }
+    fn visit_attribute_mut(&mut self, node: &mut Attribute) {
+        visit_attribute_mut(self, node);
+    }
+    fn visit_bare_fn_arg_mut(&mut self, node: &mut BareFnArg) {
+        visit_bare_fn_arg_mut(self, node);
+    }
+    fn visit_bare_variadic_mut(&mut self, node: &mut BareVariadic) {
+        visit_bare_variadic_mut(self, node);
+    }
+    fn visit_bin_op_mut(&mut self, node: &mut BinOp) {
+        visit_bin_op_mut(self, node);
+    }
+    fn visit_block_mut(&mut self, node: &mut Block) {
+        visit_block_mut(self, node);
+    }
+    fn visit_bound_lifetimes_mut(&mut self, node: &mut BoundLifetimes) {
+        visit_bound_lifetimes_mut(self, node);
+    }
+    fn visit_const_param_mut(&mut self, node: &mut ConstParam) {
+        visit_const_param_mut(self, node);
+    }
+    fn visit_constraint_mut(&mut self, node: &mut Constraint) {
+        visit_constraint_mut(self, node);
+    }
+    fn visit_expr_mut(&mut self, node: &mut Expr) {
+        visit_expr_mut(self, node);
+    }
+    fn visit_expr_array_mut(&mut self, node: &mut ExprArray) {
+        visit_expr_array_mut(self, node);
+    }
+    fn visit_expr_assign_mut(&mut self, node: &mut ExprAssign) {
+        visit_expr_assign_mut(self, node);
+    }
+    fn visit_expr_async_mut(&mut self, node: &mut ExprAsync) {
+        visit_expr_async_mut(self, node);
+    }
+    fn visit_expr_await_mut(&mut self, node: &mut ExprAwait) {
+        visit_expr_await_mut(self, node);
+    }
+    fn visit_expr_binary_mut(&mut self, node: &mut ExprBinary) {
+        visit_expr_binary_mut(self, node);
+    }
+    fn visit_expr_block_mut(&mut self, node: &mut ExprBlock) {
+        visit_expr_block_mut(self, node);
+    }
+    fn visit_expr_break_mut(&mut self, node: &mut ExprBreak) {
+        visit_expr_break_mut(self, node);
+    }
+    fn visit_expr_call_mut(&mut self, node: &mut ExprCall) {
+        visit_expr_call_mut(self, node);
+    }
+    fn visit_expr_cast_mut(&mut self, node: &mut ExprCast) {
+        visit_expr_cast_mut(self, node);
+    }
+    fn visit_expr_closure_mut(&mut self, node: &mut ExprClosure) {
+        visit_expr_closure_mut(self, node);
+    }
+    fn visit_expr_const_mut(&mut self, node: &mut ExprConst) {
+        visit_expr_const_mut(self, node);
+    }
+    fn visit_expr_continue_mut(&mut self, node: &mut ExprContinue) {
+        visit_expr_continue_mut(self, node);
+    }
+    fn visit_expr_field_mut(&mut self, node: &mut ExprField) {
+        visit_expr_field_mut(self, node);
+    }
+    fn visit_expr_for_loop_mut(&mut self, node: &mut ExprForLoop) {
+        visit_expr_for_loop_mut(self, node);
+    }
+    fn visit_expr_group_mut(&mut self, node: &mut ExprGroup) {
+        visit_expr_group_mut(self, node);
+    }
+    fn visit_expr_if_mut(&mut self, node: &mut ExprIf) {
+        visit_expr_if_mut(self, node);
+    }
+    fn visit_expr_index_mut(&mut self, node: &mut ExprIndex) {
+        visit_expr_index_mut(self, node);
+    }
+    fn visit_expr_infer_mut(&mut self, node: &mut ExprInfer) {
+        visit_expr_infer_mut(self, node);
+    }
+    fn visit_expr_let_mut(&mut self, node: &mut ExprLet) {
+        visit_expr_let_mut(self, node);
+    }
+    fn visit_expr_lit_mut(&mut self, node: &mut ExprLit) {
+        visit_expr_lit_mut(self, node);
+    }
+    fn visit_expr_loop_mut(&mut self, node: &mut ExprLoop) {
+        visit_expr_loop_mut(self, node);
+    }
+    fn visit_expr_macro_mut(&mut self, node: &mut ExprMacro) {
+        visit_expr_macro_mut(self, node);
+    }
+    fn visit_expr_match_mut(&mut self, node: &mut ExprMatch) {
+        visit_expr_match_mut(self, node);
+    }
+    fn visit_expr_method_call_mut(&mut self, node: &mut ExprMethodCall) {
+        visit_expr_method_call_mut(self, node);
+    }
+    fn visit_expr_paren_mut(&mut self, node: &mut ExprParen) {
+        visit_expr_paren_mut(self, node);
+    }
+    fn visit_expr_path_mut(&mut self, node: &mut ExprPath) {
+        visit_expr_path_mut(self, node);
+    }
+    fn visit_expr_range_mut(&mut self, node: &mut ExprRange) {
+        visit_expr_range_mut(self, node);
+    }
+    fn visit_expr_reference_mut(&mut self, node: &mut ExprReference) {
+        visit_expr_reference_mut(self, node);
+    }
+    fn visit_expr_repeat_mut(&mut self, node: &mut ExprRepeat) {
+        visit_expr_repeat_mut(self, node);
+    }
+    fn visit_expr_return_mut(&mut self, node: &mut ExprReturn) {
+        visit_expr_return_mut(self, node);
+    }
+    fn visit_expr_struct_mut(&mut self, node: &mut ExprStruct) {
+        visit_expr_struct_mut(self, node);
+    }
+    fn visit_expr_try_mut(&mut self, node: &mut ExprTry) {
+        visit_expr_try_mut(self, node);
+    }
+    fn visit_expr_try_block_mut(&mut self, node: &mut ExprTryBlock) {
+        visit_expr_try_block_mut(self, node);
+    }
+    fn visit_expr_tuple_mut(&mut self, node: &mut ExprTuple) {
+        visit_expr_tuple_mut(self, node);
+    }
+    fn visit_expr_unary_mut(&mut self, node: &mut ExprUnary) {
+        visit_expr_unary_mut(self, node);
+    }
+    fn visit_expr_unsafe_mut(&mut self, node: &mut ExprUnsafe) {
+        visit_expr_unsafe_mut(self, node);
+    }
+    fn visit_expr_while_mut(&mut self, node: &mut ExprWhile) {
+        visit_expr_while_mut(self, node);
+    }
+    fn visit_expr_yield_mut(&mut self, node: &mut ExprYield) {
+        visit_expr_yield_mut(self, node);
+    }
+    fn visit_field_mut(&mut self, node: &mut Field) {
+        visit_field_mut(self, node);
+    }
+    fn visit_field_mutability_mut(&mut self, node: &mut FieldMutability) {
+        visit_field_mutability_mut(self, node);
+    }
+    fn visit_field_pat_mut(&mut self, node: &mut FieldPat) {
+        visit_field_pat_mut(self, node);
+    }
+    fn visit_field_value_mut(&mut self, node: &mut FieldValue) {
+        visit_field_value_mut(self, node);
+    }
+    fn visit_fields_mut(&mut self, node: &mut Fields) {
+        visit_fields_mut(self, node);
+    }
+    fn visit_fields_named_mut(&mut self, node: &mut FieldsNamed) {
+        visit_fields_named_mut(self, node);
+    }
+    fn visit_fields_unnamed_mut(&mut self, node: &mut FieldsUnnamed) {
+        visit_fields_unnamed_mut(self, node);
+    }
+    fn visit_file_mut(&mut self, node: &mut File) {
+        visit_file_mut(self, node);
+    }
+    fn visit_fn_arg_mut(&mut self, node: &mut FnArg) {
+        visit_fn_arg_mut(self, node);
+    }
+    fn visit_foreign_item_mut(&mut self, node: &mut ForeignItem) {
+        visit_foreign_item_mut(self, node);
+    }
+    fn visit_foreign_item_fn_mut(&mut self, node: &mut ForeignItemFn) {
+        visit_foreign_item_fn_mut(self, node);
+    }
+    fn visit_foreign_item_macro_mut(&mut self, node: &mut ForeignItemMacro) {
+        visit_foreign_item_macro_mut(self, node);
+    }
+    fn visit_foreign_item_static_mut(&mut self, node: &mut ForeignItemStatic) {
+        visit_foreign_item_static_mut(self, node);
+    }
+    fn visit_foreign_item_type_mut(&mut self, node: &mut ForeignItemType) {
+        visit_foreign_item_type_mut(self, node);
+    }
+    fn visit_generic_argument_mut(&mut self, node: &mut GenericArgument) {
+        visit_generic_argument_mut(self, node);
+    }
+    fn visit_generic_param_mut(&mut self, node: &mut GenericParam) {
+        visit_generic_param_mut(self, node);
+    }
+    fn visit_generics_mut(&mut self, node: &mut Generics) {
+        visit_generics_mut(self, node);
+    }
+    fn visit_impl_item_mut(&mut self, node: &mut ImplItem) {
+        visit_impl_item_mut(self, node);
+    }
+    fn visit_impl_item_const_mut(&mut self, node: &mut ImplItemConst) {
+        visit_impl_item_const_mut(self, node);
+    }
+    fn visit_impl_item_fn_mut(&mut self, node: &mut ImplItemFn) {
+        visit_impl_item_fn_mut(self, node);
+    }
+    fn visit_impl_item_macro_mut(&mut self, node: &mut ImplItemMacro) {
+        visit_impl_item_macro_mut(self, node);
+    }
+    fn visit_impl_item_type_mut(&mut self, node: &mut ImplItemType) {
+        visit_impl_item_type_mut(self, node);
+    }
+    fn visit_impl_restriction_mut(&mut self, node: &mut ImplRestriction) {
+        visit_impl_restriction_mut(self, node);
+    }
+    fn visit_index_mut(&mut self, node: &mut Index) {
+        visit_index_mut(self, node);
+    }
+    fn visit_item_mut(&mut self, node: &mut Item) {
+        visit_item_mut(self, node);
+    }
+    fn visit_item_const_mut(&mut self, node: &mut ItemConst) {
+        visit_item_const_mut(self, node);
+    }
+    fn visit_item_enum_mut(&mut self, node: &mut ItemEnum) {
+        visit_item_enum_mut(self, node);
+    }
+    fn visit_item_extern_crate_mut(&mut self, node: &mut ItemExternCrate) {
+        visit_item_extern_crate_mut(self, node);
+    }
+    fn visit_item_fn_mut(&mut self, node: &mut ItemFn) {
+        visit_item_fn_mut(self, node);
+    }
+    fn visit_item_foreign_mod_mut(&mut self, node: &mut ItemForeignMod) {
+        visit_item_foreign_mod_mut(self, node);
+    }
+    fn visit_item_impl_mut(&mut self, node: &mut ItemImpl) {
+        visit_item_impl_mut(self, node);
+    }
+    fn visit_item_macro_mut(&mut self, node: &mut ItemMacro) {
+        visit_item_macro_mut(self, node);
+    }
+    fn visit_item_mod_mut(&mut self, node: &mut ItemMod) {
+        visit_item_mod_mut(self, node);
+    }
+    fn visit_item_static_mut(&mut self, node: &mut ItemStatic) {
+        visit_item_static_mut(self, node);
+    }
+    fn visit_item_struct_mut(&mut self, node: &mut ItemStruct) {
+        visit_item_struct_mut(self, node);
+    }
+    fn visit_item_trait_mut(&mut self, node: &mut ItemTrait) {
+        visit_item_trait_mut(self, node);
+    }
+    fn visit_item_trait_alias_mut(&mut self, node: &mut ItemTraitAlias) {
+        visit_item_trait_alias_mut(self, node);
+    }
+    fn visit_item_type_mut(&mut self, node: &mut ItemType) {
+        visit_item_type_mut(self, node);
+    }
+    fn visit_item_union_mut(&mut self, node: &mut ItemUnion) {
+        visit_item_union_mut(self, node);
+    }
+    fn visit_item_use_mut(&mut self, node: &mut ItemUse) {
+        visit_item_use_mut(self, node);
+    }
+    fn visit_label_mut(&mut self, node: &mut Label) {
+        visit_label_mut(self, node);
+    }
+    fn visit_lifetime_mut(&mut self, node: &mut Lifetime) {
+        visit_lifetime_mut(self, node);
+    }
+    fn visit_lifetime_param_mut(&mut self, node: &mut LifetimeParam) {
+        visit_lifetime_param_mut(self, node);
+    }
+    fn visit_lit_mut(&mut self, node: &mut Lit) {
+        visit_lit_mut(self, node);
+    }
+    fn visit_lit_bool_mut(&mut self, node: &mut LitBool) {
+        visit_lit_bool_mut(self, node);
+    }
+    fn visit_lit_byte_mut(&mut self, node: &mut LitByte) {
+        visit_lit_byte_mut(self, node);
+    }
+    fn visit_lit_byte_str_mut(&mut self, node: &mut LitByteStr) {
+        visit_lit_byte_str_mut(self, node);
+    }
+    fn visit_lit_c_str_mut(&mut self, node: &mut LitCStr) {
+        visit_lit_c_str_mut(self, node);
+    }
+    fn visit_lit_char_mut(&mut self, node: &mut LitChar) {
+        visit_lit_char_mut(self, node);
+    }
+    fn visit_lit_float_mut(&mut self, node: &mut LitFloat) {
+        visit_lit_float_mut(self, node);
+    }
+    fn visit_lit_int_mut(&mut self, node: &mut LitInt) {
+        visit_lit_int_mut(self, node);
+    }
+    fn visit_lit_str_mut(&mut self, node: &mut LitStr) {
+        visit_lit_str_mut(self, node);
+    }
+    fn visit_local_mut(&mut self, node: &mut Local) {
+        visit_local_mut(self, node);
+    }
+    fn visit_local_init_mut(&mut self, node: &mut LocalInit) {
+        visit_local_init_mut(self, node);
+    }
+    fn visit_macro_mut(&mut self, node: &mut Macro) {
+        visit_macro_mut(self, node);
+    }
+    fn visit_macro_delimiter_mut(&mut self, node: &mut MacroDelimiter) {
+        visit_macro_delimiter_mut(self, node);
+    }
+    fn visit_member_mut(&mut self, node: &mut Member) {
+        visit_member_mut(self, node);
+    }
+    fn visit_meta_mut(&mut self, node: &mut Meta) {
+        visit_meta_mut(self, node);
+    }
+    fn visit_meta_list_mut(&mut self, node: &mut MetaList) {
+        visit_meta_list_mut(self, node);
+    }
+    fn visit_meta_name_value_mut(&mut self, node: &mut MetaNameValue) {
+        visit_meta_name_value_mut(self, node);
+    }
+    fn visit_parenthesized_generic_arguments_mut(
+        &mut self,
+        node: &mut ParenthesizedGenericArguments,
+    ) {
+        visit_parenthesized_generic_arguments_mut(self, node);
+    }
+    fn visit_pat_mut(&mut self, node: &mut Pat) {
+        visit_pat_mut(self, node);
+    }
+    fn visit_pat_ident_mut(&mut self, node: &mut PatIdent) {
+        visit_pat_ident_mut(self, node);
+    }
+    fn visit_pat_or_mut(&mut self, node: &mut PatOr) {
+        visit_pat_or_mut(self, node);
+    }
+    fn visit_pat_paren_mut(&mut self, node: &mut PatParen) {
+        visit_pat_paren_mut(self, node);
+    }
+    fn visit_pat_reference_mut(&mut self, node: &mut PatReference) {
+        visit_pat_reference_mut(self, node);
+    }
+    fn visit_pat_rest_mut(&mut self, node: &mut PatRest) {
+        visit_pat_rest_mut(self, node);
+    }
+    fn visit_pat_slice_mut(&mut self, node: &mut PatSlice) {
+        visit_pat_slice_mut(self, node);
+    }
+    fn visit_pat_struct_mut(&mut self, node: &mut PatStruct) {
+        visit_pat_struct_mut(self, node);
+    }
+    fn visit_pat_tuple_mut(&mut self, node: &mut PatTuple) {
+        visit_pat_tuple_mut(self, node);
+    }
+    fn visit_pat_tuple_struct_mut(&mut self, node: &mut PatTupleStruct) {
+        visit_pat_tuple_struct_mut(self, node);
+    }
+    fn visit_pat_type_mut(&mut self, node: &mut PatType) {
+        visit_pat_type_mut(self, node);
+    }
+    fn visit_pat_wild_mut(&mut self, node: &mut PatWild) {
+        visit_pat_wild_mut(self, node);
+    }
+    fn visit_path_mut(&mut self, node: &mut Path) {
+        visit_path_mut(self, node);
+    }
+    fn visit_path_arguments_mut(&mut self, node: &mut PathArguments) {
+        visit_path_arguments_mut(self, node);
+    }
+    fn visit_path_segment_mut(&mut self, node: &mut PathSegment) {
+        visit_path_segment_mut(self, node);
+    }
+    fn visit_predicate_lifetime_mut(&mut self, node: &mut PredicateLifetime) {
+        visit_predicate_lifetime_mut(self, node);
+    }
+    fn visit_predicate_type_mut(&mut self, node: &mut PredicateType) {
+        visit_predicate_type_mut(self, node);
+    }
+    fn visit_q_self_mut(&mut self, node: &mut QSelf) {
+        visit_q_self_mut(self, node);
+    }
+    fn visit_range_limits_mut(&mut self, node: &mut RangeLimits) {
+        visit_range_limits_mut(self, node);
+    }
+    fn visit_receiver_mut(&mut self, node: &mut Receiver) {
+        visit_receiver_mut(self, node);
+    }
+    fn visit_return_type_mut(&mut self, node: &mut ReturnType) {
+        visit_return_type_mut(self, node);
+    }
+    fn visit_signature_mut(&mut self, node: &mut Signature) {
+        visit_signature_mut(self, node);
+    }
+    fn visit_static_mutability_mut(&mut self, node: &mut StaticMutability) {
+        visit_static_mutability_mut(self, node);
+    }
+    fn visit_stmt_mut(&mut self, node: &mut Stmt) {
+        visit_stmt_mut(self, node);
+    }
+    fn visit_stmt_macro_mut(&mut self, node: &mut StmtMacro) {
+        visit_stmt_macro_mut(self, node);
+    }
+    fn visit_trait_bound_mut(&mut self, node: &mut TraitBound) {
+        visit_trait_bound_mut(self, node);
+    }
+    fn visit_trait_bound_modifier_mut(&mut self, node: &mut TraitBoundModifier) {
+        visit_trait_bound_modifier_mut(self, node);
+    }
+    fn visit_trait_item_mut(&mut self, node: &mut TraitItem) {
+        visit_trait_item_mut(self, node);
+    }
+    fn visit_trait_item_const_mut(&mut self, node: &mut TraitItemConst) {
+        visit_trait_item_const_mut(self, node);
+    }
+    fn visit_trait_item_fn_mut(&mut self, node: &mut TraitItemFn) {
+        visit_trait_item_fn_mut(self, node);
+    }
+    fn visit_trait_item_macro_mut(&mut self, node: &mut TraitItemMacro) {
+        visit_trait_item_macro_mut(self, node);
+    }
+    fn visit_trait_item_type_mut(&mut self, node: &mut TraitItemType) {
+        visit_trait_item_type_mut(self, node);
+    }
+    fn visit_type_mut(&mut self, node: &mut Type) {
+        visit_type_mut(self, node);
+    }
+    fn visit_type_array_mut(&mut self, node: &mut TypeArray) {
+        visit_type_array_mut(self, node);
+    }
+    fn visit_type_bare_fn_mut(&mut self, node: &mut TypeBareFn) {
+        visit_type_bare_fn_mut(self, node);
+    }
+    fn visit_type_group_mut(&mut self, node: &mut TypeGroup) {
+        visit_type_group_mut(self, node);
+    }
+    fn visit_type_impl_trait_mut(&mut self, node: &mut TypeImplTrait) {
+        visit_type_impl_trait_mut(self, node);
+    }
+    fn visit_type_macro_mut(&mut self, node: &mut TypeMacro) {
+        visit_type_macro_mut(self, node);
+    }
+    fn visit_type_param_mut(&mut self, node: &mut TypeParam) {
+        visit_type_param_mut(self, node);
+    }
+    fn visit_type_param_bound_mut(&mut self, node: &mut TypeParamBound) {
+        visit_type_param_bound_mut(self, node);
+    }
+    fn visit_type_paren_mut(&mut self, node: &mut TypeParen) {
+        visit_type_paren_mut(self, node);
+    }
+    fn visit_type_path_mut(&mut self, node: &mut TypePath) {
+        visit_type_path_mut(self, node);
+    }
+    fn visit_type_ptr_mut(&mut self, node: &mut TypePtr) {
+        visit_type_ptr_mut(self, node);
+    }
+    fn visit_type_reference_mut(&mut self, node: &mut TypeReference) {
+        visit_type_reference_mut(self, node);
+    }
+    fn visit_type_slice_mut(&mut self, node: &mut TypeSlice) {
+        visit_type_slice_mut(self, node);
+    }
+    fn visit_type_trait_object_mut(&mut self, node: &mut TypeTraitObject) {
+        visit_type_trait_object_mut(self, node);
+    }
+    fn visit_type_tuple_mut(&mut self, node: &mut TypeTuple) {
+        visit_type_tuple_mut(self, node);
+    }
+    fn visit_un_op_mut(&mut self, node: &mut UnOp) {
+        visit_un_op_mut(self, node);
+    }
+    fn visit_use_group_mut(&mut self, node: &mut UseGroup) {
+        visit_use_group_mut(self, node);
+    }
+    fn visit_use_name_mut(&mut self, node: &mut UseName) {
+        visit_use_name_mut(self, node);
+    }
+    fn visit_use_path_mut(&mut self, node: &mut UsePath) {
+        visit_use_path_mut(self, node);
+    }
+    fn visit_use_rename_mut(&mut self, node: &mut UseRename) {
+        visit_use_rename_mut(self, node);
+    }
+    fn visit_use_tree_mut(&mut self, node: &mut UseTree) {
+        visit_use_tree_mut(self, node);
+    }
+    fn visit_variadic_mut(&mut self, node: &mut Variadic) {
+        visit_variadic_mut(self, node);
+    }
+    fn visit_variant_mut(&mut self, node: &mut Variant) {
+        visit_variant_mut(self, node);
+    }
+    fn visit_vis_restricted_mut(&mut self, node: &mut VisRestricted) {
+        visit_vis_restricted_mut(self, node);
+    }
+    fn visit_visibility_mut(&mut self, node: &mut Visibility) {
+        visit_visibility_mut(self, node);
+    }
+    fn visit_where_clause_mut(&mut self, node: &mut WhereClause) {
+        visit_where_clause_mut(self, node);
+    }
+    fn visit_where_predicate_mut(&mut self, node: &mut WherePredicate) {
+        visit_where_predicate_mut(self, node);
+    }
+    fn visit_comment_mut(&mut self, node: &mut Comment) {
+        visit_comment_mut(self, node);
+    }
+    fn visit_delimiter_mut(&mut self, node: &mut Delimiter) {
+        visit_delimiter_mut(self, node);
+    }
+    fn visit_group_mut(&mut self, node: &mut Group) {
+        visit_group_mut(self, node);
+    }
+    fn visit_ident_mut(&mut self, node: &mut Ident) {
+        visit_ident_mut(self, node);
+    }
+    fn visit_literal_mut(&mut self, node: &mut Literal) {
+        visit_literal_mut(self, node);
+    }
+    fn visit_punct_mut(&mut self, node: &mut Punct) {
+        visit_punct_mut(self, node);
+    }
+    fn visit_spacing_mut(&mut self, node: &mut Spacing) {
+        visit_spacing_mut(self, node);
+    }
+    fn visit_span_info_mut(&mut self, node: &mut SpanInfo) {
+        visit_span_info_mut(self, node);
+    }
+    fn visit_token_stream_mut(&mut self, node: &mut TokenStream) {
+        visit_token_stream_mut(self, node);
+    }
+    fn visit_token_tree_mut(&mut self, node: &mut TokenTree) {
+        visit_token_tree_mut(self, node);
+    }
+}
+/// An adapter that can be traversed with a [`VisitMut`].
+pub(crate) trait Node {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized;
+}
+impl Node for Abi {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_abi_mut(self);
+    }
+}
+impl Node for AngleBracketedGenericArguments {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_angle_bracketed_generic_arguments_mut(self);
+    }
+}
+impl Node for Arm {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_arm_mut(self);
+    }
+}
+impl Node for AssocConst {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_assoc_const_mut(self);
+    }
+}
+impl Node for AssocType {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_assoc_type_mut(self);
+    }
+}
+impl Node for AttrStyle {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_attr_style_mut(self);
+    }
+}
+impl Node for Attribute {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_attribute_mut(self);
+    }
+}
+impl Node for BareFnArg {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_bare_fn_arg_mut(self);
+    }
+}
+impl Node for BareVariadic {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_bare_variadic_mut(self);
+    }
+}
+impl Node for BinOp {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_bin_op_mut(self);
+    }
+}
+impl Node for Block {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_block_mut(self);
+    }
+}
+impl Node for BoundLifetimes {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_bound_lifetimes_mut(self);
+    }
+}
+impl Node for ConstParam {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_const_param_mut(self);
+    }
+}
+impl Node for Constraint {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_constraint_mut(self);
+    }
+}
+impl Node for Expr {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_mut(self);
+    }
+}
+impl Node for ExprArray {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_array_mut(self);
+    }
+}
+impl Node for ExprAssign {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_assign_mut(self);
+    }
+}
+impl Node for ExprAsync {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_async_mut(self);
+    }
+}
+impl Node for ExprAwait {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_await_mut(self);
+    }
+}
+impl Node for ExprBinary {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_binary_mut(self);
+    }
+}
+impl Node for ExprBlock {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_block_mut(self);
+    }
+}
+impl Node for ExprBreak {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_break_mut(self);
+    }
+}
+impl Node for ExprCall {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_call_mut(self);
+    }
+}
+impl Node for ExprCast {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_cast_mut(self);
+    }
+}
+impl Node for ExprClosure {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_closure_mut(self);
+    }
+}
+impl Node for ExprConst {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_const_mut(self);
+    }
+}
+impl Node for ExprContinue {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_continue_mut(self);
+    }
+}
+impl Node for ExprField {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_field_mut(self);
+    }
+}
+impl Node for ExprForLoop {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_for_loop_mut(self);
+    }
+}
+impl Node for ExprGroup {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_group_mut(self);
+    }
+}
+impl Node for ExprIf {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_if_mut(self);
+    }
+}
+impl Node for ExprIndex {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_index_mut(self);
+    }
+}
+impl Node for ExprInfer {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_infer_mut(self);
+    }
+}
+impl Node for ExprLet {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_let_mut(self);
+    }
+}
+impl Node for ExprLit {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_lit_mut(self);
+    }
+}
+impl Node for ExprLoop {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_loop_mut(self);
+    }
+}
+impl Node for ExprMacro {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_macro_mut(self);
+    }
+}
+impl Node for ExprMatch {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_match_mut(self);
+    }
+}
+impl Node for ExprMethodCall {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_method_call_mut(self);
+    }
+}
+impl Node for ExprParen {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_paren_mut(self);
+    }
+}
+impl Node for ExprPath {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_path_mut(self);
+    }
+}
+impl Node for ExprRange {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_range_mut(self);
+    }
+}
+impl Node for ExprReference {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_reference_mut(self);
+    }
+}
+impl Node for ExprRepeat {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_repeat_mut(self);
+    }
+}
+impl Node for ExprReturn {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_return_mut(self);
+    }
+}
+impl Node for ExprStruct {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_struct_mut(self);
+    }
+}
+impl Node for ExprTry {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_try_mut(self);
+    }
+}
+impl Node for ExprTryBlock {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_try_block_mut(self);
+    }
+}
+impl Node for ExprTuple {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_tuple_mut(self);
+    }
+}
+impl Node for ExprUnary {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_unary_mut(self);
+    }
+}
+impl Node for ExprUnsafe {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_unsafe_mut(self);
+    }
+}
+impl Node for ExprWhile {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_while_mut(self);
+    }
+}
+impl Node for ExprYield {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_expr_yield_mut(self);
+    }
+}
+impl Node for Field {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_field_mut(self);
+    }
+}
+impl Node for FieldMutability {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_field_mutability_mut(self);
+    }
+}
+impl Node for FieldPat {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_field_pat_mut(self);
+    }
+}
+impl Node for FieldValue {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_field_value_mut(self);
+    }
+}
+impl Node for Fields {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_fields_mut(self);
+    }
+}
+impl Node for FieldsNamed {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_fields_named_mut(self);
+    }
+}
+impl Node for FieldsUnnamed {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_fields_unnamed_mut(self);
+    }
+}
+impl Node for File {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_file_mut(self);
+    }
+}
+impl Node for FnArg {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_fn_arg_mut(self);
+    }
+}
+impl Node for ForeignItem {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_foreign_item_mut(self);
+    }
+}
+impl Node for ForeignItemFn {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_foreign_item_fn_mut(self);
+    }
+}
+impl Node for ForeignItemMacro {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_foreign_item_macro_mut(self);
+    }
+}
+impl Node for ForeignItemStatic {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_foreign_item_static_mut(self);
+    }
+}
+impl Node for ForeignItemType {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_foreign_item_type_mut(self);
+    }
+}
+impl Node for GenericArgument {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_generic_argument_mut(self);
+    }
+}
+impl Node for GenericParam {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_generic_param_mut(self);
+    }
+}
+impl Node for Generics {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_generics_mut(self);
+    }
+}
+impl Node for ImplItem {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_impl_item_mut(self);
+    }
+}
+impl Node for ImplItemConst {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_impl_item_const_mut(self);
+    }
+}
+impl Node for ImplItemFn {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_impl_item_fn_mut(self);
+    }
+}
+impl Node for ImplItemMacro {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_impl_item_macro_mut(self);
+    }
+}
+impl Node for ImplItemType {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_impl_item_type_mut(self);
+    }
+}
+impl Node for ImplRestriction {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_impl_restriction_mut(self);
+    }
+}
+impl Node for Index {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_index_mut(self);
+    }
+}
+impl Node for Item {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_mut(self);
+    }
+}
+impl Node for ItemConst {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_const_mut(self);
+    }
+}
+impl Node for ItemEnum {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_enum_mut(self);
+    }
+}
+impl Node for ItemExternCrate {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_extern_crate_mut(self);
+    }
+}
+impl Node for ItemFn {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_fn_mut(self);
+    }
+}
+impl Node for ItemForeignMod {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_foreign_mod_mut(self);
+    }
+}
+impl Node for ItemImpl {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_impl_mut(self);
+    }
+}
+impl Node for ItemMacro {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_macro_mut(self);
+    }
+}
+impl Node for ItemMod {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_mod_mut(self);
+    }
+}
+impl Node for ItemStatic {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_static_mut(self);
+    }
+}
+impl Node for ItemStruct {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_struct_mut(self);
+    }
+}
+impl Node for ItemTrait {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_trait_mut(self);
+    }
+}
+impl Node for ItemTraitAlias {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_trait_alias_mut(self);
+    }
+}
+impl Node for ItemType {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_type_mut(self);
+    }
+}
+impl Node for ItemUnion {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_union_mut(self);
+    }
+}
+impl Node for ItemUse {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_item_use_mut(self);
+    }
+}
+impl Node for Label {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_label_mut(self);
+    }
+}
+impl Node for Lifetime {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_lifetime_mut(self);
+    }
+}
+impl Node for LifetimeParam {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_lifetime_param_mut(self);
+    }
+}
+impl Node for Lit {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_lit_mut(self);
+    }
+}
+impl Node for LitBool {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_lit_bool_mut(self);
+    }
+}
+impl Node for LitByte {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_lit_byte_mut(self);
+    }
+}
+impl Node for LitByteStr {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_lit_byte_str_mut(self);
+    }
+}
+impl Node for LitCStr {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_lit_c_str_mut(self);
+    }
+}
+impl Node for LitChar {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_lit_char_mut(self);
+    }
+}
+impl Node for LitFloat {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_lit_float_mut(self);
+    }
+}
+impl Node for LitInt {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_lit_int_mut(self);
+    }
+}
+impl Node for LitStr {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_lit_str_mut(self);
+    }
+}
+impl Node for Local {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_local_mut(self);
+    }
+}
+impl Node for LocalInit {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_local_init_mut(self);
+    }
+}
+impl Node for Macro {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_macro_mut(self);
+    }
+}
+impl Node for MacroDelimiter {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_macro_delimiter_mut(self);
+    }
+}
+impl Node for Member {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_member_mut(self);
+    }
+}
+impl Node for Meta {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_meta_mut(self);
+    }
+}
+impl Node for MetaList {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_meta_list_mut(self);
+    }
+}
+impl Node for MetaNameValue {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_meta_name_value_mut(self);
+    }
+}
+impl Node for ParenthesizedGenericArguments {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_parenthesized_generic_arguments_mut(self);
+    }
+}
+impl Node for Pat {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_pat_mut(self);
+    }
+}
+impl Node for PatIdent {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_pat_ident_mut(self);
+    }
+}
+impl Node for PatOr {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_pat_or_mut(self);
+    }
+}
+impl Node for PatParen {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_pat_paren_mut(self);
+    }
+}
+impl Node for PatReference {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_pat_reference_mut(self);
+    }
+}
+impl Node for PatRest {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_pat_rest_mut(self);
+    }
+}
+impl Node for PatSlice {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_pat_slice_mut(self);
+    }
+}
+impl Node for PatStruct {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_pat_struct_mut(self);
+    }
+}
+impl Node for PatTuple {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_pat_tuple_mut(self);
+    }
+}
+impl Node for PatTupleStruct {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_pat_tuple_struct_mut(self);
+    }
+}
+impl Node for PatType {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_pat_type_mut(self);
+    }
+}
+impl Node for PatWild {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_pat_wild_mut(self);
+    }
+}
+impl Node for Path {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_path_mut(self);
+    }
+}
+impl Node for PathArguments {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_path_arguments_mut(self);
+    }
+}
+impl Node for PathSegment {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_path_segment_mut(self);
+    }
+}
+impl Node for PredicateLifetime {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_predicate_lifetime_mut(self);
+    }
+}
+impl Node for PredicateType {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_predicate_type_mut(self);
+    }
+}
+impl Node for QSelf {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_q_self_mut(self);
+    }
+}
+impl Node for RangeLimits {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_range_limits_mut(self);
+    }
+}
+impl Node for Receiver {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_receiver_mut(self);
+    }
+}
+impl Node for ReturnType {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_return_type_mut(self);
+    }
+}
+impl Node for Signature {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_signature_mut(self);
+    }
+}
+impl Node for StaticMutability {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_static_mutability_mut(self);
+    }
+}
+impl Node for Stmt {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_stmt_mut(self);
+    }
+}
+impl Node for StmtMacro {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_stmt_macro_mut(self);
+    }
+}
+impl Node for TraitBound {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_trait_bound_mut(self);
+    }
+}
+impl Node for TraitBoundModifier {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_trait_bound_modifier_mut(self);
+    }
+}
+impl Node for TraitItem {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_trait_item_mut(self);
+    }
+}
+impl Node for TraitItemConst {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_trait_item_const_mut(self);
+    }
+}
+impl Node for TraitItemFn {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_trait_item_fn_mut(self);
+    }
+}
+impl Node for TraitItemMacro {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_trait_item_macro_mut(self);
+    }
+}
+impl Node for TraitItemType {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_trait_item_type_mut(self);
+    }
+}
+impl Node for Type {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_mut(self);
+    }
+}
+impl Node for TypeArray {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_array_mut(self);
+    }
+}
+impl Node for TypeBareFn {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_bare_fn_mut(self);
+    }
+}
+impl Node for TypeGroup {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_group_mut(self);
+    }
+}
+impl Node for TypeImplTrait {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_impl_trait_mut(self);
+    }
+}
+impl Node for TypeMacro {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_macro_mut(self);
+    }
+}
+impl Node for TypeParam {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_param_mut(self);
+    }
+}
+impl Node for TypeParamBound {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_param_bound_mut(self);
+    }
+}
+impl Node for TypeParen {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_paren_mut(self);
+    }
+}
+impl Node for TypePath {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_path_mut(self);
+    }
+}
+impl Node for TypePtr {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_ptr_mut(self);
+    }
+}
+impl Node for TypeReference {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_reference_mut(self);
+    }
+}
+impl Node for TypeSlice {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_slice_mut(self);
+    }
+}
+impl Node for TypeTraitObject {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_trait_object_mut(self);
+    }
+}
+impl Node for TypeTuple {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_type_tuple_mut(self);
+    }
+}
+impl Node for UnOp {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_un_op_mut(self);
+    }
+}
+impl Node for UseGroup {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_use_group_mut(self);
+    }
+}
+impl Node for UseName {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_use_name_mut(self);
+    }
+}
+impl Node for UsePath {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_use_path_mut(self);
+    }
+}
+impl Node for UseRename {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_use_rename_mut(self);
+    }
+}
+impl Node for UseTree {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_use_tree_mut(self);
+    }
+}
+impl Node for Variadic {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_variadic_mut(self);
+    }
+}
+impl Node for Variant {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_variant_mut(self);
+    }
+}
+impl Node for VisRestricted {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_vis_restricted_mut(self);
+    }
+}
+impl Node for Visibility {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_visibility_mut(self);
+    }
+}
+impl Node for WhereClause {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_where_clause_mut(self);
+    }
+}
+impl Node for WherePredicate {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_where_predicate_mut(self);
+    }
+}
+impl Node for Comment {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_comment_mut(self);
+    }
+}
+impl Node for Delimiter {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_delimiter_mut(self);
+    }
+}
+impl Node for Group {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_group_mut(self);
+    }
+}
+impl Node for Ident {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_ident_mut(self);
+    }
+}
+impl Node for Literal {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_literal_mut(self);
+    }
+}
+impl Node for Punct {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_punct_mut(self);
+    }
+}
+impl Node for Spacing {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_spacing_mut(self);
+    }
+}
+impl Node for SpanInfo {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_span_info_mut(self);
+    }
+}
+impl Node for TokenStream {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_token_stream_mut(self);
+    }
+}
+impl Node for TokenTree {
+    fn visit_mut<V>(&mut self, v: &mut V)
+    where
+        V: VisitMut + ?Sized,
+    {
+        v.visit_token_tree_mut(self);
+    }
+}
+pub(crate) fn visit_abi_mut<V>(v: &mut V, node: &mut Abi)
+where
+    V: VisitMut + ?Sized,
+{
+    if let Some(it) = &mut node.name {
+        v.visit_lit_str_mut(it);
+    }
+}
+pub(crate) fn visit_angle_bracketed_generic_arguments_mut<V>(
+    v: &mut V,
+    node: &mut AngleBracketedGenericArguments,
+)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.args.iter_mut() {
+        v.visit_generic_argument_mut(it);
+    }
+}
+pub(crate) fn visit_arm_mut<V>(v: &mut V, node: &mut Arm)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_pat_mut(&mut node.pat);
+    if let Some(_0) = &mut node.guard {
+        v.visit_expr_mut(&mut **_0);
+    }
+    v.visit_expr_mut(&mut *node.body);
+}
+pub(crate) fn visit_assoc_const_mut<V>(v: &mut V, node: &mut AssocConst)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.ident);
+    if let Some(it) = &mut node.generics {
+        v.visit_angle_bracketed_generic_arguments_mut(it);
+    }
+    v.visit_expr_mut(&mut node.value);
+}
+pub(crate) fn visit_assoc_type_mut<V>(v: &mut V, node: &mut AssocType)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.ident);
+    if let Some(it) = &mut node.generics {
+        v.visit_angle_bracketed_generic_arguments_mut(it);
+    }
+    v.visit_type_mut(&mut node.ty);
+}
+pub(crate) fn visit_attr_style_mut<V>(_v: &mut V, _node: &mut AttrStyle)
+where
+    V: VisitMut + ?Sized,
+{}
+pub(crate) fn visit_attribute_mut<V>(v: &mut V, node: &mut Attribute)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_attr_style_mut(&mut node.style);
+    v.visit_meta_mut(&mut node.meta);
+}
+pub(crate) fn visit_bare_fn_arg_mut<V>(v: &mut V, node: &mut BareFnArg)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(_0) = &mut node.name {
+        v.visit_ident_mut(_0);
+    }
+    v.visit_type_mut(&mut node.ty);
+}
+pub(crate) fn visit_bare_variadic_mut<V>(v: &mut V, node: &mut BareVariadic)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(_0) = &mut node.name {
+        v.visit_ident_mut(_0);
+    }
+}
+pub(crate) fn visit_bin_op_mut<V>(_v: &mut V, _node: &mut BinOp)
+where
+    V: VisitMut + ?Sized,
+{}
+pub(crate) fn visit_block_mut<V>(v: &mut V, node: &mut Block)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.stmts.iter_mut() {
+        v.visit_stmt_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_bound_lifetimes_mut<V>(v: &mut V, node: &mut BoundLifetimes)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.lifetimes.iter_mut() {
+        v.visit_generic_param_mut(it);
+    }
+}
+pub(crate) fn visit_const_param_mut<V>(v: &mut V, node: &mut ConstParam)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_type_mut(&mut node.ty);
+    if let Some(it) = &mut node.default {
+        v.visit_expr_mut(it);
+    }
+}
+pub(crate) fn visit_constraint_mut<V>(v: &mut V, node: &mut Constraint)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.ident);
+    if let Some(it) = &mut node.generics {
+        v.visit_angle_bracketed_generic_arguments_mut(it);
+    }
+    for it in node.bounds.iter_mut() {
+        v.visit_type_param_bound_mut(it);
+    }
+}
+pub(crate) fn visit_expr_mut<V>(v: &mut V, node: &mut Expr)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        Expr::Array(it) => {
+            v.visit_expr_array_mut(it);
+        }
+        Expr::Assign(it) => {
+            v.visit_expr_assign_mut(it);
+        }
+        Expr::Async(it) => {
+            v.visit_expr_async_mut(it);
+        }
+        Expr::Await(it) => {
+            v.visit_expr_await_mut(it);
+        }
+        Expr::Binary(it) => {
+            v.visit_expr_binary_mut(it);
+        }
+        Expr::Block(it) => {
+            v.visit_expr_block_mut(it);
+        }
+        Expr::Break(it) => {
+            v.visit_expr_break_mut(it);
+        }
+        Expr::Call(it) => {
+            v.visit_expr_call_mut(it);
+        }
+        Expr::Cast(it) => {
+            v.visit_expr_cast_mut(it);
+        }
+        Expr::Closure(it) => {
+            v.visit_expr_closure_mut(it);
+        }
+        Expr::Const(it) => {
+            v.visit_expr_const_mut(it);
+        }
+        Expr::Continue(it) => {
+            v.visit_expr_continue_mut(it);
+        }
+        Expr::Field(it) => {
+            v.visit_expr_field_mut(it);
+        }
+        Expr::ForLoop(it) => {
+            v.visit_expr_for_loop_mut(it);
+        }
+        Expr::Group(it) => {
+            v.visit_expr_group_mut(it);
+        }
+        Expr::If(it) => {
+            v.visit_expr_if_mut(it);
+        }
+        Expr::Index(it) => {
+            v.visit_expr_index_mut(it);
+        }
+        Expr::Infer(it) => {
+            v.visit_expr_infer_mut(it);
+        }
+        Expr::Let(it) => {
+            v.visit_expr_let_mut(it);
+        }
+        Expr::Lit(it) => {
+            v.visit_expr_lit_mut(it);
+        }
+        Expr::Loop(it) => {
+            v.visit_expr_loop_mut(it);
+        }
+        Expr::Macro(it) => {
+            v.visit_expr_macro_mut(it);
+        }
+        Expr::Match(it) => {
+            v.visit_expr_match_mut(it);
+        }
+        Expr::MethodCall(it) => {
+            v.visit_expr_method_call_mut(it);
+        }
+        Expr::Paren(it) => {
+            v.visit_expr_paren_mut(it);
+        }
+        Expr::Path(it) => {
+            v.visit_expr_path_mut(it);
+        }
+        Expr::Range(it) => {
+            v.visit_expr_range_mut(it);
+        }
+        Expr::Reference(it) => {
+            v.visit_expr_reference_mut(it);
+        }
+        Expr::Repeat(it) => {
+            v.visit_expr_repeat_mut(it);
+        }
+        Expr::Return(it) => {
+            v.visit_expr_return_mut(it);
+        }
+        Expr::Struct(it) => {
+            v.visit_expr_struct_mut(it);
+        }
+        Expr::Try(it) => {
+            v.visit_expr_try_mut(it);
+        }
+        Expr::TryBlock(it) => {
+            v.visit_expr_try_block_mut(it);
+        }
+        Expr::Tuple(it) => {
+            v.visit_expr_tuple_mut(it);
+        }
+        Expr::Unary(it) => {
+            v.visit_expr_unary_mut(it);
+        }
+        Expr::Unsafe(it) => {
+            v.visit_expr_unsafe_mut(it);
+        }
+        Expr::Verbatim(it) => {
+            v.visit_token_stream_mut(it);
+        }
+        Expr::While(it) => {
+            v.visit_expr_while_mut(it);
+        }
+        Expr::Yield(it) => {
+            v.visit_expr_yield_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_expr_array_mut<V>(v: &mut V, node: &mut ExprArray)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    for it in node.elems.iter_mut() {
+        v.visit_expr_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_expr_assign_mut<V>(v: &mut V, node: &mut ExprAssign)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.left);
+    v.visit_expr_mut(&mut *node.right);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_expr_async_mut<V>(v: &mut V, node: &mut ExprAsync)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_block_mut(&mut node.block);
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_expr_await_mut<V>(v: &mut V, node: &mut ExprAwait)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.base);
+}
+pub(crate) fn visit_expr_binary_mut<V>(v: &mut V, node: &mut ExprBinary)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.left);
+    v.visit_bin_op_mut(&mut node.op);
+    v.visit_expr_mut(&mut *node.right);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_expr_block_mut<V>(v: &mut V, node: &mut ExprBlock)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.label {
+        v.visit_label_mut(it);
+    }
+    v.visit_block_mut(&mut node.block);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_expr_break_mut<V>(v: &mut V, node: &mut ExprBreak)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.label {
+        v.visit_lifetime_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(&mut **it);
+    }
+}
+pub(crate) fn visit_expr_call_mut<V>(v: &mut V, node: &mut ExprCall)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.func);
+    for it in node.args.iter_mut() {
+        v.visit_expr_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_expr_cast_mut<V>(v: &mut V, node: &mut ExprCast)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.expr);
+    v.visit_type_mut(&mut *node.ty);
+}
+pub(crate) fn visit_expr_closure_mut<V>(v: &mut V, node: &mut ExprClosure)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.lifetimes {
+        v.visit_bound_lifetimes_mut(it);
+    }
+    for it in node.inputs.iter_mut() {
+        v.visit_pat_mut(it);
+    }
+    v.visit_return_type_mut(&mut node.output);
+    v.visit_expr_mut(&mut *node.body);
+}
+pub(crate) fn visit_expr_const_mut<V>(v: &mut V, node: &mut ExprConst)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_block_mut(&mut node.block);
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_expr_continue_mut<V>(v: &mut V, node: &mut ExprContinue)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.label {
+        v.visit_lifetime_mut(it);
+    }
+}
+pub(crate) fn visit_expr_field_mut<V>(v: &mut V, node: &mut ExprField)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.base);
+    v.visit_member_mut(&mut node.member);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_expr_for_loop_mut<V>(v: &mut V, node: &mut ExprForLoop)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.label {
+        v.visit_label_mut(it);
+    }
+    v.visit_pat_mut(&mut *node.pat);
+    v.visit_expr_mut(&mut *node.expr);
+    v.visit_block_mut(&mut node.body);
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_expr_group_mut<V>(v: &mut V, node: &mut ExprGroup)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.expr);
+}
+pub(crate) fn visit_expr_if_mut<V>(v: &mut V, node: &mut ExprIf)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.cond);
+    v.visit_block_mut(&mut node.then_branch);
+    if let Some(_0) = &mut node.else_branch {
+        v.visit_expr_mut(&mut **_0);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_expr_index_mut<V>(v: &mut V, node: &mut ExprIndex)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.expr);
+    v.visit_expr_mut(&mut *node.index);
+}
+pub(crate) fn visit_expr_infer_mut<V>(v: &mut V, node: &mut ExprInfer)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+}
+pub(crate) fn visit_expr_let_mut<V>(v: &mut V, node: &mut ExprLet)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_pat_mut(&mut *node.pat);
+    v.visit_expr_mut(&mut *node.expr);
+}
+pub(crate) fn visit_expr_lit_mut<V>(v: &mut V, node: &mut ExprLit)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_lit_mut(&mut node.lit);
+}
+pub(crate) fn visit_expr_loop_mut<V>(v: &mut V, node: &mut ExprLoop)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.label {
+        v.visit_label_mut(it);
+    }
+    v.visit_block_mut(&mut node.body);
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_expr_macro_mut<V>(v: &mut V, node: &mut ExprMacro)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_macro_mut(&mut node.mac);
+}
+pub(crate) fn visit_expr_match_mut<V>(v: &mut V, node: &mut ExprMatch)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.expr);
+    for it in node.arms.iter_mut() {
+        v.visit_arm_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_expr_method_call_mut<V>(v: &mut V, node: &mut ExprMethodCall)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.receiver);
+    v.visit_ident_mut(&mut node.method);
+    if let Some(it) = &mut node.turbofish {
+        v.visit_angle_bracketed_generic_arguments_mut(it);
+    }
+    for it in node.args.iter_mut() {
+        v.visit_expr_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_expr_paren_mut<V>(v: &mut V, node: &mut ExprParen)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.expr);
+}
+pub(crate) fn visit_expr_path_mut<V>(v: &mut V, node: &mut ExprPath)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.qself {
+        v.visit_q_self_mut(it);
+    }
+    v.visit_path_mut(&mut node.path);
+}
+pub(crate) fn visit_expr_range_mut<V>(v: &mut V, node: &mut ExprRange)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.start {
+        v.visit_expr_mut(&mut **it);
+    }
+    v.visit_range_limits_mut(&mut node.limits);
+    if let Some(it) = &mut node.end {
+        v.visit_expr_mut(&mut **it);
+    }
+}
+pub(crate) fn visit_expr_reference_mut<V>(v: &mut V, node: &mut ExprReference)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.expr);
+}
+pub(crate) fn visit_expr_repeat_mut<V>(v: &mut V, node: &mut ExprRepeat)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.expr);
+    v.visit_expr_mut(&mut *node.len);
+}
+pub(crate) fn visit_expr_return_mut<V>(v: &mut V, node: &mut ExprReturn)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(&mut **it);
+    }
+}
+pub(crate) fn visit_expr_struct_mut<V>(v: &mut V, node: &mut ExprStruct)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.qself {
+        v.visit_q_self_mut(it);
+    }
+    v.visit_path_mut(&mut node.path);
+    for it in node.fields.iter_mut() {
+        v.visit_field_value_mut(it);
+    }
+    if let Some(it) = &mut node.rest {
+        v.visit_expr_mut(&mut **it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_expr_try_mut<V>(v: &mut V, node: &mut ExprTry)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.expr);
+}
+pub(crate) fn visit_expr_try_block_mut<V>(v: &mut V, node: &mut ExprTryBlock)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_block_mut(&mut node.block);
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_expr_tuple_mut<V>(v: &mut V, node: &mut ExprTuple)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    for it in node.elems.iter_mut() {
+        v.visit_expr_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_expr_unary_mut<V>(v: &mut V, node: &mut ExprUnary)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_un_op_mut(&mut node.op);
+    v.visit_expr_mut(&mut *node.expr);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_expr_unsafe_mut<V>(v: &mut V, node: &mut ExprUnsafe)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_block_mut(&mut node.block);
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_expr_while_mut<V>(v: &mut V, node: &mut ExprWhile)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.label {
+        v.visit_label_mut(it);
+    }
+    v.visit_expr_mut(&mut *node.cond);
+    v.visit_block_mut(&mut node.body);
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_expr_yield_mut<V>(v: &mut V, node: &mut ExprYield)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.expr {
+        v.visit_expr_mut(&mut **it);
+    }
+}
+pub(crate) fn visit_field_mut<V>(v: &mut V, node: &mut Field)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_field_mutability_mut(&mut node.mutability);
+    if let Some(it) = &mut node.ident {
+        v.visit_ident_mut(it);
+    }
+    v.visit_type_mut(&mut node.ty);
+}
+pub(crate) fn visit_field_mutability_mut<V>(_v: &mut V, _node: &mut FieldMutability)
+where
+    V: VisitMut + ?Sized,
+{}
+pub(crate) fn visit_field_pat_mut<V>(v: &mut V, node: &mut FieldPat)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_member_mut(&mut node.member);
+    v.visit_pat_mut(&mut *node.pat);
+}
+pub(crate) fn visit_field_value_mut<V>(v: &mut V, node: &mut FieldValue)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_member_mut(&mut node.member);
+    v.visit_expr_mut(&mut node.expr);
+}
+pub(crate) fn visit_fields_mut<V>(v: &mut V, node: &mut Fields)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        Fields::Named(it) => {
+            v.visit_fields_named_mut(it);
+        }
+        Fields::Unnamed(it) => {
+            v.visit_fields_unnamed_mut(it);
+        }
+        Fields::Unit => {}
+    }
+}
+pub(crate) fn visit_fields_named_mut<V>(v: &mut V, node: &mut FieldsNamed)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.named.iter_mut() {
+        v.visit_field_mut(it);
+    }
+}
+pub(crate) fn visit_fields_unnamed_mut<V>(v: &mut V, node: &mut FieldsUnnamed)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.unnamed.iter_mut() {
+        v.visit_field_mut(it);
+    }
+}
+pub(crate) fn visit_file_mut<V>(v: &mut V, node: &mut File)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    for it in node.items.iter_mut() {
+        v.visit_item_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_fn_arg_mut<V>(v: &mut V, node: &mut FnArg)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        FnArg::Receiver(it) => {
+            v.visit_receiver_mut(it);
+        }
+        FnArg::Typed(it) => {
+            v.visit_pat_type_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_foreign_item_mut<V>(v: &mut V, node: &mut ForeignItem)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        ForeignItem::Fn(it) => {
+            v.visit_foreign_item_fn_mut(it);
+        }
+        ForeignItem::Static(it) => {
+            v.visit_foreign_item_static_mut(it);
+        }
+        ForeignItem::Type(it) => {
+            v.visit_foreign_item_type_mut(it);
+        }
+        ForeignItem::Macro(it) => {
+            v.visit_foreign_item_macro_mut(it);
+        }
+        ForeignItem::Verbatim(it) => {
+            v.visit_token_stream_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_foreign_item_fn_mut<V>(v: &mut V, node: &mut ForeignItemFn)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_signature_mut(&mut node.sig);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_foreign_item_macro_mut<V>(v: &mut V, node: &mut ForeignItemMacro)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_macro_mut(&mut node.mac);
+}
+pub(crate) fn visit_foreign_item_static_mut<V>(v: &mut V, node: &mut ForeignItemStatic)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_static_mutability_mut(&mut node.mutability);
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_type_mut(&mut *node.ty);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_foreign_item_type_mut<V>(v: &mut V, node: &mut ForeignItemType)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_generic_argument_mut<V>(v: &mut V, node: &mut GenericArgument)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        GenericArgument::Lifetime(it) => {
+            v.visit_lifetime_mut(it);
+        }
+        GenericArgument::Type(it) => {
+            v.visit_type_mut(it);
+        }
+        GenericArgument::Const(it) => {
+            v.visit_expr_mut(it);
+        }
+        GenericArgument::AssocType(it) => {
+            v.visit_assoc_type_mut(it);
+        }
+        GenericArgument::AssocConst(it) => {
+            v.visit_assoc_const_mut(it);
+        }
+        GenericArgument::Constraint(it) => {
+            v.visit_constraint_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_generic_param_mut<V>(v: &mut V, node: &mut GenericParam)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        GenericParam::Lifetime(it) => {
+            v.visit_lifetime_param_mut(it);
+        }
+        GenericParam::Type(it) => {
+            v.visit_type_param_mut(it);
+        }
+        GenericParam::Const(it) => {
+            v.visit_const_param_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_generics_mut<V>(v: &mut V, node: &mut Generics)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.params.iter_mut() {
+        v.visit_generic_param_mut(it);
+    }
+    if let Some(it) = &mut node.where_clause {
+        v.visit_where_clause_mut(it);
+    }
+}
+pub(crate) fn visit_impl_item_mut<V>(v: &mut V, node: &mut ImplItem)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        ImplItem::Const(it) => {
+            v.visit_impl_item_const_mut(it);
+        }
+        ImplItem::Fn(it) => {
+            v.visit_impl_item_fn_mut(it);
+        }
+        ImplItem::Type(it) => {
+            v.visit_impl_item_type_mut(it);
+        }
+        ImplItem::Macro(it) => {
+            v.visit_impl_item_macro_mut(it);
+        }
+        ImplItem::Verbatim(it) => {
+            v.visit_token_stream_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_impl_item_const_mut<V>(v: &mut V, node: &mut ImplItemConst)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    v.visit_type_mut(&mut node.ty);
+    v.visit_expr_mut(&mut node.expr);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_impl_item_fn_mut<V>(v: &mut V, node: &mut ImplItemFn)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_signature_mut(&mut node.sig);
+    v.visit_block_mut(&mut node.block);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_impl_item_macro_mut<V>(v: &mut V, node: &mut ImplItemMacro)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_macro_mut(&mut node.mac);
+}
+pub(crate) fn visit_impl_item_type_mut<V>(v: &mut V, node: &mut ImplItemType)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    v.visit_type_mut(&mut node.ty);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_impl_restriction_mut<V>(_v: &mut V, _node: &mut ImplRestriction)
+where
+    V: VisitMut + ?Sized,
+{}
+pub(crate) fn visit_index_mut<V>(v: &mut V, node: &mut Index)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_span_info_mut(&mut node.span);
+}
+pub(crate) fn visit_item_mut<V>(v: &mut V, node: &mut Item)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        Item::Const(it) => {
+            v.visit_item_const_mut(it);
+        }
+        Item::Enum(it) => {
+            v.visit_item_enum_mut(it);
+        }
+        Item::ExternCrate(it) => {
+            v.visit_item_extern_crate_mut(it);
+        }
+        Item::Fn(it) => {
+            v.visit_item_fn_mut(it);
+        }
+        Item::ForeignMod(it) => {
+            v.visit_item_foreign_mod_mut(it);
+        }
+        Item::Impl(it) => {
+            v.visit_item_impl_mut(it);
+        }
+        Item::Macro(it) => {
+            v.visit_item_macro_mut(it);
+        }
+        Item::Mod(it) => {
+            v.visit_item_mod_mut(it);
+        }
+        Item::Static(it) => {
+            v.visit_item_static_mut(it);
+        }
+        Item::Struct(it) => {
+            v.visit_item_struct_mut(it);
+        }
+        Item::Trait(it) => {
+            v.visit_item_trait_mut(it);
+        }
+        Item::TraitAlias(it) => {
+            v.visit_item_trait_alias_mut(it);
+        }
+        Item::Type(it) => {
+            v.visit_item_type_mut(it);
+        }
+        Item::Union(it) => {
+            v.visit_item_union_mut(it);
+        }
+        Item::Use(it) => {
+            v.visit_item_use_mut(it);
+        }
+        Item::Verbatim(it) => {
+            v.visit_token_stream_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_item_const_mut<V>(v: &mut V, node: &mut ItemConst)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    v.visit_type_mut(&mut *node.ty);
+    v.visit_expr_mut(&mut *node.expr);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_item_enum_mut<V>(v: &mut V, node: &mut ItemEnum)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    for it in node.variants.iter_mut() {
+        v.visit_variant_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_item_extern_crate_mut<V>(v: &mut V, node: &mut ItemExternCrate)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_ident_mut(&mut node.ident);
+    if let Some(_0) = &mut node.rename {
+        v.visit_ident_mut(_0);
+    }
+}
+pub(crate) fn visit_item_fn_mut<V>(v: &mut V, node: &mut ItemFn)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_signature_mut(&mut node.sig);
+    v.visit_block_mut(&mut *node.block);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_item_foreign_mod_mut<V>(v: &mut V, node: &mut ItemForeignMod)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_abi_mut(&mut node.abi);
+    for it in node.items.iter_mut() {
+        v.visit_foreign_item_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_item_impl_mut<V>(v: &mut V, node: &mut ItemImpl)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_generics_mut(&mut node.generics);
+    if let Some((_0, _1)) = &mut node.trait_ {
+        v.visit_path_mut(_1);
+    }
+    v.visit_type_mut(&mut *node.self_ty);
+    for it in node.items.iter_mut() {
+        v.visit_impl_item_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_item_macro_mut<V>(v: &mut V, node: &mut ItemMacro)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.ident {
+        v.visit_ident_mut(it);
+    }
+    v.visit_macro_mut(&mut node.mac);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_item_mod_mut<V>(v: &mut V, node: &mut ItemMod)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_ident_mut(&mut node.ident);
+    if let Some(_0) = &mut node.content {
+        for it in _0.iter_mut() {
+            v.visit_item_mut(it);
+        }
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_item_static_mut<V>(v: &mut V, node: &mut ItemStatic)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_static_mutability_mut(&mut node.mutability);
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_type_mut(&mut *node.ty);
+    v.visit_expr_mut(&mut *node.expr);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_item_struct_mut<V>(v: &mut V, node: &mut ItemStruct)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    v.visit_fields_mut(&mut node.fields);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_item_trait_mut<V>(v: &mut V, node: &mut ItemTrait)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    if let Some(it) = &mut node.restriction {
+        v.visit_impl_restriction_mut(it);
+    }
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    for it in node.supertraits.iter_mut() {
+        v.visit_type_param_bound_mut(it);
+    }
+    for it in node.items.iter_mut() {
+        v.visit_trait_item_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_item_trait_alias_mut<V>(v: &mut V, node: &mut ItemTraitAlias)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    for it in node.bounds.iter_mut() {
+        v.visit_type_param_bound_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_item_type_mut<V>(v: &mut V, node: &mut ItemType)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    v.visit_type_mut(&mut *node.ty);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_item_union_mut<V>(v: &mut V, node: &mut ItemUnion)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    v.visit_fields_named_mut(&mut node.fields);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_item_use_mut<V>(v: &mut V, node: &mut ItemUse)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_visibility_mut(&mut node.vis);
+    v.visit_use_tree_mut(&mut node.tree);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    for it in node.comments.iter_mut() {
+        v.visit_comment_mut(it);
+    }
+}
+pub(crate) fn visit_label_mut<V>(v: &mut V, node: &mut Label)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_lifetime_mut(&mut node.name);
+}
+pub(crate) fn visit_lifetime_mut<V>(v: &mut V, node: &mut Lifetime)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_span_info_mut(&mut node.apostrophe);
+    v.visit_ident_mut(&mut node.ident);
+}
+pub(crate) fn visit_lifetime_param_mut<V>(v: &mut V, node: &mut LifetimeParam)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_lifetime_mut(&mut node.lifetime);
+    for it in node.bounds.iter_mut() {
+        v.visit_lifetime_mut(it);
+    }
+}
+pub(crate) fn visit_lit_mut<V>(v: &mut V, node: &mut Lit)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        Lit::Str(it) => {
+            v.visit_lit_str_mut(it);
+        }
+        Lit::ByteStr(it) => {
+            v.visit_lit_byte_str_mut(it);
+        }
+        Lit::CStr(it) => {
+            v.visit_lit_c_str_mut(it);
+        }
+        Lit::Byte(it) => {
+            v.visit_lit_byte_mut(it);
+        }
+        Lit::Char(it) => {
+            v.visit_lit_char_mut(it);
+        }
+        Lit::Int(it) => {
+            v.visit_lit_int_mut(it);
+        }
+        Lit::Float(it) => {
+            v.visit_lit_float_mut(it);
+        }
+        Lit::Bool(it) => {
+            v.visit_lit_bool_mut(it);
+        }
+        Lit::Verbatim(it) => {
+            v.visit_literal_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_lit_bool_mut<V>(v: &mut V, node: &mut LitBool)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_span_info_mut(&mut node.span);
+}
+pub(crate) fn visit_lit_byte_mut<V>(v: &mut V, node: &mut LitByte)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_literal_mut(&mut node.token);
+}
+pub(crate) fn visit_lit_byte_str_mut<V>(v: &mut V, node: &mut LitByteStr)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_literal_mut(&mut node.token);
+}
+pub(crate) fn visit_lit_c_str_mut<V>(v: &mut V, node: &mut LitCStr)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_literal_mut(&mut node.token);
+}
+pub(crate) fn visit_lit_char_mut<V>(v: &mut V, node: &mut LitChar)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_literal_mut(&mut node.token);
+}
+pub(crate) fn visit_lit_float_mut<V>(v: &mut V, node: &mut LitFloat)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_literal_mut(&mut node.token);
+}
+pub(crate) fn visit_lit_int_mut<V>(v: &mut V, node: &mut LitInt)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_literal_mut(&mut node.token);
+}
+pub(crate) fn visit_lit_str_mut<V>(v: &mut V, node: &mut LitStr)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_literal_mut(&mut node.token);
+}
+pub(crate) fn visit_local_mut<V>(v: &mut V, node: &mut Local)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_pat_mut(&mut node.pat);
+    if let Some(it) = &mut node.init {
+        v.visit_local_init_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_local_init_mut<V>(v: &mut V, node: &mut LocalInit)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_expr_mut(&mut *node.expr);
+    if let Some(_0) = &mut node.diverge {
+        v.visit_expr_mut(&mut **_0);
+    }
+}
+pub(crate) fn visit_macro_mut<V>(v: &mut V, node: &mut Macro)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_path_mut(&mut node.path);
+    v.visit_macro_delimiter_mut(&mut node.delimiter);
+    v.visit_token_stream_mut(&mut node.tokens);
+}
+pub(crate) fn visit_macro_delimiter_mut<V>(_v: &mut V, _node: &mut MacroDelimiter)
+where
+    V: VisitMut + ?Sized,
+{}
+pub(crate) fn visit_member_mut<V>(v: &mut V, node: &mut Member)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        Member::Named(it) => {
+            v.visit_ident_mut(it);
+        }
+        Member::Unnamed(it) => {
+            v.visit_index_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_meta_mut<V>(v: &mut V, node: &mut Meta)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        Meta::Path(it) => {
+            v.visit_path_mut(it);
+        }
+        Meta::List(it) => {
+            v.visit_meta_list_mut(it);
+        }
+        Meta::NameValue(it) => {
+            v.visit_meta_name_value_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_meta_list_mut<V>(v: &mut V, node: &mut MetaList)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_path_mut(&mut node.path);
+    v.visit_macro_delimiter_mut(&mut node.delimiter);
+    v.visit_token_stream_mut(&mut node.tokens);
+}
+pub(crate) fn visit_meta_name_value_mut<V>(v: &mut V, node: &mut MetaNameValue)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_path_mut(&mut node.path);
+    v.visit_expr_mut(&mut node.value);
+}
+pub(crate) fn visit_parenthesized_generic_arguments_mut<V>(
+    v: &mut V,
+    node: &mut ParenthesizedGenericArguments,
+)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.inputs.iter_mut() {
+        v.visit_type_mut(it);
+    }
+    v.visit_return_type_mut(&mut node.output);
+}
+pub(crate) fn visit_pat_mut<V>(v: &mut V, node: &mut Pat)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        Pat::Const(it) => {
+            v.visit_expr_const_mut(it);
+        }
+        Pat::Ident(it) => {
+            v.visit_pat_ident_mut(it);
+        }
+        Pat::Lit(it) => {
+            v.visit_expr_lit_mut(it);
+        }
+        Pat::Macro(it) => {
+            v.visit_expr_macro_mut(it);
+        }
+        Pat::Or(it) => {
+            v.visit_pat_or_mut(it);
+        }
+        Pat::Paren(it) => {
+            v.visit_pat_paren_mut(it);
+        }
+        Pat::Path(it) => {
+            v.visit_expr_path_mut(it);
+        }
+        Pat::Range(it) => {
+            v.visit_expr_range_mut(it);
+        }
+        Pat::Reference(it) => {
+            v.visit_pat_reference_mut(it);
+        }
+        Pat::Rest(it) => {
+            v.visit_pat_rest_mut(it);
+        }
+        Pat::Slice(it) => {
+            v.visit_pat_slice_mut(it);
+        }
+        Pat::Struct(it) => {
+            v.visit_pat_struct_mut(it);
+        }
+        Pat::Tuple(it) => {
+            v.visit_pat_tuple_mut(it);
+        }
+        Pat::TupleStruct(it) => {
+            v.visit_pat_tuple_struct_mut(it);
+        }
+        Pat::Type(it) => {
+            v.visit_pat_type_mut(it);
+        }
+        Pat::Verbatim(it) => {
+            v.visit_token_stream_mut(it);
+        }
+        Pat::Wild(it) => {
+            v.visit_pat_wild_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_pat_ident_mut<V>(v: &mut V, node: &mut PatIdent)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_ident_mut(&mut node.ident);
+    if let Some(_0) = &mut node.subpat {
+        v.visit_pat_mut(&mut **_0);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_pat_or_mut<V>(v: &mut V, node: &mut PatOr)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    for it in node.cases.iter_mut() {
+        v.visit_pat_mut(it);
+    }
+}
+pub(crate) fn visit_pat_paren_mut<V>(v: &mut V, node: &mut PatParen)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_pat_mut(&mut *node.pat);
+}
+pub(crate) fn visit_pat_reference_mut<V>(v: &mut V, node: &mut PatReference)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_pat_mut(&mut *node.pat);
+}
+pub(crate) fn visit_pat_rest_mut<V>(v: &mut V, node: &mut PatRest)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+}
+pub(crate) fn visit_pat_slice_mut<V>(v: &mut V, node: &mut PatSlice)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    for it in node.elems.iter_mut() {
+        v.visit_pat_mut(it);
+    }
+}
+pub(crate) fn visit_pat_struct_mut<V>(v: &mut V, node: &mut PatStruct)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.qself {
+        v.visit_q_self_mut(it);
+    }
+    v.visit_path_mut(&mut node.path);
+    for it in node.fields.iter_mut() {
+        v.visit_field_pat_mut(it);
+    }
+    if let Some(it) = &mut node.rest {
+        v.visit_pat_rest_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_pat_tuple_mut<V>(v: &mut V, node: &mut PatTuple)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    for it in node.elems.iter_mut() {
+        v.visit_pat_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_pat_tuple_struct_mut<V>(v: &mut V, node: &mut PatTupleStruct)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.qself {
+        v.visit_q_self_mut(it);
+    }
+    v.visit_path_mut(&mut node.path);
+    for it in node.elems.iter_mut() {
+        v.visit_pat_mut(it);
+    }
+}
+pub(crate) fn visit_pat_type_mut<V>(v: &mut V, node: &mut PatType)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_pat_mut(&mut *node.pat);
+    v.visit_type_mut(&mut *node.ty);
+}
+pub(crate) fn visit_pat_wild_mut<V>(v: &mut V, node: &mut PatWild)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+}
+pub(crate) fn visit_path_mut<V>(v: &mut V, node: &mut Path)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.segments.iter_mut() {
+        v.visit_path_segment_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_path_arguments_mut<V>(v: &mut V, node: &mut PathArguments)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        PathArguments::None => {}
+        PathArguments::AngleBracketed(it) => {
+            v.visit_angle_bracketed_generic_arguments_mut(it);
+        }
+        PathArguments::Parenthesized(it) => {
+            v.visit_parenthesized_generic_arguments_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_path_segment_mut<V>(v: &mut V, node: &mut PathSegment)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_path_arguments_mut(&mut node.arguments);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_predicate_lifetime_mut<V>(v: &mut V, node: &mut PredicateLifetime)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_lifetime_mut(&mut node.lifetime);
+    for it in node.bounds.iter_mut() {
+        v.visit_lifetime_mut(it);
+    }
+}
+pub(crate) fn visit_predicate_type_mut<V>(v: &mut V, node: &mut PredicateType)
+where
+    V: VisitMut + ?Sized,
+{
+    if let Some(it) = &mut node.lifetimes {
+        v.visit_bound_lifetimes_mut(it);
+    }
+    v.visit_type_mut(&mut node.bounded_ty);
+    for it in node.bounds.iter_mut() {
+        v.visit_type_param_bound_mut(it);
+    }
+}
+pub(crate) fn visit_q_self_mut<V>(v: &mut V, node: &mut QSelf)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_type_mut(&mut *node.ty);
+}
+pub(crate) fn visit_range_limits_mut<V>(_v: &mut V, _node: &mut RangeLimits)
+where
+    V: VisitMut + ?Sized,
+{}
+pub(crate) fn visit_receiver_mut<V>(v: &mut V, node: &mut Receiver)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(it) = &mut node.lifetime {
+        v.visit_lifetime_mut(it);
+    }
+    v.visit_type_mut(&mut *node.ty);
+}
+pub(crate) fn visit_return_type_mut<V>(v: &mut V, node: &mut ReturnType)
+where
+    V: VisitMut + ?Sized,
+{
+    if let Some(it) = &mut node.ty {
+        v.visit_type_mut(&mut **it);
+    }
+}
+pub(crate) fn visit_signature_mut<V>(v: &mut V, node: &mut Signature)
+where
+    V: VisitMut + ?Sized,
+{
+    if let Some(it) = &mut node.abi {
+        v.visit_abi_mut(it);
+    }
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    for it in node.inputs.iter_mut() {
+        v.visit_fn_arg_mut(it);
+    }
+    if let Some(it) = &mut node.variadic {
+        v.visit_variadic_mut(it);
+    }
+    v.visit_return_type_mut(&mut node.output);
+}
+pub(crate) fn visit_static_mutability_mut<V>(_v: &mut V, _node: &mut StaticMutability)
+where
+    V: VisitMut + ?Sized,
+{}
+pub(crate) fn visit_stmt_mut<V>(v: &mut V, node: &mut Stmt)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        Stmt::Local(it) => {
+            v.visit_local_mut(it);
+        }
+        Stmt::Item(it) => {
+            v.visit_item_mut(it);
+        }
+        Stmt::Expr { expr, .. } => {
+            v.visit_expr_mut(expr);
+        }
+        Stmt::Macro(it) => {
+            v.visit_stmt_macro_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_stmt_macro_mut<V>(v: &mut V, node: &mut StmtMacro)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_macro_mut(&mut node.mac);
+}
+pub(crate) fn visit_trait_bound_mut<V>(v: &mut V, node: &mut TraitBound)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_trait_bound_modifier_mut(&mut node.modifier);
+    if let Some(it) = &mut node.lifetimes {
+        v.visit_bound_lifetimes_mut(it);
+    }
+    v.visit_path_mut(&mut node.path);
+}
+pub(crate) fn visit_trait_bound_modifier_mut<V>(
+    _v: &mut V,
+    _node: &mut TraitBoundModifier,
+)
+where
+    V: VisitMut + ?Sized,
+{}
+pub(crate) fn visit_trait_item_mut<V>(v: &mut V, node: &mut TraitItem)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        TraitItem::Const(it) => {
+            v.visit_trait_item_const_mut(it);
+        }
+        TraitItem::Fn(it) => {
+            v.visit_trait_item_fn_mut(it);
+        }
+        TraitItem::Type(it) => {
+            v.visit_trait_item_type_mut(it);
+        }
+        TraitItem::Macro(it) => {
+            v.visit_trait_item_macro_mut(it);
+        }
+        TraitItem::Verbatim(it) => {
+            v.visit_token_stream_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_trait_item_const_mut<V>(v: &mut V, node: &mut TraitItemConst)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    v.visit_type_mut(&mut node.ty);
+    if let Some(_0) = &mut node.default {
+        v.visit_expr_mut(_0);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_trait_item_fn_mut<V>(v: &mut V, node: &mut TraitItemFn)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_signature_mut(&mut node.sig);
+    if let Some(it) = &mut node.default {
+        v.visit_block_mut(it);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_trait_item_macro_mut<V>(v: &mut V, node: &mut TraitItemMacro)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_macro_mut(&mut node.mac);
+}
+pub(crate) fn visit_trait_item_type_mut<V>(v: &mut V, node: &mut TraitItemType)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_generics_mut(&mut node.generics);
+    for it in node.bounds.iter_mut() {
+        v.visit_type_param_bound_mut(it);
+    }
+    if let Some(_0) = &mut node.default {
+        v.visit_type_mut(_0);
+    }
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_type_mut<V>(v: &mut V, node: &mut Type)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        Type::Array(it) => {
+            v.visit_type_array_mut(it);
+        }
+        Type::BareFn(it) => {
+            v.visit_type_bare_fn_mut(it);
+        }
+        Type::Group(it) => {
+            v.visit_type_group_mut(it);
+        }
+        Type::ImplTrait(it) => {
+            v.visit_type_impl_trait_mut(it);
+        }
+        Type::Infer => {}
+        Type::Macro(it) => {
+            v.visit_type_macro_mut(it);
+        }
+        Type::Never => {}
+        Type::Paren(it) => {
+            v.visit_type_paren_mut(it);
+        }
+        Type::Path(it) => {
+            v.visit_type_path_mut(it);
+        }
+        Type::Ptr(it) => {
+            v.visit_type_ptr_mut(it);
+        }
+        Type::Reference(it) => {
+            v.visit_type_reference_mut(it);
+        }
+        Type::Slice(it) => {
+            v.visit_type_slice_mut(it);
+        }
+        Type::TraitObject(it) => {
+            v.visit_type_trait_object_mut(it);
+        }
+        Type::Tuple(it) => {
+            v.visit_type_tuple_mut(it);
+        }
+        Type::Verbatim(it) => {
+            v.visit_token_stream_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_type_array_mut<V>(v: &mut V, node: &mut TypeArray)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_type_mut(&mut *node.elem);
+    v.visit_expr_mut(&mut node.len);
+}
+pub(crate) fn visit_type_bare_fn_mut<V>(v: &mut V, node: &mut TypeBareFn)
+where
+    V: VisitMut + ?Sized,
+{
+    if let Some(it) = &mut node.lifetimes {
+        v.visit_bound_lifetimes_mut(it);
+    }
+    if let Some(it) = &mut node.abi {
+        v.visit_abi_mut(it);
+    }
+    for it in node.inputs.iter_mut() {
+        v.visit_bare_fn_arg_mut(it);
+    }
+    if let Some(it) = &mut node.variadic {
+        v.visit_bare_variadic_mut(it);
+    }
+    v.visit_return_type_mut(&mut node.output);
+}
+pub(crate) fn visit_type_group_mut<V>(v: &mut V, node: &mut TypeGroup)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_type_mut(&mut *node.elem);
+}
+pub(crate) fn visit_type_impl_trait_mut<V>(v: &mut V, node: &mut TypeImplTrait)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.bounds.iter_mut() {
+        v.visit_type_param_bound_mut(it);
+    }
+}
+pub(crate) fn visit_type_macro_mut<V>(v: &mut V, node: &mut TypeMacro)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_macro_mut(&mut node.mac);
+}
+pub(crate) fn visit_type_param_mut<V>(v: &mut V, node: &mut TypeParam)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_ident_mut(&mut node.ident);
+    for it in node.bounds.iter_mut() {
+        v.visit_type_param_bound_mut(it);
+    }
+    if let Some(it) = &mut node.default {
+        v.visit_type_mut(it);
+    }
+}
+pub(crate) fn visit_type_param_bound_mut<V>(v: &mut V, node: &mut TypeParamBound)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        TypeParamBound::Trait(it) => {
+            v.visit_trait_bound_mut(it);
+        }
+        TypeParamBound::Lifetime(it) => {
+            v.visit_lifetime_mut(it);
+        }
+        TypeParamBound::Verbatim(it) => {
+            v.visit_token_stream_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_type_paren_mut<V>(v: &mut V, node: &mut TypeParen)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_type_mut(&mut *node.elem);
+}
+pub(crate) fn visit_type_path_mut<V>(v: &mut V, node: &mut TypePath)
+where
+    V: VisitMut + ?Sized,
+{
+    if let Some(it) = &mut node.qself {
+        v.visit_q_self_mut(it);
+    }
+    v.visit_path_mut(&mut node.path);
+}
+pub(crate) fn visit_type_ptr_mut<V>(v: &mut V, node: &mut TypePtr)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_type_mut(&mut *node.elem);
+}
+pub(crate) fn visit_type_reference_mut<V>(v: &mut V, node: &mut TypeReference)
+where
+    V: VisitMut + ?Sized,
+{
+    if let Some(it) = &mut node.lifetime {
+        v.visit_lifetime_mut(it);
+    }
+    v.visit_type_mut(&mut *node.elem);
+}
+pub(crate) fn visit_type_slice_mut<V>(v: &mut V, node: &mut TypeSlice)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_type_mut(&mut *node.elem);
+}
+pub(crate) fn visit_type_trait_object_mut<V>(v: &mut V, node: &mut TypeTraitObject)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.bounds.iter_mut() {
+        v.visit_type_param_bound_mut(it);
+    }
+}
+pub(crate) fn visit_type_tuple_mut<V>(v: &mut V, node: &mut TypeTuple)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.elems.iter_mut() {
+        v.visit_type_mut(it);
+    }
+}
+pub(crate) fn visit_un_op_mut<V>(_v: &mut V, _node: &mut UnOp)
+where
+    V: VisitMut + ?Sized,
+{}
+pub(crate) fn visit_use_group_mut<V>(v: &mut V, node: &mut UseGroup)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.items.iter_mut() {
+        v.visit_use_tree_mut(it);
+    }
+}
+pub(crate) fn visit_use_name_mut<V>(v: &mut V, node: &mut UseName)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.ident);
+}
+pub(crate) fn visit_use_path_mut<V>(v: &mut V, node: &mut UsePath)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_use_tree_mut(&mut *node.tree);
+}
+pub(crate) fn visit_use_rename_mut<V>(v: &mut V, node: &mut UseRename)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_ident_mut(&mut node.rename);
+}
+pub(crate) fn visit_use_tree_mut<V>(v: &mut V, node: &mut UseTree)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        UseTree::Path(it) => {
+            v.visit_use_path_mut(it);
+        }
+        UseTree::Name(it) => {
+            v.visit_use_name_mut(it);
+        }
+        UseTree::Rename(it) => {
+            v.visit_use_rename_mut(it);
+        }
+        UseTree::Glob => {}
+        UseTree::Group(it) => {
+            v.visit_use_group_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_variadic_mut<V>(v: &mut V, node: &mut Variadic)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    if let Some(_0) = &mut node.pat {
+        v.visit_pat_mut(&mut **_0);
+    }
+}
+pub(crate) fn visit_variant_mut<V>(v: &mut V, node: &mut Variant)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.attrs.iter_mut() {
+        v.visit_attribute_mut(it);
+    }
+    v.visit_ident_mut(&mut node.ident);
+    v.visit_fields_mut(&mut node.fields);
+    if let Some(_0) = &mut node.discriminant {
+        v.visit_expr_mut(_0);
+    }
+}
+pub(crate) fn visit_vis_restricted_mut<V>(v: &mut V, node: &mut VisRestricted)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_path_mut(&mut *node.path);
+}
+pub(crate) fn visit_visibility_mut<V>(v: &mut V, node: &mut Visibility)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        Visibility::Public => {}
+        Visibility::Restricted(it) => {
+            v.visit_vis_restricted_mut(it);
+        }
+        Visibility::Inherited => {}
+    }
+}
+pub(crate) fn visit_where_clause_mut<V>(v: &mut V, node: &mut WhereClause)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.predicates.iter_mut() {
+        v.visit_where_predicate_mut(it);
+    }
+}
+pub(crate) fn visit_where_predicate_mut<V>(v: &mut V, node: &mut WherePredicate)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        WherePredicate::Lifetime(it) => {
+            v.visit_predicate_lifetime_mut(it);
+        }
+        WherePredicate::Type(it) => {
+            v.visit_predicate_type_mut(it);
+        }
+    }
+}
+pub(crate) fn visit_comment_mut<V>(v: &mut V, node: &mut Comment)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_span_info_mut(&mut node.span);
+}
+pub(crate) fn visit_delimiter_mut<V>(_v: &mut V, _node: &mut Delimiter)
+where
+    V: VisitMut + ?Sized,
+{}
+pub(crate) fn visit_group_mut<V>(v: &mut V, node: &mut Group)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_delimiter_mut(&mut node.delimiter);
+    v.visit_token_stream_mut(&mut node.stream);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+    if let Some(it) = &mut node.span_open {
+        v.visit_span_info_mut(it);
+    }
+    if let Some(it) = &mut node.span_close {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_ident_mut<V>(v: &mut V, node: &mut Ident)
+where
+    V: VisitMut + ?Sized,
+{
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_literal_mut<V>(v: &mut V, node: &mut Literal)
+where
+    V: VisitMut + ?Sized,
+{
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_punct_mut<V>(v: &mut V, node: &mut Punct)
+where
+    V: VisitMut + ?Sized,
+{
+    v.visit_spacing_mut(&mut node.spacing);
+    if let Some(it) = &mut node.span {
+        v.visit_span_info_mut(it);
+    }
+}
+pub(crate) fn visit_spacing_mut<V>(_v: &mut V, _node: &mut Spacing)
+where
+    V: VisitMut + ?Sized,
+{}
+pub(crate) fn visit_span_info_mut<V>(_v: &mut V, _node: &mut SpanInfo)
+where
+    V: VisitMut + ?Sized,
+{}
+pub(crate) fn visit_token_stream_mut<V>(v: &mut V, node: &mut TokenStream)
+where
+    V: VisitMut + ?Sized,
+{
+    for it in node.inner.iter_mut() {
+        v.visit_token_tree_mut(it);
+    }
+}
+pub(crate) fn visit_token_tree_mut<V>(v: &mut V, node: &mut TokenTree)
+where
+    V: VisitMut + ?Sized,
+{
+    match node {
+        TokenTree::Group(it) => v.visit_group_mut(it),
+        TokenTree::Ident(it) => v.visit_ident_mut(it),
+        TokenTree::Punct(it) => v.visit_punct_mut(it),
+        TokenTree::Literal(it) => v.visit_literal_mut(it),
+    }
+}
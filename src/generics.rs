@@ -13,20 +13,25 @@ ast_struct! {
     /// An adapter for [`struct@syn::Generics`].
     #[derive(Default)]
     pub struct Generics {
-        // #[serde(default, skip_serializing_if = "not")]
-        // pub(crate) lt_token: bool,
-        #[serde(default, skip_serializing_if = "Punctuated::is_empty")]
+        #[serde(default, skip_serializing_if = "lossless::skip")]
+        #[serde(serialize_with = "lossless::serialize_field")]
+        pub(crate) lt_token: bool,
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) params: Punctuated<GenericParam>,
-        // #[serde(default, skip_serializing_if = "not")]
-        // pub(crate) gt_token: bool,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[serde(default, skip_serializing_if = "lossless::skip")]
+        #[serde(serialize_with = "lossless::serialize_field")]
+        pub(crate) gt_token: bool,
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) where_clause: Option<WhereClause>,
     }
 }
 
 impl Generics {
     pub(crate) fn is_none(&self) -> bool {
-        self.params.is_empty() && self.where_clause.is_none() // && !self.lt_token && !self.gt_token
+        self.params.is_empty()
+            && self.where_clause.is_none()
+            && lossless::skip(&self.lt_token)
+            && lossless::skip(&self.gt_token)
     }
 }
 
@@ -48,12 +53,14 @@ impl Default for TraitBoundModifier {
 ast_struct! {
     /// An adapter for [`struct@syn::PredicateType`].
     pub struct PredicateType {
-        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) lifetimes: Option<BoundLifetimes>,
         pub(crate) bounded_ty: Type,
         // TODO: should allow default?
         // #[serde(default, skip_serializing_if = "Punctuated::is_empty")]
         pub(crate) bounds: Punctuated<TypeParamBound>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub(crate) trivia: Vec<crate::trivia::TokenTrivia>,
     }
 }
 
@@ -69,15 +76,20 @@ mod convert {
             // `ident T`
             assert!(other.params.is_empty() || other.lt_token.is_some(), "expected `<`");
 
-            Self { params: other.params.map_into(), where_clause: other.where_clause.map_into() }
+            Self {
+                lt_token: other.lt_token.is_some(),
+                params: other.params.map_into(),
+                gt_token: other.gt_token.is_some(),
+                where_clause: other.where_clause.map_into(),
+            }
         }
     }
     impl From<&Generics> for syn::Generics {
         fn from(other: &Generics) -> Self {
             Self {
-                lt_token: default_or_none(!other.params.is_empty()),
+                lt_token: default_or_none(other.lt_token || !other.params.is_empty()),
                 params: other.params.map_into(),
-                gt_token: default_or_none(!other.params.is_empty()),
+                gt_token: default_or_none(other.gt_token || !other.params.is_empty()),
                 where_clause: other.where_clause.map_into(),
             }
         }
@@ -0,0 +1,123 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Node ids and parent links.
+//!
+//! [`WithIds`] serializes a syntax tree with an `"id"` field in each node, and
+//! provides a side table that records the parent and the role of each node,
+//! so that consumers can link nodes without walking the tree again.
+//!
+//! Ids are assigned to nodes in pre-order, starting from 0 for the root. They
+//! only depend on the syntax, so the same tree always gets the same ids,
+//! regardless of whether it records spans and comments. These are also the
+//! ids that comments are associated with in
+//! [`File::from_syn_with_comments`](crate::File::from_syn_with_comments).
+//!
+//! Every node has an id, but nodes that are not serialized as JSON objects
+//! (e.g., `Lit::Str`, which is serialized as a string) cannot record it
+//! themselves, and can only be found through the [`path`](NodeInfo::path) in
+//! the side table.
+//!
+//! Trees with ids can be deserialized as usual; the `"id"` fields are
+//! ignored.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn dox() {
+//! use syn_serde::ids::WithIds;
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     fn main() {}
+//! };
+//! let tree = WithIds::from_syn(&syn_file);
+//!
+//! let json = serde_json::to_value(&tree).unwrap();
+//! assert_eq!(json["id"], 0);
+//! assert_eq!(json["items"][0]["fn"]["id"], 1);
+//!
+//! let node = &tree.nodes()[1];
+//! assert_eq!(node.parent, Some(0));
+//! assert_eq!(node.role.as_deref(), Some("items/0"));
+//! assert_eq!(node.kind.as_deref(), Some("fn"));
+//! # }
+//! ```
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::node::{self, Value, Variant};
+
+value_tree! {
+    /// A syntax tree that is serialized with node ids.
+    ///
+    /// See the [module level documentation](self) for more.
+    pub struct WithIds {
+        nodes: Vec<NodeInfo> = node_table,
+    };
+    new: "Assigns ids to the nodes of",
+    transform: |_, value| node::insert_ids(value),
+}
+
+/// An entry of the side table of [`WithIds`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NodeInfo {
+    /// The id of the node.
+    pub id: usize,
+    /// The id of the parent node, `None` for the root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<usize>,
+    /// The position of the node in its parent, as a JSON Pointer relative to
+    /// the fields of the parent, e.g., `"receiver"` or `"args/0"`. `None` for
+    /// the root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+    /// The kind of the node, e.g., `"method_call"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// The JSON Pointer (RFC 6901) to the node in the serialized tree.
+    pub path: String,
+}
+
+impl WithIds {
+    /// Returns the side table. The entry of each node is at the index of its
+    /// id.
+    #[must_use]
+    pub fn nodes(&self) -> &[NodeInfo] {
+        &self.nodes
+    }
+}
+
+pub(crate) fn node_table(root: &Value) -> Vec<NodeInfo> {
+    let entries = node::index(root);
+    entries
+        .iter()
+        .enumerate()
+        .map(|(id, entry)| {
+            let role = entry.parent.map(|parent| {
+                let parent = &entries[parent];
+                let base_len = match parent.value {
+                    Value::Variant(Variant { name, .. }) => {
+                        parent.path.len() + 1 + node::escape_pointer_token(name).len()
+                    }
+                    _ => parent.path.len(),
+                };
+                // Skip the `/` that follows the path of the parent. The role
+                // is empty if the node is the content of a variant that
+                // directly contains it, e.g., `GenericArgument::Type`.
+                entry.path.get(base_len + 1..).unwrap_or_default().to_owned()
+            });
+            NodeInfo {
+                id,
+                parent: entry.parent,
+                role,
+                kind: entry.value.kind(),
+                path: entry.path.clone(),
+            }
+        })
+        .collect()
+}
+
+/// Returns the ids of the nodes in the given tree, keyed by their paths.
+pub(crate) fn ids_by_path(root: &Value) -> std::collections::HashMap<String, usize> {
+    node::index(root).into_iter().enumerate().map(|(id, entry)| (entry.path, id)).collect()
+}
@@ -0,0 +1,380 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Flattened `use` declarations.
+//!
+//! A `use` declaration is serialized as a tree of [`UseTree`]s, e.g.,
+//! `use std::{fmt, io::{self, Write as _}};`. [`flatten`] turns every `use`
+//! declaration of a file into individual [`Import`]s, one for each name it
+//! brings into scope:
+//!
+//! | path             | alias |
+//! | ---------------- | ----- |
+//! | `std::fmt`       |       |
+//! | `std::io`        |       |
+//! | `std::io::Write` | `_`   |
+//!
+//! Paths that start with `self::` or `super::` are made absolute against the
+//! module that contains the `use` declaration, e.g., `use super::b::C;` in
+//! module `crate::a` imports `crate::b::C`.
+//!
+//! [`resolve`] also resolves the imports of a [`Crate`] to the items that
+//! define them, following paths relative to the current module and
+//! re-exports (including glob imports). Imports of other crates are not
+//! resolved.
+//!
+//! # Examples
+//!
+//! ```
+//! use syn_serde::{Syn as _, imports};
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     pub use std::{fmt, io::{self, Write as _}};
+//! };
+//!
+//! let imports = imports::flatten(&syn_file.to_adapter());
+//! assert_eq!(imports.len(), 3);
+//! assert_eq!(imports[2].path, "std::io::Write");
+//! assert_eq!(imports[2].alias.as_deref(), Some("_"));
+//! assert_eq!(imports[2].visibility, "pub");
+//! ```
+
+use core::mem;
+use std::collections::{HashMap, HashSet};
+
+use quote::quote;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    Block, File, Item, ItemMod, ItemUse, SpanInfo, UseTree,
+    krate::Crate,
+    symbols::{self, SymbolKind},
+    visit::{self, Visit},
+};
+
+/// A name brought into scope by a `use` declaration.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Import {
+    /// The imported path, e.g., `crate::a::B`, `::std::io`, or `std::io::*`
+    /// for glob imports. `self` in groups is removed, so the path of
+    /// `use std::io::{self};` is `std::io`, and paths that start with `self`
+    /// or `super` are made absolute against [`module`](Self::module).
+    pub path: String,
+    /// The name that the item is imported as, e.g., `Other` in
+    /// `use a::B as Other;`, or `_` for `use a::B as _;`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alias: Option<String>,
+    /// The path of the module that contains the `use` declaration, e.g.,
+    /// `crate::a`.
+    pub module: String,
+    /// `true` if the `use` declaration is in a block, e.g., a function body,
+    /// rather than directly in a module. Local imports are only in scope in
+    /// the block.
+    pub local: bool,
+    /// The visibility of the `use` declaration, e.g., `pub(crate)`, or an
+    /// empty string if it is private.
+    pub visibility: String,
+    /// The span of the `use` keyword.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<SpanInfo>,
+    /// The fully qualified path of the item that the import refers to, e.g.,
+    /// `crate::a::B`, or of the module or enum whose items a glob import
+    /// brings into scope. This is only set by [`resolve`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolved: Option<String>,
+}
+
+impl Import {
+    /// Returns the name that the import brings into scope, or `None` for glob
+    /// imports and imports as `_`.
+    #[must_use]
+    pub fn name(&self) -> Option<&str> {
+        let name = match &self.alias {
+            Some(alias) => alias,
+            None => self.path.rsplit("::").next().unwrap_or(&self.path),
+        };
+        if name == "*" || name == "_" { None } else { Some(name) }
+    }
+
+    /// Returns `true` if this is a glob import, e.g., `use a::*;`.
+    #[must_use]
+    pub fn is_glob(&self) -> bool {
+        self.path == "*" || self.path.ends_with("::*")
+    }
+}
+
+/// Returns the imports of all `use` declarations in a file, in the order in
+/// which they appear.
+///
+/// See the [module level documentation](self) for more.
+#[must_use]
+pub fn flatten(file: &File) -> Vec<Import> {
+    let mut visitor = Flatten { module: "crate".to_owned(), blocks: 0, imports: vec![] };
+    visitor.visit_file(file);
+    visitor.imports
+}
+
+/// Returns the imports of all `use` declarations in a crate, with the items
+/// that they refer to.
+///
+/// See the [module level documentation](self) for more.
+#[must_use]
+pub fn resolve(krate: &Crate) -> Vec<Import> {
+    let mut imports = flatten(krate.root());
+    let resolver = Resolver::new(krate.root(), &imports);
+    let resolved: Vec<_> = imports.iter().map(|import| resolver.resolve(import)).collect();
+    for (import, resolved) in imports.iter_mut().zip(resolved) {
+        import.resolved = resolved;
+    }
+    imports
+}
+
+struct Flatten {
+    /// The path of the current module.
+    module: String,
+    /// The number of blocks that the current node is in.
+    blocks: usize,
+    imports: Vec<Import>,
+}
+
+impl Visit<'_> for Flatten {
+    fn visit_item_use(&mut self, item: &ItemUse) {
+        flatten_use(item, &self.module, self.blocks != 0, &mut self.imports);
+    }
+
+    fn visit_item_mod(&mut self, item: &ItemMod) {
+        // The imports of modules in blocks are treated as the imports of the
+        // block.
+        if self.blocks != 0 {
+            visit::visit_item_mod(self, item);
+            return;
+        }
+        let ident = proc_macro2::Ident::from(&item.ident);
+        let module = format!("{}::{}", self.module, ident);
+        let parent = mem::replace(&mut self.module, module);
+        visit::visit_item_mod(self, item);
+        self.module = parent;
+    }
+
+    fn visit_block(&mut self, block: &Block) {
+        self.blocks += 1;
+        visit::visit_block(self, block);
+        self.blocks -= 1;
+    }
+}
+
+fn flatten_use(item: &ItemUse, module: &str, local: bool, imports: &mut Vec<Import>) {
+    let vis = syn::Visibility::from(&item.vis);
+    let import = Import {
+        path: String::new(),
+        alias: None,
+        module: module.to_owned(),
+        local,
+        visibility: symbols::print(&quote!(#vis)),
+        span: item.span,
+        resolved: None,
+    };
+    let prefix = if item.leading_colon { "::" } else { "" };
+    flatten_tree(&item.tree, prefix, &import, imports);
+}
+
+fn flatten_tree(tree: &UseTree, prefix: &str, import: &Import, imports: &mut Vec<Import>) {
+    let path = |name: &str| absolute(&join(prefix, name), &import.module);
+    match tree {
+        UseTree::Path(tree) => {
+            let ident = proc_macro2::Ident::from(&tree.ident);
+            flatten_tree(&tree.tree, &join(prefix, &ident.to_string()), import, imports);
+        }
+        UseTree::Name(tree) => {
+            let ident = proc_macro2::Ident::from(&tree.ident);
+            imports.push(Import { path: path(&ident.to_string()), ..import.clone() });
+        }
+        UseTree::Rename(tree) => {
+            let ident = proc_macro2::Ident::from(&tree.ident);
+            let path = path(&ident.to_string());
+            let alias = Some(proc_macro2::Ident::from(&tree.rename).to_string());
+            imports.push(Import { path, alias, ..import.clone() });
+        }
+        UseTree::Glob => imports.push(Import { path: path("*"), ..import.clone() }),
+        UseTree::Group(tree) => {
+            for tree in &tree.items {
+                flatten_tree(tree, prefix, import, imports);
+            }
+        }
+    }
+}
+
+/// Appends a segment to the path of an import.
+fn join(prefix: &str, name: &str) -> String {
+    if prefix.is_empty() || prefix == "::" {
+        format!("{}{}", prefix, name)
+    } else if name == "self" {
+        // `use a::{self};` imports `a`.
+        prefix.to_owned()
+    } else {
+        format!("{}::{}", prefix, name)
+    }
+}
+
+/// Makes a path that starts with `self` or `super` absolute against `module`,
+/// e.g., `super::b::C` in `crate::a` to `crate::b::C`. Other paths, and paths
+/// that go above the root of the crate, are returned as they are.
+fn absolute(path: &str, module: &str) -> String {
+    let mut segments = path.split("::").peekable();
+    if !matches!(segments.peek(), Some(&"self" | &"super")) {
+        return path.to_owned();
+    }
+    let mut absolute: Vec<_> = module.split("::").collect();
+    while let Some(&segment) = segments.peek() {
+        match segment {
+            "self" => {}
+            // The root of the crate has no parent.
+            "super" if absolute.len() == 1 => return path.to_owned(),
+            "super" => {
+                absolute.pop();
+            }
+            _ => break,
+        }
+        segments.next();
+    }
+    absolute.extend(segments);
+    absolute.join("::")
+}
+
+struct Resolver<'a> {
+    /// The kinds of the items of the crate, by their paths.
+    defs: HashMap<String, SymbolKind>,
+    /// The paths of the variants of enums.
+    variants: HashSet<String>,
+    /// The imports that are in scope in each module.
+    imports: HashMap<&'a str, Vec<&'a Import>>,
+}
+
+impl<'a> Resolver<'a> {
+    fn new(root: &File, imports: &'a [Import]) -> Self {
+        let mut defs: HashMap<_, _> =
+            symbols::index(root).into_iter().map(|symbol| (symbol.path, symbol.kind)).collect();
+        defs.insert("crate".to_owned(), SymbolKind::Mod);
+        let mut variants = HashSet::new();
+        collect_variants(&root.items, "crate", &mut variants);
+        let mut by_module: HashMap<_, Vec<_>> = HashMap::new();
+        for import in imports.iter().filter(|import| !import.local) {
+            by_module.entry(&*import.module).or_default().push(import);
+        }
+        Self { defs, variants, imports: by_module }
+    }
+
+    fn resolve(&self, import: &Import) -> Option<String> {
+        let path = self.absolute(import)?;
+        let path = path.strip_suffix("::*").unwrap_or(&path);
+        self.lookup(path, &mut vec![])
+    }
+
+    /// Returns the path of an import relative to the root of the crate, or
+    /// `None` if it is in another crate.
+    fn absolute(&self, import: &Import) -> Option<String> {
+        let mut segments = import.path.split("::");
+        let first = segments.next()?;
+        let mut path: Vec<&str> = match first {
+            // `flatten` makes `self::` and `super::` paths absolute.
+            "crate" => vec!["crate"],
+            // `::name` and `$crate::name` are in other crates, and `self::`
+            // and `super::` paths that are still relative go above the root
+            // of the crate.
+            "" | "$crate" | "self" | "super" => return None,
+            // A path relative to the current module. Other names are in other
+            // crates or in the prelude.
+            name if self.in_scope(&import.module, name) => {
+                let mut path: Vec<_> = import.module.split("::").collect();
+                path.push(name);
+                path
+            }
+            _ => return None,
+        };
+        for segment in segments {
+            if segment == "super" {
+                path.pop();
+            } else {
+                path.push(segment);
+            }
+        }
+        if path.is_empty() { None } else { Some(path.join("::")) }
+    }
+
+    /// Returns `true` if `name` is an item or an import in `module`.
+    fn in_scope(&self, module: &str, name: &str) -> bool {
+        self.defs.contains_key(&format!("{}::{}", module, name))
+            || self
+                .imports
+                .get(module)
+                .map_or(false, |imports| imports.iter().any(|import| import.name() == Some(name)))
+    }
+
+    /// Returns the path of the item that `path` refers to, following
+    /// re-exports. `stack` is the paths being looked up, to stop on circular
+    /// imports.
+    fn lookup(&self, path: &str, stack: &mut Vec<String>) -> Option<String> {
+        if self.defs.contains_key(path) {
+            return Some(path.to_owned());
+        }
+        if stack.iter().any(|p| p == path) {
+            return None;
+        }
+        stack.push(path.to_owned());
+        let found = self.lookup_inner(path, stack);
+        stack.pop();
+        found
+    }
+
+    fn lookup_inner(&self, path: &str, stack: &mut Vec<String>) -> Option<String> {
+        let (parent, name) = path.rsplit_once("::")?;
+        let parent = self.lookup(parent, stack)?;
+        let path = format!("{}::{}", parent, name);
+        if self.defs.contains_key(&path) || self.variants.contains(&path) {
+            return Some(path);
+        }
+        if self.defs.get(&parent) != Some(&SymbolKind::Mod) {
+            return None;
+        }
+        let imports = self.imports.get(&*parent)?;
+        for import in imports.iter().filter(|import| import.name() == Some(name)) {
+            if let Some(found) = self.absolute(import).and_then(|p| self.lookup(&p, stack)) {
+                return Some(found);
+            }
+        }
+        for import in imports.iter().filter(|import| import.is_glob()) {
+            let module = match self.absolute(import) {
+                Some(target) => target.trim_end_matches("::*").to_owned(),
+                None => continue,
+            };
+            let found = self
+                .lookup(&module, stack)
+                .and_then(|module| self.lookup(&format!("{}::{}", module, name), stack));
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    }
+}
+
+/// Collects the paths of the variants of the enums in `items`.
+fn collect_variants(items: &[Item], module: &str, variants: &mut HashSet<String>) {
+    for item in items {
+        match item {
+            Item::Enum(item) => {
+                let ident = proc_macro2::Ident::from(&item.ident);
+                for variant in &item.variants {
+                    let name = proc_macro2::Ident::from(&variant.ident);
+                    variants.insert(format!("{}::{}::{}", module, ident, name));
+                }
+            }
+            Item::Mod(item) => {
+                if let Some(items) = &item.content {
+                    let ident = proc_macro2::Ident::from(&item.ident);
+                    collect_variants(items, &format!("{}::{}", module, ident), variants);
+                }
+            }
+            _ => {}
+        }
+    }
+}
@@ -17,70 +17,86 @@ pub use crate::{
 ast_struct! {
     /// An adapter for [`struct@syn::ItemMod`].
     pub struct ItemMod {
-        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) attrs: Vec<Attribute>,
-        #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) vis: Visibility,
         #[serde(rename = "unsafe")]
-        #[serde(default, skip_serializing_if = "not")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) unsafety: bool,
         pub(crate) ident: Ident,
         // TODO: should not skip_serializing_if
-        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) content: Option<Vec<Item>>,
         // TODO: can remove
-        #[serde(default, skip_serializing_if = "not")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) semi: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub(crate) span: Option<SpanInfo>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub(crate) trivia: Vec<crate::trivia::TokenTrivia>,
     }
 }
 
 ast_struct! {
     /// An adapter for [`struct@syn::ItemStruct`].
     pub struct ItemStruct {
-        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) attrs: Vec<Attribute>,
-        #[serde(default, skip_serializing_if = "Visibility::is_inherited")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) vis: Visibility,
         pub(crate) ident: Ident,
-        #[serde(default, skip_serializing_if = "Generics::is_none")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) generics: Generics,
         pub(crate) fields: Fields,
-        // #[serde(default, skip_serializing_if = "not")]
-        // pub(crate) semi_token: bool,
+        #[serde(default, skip_serializing_if = "lossless::skip")]
+        #[serde(serialize_with = "lossless::serialize_field")]
+        pub(crate) semi_token: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub(crate) span: Option<SpanInfo>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub(crate) trivia: Vec<crate::trivia::TokenTrivia>,
     }
 }
 
 ast_struct! {
     /// An adapter for [`struct@syn::TraitItemFn`].
     pub struct TraitItemFn {
-        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) attrs: Vec<Attribute>,
         #[serde(flatten)]
         pub(crate) sig: Signature,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) default: Option<Block>,
-        // #[serde(default, skip_serializing_if = "not")]
-        // pub(crate) semi_token: bool,
+        #[serde(default, skip_serializing_if = "lossless::skip")]
+        #[serde(serialize_with = "lossless::serialize_field")]
+        pub(crate) semi_token: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub(crate) span: Option<SpanInfo>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub(crate) trivia: Vec<crate::trivia::TokenTrivia>,
     }
 }
 
 ast_struct! {
     /// An adapter for [`struct@syn::Receiver`].
     pub struct Receiver {
-        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) attrs: Vec<Attribute>,
         #[serde(rename = "ref")]
-        #[serde(default, skip_serializing_if = "not")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) reference: bool,
-        #[serde(default, skip_serializing_if = "Option::is_none")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) lifetime: Option<Lifetime>,
         #[serde(rename = "mut")]
-        #[serde(default, skip_serializing_if = "not")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) mutability: bool,
-        #[serde(default, skip_serializing_if = "not")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) colon_token: bool,
         // TODO: skip if colon_token=false?
         pub(crate) ty: Box<Type>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub(crate) trivia: Vec<crate::trivia::TokenTrivia>,
     }
 }
 
@@ -111,6 +127,9 @@ mod convert {
                 ident: other.ident.ref_into(),
                 generics: other.generics.ref_into(),
                 fields,
+                semi_token: other.semi_token.is_some(),
+                span: Some(SpanInfo::from_span(other.ident.span())),
+                trivia: crate::trivia::take(other),
             }
         }
     }
@@ -123,7 +142,7 @@ mod convert {
                 ident: other.ident.ref_into(),
                 generics: other.generics.ref_into(),
                 fields: other.fields.ref_into(),
-                semi_token: default_or_none(!other.fields.is_named()),
+                semi_token: default_or_none(other.semi_token || !other.fields.is_named()),
             }
         }
     }
@@ -144,6 +163,9 @@ mod convert {
                 attrs: other.attrs.map_into(),
                 sig: other.sig.ref_into(),
                 default: other.default.map_into(),
+                semi_token: other.semi_token.is_some(),
+                span: Some(SpanInfo::from_span(other.sig.ident.span())),
+                trivia: crate::trivia::take(other),
             }
         }
     }
@@ -153,7 +175,7 @@ mod convert {
                 attrs: other.attrs.map_into(),
                 sig: other.sig.ref_into(),
                 default: other.default.map_into(),
-                semi_token: default_or_none(other.default.is_none()),
+                semi_token: default_or_none(other.semi_token || other.default.is_none()),
             }
         }
     }
@@ -169,6 +191,7 @@ mod convert {
                 mutability: node.mutability.is_some(),
                 colon_token: node.colon_token.is_some(),
                 ty: node.ty.map_into(),
+                trivia: crate::trivia::take(node),
             }
         }
     }
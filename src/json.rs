@@ -212,3 +212,39 @@ where
     let adapter: S::Adapter = serde_json::from_str(s)?;
     Ok(S::from_adapter(&adapter))
 }
+
+/// Deserialize a [`syn::File`] from bytes of JSON text, without overflowing
+/// the stack on deeply nested expressions and blocks.
+///
+/// [`from_slice`] and the other functions in this module recurse on each
+/// level of nesting, so they overflow the stack on, e.g., a sum of 100000
+/// terms. This function parses the JSON text with serde_json's recursion
+/// limit disabled, growing the stack as needed, and then deserializes and
+/// converts the tree in shallow chunks, at the cost of some speed.
+///
+/// This function also accepts the internally tagged form of
+/// [`Tagged`](crate::tagged::Tagged).
+///
+/// Note that the resulting syntax tree is as deep as the input, and
+/// dropping, printing, or visiting it is still recursive.
+///
+/// # Errors
+///
+/// Returns an error if the input is not valid JSON, or if it does not
+/// represent a [`syn::File`].
+pub fn from_slice_deep(v: &[u8]) -> Result<syn::File> {
+    crate::deep::from_slice(v)
+}
+
+/// Deserialize a [`syn::File`] from a string of JSON text, without
+/// overflowing the stack on deeply nested expressions and blocks.
+///
+/// See [`from_slice_deep`] for more.
+///
+/// # Errors
+///
+/// Returns an error if the input is not valid JSON, or if it does not
+/// represent a [`syn::File`].
+pub fn from_str_deep(s: &str) -> Result<syn::File> {
+    crate::deep::from_slice(s.as_bytes())
+}
@@ -0,0 +1,279 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Module trees of whole crates.
+//!
+//! A [`Crate`] is loaded from the root module of a crate (e.g., `src/lib.rs`
+//! or `src/main.rs`), and follows the out-of-line modules (`mod foo;`) of the
+//! crate as the compiler does:
+//!
+//! - `mod foo;` is loaded from `foo.rs` or `foo/mod.rs` in the directory of
+//!   the parent module. The directory of a module in `bar.rs` is `bar/`, and
+//!   the directory of a module in `mod.rs`, `lib.rs`, or `main.rs` is the
+//!   directory of the file.
+//! - `#[path = "..."]` is relative to the directory of the file that contains
+//!   the module, or to the directory of the parent module if it is inside an
+//!   inline module (`mod bar { mod foo; }`).
+//!
+//! The items and inner attributes of each file are inlined into the content
+//! of the `ItemMod` that declares it, so the crate is serialized as a single
+//! [`File`]. The spans in the tree record the files they came from as ids in
+//! the [`files`](Crate::files) of the crate.
+//!
+//! Modules whose files do not exist are left as they are if they have a
+//! `#[cfg]` attribute, since they are often only compiled on other platforms.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! # #[cfg(feature = "json")]
+//! # fn dox() -> Result<(), Box<dyn std::error::Error>> {
+//! use syn_serde::krate::Crate;
+//!
+//! let krate = Crate::load("src/lib.rs")?;
+//! println!("{}", serde_json::to_string_pretty(&krate)?);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! This prints the following JSON:
+//!
+//! ```json
+//! {
+//!   "files": ["lib.rs", "foo.rs"],
+//!   "root": {
+//!     "items": [
+//!       {
+//!         "mod": {
+//!           "ident": "foo",
+//!           "content": [...],
+//!           "span": { "start_line": 1, ..., "file": 0 }
+//!         }
+//!       }
+//!     ],
+//!     "span": { "start_line": 1, ..., "file": 0 }
+//!   }
+//! }
+//! ```
+
+use std::{
+    borrow::Cow,
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{Error, File, Item, ItemMod, Syn as _, span::SetFile, visit_mut::VisitMut as _};
+
+/// The module tree of a crate.
+///
+/// See the [module level documentation](self) for more.
+#[derive(Serialize, Deserialize)]
+pub struct Crate {
+    files: Vec<String>,
+    root: File,
+}
+
+impl Crate {
+    /// Loads the crate whose root module is in `root`.
+    pub fn load<P>(root: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        Self::load_inner(root.as_ref(), false)
+    }
+
+    /// Loads the crate whose root module is in `root`, and distributes the
+    /// comments of each file to its nodes, as
+    /// [`File::from_syn_with_comments`] does.
+    pub fn load_with_comments<P>(root: P) -> Result<Self, Error>
+    where
+        P: AsRef<Path>,
+    {
+        Self::load_inner(root.as_ref(), true)
+    }
+
+    fn load_inner(root: &Path, comments: bool) -> Result<Self, Error> {
+        let base = root.parent().unwrap_or_else(|| Path::new(""));
+        let name = match root.file_name() {
+            Some(name) => Path::new(name),
+            None => return Err(Error::new(format!("{}: not a file", root.display()))),
+        };
+        let mut loader = Loader { base, comments, files: vec![], stack: vec![] };
+        // The root module owns its directory, as `mod.rs` does.
+        let root = loader.load_file(name, true)?;
+        Ok(Self { files: loader.files, root })
+    }
+
+    /// Returns the paths of the files of the crate, relative to the directory
+    /// of the root module. The root module is the first file, and the `file`
+    /// of a [`SpanInfo`](crate::SpanInfo) is an index into this list.
+    #[must_use]
+    pub fn files(&self) -> &[String] {
+        &self.files
+    }
+
+    /// Returns the root module, with all of the modules of the crate inlined.
+    #[must_use]
+    pub fn root(&self) -> &File {
+        &self.root
+    }
+
+    /// Converts the crate into its root module.
+    #[must_use]
+    pub fn into_root(self) -> File {
+        self.root
+    }
+}
+
+struct Loader<'a> {
+    /// The directory of the root module.
+    base: &'a Path,
+    comments: bool,
+    files: Vec<String>,
+    /// The files being loaded, to detect circular modules.
+    stack: Vec<PathBuf>,
+}
+
+impl Loader<'_> {
+    /// Loads the file at `path`, relative to the directory of the root
+    /// module. `mod_rs` is `true` if the file owns its directory, as
+    /// `mod.rs` does.
+    fn load_file(&mut self, path: &Path, mod_rs: bool) -> Result<File, Error> {
+        let name = display(path);
+        let full_path = self.base.join(path);
+        let canonical = fs::canonicalize(&full_path).unwrap_or_else(|_| full_path.clone());
+        if self.stack.contains(&canonical) {
+            return Err(Error::new(format!("{}: circular modules", name)));
+        }
+
+        let source =
+            fs::read_to_string(&full_path).map_err(|e| Error::new(format!("{}: {}", name, e)))?;
+        let syn_file = syn::parse_file(&source).map_err(|e| {
+            let start = e.span().start();
+            Error::new(format!("{}:{}:{}: {}", name, start.line, start.column + 1, e))
+        })?;
+        let id = self.files.len();
+        let mut file = if self.comments {
+            let mut file = File::from_syn_with_comments(&syn_file, &source);
+            SetFile(id).visit_file_mut(&mut file);
+            file
+        } else {
+            syn_file.to_adapter_in_file(id)
+        };
+        self.files.push(name);
+
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+        let module_dir =
+            if mod_rs { dir.to_owned() } else { dir.join(path.file_stem().unwrap_or_default()) };
+        self.stack.push(canonical);
+        self.load_modules(&mut file.items, &module_dir, dir)?;
+        self.stack.pop();
+        Ok(file)
+    }
+
+    /// Loads the out-of-line modules in `items`. `module_dir` is the directory
+    /// of the module that contains `items`, and `path_dir` is the directory
+    /// that `#[path]` is relative to.
+    fn load_modules(
+        &mut self,
+        items: &mut [Item],
+        module_dir: &Path,
+        path_dir: &Path,
+    ) -> Result<(), Error> {
+        for item in items {
+            let item = match item {
+                Item::Mod(item) => item,
+                _ => continue,
+            };
+            let name = proc_macro2::Ident::from(&item.ident).to_string();
+            let name = name.strip_prefix("r#").unwrap_or(&name);
+            let attrs: Vec<syn::Attribute> = item.attrs.iter().map(syn::Attribute::from).collect();
+            if let Some(items) = &mut item.content {
+                let dir = match path_attr(&attrs)? {
+                    Some(path) => path_dir.join(path),
+                    None => module_dir.join(name),
+                };
+                self.load_modules(items, &dir, &dir)?;
+                continue;
+            }
+
+            let file = module_dir.join(format!("{}.rs", name));
+            let mod_file = module_dir.join(name).join("mod.rs");
+            let (path, mod_rs) = match path_attr(&attrs)? {
+                Some(path) => (path_dir.join(path), true),
+                None => {
+                    match (self.base.join(&file).is_file(), self.base.join(&mod_file).is_file()) {
+                        (true, true) => {
+                            return Err(Error::new(format!(
+                                "file for module `{}` found at both {} and {}",
+                                name,
+                                display(&file),
+                                display(&mod_file)
+                            )));
+                        }
+                        (false, true) => (mod_file.clone(), true),
+                        _ => (file.clone(), false),
+                    }
+                }
+            };
+            if !self.base.join(&path).is_file() {
+                if attrs.iter().any(|attr| attr.path().is_ident("cfg")) {
+                    continue;
+                }
+                let msg = if path == file {
+                    format!(
+                        "file not found for module `{}`: expected {} or {}",
+                        name,
+                        display(&file),
+                        display(&mod_file)
+                    )
+                } else {
+                    format!("file not found for module `{}`: {}", name, display(&path))
+                };
+                return Err(Error::new(msg));
+            }
+            let file = self.load_file(&path, mod_rs)?;
+            inline(item, file);
+        }
+        Ok(())
+    }
+}
+
+/// Returns the value of the `#[path]` attribute of a module.
+fn path_attr(attrs: &[syn::Attribute]) -> Result<Option<String>, Error> {
+    let attr = match attrs.iter().find(|attr| attr.path().is_ident("path")) {
+        Some(attr) => attr,
+        None => return Ok(None),
+    };
+    match &attr.meta {
+        syn::Meta::NameValue(syn::MetaNameValue {
+            value: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }),
+            ..
+        }) => Ok(Some(lit.value())),
+        _ => Err(Error::new("malformed `path` attribute: expected `#[path = \"file\"]`")),
+    }
+}
+
+/// Moves the items and inner attributes of `file` into the content of `item`.
+fn inline(item: &mut ItemMod, file: File) {
+    item.attrs.extend(file.attrs);
+    item.content = Some(file.items);
+    item.semi = false;
+}
+
+/// Formats a relative path with `/` as the separator on all platforms, and
+/// with `..` removed where possible.
+fn display(path: &Path) -> String {
+    let mut components: Vec<Cow<'_, str>> = vec![];
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir if components.last().map_or(false, |last| last != "..") => {
+                components.pop();
+            }
+            component => components.push(component.as_os_str().to_string_lossy()),
+        }
+    }
+    components.join("/")
+}
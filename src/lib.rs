@@ -83,10 +83,34 @@ and print out a JSON representation of the syntax tree.
 The [`json2rust`] example parse a JSON file into a `syn_serde::File` and
 print out a Rust syntax tree.
 
+### Finding nodes in the syntax tree
+
+The [`query`] module provides CSS-selector-like queries over syntax trees, such
+as `fn > block method_call[method=unwrap]`. The [`rust-query`] example prints
+out the locations of the nodes that match a query.
+
+### Command-line tool
+
+The [`syn-serde`][cli] command-line tool provides all of the above in one
+binary, with subcommands to convert between Rust code and the serialized
+formats, and to query, compare, and validate syntax trees:
+
+```sh
+cargo install --git https://github.com/taiki-e/syn-serde syn-serde-cli
+syn-serde parse src/main.rs --comments --pretty -o main.json
+syn-serde print main.json
+syn-serde convert main.json --to protobuf -o main.pb
+syn-serde query 'method_call[method=unwrap]' src/main.rs
+syn-serde diff old.rs new.rs
+syn-serde validate main.json main.pb
+```
+
 ## Optional features
 
 - **`json`** — Provides functions for JSON <-> Rust serializing and
   deserializing.
+- **`protobuf`** — Provides functions for Protocol Buffers <-> Rust
+  serializing and deserializing, along with the schema of the messages.
 
 ## Relationship to Syn
 
@@ -101,6 +125,8 @@ The data structures of syn-serde 0.3 is compatible with the data structures of
 [proc-macro2]: https://github.com/alexcrichton/proc-macro2
 [`rust2json`]: https://github.com/taiki-e/syn-serde/tree/HEAD/examples/rust2json
 [`json2rust`]: https://github.com/taiki-e/syn-serde/tree/HEAD/examples/json2rust
+[`rust-query`]: https://github.com/taiki-e/syn-serde/tree/HEAD/examples/rust-query
+[cli]: https://github.com/taiki-e/syn-serde/tree/HEAD/cli
 
 <!-- tidy:sync-markdown-to-rustdoc:end -->
 */
@@ -146,6 +172,12 @@ mod ast_enum;
 #[path = "gen/convert.rs"]
 mod convert;
 
+#[path = "gen/visit.rs"]
+mod visit;
+
+#[path = "gen/visit_mut.rs"]
+mod visit_mut;
+
 mod attr {
     pub use crate::{
         ast_enum::{AttrStyle, Meta},
@@ -177,6 +209,7 @@ mod file {
     
     impl File {
         /// Create a File from a syn::File and source code, distributing comments to appropriate AST nodes.
+        #[must_use]
         pub fn from_syn_with_comments(syn_file: &syn::File, source: &str) -> Self {
             // First, create the basic file structure
             let mut file = Self::from(syn_file);
@@ -184,26 +217,46 @@ mod file {
             // Extract comments from the source code
             let comments = crate::comment::extract_comments(source);
             
-            // Associate comments with AST nodes
-            let comment_associations = associate_comments_with_file_nodes(&comments, &file);
+            // Associate comments with AST nodes, identified by their node ids
+            let ids = NodeIds::new(&file);
+            let comment_associations = associate_comments_with_file_nodes(&comments, &file, &ids);
             
             // Apply the comment associations to the file structure
-            apply_comment_associations(&mut file, comment_associations);
+            apply_comment_associations(&mut file, &ids, &comment_associations);
             
             file
         }
     }
     
+    /// Ids of the items of a file and of the blocks of its functions, as
+    /// assigned by [`crate::ids`].
+    struct NodeIds {
+        items: Vec<usize>,
+        blocks: Vec<Option<usize>>,
+    }
+    
+    impl NodeIds {
+        fn new(file: &File) -> Self {
+            let ids = crate::ids::ids_by_path(&crate::node::to_value(file));
+            let items = (0..file.items.len()).map(|i| ids[&format!("/items/{}", i)]).collect();
+            let blocks = (0..file.items.len())
+                .map(|i| ids.get(&format!("/items/{}/fn/stmts", i)).copied())
+                .collect();
+            Self { items, blocks }
+        }
+    }
+    
     /// Associate comments with AST nodes in a file
     fn associate_comments_with_file_nodes(
         comments: &[crate::Comment],
         file: &File,
-    ) -> HashMap<String, Vec<crate::Comment>> {
+        ids: &NodeIds,
+    ) -> HashMap<usize, Vec<crate::Comment>> {
         let mut node_spans = Vec::new();
         
         // Collect spans from all items in the file
         for (i, item) in file.items.iter().enumerate() {
-            collect_item_spans(item, &format!("item_{}", i), &mut node_spans);
+            collect_item_spans(item, ids.items[i], ids.blocks[i], &mut node_spans);
         }
         
         // Associate comments with nodes
@@ -211,21 +264,26 @@ mod file {
     }
     
     /// Collect span information from an item and its children
-    fn collect_item_spans(item: &crate::Item, item_id: &str, spans: &mut Vec<(String, crate::SpanInfo)>) {
+    fn collect_item_spans(
+        item: &crate::Item,
+        item_id: usize,
+        block_id: Option<usize>,
+        spans: &mut Vec<(usize, crate::SpanInfo)>,
+    ) {
         // Add span information for different item types
         match item {
             crate::Item::Fn(item_fn) => {
                 if let Some(span) = &item_fn.span {
-                    spans.push((item_id.to_string(), span.clone()));
+                    spans.push((item_id, *span));
                 }
                 // Add block span if present
-                if let Some(block_span) = &item_fn.block.span {
-                    spans.push((format!("{}_block", item_id), block_span.clone()));
+                if let (Some(block_id), Some(block_span)) = (block_id, &item_fn.block.span) {
+                    spans.push((block_id, *block_span));
                 }
             }
             crate::Item::Enum(item_enum) => {
                 if let Some(span) = &item_enum.span {
-                    spans.push((item_id.to_string(), span.clone()));
+                    spans.push((item_id, *span));
                 }
             }
             crate::Item::Struct(_item_struct) => {
@@ -233,32 +291,32 @@ mod file {
             }
             crate::Item::Trait(item_trait) => {
                 if let Some(span) = &item_trait.span {
-                    spans.push((item_id.to_string(), span.clone()));
+                    spans.push((item_id, *span));
                 }
             }
             crate::Item::Impl(item_impl) => {
                 if let Some(span) = &item_impl.span {
-                    spans.push((item_id.to_string(), span.clone()));
+                    spans.push((item_id, *span));
                 }
             }
             crate::Item::Use(item_use) => {
                 if let Some(span) = &item_use.span {
-                    spans.push((item_id.to_string(), span.clone()));
+                    spans.push((item_id, *span));
                 }
             }
             crate::Item::Const(item_const) => {
                 if let Some(span) = &item_const.span {
-                    spans.push((item_id.to_string(), span.clone()));
+                    spans.push((item_id, *span));
                 }
             }
             crate::Item::Static(item_static) => {
                 if let Some(span) = &item_static.span {
-                    spans.push((item_id.to_string(), span.clone()));
+                    spans.push((item_id, *span));
                 }
             }
             crate::Item::Type(item_type) => {
                 if let Some(span) = &item_type.span {
-                    spans.push((item_id.to_string(), span.clone()));
+                    spans.push((item_id, *span));
                 }
             }
             crate::Item::Union(_item_union) => {
@@ -286,20 +344,21 @@ mod file {
     }
     
     /// Apply comment associations to the file structure
-    fn apply_comment_associations(file: &mut File, associations: HashMap<String, Vec<crate::Comment>>) {
+    fn apply_comment_associations(
+        file: &mut File,
+        ids: &NodeIds,
+        associations: &HashMap<usize, Vec<crate::Comment>>,
+    ) {
         for (i, item) in file.items.iter_mut().enumerate() {
-            let item_id = format!("item_{}", i);
-            
             // Apply comments to the item
-            if let Some(comments) = associations.get(&item_id) {
+            if let Some(comments) = associations.get(&ids.items[i]) {
                 apply_comments_to_item(item, comments.clone());
             }
             
             // Apply comments to the item's block if it's a function
-            if let crate::Item::Fn(item_fn) = item {
-                let block_id = format!("{}_block", item_id);
+            if let (crate::Item::Fn(item_fn), Some(block_id)) = (&mut *item, ids.blocks[i]) {
                 if let Some(comments) = associations.get(&block_id) {
-                    item_fn.block.comments = comments.clone();
+                    item_fn.block.comments.clone_from(comments);
                 }
             }
         }
@@ -390,7 +449,7 @@ pub use crate::lifetime::Lifetime;
 mod lit;
 #[doc(hidden)]
 pub use crate::lit::{
-    Lit, LitBool, LitByte, LitByteStr, LitChar, LitFloat, LitInt, LitStr, StrStyle,
+    Lit, LitBool, LitByte, LitByteStr, LitCStr, LitChar, LitFloat, LitInt, LitStr, StrStyle,
 };
 
 mod mac {
@@ -456,10 +515,60 @@ pub use crate::comment::{Comment, CommentKind};
 
 mod comment_association;
 
+mod node;
+
+mod error;
+pub use crate::error::Error;
+
+pub mod arena;
+
+pub mod cfg;
+
+pub mod docs;
+
+pub mod explicit;
+
+pub mod ids;
+
+pub mod imports;
+
+pub mod krate;
+
+pub mod lit_values;
+
+pub mod lossless;
+
+pub mod macro_bodies;
+
+pub mod nested_meta;
+
+pub mod options;
+
+pub mod query;
+
+pub mod rewrite;
+
+pub mod symbols;
+
+pub mod tagged;
+
+pub mod trivia;
+
+#[cfg(feature = "json")]
+#[cfg_attr(docsrs, doc(cfg(feature = "json")))]
+pub mod batch;
+
 #[cfg(feature = "json")]
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
 pub mod json;
 
+#[cfg(feature = "json")]
+mod deep;
+
+#[cfg(feature = "protobuf")]
+#[cfg_attr(docsrs, doc(cfg(feature = "protobuf")))]
+pub mod protobuf;
+
 mod sealed {
     #[allow(unknown_lints, unnameable_types)] // Not public API. unnameable_types is available on Rust 1.79+
     pub trait Sealed {}
@@ -520,17 +629,39 @@ pub trait Syn: Sized + sealed::Sealed {
     /// # }
     /// ```
     fn from_adapter(adapter: &Self::Adapter) -> Self;
+
+    /// Converts a `Syn` type into an adapter, and records `file` as the
+    /// [`file`](SpanInfo::file) of all of its spans.
+    ///
+    /// `proc_macro2::Span` does not know which file it came from, so this is
+    /// how the spans of a syntax tree parsed from one of several files are
+    /// told apart, e.g., with an index into a table of file paths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "json")]
+    /// # fn dox() {
+    /// use syn_serde::Syn;
+    ///
+    /// let syn_file: syn::File = syn::parse_str("fn main() {}").unwrap();
+    ///
+    /// let serializable_file = syn_file.to_adapter_in_file(2);
+    /// let json = serde_json::to_value(&serializable_file).unwrap();
+    /// assert_eq!(json["items"][0]["fn"]["span"]["file"], 2);
+    /// # }
+    /// # fn main() {} // rustdoc bug: https://github.com/rust-lang/rust/issues/131893
+    /// ```
+    fn to_adapter_in_file(&self, file: usize) -> Self::Adapter;
 }
 
 // -----------------------------------------------------------------------------
 
-use core::ops;
-
 use proc_macro2::Span;
 use serde::{de::Deserialize, ser::Serialize};
 use serde_derive::{Deserialize, Serialize};
 
-type Punctuated<T> = Vec<T>;
+use crate::lossless::Punctuated;
 
 fn default<T>() -> T
 where
@@ -546,13 +677,6 @@ where
     if x { Some(T::default()) } else { None }
 }
 
-fn not<T>(x: T) -> T::Output
-where
-    T: ops::Not,
-{
-    !x
-}
-
 // https://github.com/rust-lang/rust/issues/51443
 trait RefInto<U>: Sized {
     fn ref_into<'a>(&'a self) -> U
@@ -593,7 +717,7 @@ impl<T, U> MapInto<U, Vec<U>> for Vec<T> {
     }
 }
 
-impl<T, U, P> MapInto<U, syn::punctuated::Punctuated<U, P>> for Vec<T>
+impl<T, U, P> MapInto<U, syn::punctuated::Punctuated<U, P>> for Punctuated<T>
 where
     P: Default,
 {
@@ -603,21 +727,27 @@ where
     where
         F: FnMut(&'a Self::T) -> U,
     {
-        self.iter().map(f).collect()
+        let mut punctuated: syn::punctuated::Punctuated<U, P> = self.iter().map(f).collect();
+        if self.trailing && !punctuated.empty_or_trailing() {
+            punctuated.push_punct(P::default());
+        }
+        punctuated
     }
 }
 
-impl<T, U, P> MapInto<U, Vec<U>> for syn::punctuated::Punctuated<T, P>
+impl<T, U, P> MapInto<U, Punctuated<U>> for syn::punctuated::Punctuated<T, P>
 where
     P: Default,
 {
     type T = T;
 
-    fn ref_map<'a, F>(&'a self, f: F) -> Vec<U>
+    fn ref_map<'a, F>(&'a self, f: F) -> Punctuated<U>
     where
         F: FnMut(&'a Self::T) -> U,
     {
-        self.iter().map(f).collect()
+        let mut punctuated: Punctuated<U> = self.iter().map(f).collect();
+        punctuated.trailing = self.trailing_punct();
+        punctuated
     }
 }
 
@@ -3,51 +3,208 @@
 use super::*;
 pub use crate::{ast_enum::Lit, ast_struct::LitBool};
 
-ast_struct! {
+// Literals are serialized as their tokens, e.g., `"\"Hello\\n\""`, but they
+// can also be deserialized from the form that includes the decoded value; see
+// the `lit_values` module.
+macro_rules! ast_lit {
+    (
+        $(#[$attrs:meta])*
+        pub struct $name:ident;
+    ) => {
+        #[derive(serde_derive::Deserialize)]
+        $(#[$attrs])*
+        pub struct $name {
+            pub(crate) token: Literal,
+        }
+
+        impl Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                self.token.serialize(serializer)
+            }
+        }
+    };
+}
+
+ast_lit! {
     /// An adapter for [`struct@syn::LitStr`].
-    #[serde(transparent)]
-    pub struct LitStr {
-        token: Literal,
-    }
+    #[serde(try_from = "LitRepr<String>")]
+    pub struct LitStr;
 }
 
-ast_struct! {
+ast_lit! {
     /// An adapter for [`struct@syn::LitByteStr`].
-    #[serde(transparent)]
-    pub struct LitByteStr {
-        token: Literal,
-    }
+    #[serde(try_from = "LitRepr<Vec<u8>>")]
+    pub struct LitByteStr;
 }
 
-ast_struct! {
+ast_lit! {
+    /// An adapter for [`struct@syn::LitCStr`].
+    #[serde(try_from = "LitRepr<Vec<u8>>")]
+    pub struct LitCStr;
+}
+
+ast_lit! {
     /// An adapter for [`struct@syn::LitByte`].
-    #[serde(transparent)]
-    pub struct LitByte {
-        token: Literal,
-    }
+    #[serde(try_from = "LitRepr<u8>")]
+    pub struct LitByte;
 }
 
-ast_struct! {
+ast_lit! {
     /// An adapter for [`struct@syn::LitChar`].
-    #[serde(transparent)]
-    pub struct LitChar {
-        token: Literal,
-    }
+    #[serde(try_from = "LitRepr<char>")]
+    pub struct LitChar;
 }
 
-ast_struct! {
+ast_lit! {
     /// An adapter for [`struct@syn::LitInt`].
-    #[serde(transparent)]
-    pub struct LitInt {
-        token: Literal,
-    }
+    #[serde(try_from = "LitRepr<String>")]
+    pub struct LitInt;
 }
 
-ast_struct! {
+ast_lit! {
     /// An adapter for [`struct@syn::LitFloat`].
-    #[serde(transparent)]
-    pub struct LitFloat {
-        token: Literal,
+    #[serde(try_from = "LitRepr<String>")]
+    pub struct LitFloat;
+}
+
+/// The serialized forms of literals: the token, or an object with the token
+/// and the decoded value.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LitRepr<T> {
+    Token(Literal),
+    Decoded {
+        #[serde(default)]
+        token: Option<Literal>,
+        #[serde(default)]
+        value: Option<T>,
+        #[serde(default)]
+        base: Option<u32>,
+        #[serde(default)]
+        suffix: String,
+    },
+}
+
+impl<T> LitRepr<T> {
+    /// Returns the token, building it from the decoded value if it is omitted.
+    fn into_token<F>(self, encode: F) -> Result<Literal, String>
+    where
+        F: FnOnce(T, Option<u32>) -> Result<String, String>,
+    {
+        match self {
+            LitRepr::Token(token) | LitRepr::Decoded { token: Some(token), .. } => Ok(token),
+            LitRepr::Decoded { token: None, value: Some(value), base, suffix } => {
+                let mut text = encode(value, base)?;
+                text.push_str(&suffix);
+                value::to_literal(&text)
+            }
+            LitRepr::Decoded { token: None, value: None, .. } => {
+                Err("literal must have either `token` or `value`".to_owned())
+            }
+        }
+    }
+}
+
+/// Checks that `token` is a single literal that `parse` accepts, and that syn
+/// sees as a literal of the kind that `is_kind` accepts, so that converting
+/// the adapter to a syn type never panics.
+fn validate<T>(
+    token: &Literal,
+    parse: fn(&str) -> Result<T, String>,
+    is_kind: fn(&syn::Lit) -> bool,
+) -> Result<(), String> {
+    let literal = value::to_literal(&token.text)?;
+    parse(&token.text)?;
+    if is_kind(&syn::Lit::new((&literal).into())) {
+        Ok(())
+    } else {
+        Err(format!("unexpected kind of literal `{}`", token.text))
+    }
+}
+
+impl TryFrom<LitRepr<String>> for LitStr {
+    type Error = String;
+
+    fn try_from(repr: LitRepr<String>) -> Result<Self, String> {
+        let token = repr.into_token(|value, _| Ok(Literal::string(&value).text))?;
+        validate(&token, value::parse_lit_str, |lit| matches!(lit, syn::Lit::Str(_)))?;
+        Ok(Self { token })
+    }
+}
+
+impl TryFrom<LitRepr<Vec<u8>>> for LitByteStr {
+    type Error = String;
+
+    fn try_from(repr: LitRepr<Vec<u8>>) -> Result<Self, String> {
+        let token = repr.into_token(|value, _| Ok(Literal::byte_string(&value).text))?;
+        validate(&token, value::parse_lit_byte_str, |lit| matches!(lit, syn::Lit::ByteStr(_)))?;
+        Ok(Self { token })
+    }
+}
+
+impl TryFrom<LitRepr<Vec<u8>>> for LitCStr {
+    type Error = String;
+
+    fn try_from(repr: LitRepr<Vec<u8>>) -> Result<Self, String> {
+        let token = repr.into_token(|value, _| Ok(Literal::c_string(&value).text))?;
+        validate(&token, value::parse_lit_c_str, |lit| matches!(lit, syn::Lit::CStr(_)))?;
+        Ok(Self { token })
+    }
+}
+
+impl TryFrom<LitRepr<u8>> for LitByte {
+    type Error = String;
+
+    fn try_from(repr: LitRepr<u8>) -> Result<Self, String> {
+        let token = repr.into_token(|value, _| Ok(Literal::byte_character(value).text))?;
+        validate(&token, value::parse_lit_byte, |lit| matches!(lit, syn::Lit::Byte(_)))?;
+        Ok(Self { token })
+    }
+}
+
+impl TryFrom<LitRepr<char>> for LitChar {
+    type Error = String;
+
+    fn try_from(repr: LitRepr<char>) -> Result<Self, String> {
+        let token = repr.into_token(|value, _| Ok(Literal::character(value).text))?;
+        validate(&token, value::parse_lit_char, |lit| matches!(lit, syn::Lit::Char(_)))?;
+        Ok(Self { token })
+    }
+}
+
+impl TryFrom<LitRepr<String>> for LitInt {
+    type Error = String;
+
+    fn try_from(repr: LitRepr<String>) -> Result<Self, String> {
+        let token = repr.into_token(|value, base| {
+            let n: u128 =
+                value.parse().map_err(|_| format!("invalid integer literal value `{}`", value))?;
+            match base.unwrap_or(10) {
+                2 => Ok(format!("0b{:b}", n)),
+                8 => Ok(format!("0o{:o}", n)),
+                10 => Ok(value),
+                16 => Ok(format!("0x{:x}", n)),
+                base => Err(format!("invalid integer literal base {}", base)),
+            }
+        })?;
+        validate(&token, value::parse_lit_int, |lit| matches!(lit, syn::Lit::Int(_)))?;
+        Ok(Self { token })
+    }
+}
+
+impl TryFrom<LitRepr<String>> for LitFloat {
+    type Error = String;
+
+    fn try_from(repr: LitRepr<String>) -> Result<Self, String> {
+        let token = repr.into_token(|value, _| match value::parse_lit_float(&value) {
+            Ok((_, suffix)) if suffix.is_empty() => Ok(value),
+            _ => Err(format!("invalid float literal value `{}`", value)),
+        })?;
+        validate(&token, value::parse_lit_float, |lit| matches!(lit, syn::Lit::Float(_)))?;
+        Ok(Self { token })
     }
 }
 
@@ -59,16 +216,16 @@ ast_enum! {
     }
 }
 
-mod value {
-    use core::{
-        char,
-        ops::{Index, RangeFrom},
-    };
+pub(crate) mod value {
+    use core::char;
 
     use proc_macro2::{TokenStream, TokenTree};
 
     use super::*;
 
+    // All functions in this module return an error instead of panicking on
+    // invalid input, since the input may come from deserialized data.
+
     /// Get the byte at offset idx, or a default of `b'\0'` if we're looking
     /// past the end of the input buffer.
     pub(crate) fn byte<S: ?Sized + AsRef<[u8]>>(s: &S, idx: usize) -> u8 {
@@ -76,309 +233,385 @@ mod value {
         if idx < s.len() { s[idx] } else { 0 }
     }
 
-    fn next_chr(s: &str) -> char {
-        s.chars().next().unwrap_or('\0')
+    /// The kind of a string-like literal, which determines the characters and
+    /// escapes that it can contain.
+    #[derive(Clone, Copy, PartialEq)]
+    enum Kind {
+        /// `"..."` and `'.'`
+        Str,
+        /// `b"..."` and `b'.'`
+        ByteStr,
+        /// `c"..."`
+        CStr,
+    }
+
+    /// A character or a byte, produced by a character or an escape.
+    enum Unit {
+        Char(char),
+        Byte(u8),
     }
 
     // Returns (content, suffix).
-    pub(crate) fn parse_lit_str(s: &str) -> (Box<str>, Box<str>) {
-        match byte(s, 0) {
-            b'"' => parse_lit_str_cooked(s),
-            b'r' => parse_lit_str_raw(s),
-            _ => unreachable!(),
-        }
+    pub(crate) fn parse_lit_str(s: &str) -> Result<(Box<str>, Box<str>), String> {
+        let (content, suffix) = match byte(s, 0) {
+            b'"' => parse_cooked(&s[1..], Kind::Str)?,
+            b'r' => {
+                let (content, rest) = parse_raw(&s[1..])?;
+                (content.as_bytes().to_vec(), rest)
+            }
+            _ => return Err(format!("expected string literal, found `{}`", s)),
+        };
+        // Only characters are pushed into the content of a `Kind::Str`
+        // literal, so it is always valid UTF-8.
+        let content = String::from_utf8(content).map_err(|e| e.to_string())?;
+        Ok((content.into_boxed_str(), parse_suffix(suffix)?))
+    }
+
+    // Returns (content, suffix).
+    pub(crate) fn parse_lit_byte_str(s: &str) -> Result<(Vec<u8>, Box<str>), String> {
+        let (content, suffix) = match (byte(s, 0), byte(s, 1)) {
+            (b'b', b'"') => parse_cooked(&s[2..], Kind::ByteStr)?,
+            (b'b', b'r') => {
+                let (content, rest) = parse_raw(&s[2..])?;
+                if !content.is_ascii() {
+                    return Err("non-ASCII character in raw byte string literal".to_owned());
+                }
+                (content.as_bytes().to_vec(), rest)
+            }
+            _ => return Err(format!("expected byte string literal, found `{}`", s)),
+        };
+        Ok((content, parse_suffix(suffix)?))
     }
 
-    fn parse_lit_str_cooked(mut s: &str) -> (Box<str>, Box<str>) {
-        assert_eq!(byte(s, 0), b'"');
-        s = &s[1..];
+    // Returns (content without the trailing nul, suffix).
+    pub(crate) fn parse_lit_c_str(s: &str) -> Result<(Vec<u8>, Box<str>), String> {
+        let (content, suffix) = match (byte(s, 0), byte(s, 1)) {
+            (b'c', b'"') => parse_cooked(&s[2..], Kind::CStr)?,
+            (b'c', b'r') => {
+                let (content, rest) = parse_raw(&s[2..])?;
+                if content.contains('\0') {
+                    return Err("null character in raw C string literal".to_owned());
+                }
+                (content.as_bytes().to_vec(), rest)
+            }
+            _ => return Err(format!("expected C string literal, found `{}`", s)),
+        };
+        Ok((content, parse_suffix(suffix)?))
+    }
+
+    // Returns (value, suffix).
+    pub(crate) fn parse_lit_byte(s: &str) -> Result<(u8, Box<str>), String> {
+        let rest =
+            s.strip_prefix("b'").ok_or_else(|| format!("expected byte literal, found `{}`", s))?;
+        let (unit, suffix) = parse_quoted_unit(rest, Kind::ByteStr)?;
+        let b = match unit {
+            Unit::Byte(b) => b,
+            // `parse_quoted_unit` only accepts ASCII characters in byte
+            // literals.
+            Unit::Char(ch) => ch as u8,
+        };
+        Ok((b, parse_suffix(suffix)?))
+    }
+
+    // Returns (value, suffix).
+    pub(crate) fn parse_lit_char(s: &str) -> Result<(char, Box<str>), String> {
+        let rest = s
+            .strip_prefix('\'')
+            .ok_or_else(|| format!("expected character literal, found `{}`", s))?;
+        let (unit, suffix) = parse_quoted_unit(rest, Kind::Str)?;
+        let ch = match unit {
+            Unit::Char(ch) => ch,
+            Unit::Byte(b) => char::from(b),
+        };
+        Ok((ch, parse_suffix(suffix)?))
+    }
 
-        let mut content = String::new();
-        'outer: loop {
-            let ch = match byte(s, 0) {
-                b'"' => break,
-                b'\\' => {
-                    let b = byte(s, 1);
-                    s = &s[2..];
-                    match b {
-                        b'x' => {
-                            let (byte, rest) = backslash_x(s);
-                            s = rest;
-                            assert!(byte <= 0x80, "Invalid \\x byte in string literal");
-                            char::from_u32(byte as u32).unwrap()
-                        }
-                        b'u' => {
-                            let (chr, rest) = backslash_u(s);
-                            s = rest;
-                            chr
-                        }
-                        b'n' => '\n',
-                        b'r' => '\r',
-                        b't' => '\t',
-                        b'\\' => '\\',
-                        b'0' => '\0',
-                        b'\'' => '\'',
-                        b'"' => '"',
-                        b'\r' | b'\n' => loop {
-                            let ch = next_chr(s);
-                            if ch.is_whitespace() {
-                                s = &s[ch.len_utf8()..];
-                            } else {
-                                continue 'outer;
-                            }
-                        },
-                        b => panic!("unexpected byte {:?} after \\ character in byte literal", b),
+    /// Parses the content of a non-raw string literal, after the opening
+    /// quote. Returns the content and the rest after the closing quote.
+    fn parse_cooked(mut s: &str, kind: Kind) -> Result<(Vec<u8>, &str), String> {
+        let mut out = vec![];
+        loop {
+            let mut chars = s.chars();
+            let unit = match chars.next() {
+                Some('"') => return Ok((out, chars.as_str())),
+                Some('\\') => {
+                    let (unit, rest) = parse_escape(chars.as_str(), kind)?;
+                    s = rest;
+                    match unit {
+                        Some(unit) => unit,
+                        None => continue,
                     }
                 }
-                b'\r' => {
-                    assert_eq!(byte(s, 1), b'\n', "Bare CR not allowed in string");
+                Some('\r') => {
+                    if byte(s, 1) != b'\n' {
+                        return Err("bare CR not allowed in string".to_owned());
+                    }
                     s = &s[2..];
-                    '\n'
+                    Unit::Char('\n')
                 }
-                _ => {
-                    let ch = next_chr(s);
-                    s = &s[ch.len_utf8()..];
-                    ch
+                Some(ch) => {
+                    check_char(ch, kind)?;
+                    s = chars.as_str();
+                    Unit::Char(ch)
                 }
+                None => return Err("unterminated string literal".to_owned()),
             };
-            content.push(ch);
+            match unit {
+                Unit::Char(ch) => out.extend_from_slice(ch.encode_utf8(&mut [0; 4]).as_bytes()),
+                Unit::Byte(b) => out.push(b),
+            }
         }
-
-        assert!(s.starts_with('"'));
-        let content = content.into_boxed_str();
-        let suffix = s[1..].to_owned().into_boxed_str();
-        (content, suffix)
     }
 
-    fn parse_lit_str_raw(mut s: &str) -> (Box<str>, Box<str>) {
-        assert_eq!(byte(s, 0), b'r');
-        s = &s[1..];
-
-        let mut pounds = 0;
-        while byte(s, pounds) == b'#' {
-            pounds += 1;
+    /// Parses the content of a raw string literal, after the `r`. Returns the
+    /// content and the rest after the closing quote and pounds.
+    fn parse_raw(s: &str) -> Result<(&str, &str), String> {
+        let pounds = s.bytes().take_while(|&b| b == b'#').count();
+        if pounds > 255 {
+            return Err("raw strings may be delimited by up to 255 `#` symbols".to_owned());
         }
-        assert_eq!(byte(s, pounds), b'"');
-        assert_eq!(byte(s, s.len() - pounds - 1), b'"');
-        for end in s[s.len() - pounds..].bytes() {
-            assert_eq!(end, b'#');
+        if byte(s, pounds) != b'"' {
+            return Err("expected `\"` in raw string literal".to_owned());
+        }
+        let s = &s[pounds + 1..];
+        let close = format!("\"{}", "#".repeat(pounds));
+        let end = s.find(&close).ok_or("unterminated raw string literal")?;
+        let content = &s[..end];
+        if content.contains('\r') {
+            return Err("bare CR not allowed in raw string".to_owned());
+        }
+        Ok((content, &s[end + close.len()..]))
+    }
+
+    /// Parses a character or an escape in a character or byte literal, after
+    /// the opening quote. Returns the unit and the rest after the closing
+    /// quote.
+    fn parse_quoted_unit(s: &str, kind: Kind) -> Result<(Unit, &str), String> {
+        let mut chars = s.chars();
+        let (unit, rest) = match chars.next() {
+            Some('\\') => match parse_escape(chars.as_str(), kind)? {
+                (Some(unit), rest) => (unit, rest),
+                (None, _) => return Err("invalid escape in character literal".to_owned()),
+            },
+            Some(ch @ ('\'' | '\n' | '\r' | '\t')) => {
+                return Err(format!(
+                    "character constant `{}` must be escaped",
+                    ch.escape_default()
+                ));
+            }
+            Some(ch) => {
+                check_char(ch, kind)?;
+                (Unit::Char(ch), chars.as_str())
+            }
+            None => return Err("unterminated character literal".to_owned()),
+        };
+        match rest.strip_prefix('\'') {
+            Some(rest) => Ok((unit, rest)),
+            None => Err("character literal may only contain one codepoint".to_owned()),
         }
-
-        let content = s[pounds + 1..s.len() - pounds - 1].to_owned().into_boxed_str();
-        let suffix = Box::<str>::default(); // todo
-        (content, suffix)
     }
 
-    pub(crate) fn parse_lit_byte_str(s: &str) -> Vec<u8> {
-        assert_eq!(byte(s, 0), b'b');
-        match byte(s, 1) {
-            b'"' => parse_lit_byte_str_cooked(s),
-            b'r' => parse_lit_byte_str_raw(s),
-            _ => unreachable!(),
+    fn check_char(ch: char, kind: Kind) -> Result<(), String> {
+        match kind {
+            Kind::ByteStr if !ch.is_ascii() => {
+                Err(format!("non-ASCII character `{}` in byte literal", ch.escape_default()))
+            }
+            Kind::CStr if ch == '\0' => Err("null character in C string literal".to_owned()),
+            _ => Ok(()),
         }
     }
 
-    fn parse_lit_byte_str_cooked(mut s: &str) -> Vec<u8> {
-        assert_eq!(byte(s, 0), b'b');
-        assert_eq!(byte(s, 1), b'"');
-        s = &s[2..];
-
-        // We're going to want to have slices which don't respect codepoint boundaries.
-        let mut s = s.as_bytes();
-
-        let mut out = vec![];
-        'outer: loop {
-            let byte = match byte(s, 0) {
-                b'"' => break,
-                b'\\' => {
-                    let b = byte(s, 1);
-                    s = &s[2..];
-                    match b {
-                        b'x' => {
-                            let (b, rest) = backslash_x(s);
-                            s = rest;
-                            b
-                        }
-                        b'n' => b'\n',
-                        b'r' => b'\r',
-                        b't' => b'\t',
-                        b'\\' => b'\\',
-                        b'0' => b'\0',
-                        b'\'' => b'\'',
-                        b'"' => b'"',
-                        b'\r' | b'\n' => loop {
-                            let byte = byte(s, 0);
-                            let ch = char::from_u32(byte as u32).unwrap();
-                            if ch.is_whitespace() {
-                                s = &s[1..];
-                            } else {
-                                continue 'outer;
-                            }
-                        },
-                        b => panic!("unexpected byte {:?} after \\ character in byte literal", b),
-                    }
-                }
-                b'\r' => {
-                    assert_eq!(byte(s, 1), b'\n', "Bare CR not allowed in string");
-                    s = &s[2..];
-                    b'\n'
+    /// Parses an escape, after the backslash. Returns `None` for a line
+    /// continuation.
+    fn parse_escape(s: &str, kind: Kind) -> Result<(Option<Unit>, &str), String> {
+        let mut chars = s.chars();
+        let esc = chars.next().ok_or("unterminated escape")?;
+        let rest = chars.as_str();
+        let ch = match esc {
+            'x' => {
+                let (b, rest) = backslash_x(rest)?;
+                return match kind {
+                    Kind::Str if b > 0x7F => Err(format!("out of range hex escape `\\x{:02X}`", b)),
+                    Kind::Str => Ok((Some(Unit::Char(char::from(b))), rest)),
+                    Kind::CStr if b == 0 => Err("null character in C string literal".to_owned()),
+                    Kind::ByteStr | Kind::CStr => Ok((Some(Unit::Byte(b)), rest)),
+                };
+            }
+            'u' => {
+                if kind == Kind::ByteStr {
+                    return Err("unicode escape in byte literal".to_owned());
                 }
-                b => {
-                    s = &s[1..];
-                    b
+                let (ch, rest) = backslash_u(rest)?;
+                if kind == Kind::CStr && ch == '\0' {
+                    return Err("null character in C string literal".to_owned());
                 }
-            };
-            out.push(byte);
-        }
-
-        assert_eq!(s, b"\"");
-        out
+                return Ok((Some(Unit::Char(ch)), rest));
+            }
+            'n' => '\n',
+            'r' => '\r',
+            't' => '\t',
+            '\\' => '\\',
+            '0' if kind == Kind::CStr => {
+                return Err("null character in C string literal".to_owned());
+            }
+            '0' => '\0',
+            '\'' => '\'',
+            '"' => '"',
+            '\n' => {
+                let rest = rest.trim_start_matches(|ch| matches!(ch, ' ' | '\t' | '\n' | '\r'));
+                return Ok((None, rest));
+            }
+            '\r' if byte(rest, 0) == b'\n' => return parse_escape(rest, kind),
+            ch => return Err(format!("unknown character escape `\\{}`", ch.escape_default())),
+        };
+        Ok((Some(Unit::Char(ch)), rest))
     }
 
-    fn parse_lit_byte_str_raw(s: &str) -> Vec<u8> {
-        assert_eq!(byte(s, 0), b'b');
-        String::from(parse_lit_str_raw(&s[1..]).0).into_bytes()
+    fn hex_digit(b: u8) -> Option<u8> {
+        match b {
+            b'0'..=b'9' => Some(b - b'0'),
+            b'a'..=b'f' => Some(10 + b - b'a'),
+            b'A'..=b'F' => Some(10 + b - b'A'),
+            _ => None,
+        }
     }
 
-    pub(crate) fn parse_lit_byte(s: &str) -> u8 {
-        assert_eq!(byte(s, 0), b'b');
-        assert_eq!(byte(s, 1), b'\'');
-
-        // We're going to want to have slices which don't respect codepoint boundaries.
-        let mut s = &s.as_bytes()[2..];
+    fn backslash_x(s: &str) -> Result<(u8, &str), String> {
+        match (hex_digit(byte(s, 0)), hex_digit(byte(s, 1))) {
+            (Some(b0), Some(b1)) => Ok((b0 * 0x10 + b1, &s[2..])),
+            _ => Err("numeric character escape is too short".to_owned()),
+        }
+    }
 
-        let b = match byte(s, 0) {
-            b'\\' => {
-                let b = byte(s, 1);
-                s = &s[2..];
-                match b {
-                    b'x' => {
-                        let (b, rest) = backslash_x(s);
-                        s = rest;
-                        b
-                    }
-                    b'n' => b'\n',
-                    b'r' => b'\r',
-                    b't' => b'\t',
-                    b'\\' => b'\\',
-                    b'0' => b'\0',
-                    b'\'' => b'\'',
-                    b'"' => b'"',
-                    b => panic!("unexpected byte {:?} after \\ character in byte literal", b),
-                }
-            }
-            b => {
-                s = &s[1..];
-                b
+    fn backslash_u(s: &str) -> Result<(char, &str), String> {
+        let s = s.strip_prefix('{').ok_or("incorrect unicode escape sequence")?;
+        let end = s.find('}').ok_or("unterminated unicode escape")?;
+        let digits = &s[..end];
+        if digits.is_empty() {
+            return Err("empty unicode escape".to_owned());
+        }
+        if digits.starts_with('_') {
+            return Err("invalid start of unicode escape: `_`".to_owned());
+        }
+        let mut ch = 0_u32;
+        let mut len = 0;
+        for b in digits.bytes().filter(|&b| b != b'_') {
+            let digit = hex_digit(b)
+                .ok_or_else(|| format!("invalid character in unicode escape: `{}`", b as char))?;
+            len += 1;
+            if len > 6 {
+                return Err("overlong unicode escape".to_owned());
             }
-        };
-
-        assert_eq!(byte(s, 0), b'\'');
-        b
+            ch = ch * 0x10 + u32::from(digit);
+        }
+        match char::from_u32(ch) {
+            Some(ch) => Ok((ch, &s[end + 1..])),
+            None => Err(format!("invalid unicode character escape `\\u{{{:X}}}`", ch)),
+        }
     }
 
-    pub(crate) fn parse_lit_char(mut s: &str) -> char {
-        assert_eq!(byte(s, 0), b'\'');
-        s = &s[1..];
-
-        let ch = if byte(s, 0) == b'\\' {
-            let b = byte(s, 1);
-            s = &s[2..];
-            match b {
-                b'x' => {
-                    let (byte, rest) = backslash_x(s);
-                    s = rest;
-                    assert!(byte <= 0x80, "Invalid \\x byte in string literal");
-                    char::from_u32(byte as u32).unwrap()
-                }
-                b'u' => {
-                    let (chr, rest) = backslash_u(s);
-                    s = rest;
-                    chr
-                }
-                b'n' => '\n',
-                b'r' => '\r',
-                b't' => '\t',
-                b'\\' => '\\',
-                b'0' => '\0',
-                b'\'' => '\'',
-                b'"' => '"',
-                b => panic!("unexpected byte {:?} after \\ character in byte literal", b),
+    fn parse_suffix(s: &str) -> Result<Box<str>, String> {
+        let mut chars = s.chars();
+        let valid = match chars.next() {
+            None => true,
+            Some(ch) => {
+                (ch.is_alphabetic() || ch == '_')
+                    && chars.all(|ch| ch.is_alphanumeric() || ch == '_')
             }
-        } else {
-            let ch = next_chr(s);
-            s = &s[ch.len_utf8()..];
-            ch
         };
-        assert_eq!(s, "\'", "Expected end of char literal");
-        ch
+        if valid { Ok(s.into()) } else { Err(format!("invalid literal suffix `{}`", s)) }
     }
 
-    fn backslash_x<S>(s: &S) -> (u8, &S)
-    where
-        S: ?Sized + Index<RangeFrom<usize>, Output = S> + AsRef<[u8]>,
-    {
-        let mut ch = 0;
-        let b0 = byte(s, 0);
-        let b1 = byte(s, 1);
-        ch += 0x10
-            * match b0 {
-                b'0'..=b'9' => b0 - b'0',
-                b'a'..=b'f' => 10 + (b0 - b'a'),
-                b'A'..=b'F' => 10 + (b0 - b'A'),
-                _ => panic!("unexpected non-hex character after \\x"),
-            };
-        ch += match b1 {
-            b'0'..=b'9' => b1 - b'0',
-            b'a'..=b'f' => 10 + (b1 - b'a'),
-            b'A'..=b'F' => 10 + (b1 - b'A'),
-            _ => panic!("unexpected non-hex character after \\x"),
+    /// Returns (decimal digits, base, suffix). The digits are `None` if the
+    /// value does not fit in a `u128`, which syn accepts as well.
+    pub(crate) fn parse_lit_int(s: &str) -> Result<(Option<String>, u32, Box<str>), String> {
+        let base = match (byte(s, 0), byte(s, 1)) {
+            (b'0', b'x') => 16,
+            (b'0', b'o') => 8,
+            (b'0', b'b') => 2,
+            _ => 10,
         };
-        (ch, &s[2..])
+        let mut rest = if base == 10 { s } else { &s[2..] };
+
+        let mut value = Some(0_u128);
+        let mut has_digit = false;
+        loop {
+            let b = byte(rest, 0);
+            if b == b'_' {
+                rest = &rest[1..];
+                continue;
+            }
+            let digit = match hex_digit(b) {
+                Some(digit) if u32::from(digit) < base => digit,
+                _ => break,
+            };
+            value = value
+                .and_then(|v| v.checked_mul(u128::from(base)))
+                .and_then(|v| v.checked_add(u128::from(digit)));
+            has_digit = true;
+            rest = &rest[1..];
+        }
+
+        // Digits that are out of range for the base, and floats, are not
+        // integers.
+        let is_float = base == 10
+            && match byte(rest, 0) {
+                b'.' => true,
+                b'e' | b'E' => {
+                    matches!(
+                        rest[1..].bytes().find(|&b| b != b'_'),
+                        Some(b'0'..=b'9' | b'+' | b'-')
+                    )
+                }
+                _ => false,
+            };
+        if !has_digit || is_float || byte(rest, 0).is_ascii_digit() {
+            return Err(format!("expected integer literal, found `{}`", s));
+        }
+        Ok((value.map(|v| v.to_string()), base, parse_suffix(rest)?))
     }
 
-    fn backslash_u(mut s: &str) -> (char, &str) {
-        assert_eq!(byte(s, 0), b'{', "{}", "expected { after \\u");
-        s = &s[1..];
+    /// Returns (digits, suffix). The digits do not contain underscores.
+    pub(crate) fn parse_lit_float(s: &str) -> Result<(String, Box<str>), String> {
+        let digits_end = |mut i: usize| {
+            while let b'0'..=b'9' | b'_' = byte(s, i) {
+                i += 1;
+            }
+            i
+        };
+        let err = || format!("expected float literal, found `{}`", s);
 
-        let mut ch = 0;
-        for _ in 0..6 {
-            let b = byte(s, 0);
-            match b {
-                b'0'..=b'9' => {
-                    ch *= 0x10;
-                    ch += (b - b'0') as u32;
-                    s = &s[1..];
-                }
-                b'a'..=b'f' => {
-                    ch *= 0x10;
-                    ch += (10 + b - b'a') as u32;
-                    s = &s[1..];
-                }
-                b'A'..=b'F' => {
-                    ch *= 0x10;
-                    ch += (10 + b - b'A') as u32;
-                    s = &s[1..];
-                }
-                b'}' => break,
-                _ => panic!("unexpected non-hex character after \\u"),
+        let mut end = digits_end(0);
+        if end == 0 || byte(s, 0) == b'_' {
+            return Err(err());
+        }
+        if byte(s, end) == b'.'
+            && !matches!(byte(s, end + 1), b'.' | b'_' | b'a'..=b'z' | b'A'..=b'Z')
+        {
+            end = digits_end(end + 1);
+        }
+        if let b'e' | b'E' = byte(s, end) {
+            let mut exp = end + 1;
+            if let b'+' | b'-' = byte(s, exp) {
+                exp += 1;
             }
+            let exp_end = digits_end(exp);
+            if !s[exp..exp_end].bytes().any(|b| b != b'_') {
+                return Err(err());
+            }
+            end = exp_end;
         }
-        assert!(byte(s, 0) == b'}');
-        s = &s[1..];
 
-        if let Some(ch) = char::from_u32(ch) {
-            (ch, s)
-        } else {
-            panic!("character code {:x} is not a valid unicode character", ch);
-        }
+        let (digits, suffix) = s.split_at(end);
+        Ok((digits.replace('_', ""), parse_suffix(suffix)?))
     }
 
-    pub(crate) fn to_literal(s: &str) -> Literal {
-        let stream = s.parse::<TokenStream>().unwrap();
-        match stream.into_iter().next().unwrap() {
-            TokenTree::Literal(l) => l.ref_into(),
-            _ => unreachable!(),
+    pub(crate) fn to_literal(s: &str) -> Result<Literal, String> {
+        let err = || format!("expected a literal, found `{}`", s);
+        let mut iter = s.parse::<TokenStream>().map_err(|_| err())?.into_iter();
+        match (iter.next(), iter.next()) {
+            (Some(TokenTree::Literal(l)), None) => Ok(l.ref_into()),
+            _ => Err(err()),
         }
     }
 }
@@ -386,62 +619,94 @@ mod value {
 mod convert {
     use super::*;
 
+    // The original tokens are kept, so raw strings and suffixes survive a
+    // round trip. Tokens are validated when adapters are deserialized, so
+    // converting them back to syn types cannot fail.
+
     // LitStr
     impl From<&syn::LitStr> for LitStr {
         fn from(other: &syn::LitStr) -> Self {
-            Self { token: Literal::string(&other.value()) }
+            Self { token: other.token().ref_into() }
         }
     }
     impl From<&LitStr> for syn::LitStr {
         fn from(other: &LitStr) -> Self {
-            let (value, _) = value::parse_lit_str(&other.token.text);
-            Self::new(&value, Span::call_site())
+            match syn::Lit::new((&other.token).into()) {
+                syn::Lit::Str(lit) => lit,
+                // `validate` checks the kind of the token.
+                _ => unreachable!(),
+            }
         }
     }
 
     // LitByteStr
     impl From<&syn::LitByteStr> for LitByteStr {
         fn from(other: &syn::LitByteStr) -> Self {
-            Self { token: Literal::byte_string(&other.value()) }
+            Self { token: other.token().ref_into() }
         }
     }
     impl From<&LitByteStr> for syn::LitByteStr {
         fn from(other: &LitByteStr) -> Self {
-            let value = value::parse_lit_byte_str(&other.token.text);
-            Self::new(&value, Span::call_site())
+            match syn::Lit::new((&other.token).into()) {
+                syn::Lit::ByteStr(lit) => lit,
+                // `validate` checks the kind of the token.
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    // LitCStr
+    impl From<&syn::LitCStr> for LitCStr {
+        fn from(other: &syn::LitCStr) -> Self {
+            Self { token: other.token().ref_into() }
+        }
+    }
+    impl From<&LitCStr> for syn::LitCStr {
+        fn from(other: &LitCStr) -> Self {
+            match syn::Lit::new((&other.token).into()) {
+                syn::Lit::CStr(lit) => lit,
+                // `validate` checks the kind of the token.
+                _ => unreachable!(),
+            }
         }
     }
 
     // LitByte
     impl From<&syn::LitByte> for LitByte {
         fn from(other: &syn::LitByte) -> Self {
-            Self { token: Literal::u8_suffixed(other.value()) }
+            Self { token: other.token().ref_into() }
         }
     }
     impl From<&LitByte> for syn::LitByte {
         fn from(other: &LitByte) -> Self {
-            let value = value::parse_lit_byte(&other.token.text);
-            Self::new(value, Span::call_site())
+            match syn::Lit::new((&other.token).into()) {
+                syn::Lit::Byte(lit) => lit,
+                // `validate` checks the kind of the token.
+                _ => unreachable!(),
+            }
         }
     }
 
     // LitChar
     impl From<&syn::LitChar> for LitChar {
         fn from(other: &syn::LitChar) -> Self {
-            Self { token: Literal::character(other.value()) }
+            Self { token: other.token().ref_into() }
         }
     }
     impl From<&LitChar> for syn::LitChar {
         fn from(other: &LitChar) -> Self {
-            let value = value::parse_lit_char(&other.token.text);
-            Self::new(value, Span::call_site())
+            match syn::Lit::new((&other.token).into()) {
+                syn::Lit::Char(lit) => lit,
+                // `validate` checks the kind of the token.
+                _ => unreachable!(),
+            }
         }
     }
 
     // LitInt
     impl From<&syn::LitInt> for LitInt {
         fn from(other: &syn::LitInt) -> Self {
-            Self { token: value::to_literal(&other.to_string()) }
+            Self { token: other.token().ref_into() }
         }
     }
     impl From<&LitInt> for syn::LitInt {
@@ -453,7 +718,7 @@ mod convert {
     // LitFloat
     impl From<&syn::LitFloat> for LitFloat {
         fn from(other: &syn::LitFloat) -> Self {
-            Self { token: value::to_literal(&other.to_string()) }
+            Self { token: other.token().ref_into() }
         }
     }
     impl From<&LitFloat> for syn::LitFloat {
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Decoded values of literals.
+//!
+//! Literals are serialized as their tokens, e.g., `"\"Hello\\n\""` or
+//! `"0x1F_u8"`, so consumers have to implement the escape rules of Rust to get
+//! their values. [`WithLitValues`] serializes each literal as an object that
+//! also contains its decoded value:
+//!
+//! | Literal     | Form                                                                  |
+//! | ----------- | --------------------------------------------------------------------- |
+//! | `"a\n"sfx`  | `{"token": "\"a\\n\"sfx", "value": "a\n", "suffix": "sfx"}`           |
+//! | `b"a\n"`    | `{"token": "b\"a\\n\"", "value": [97, 10]}`                           |
+//! | `c"a"`      | `{"token": "c\"a\"", "value": [97]}`                                  |
+//! | `b'a'`      | `{"token": "b'a'", "value": 97}`                                      |
+//! | `'a'`       | `{"token": "'a'", "value": "a"}`                                      |
+//! | `0x1F_u8`   | `{"token": "0x1F_u8", "value": "31", "base": 16, "suffix": "u8"}`     |
+//! | `1_000.5e3` | `{"token": "1_000.5e3", "value": "1000.5e3"}`                         |
+//!
+//! Integers are written as decimal strings, since they may not fit in the
+//! numbers of the output format. The `value` of an integer is omitted if it
+//! does not fit in a `u128`. The value of a C string does not include the
+//! trailing nul. `suffix` is omitted if it is empty.
+//!
+//! Both forms can be deserialized. If `token` is omitted, the literal is built
+//! from `value`, `base`, and `suffix`.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn dox() {
+//! use syn_serde::lit_values::WithLitValues;
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     const S: &str = "Hello\n";
+//! };
+//! let tree = WithLitValues::from_syn(&syn_file);
+//!
+//! let json = serde_json::to_value(&tree).unwrap();
+//! let lit = &json["items"][0]["const"]["expr"]["lit"]["str"];
+//! assert_eq!(lit["token"], "\"Hello\\n\"");
+//! assert_eq!(lit["value"], "Hello\n");
+//!
+//! let syn_file2: syn::File = syn_serde::json::from_str(&json.to_string()).unwrap();
+//! assert_eq!(syn_file, syn_file2);
+//! # }
+//! ```
+
+use crate::{
+    lit::value,
+    node::{Map, Value, Variant},
+};
+
+value_tree! {
+    /// A syntax tree that is serialized with the decoded values of literals.
+    ///
+    /// See the [module level documentation](self) for more.
+    pub struct WithLitValues;
+    new: "Decodes the literals of",
+    transform: |_, value| insert_values(value),
+}
+
+fn insert_values(value: &mut Value) {
+    match value {
+        Value::Variant(Variant { name, value: Some(inner) }) => {
+            let decoded = match &**inner {
+                Value::Str(token) => decode(name, token),
+                _ => None,
+            };
+            match decoded {
+                Some(fields) => replace_token(inner, fields),
+                None => insert_values(inner),
+            }
+        }
+        Value::Map(map) => {
+            for (key, value) in &mut map.fields {
+                // Flattened enums (e.g., `Lit` in `ExprLit`) are serialized as
+                // map entries.
+                let decoded = match value {
+                    Value::Str(token) if matches!(map.ty, None | Some("ExprLit")) => {
+                        decode(key, token)
+                    }
+                    _ => None,
+                };
+                match decoded {
+                    Some(fields) => replace_token(value, fields),
+                    None => insert_values(value),
+                }
+            }
+        }
+        Value::Seq(elems) => elems.iter_mut().for_each(insert_values),
+        _ => {}
+    }
+}
+
+fn replace_token(value: &mut Value, mut fields: Vec<(String, Value)>) {
+    let token = core::mem::replace(value, Value::Null);
+    fields.insert(0, ("token".to_owned(), token));
+    *value = Value::Map(Map { ty: None, fields });
+}
+
+/// Decodes the token of the `Lit` variant `kind`. Returns `None` if `kind` is
+/// not a variant of `Lit`, or if the token cannot be decoded.
+fn decode(kind: &str, token: &str) -> Option<Vec<(String, Value)>> {
+    let mut fields = vec![];
+    let suffix = match kind {
+        "str" => {
+            let (value, suffix) = value::parse_lit_str(token).ok()?;
+            fields.push(("value".to_owned(), Value::Str(value.into())));
+            suffix
+        }
+        "byte_str" => {
+            let (value, suffix) = value::parse_lit_byte_str(token).ok()?;
+            fields.push(("value".to_owned(), bytes(value)));
+            suffix
+        }
+        "c_str" => {
+            let (value, suffix) = value::parse_lit_c_str(token).ok()?;
+            fields.push(("value".to_owned(), bytes(value)));
+            suffix
+        }
+        "byte" => {
+            let (value, suffix) = value::parse_lit_byte(token).ok()?;
+            fields.push(("value".to_owned(), Value::U64(u64::from(value))));
+            suffix
+        }
+        "char" => {
+            let (value, suffix) = value::parse_lit_char(token).ok()?;
+            fields.push(("value".to_owned(), Value::Str(value.to_string())));
+            suffix
+        }
+        "int" => {
+            let (value, base, suffix) = value::parse_lit_int(token).ok()?;
+            if let Some(value) = value {
+                fields.push(("value".to_owned(), Value::Str(value)));
+            }
+            fields.push(("base".to_owned(), Value::U64(u64::from(base))));
+            suffix
+        }
+        "float" => {
+            let (value, suffix) = value::parse_lit_float(token).ok()?;
+            fields.push(("value".to_owned(), Value::Str(value)));
+            suffix
+        }
+        _ => return None,
+    };
+    if !suffix.is_empty() {
+        fields.push(("suffix".to_owned(), Value::Str(suffix.into())));
+    }
+    Some(fields)
+}
+
+fn bytes(value: Vec<u8>) -> Value {
+    Value::Seq(value.into_iter().map(|b| Value::U64(u64::from(b))).collect())
+}
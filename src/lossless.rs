@@ -0,0 +1,246 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Lossless serialization of punctuation and optional tokens.
+//!
+//! By default, syntax trees are serialized without the tokens that can be
+//! inferred from the rest of the tree, such as the separators of lists. Since
+//! these tokens are rebuilt from the tree, trailing commas, explicit empty
+//! `<>`, and the commas after match arms whose bodies are blocks are lost.
+//! [`Lossless`] serializes a syntax tree with these tokens:
+//!
+//! | Syntax                      | Form                                        |
+//! | --------------------------- | ------------------------------------------- |
+//! | Trailing punctuation        | `{"elems": [...], "trailing": true}`        |
+//! | `<>` of [`Generics`]        | `"lt_token": true` and `"gt_token": true`   |
+//! | `,` after an [`Arm`]        | `"comma": true` or `"comma": false`         |
+//! | `;` after an [`ItemStruct`] | `"semi_token": true`                        |
+//! | `;` after a [`TraitItemFn`] | `"semi_token": true`                        |
+//!
+//! The `comma` of an arm is only recorded if it differs from the default,
+//! which is a comma after arms whose bodies are not blocks. Lists without
+//! trailing punctuation are serialized as usual.
+//!
+//! Lossless trees can be deserialized as usual, and the recorded tokens are
+//! restored when converting them to syn types.
+//!
+//! The other encodings and analyses of this crate (e.g., [`arena`],
+//! [`rewrite`], and [`cfg`]) always keep these tokens when they convert
+//! syntax trees back, and only write them in their output in lossless mode.
+//!
+//! [`arena`]: crate::arena
+//! [`cfg`]: crate::cfg
+//! [`rewrite`]: crate::rewrite
+//!
+//! [`Arm`]: crate::Arm
+//! [`Generics`]: crate::Generics
+//! [`ItemStruct`]: crate::ItemStruct
+//! [`TraitItemFn`]: crate::TraitItemFn
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn dox() {
+//! use syn_serde::lossless::Lossless;
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     fn f<>(a: u8, b: u8,) {}
+//! };
+//! let tree = Lossless::from_syn(&syn_file);
+//!
+//! let json = serde_json::to_value(&tree).unwrap();
+//! let f = &json["items"][0]["fn"];
+//! assert_eq!(f["generics"]["lt_token"], true);
+//! assert_eq!(f["inputs"]["trailing"], true);
+//!
+//! let syn_file2: syn::File = syn_serde::json::from_str(&json.to_string()).unwrap();
+//! assert_eq!(syn_file, syn_file2);
+//! # }
+//! ```
+
+use core::{
+    fmt,
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use serde::{
+    de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeStruct as _, Serializer},
+};
+
+use crate::{
+    node,
+    options::{self, Options},
+};
+
+/// Skips the fields that are only serialized by [`Lossless`], if they are
+/// not enabled or have the default value.
+pub(crate) fn skip<T>(x: &T) -> bool
+where
+    T: Default + PartialEq,
+{
+    !options::current().lossless || *x == T::default()
+}
+
+/// Serializes a field that is only serialized by [`Lossless`].
+///
+/// This is the same as serializing the field itself, except that
+/// [`node::to_value`] records it so that it can be omitted from the output.
+pub(crate) fn serialize_field<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Serialize,
+    S: Serializer,
+{
+    serializer.serialize_newtype_struct(node::LOSSLESS_FIELD, value)
+}
+
+/// Serializes the given value in [`Lossless`] mode.
+pub(crate) fn serialize_enabled<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: ?Sized + Serialize,
+    S: Serializer,
+{
+    let _enable = options::Enable::add(Options::new().lossless(true));
+    value.serialize(serializer)
+}
+
+value_tree! {
+    /// A syntax tree that is serialized with punctuation and optional tokens.
+    ///
+    /// See the [module level documentation](self) for more.
+    pub struct Lossless;
+    new: "Records the punctuation and optional tokens of",
+    options: Options::new().lossless(true),
+}
+
+/// An adapter for [`struct@syn::punctuated::Punctuated`].
+///
+/// This is serialized as a sequence of the elements, or in [`Lossless`] mode,
+/// as `{"elems": [...], "trailing": true}` if it has trailing punctuation.
+pub(crate) struct Punctuated<T> {
+    elems: Vec<T>,
+    pub(crate) trailing: bool,
+}
+
+impl<T> Punctuated<T> {
+    pub(crate) fn is_empty(&self) -> bool {
+        self.elems.is_empty()
+    }
+}
+
+impl<T> Default for Punctuated<T> {
+    fn default() -> Self {
+        Self { elems: vec![], trailing: false }
+    }
+}
+
+impl<T> From<Vec<T>> for Punctuated<T> {
+    fn from(elems: Vec<T>) -> Self {
+        Self { elems, trailing: false }
+    }
+}
+
+impl<T> FromIterator<T> for Punctuated<T> {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        iter.into_iter().collect::<Vec<_>>().into()
+    }
+}
+
+impl<T> Deref for Punctuated<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.elems
+    }
+}
+
+impl<T> DerefMut for Punctuated<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.elems
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Punctuated<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.elems.iter()
+    }
+}
+
+impl<T> Serialize for Punctuated<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if self.trailing && options::current().lossless {
+            let mut state = serializer.serialize_struct("Punctuated", 2)?;
+            state.serialize_field("elems", &self.elems)?;
+            state.serialize_field("trailing", &self.trailing)?;
+            state.end()
+        } else {
+            self.elems.serialize(serializer)
+        }
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Punctuated<T>
+where
+    T: Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct PunctuatedVisitor<T>(PhantomData<T>);
+
+        impl<'de, T> Visitor<'de> for PunctuatedVisitor<T>
+        where
+            T: Deserialize<'de>,
+        {
+            type Value = Punctuated<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence, or a map with `elems` and `trailing`")
+            }
+
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mut elems = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+                while let Some(elem) = seq.next_element()? {
+                    elems.push(elem);
+                }
+                Ok(elems.into())
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut elems = None;
+                let mut trailing = false;
+                while let Some(key) = map.next_key::<String>()? {
+                    match &*key {
+                        "elems" => elems = Some(map.next_value()?),
+                        "trailing" => trailing = map.next_value()?,
+                        _ => return Err(de::Error::unknown_field(&key, &["elems", "trailing"])),
+                    }
+                }
+                let elems = elems.ok_or_else(|| de::Error::missing_field("elems"))?;
+                Ok(Punctuated { elems, trailing })
+            }
+        }
+
+        deserializer.deserialize_any(PunctuatedVisitor(PhantomData))
+    }
+}
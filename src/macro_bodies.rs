@@ -0,0 +1,406 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Structured bodies of macro invocations.
+//!
+//! The bodies of macro invocations are serialized as tokens, because they can
+//! contain arbitrary tokens. [`MacroBodies`] interprets the bodies of
+//! well-known macros, and of other macros whose bodies are lists of
+//! expressions, and serializes each of them as a `"body"` field next to the
+//! `"tokens"` of the macro:
+//!
+//! | Macro                              | Body                                                        |
+//! | ---------------------------------- | ----------------------------------------------------------- |
+//! | `format!("{} {x}", a, x = b)`      | `{"format": {"string": {...}, "args": [{"expr": {...}}, {"name": "x", "expr": {...}}]}}` |
+//! | `write!(f, "{}", a)`               | `{"format": {"target": {...}, "string": {...}, "args": [...]}}` |
+//! | `assert_eq!(a, b, "{}", c)`        | `{"assert": {"exprs": [{...}, {...}], "format": {...}}}`    |
+//! | `vec![a, b]`, `m!(a, b)`           | `{"exprs": [{...}, {...}]}`                                 |
+//! | `vec![a; n]`                       | `{"repeat": {"expr": {...}, "len": {...}}}`                 |
+//! | `matches!(x, Some(_) if c)`        | `{"matches": {"expr": {...}, "pat": {...}, "guard": {...}}}` |
+//!
+//! The expressions and patterns are serialized as usual, without spans; the
+//! spans are recorded by `"tokens"`. Macros are identified by the last
+//! segment of their paths, so `std::println!` is also interpreted as
+//! `println!`. Bodies that cannot be parsed have no `"body"`.
+//!
+//! `"tokens"` is kept unchanged, and `"body"` is ignored when the tree is
+//! deserialized, so the tree can be read back losslessly with the usual
+//! functions such as [`json::from_str`](crate::json::from_str).
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn dox() {
+//! use syn_serde::macro_bodies::{BodyKind, MacroBodies};
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     fn f(x: u8) {
+//!         println!("{} {}", x, my_vec!(1, 2));
+//!     }
+//! };
+//! let mut bodies = MacroBodies::new();
+//! bodies.insert("my_vec", BodyKind::Vec);
+//! let tree = bodies.interpret_syn(&syn_file);
+//!
+//! let json = serde_json::to_value(&tree).unwrap();
+//! let mac = &json["items"][0]["fn"]["stmts"]["stmts"][0]["macro"];
+//! let format = &mac["body"]["format"];
+//! assert_eq!(format["string"]["lit"]["str"], "\"{} {}\"");
+//! assert_eq!(format["args"][0]["expr"]["path"]["segments"][0]["ident"], "x");
+//! assert!(mac["tokens"].is_array());
+//!
+//! let syn_file2: syn::File = syn_serde::json::from_str(&json.to_string()).unwrap();
+//! assert_eq!(syn_file, syn_file2);
+//! # }
+//! ```
+
+use std::collections::BTreeMap;
+
+use serde::ser::Serialize;
+use serde_derive::Serialize;
+use syn::{
+    Token,
+    ext::IdentExt as _,
+    parse::{ParseStream, Parser as _},
+    punctuated::Punctuated,
+};
+
+use crate::{
+    Expr, Pat, Syn, TokenStream,
+    node::{self, Map, Value},
+    span,
+};
+
+/// How the body of a macro is interpreted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BodyKind {
+    /// A format string and its arguments, as in `format!("{}", a)`.
+    Format,
+    /// A destination, a format string, and its arguments, as in
+    /// `write!(f, "{}", a)`.
+    Write,
+    /// The given number of expressions, optionally followed by a format
+    /// string and its arguments, as in `assert_eq!(a, b, "{}", c)`.
+    Assert(usize),
+    /// Comma-separated expressions or `expr; len`, as in `vec![a, b]` and
+    /// `vec![a; n]`.
+    Vec,
+    /// An expression and a pattern with an optional guard, as in
+    /// `matches!(x, Some(_) if c)`.
+    Matches,
+    /// Comma-separated expressions, as in `m!(a, b)`.
+    Exprs,
+}
+
+/// A configuration of the macros whose bodies are interpreted.
+///
+/// See the [module level documentation](self) for more.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MacroBodies {
+    macros: BTreeMap<String, BodyKind>,
+    /// Whether the bodies of other macros are interpreted as expressions.
+    exprs: bool,
+}
+
+impl MacroBodies {
+    /// Creates a configuration that interprets the well-known macros of the
+    /// standard library, and the bodies of other macros that are lists of
+    /// expressions.
+    ///
+    /// The well-known macros are `format!`, `format_args!`, `print!`,
+    /// `println!`, `eprint!`, `eprintln!`, `panic!`, `todo!`,
+    /// `unimplemented!`, `unreachable!`, `write!`, `writeln!`, `assert!`,
+    /// `assert_eq!`, `assert_ne!` and their `debug_` variants, `vec!`, and
+    /// `matches!`.
+    #[must_use]
+    pub fn new() -> Self {
+        let mut bodies = Self::empty();
+        bodies.exprs = true;
+        for name in [
+            "format",
+            "format_args",
+            "print",
+            "println",
+            "eprint",
+            "eprintln",
+            "panic",
+            "todo",
+            "unimplemented",
+            "unreachable",
+        ] {
+            bodies.insert(name, BodyKind::Format);
+        }
+        bodies.insert("write", BodyKind::Write);
+        bodies.insert("writeln", BodyKind::Write);
+        for (name, exprs) in [("assert", 1), ("assert_eq", 2), ("assert_ne", 2)] {
+            bodies.insert(name, BodyKind::Assert(exprs));
+            bodies.insert(&format!("debug_{}", name), BodyKind::Assert(exprs));
+        }
+        bodies.insert("vec", BodyKind::Vec);
+        bodies.insert("matches", BodyKind::Matches);
+        bodies
+    }
+
+    /// Creates a configuration that does not interpret any macro.
+    #[must_use]
+    pub fn empty() -> Self {
+        Self { macros: BTreeMap::new(), exprs: false }
+    }
+
+    /// Interprets the bodies of the macros named `name` (without `!`) as
+    /// `kind`.
+    pub fn insert(&mut self, name: &str, kind: BodyKind) {
+        self.macros.insert(name.to_owned(), kind);
+    }
+
+    /// Stops interpreting the bodies of the macros named `name` (without
+    /// `!`), unless they are lists of expressions and
+    /// [`set_exprs`](Self::set_exprs) is enabled.
+    pub fn remove(&mut self, name: &str) {
+        self.macros.remove(name);
+    }
+
+    /// Sets whether the bodies of the other macros are interpreted as
+    /// comma-separated expressions if they can be parsed as such.
+    pub fn set_exprs(&mut self, enabled: bool) {
+        self.exprs = enabled;
+    }
+
+    /// Interprets the macro bodies of the given adapter.
+    pub fn interpret<T>(&self, adapter: &T) -> WithMacroBodies
+    where
+        T: ?Sized + Serialize,
+    {
+        let value = node::to_value(adapter);
+        WithMacroBodies { value, bodies: self.clone() }
+    }
+
+    /// Interprets the macro bodies of the given [`Syn`] type.
+    ///
+    /// This function is equivalent to the following code:
+    ///
+    /// ```
+    /// # fn interpret_syn(
+    /// #     bodies: &syn_serde::macro_bodies::MacroBodies,
+    /// #     syn_file: &syn::File,
+    /// # ) -> syn_serde::macro_bodies::WithMacroBodies {
+    /// use syn_serde::Syn;
+    ///
+    /// let adapter = syn_file.to_adapter();
+    /// bodies.interpret(&adapter)
+    /// # }
+    /// ```
+    pub fn interpret_syn<S>(&self, syn: &S) -> WithMacroBodies
+    where
+        S: Syn,
+    {
+        self.interpret(&syn.to_adapter())
+    }
+
+    fn insert_bodies(&self, value: &mut Value) {
+        let mut stack = vec![value];
+        while let Some(value) = stack.pop() {
+            match value {
+                Value::Map(map) => {
+                    // `Macro` is flattened into the nodes containing it, so
+                    // macros are recognized by their fields. Attribute
+                    // arguments have the same fields, but are `MetaList`s.
+                    if map.ty != Some("MetaList")
+                        && ["path", "delimiter", "tokens"].iter().all(|f| map.get(f).is_some())
+                    {
+                        if let Some(body) = self.body(map) {
+                            map.fields.push(("body".to_owned(), body));
+                        }
+                    }
+                    // Macros in bodies are also interpreted.
+                    stack.extend(map.fields.iter_mut().map(|(_, v)| v));
+                }
+                Value::Seq(elems) => stack.extend(elems),
+                Value::Variant(variant) => stack.extend(variant.value.as_deref_mut()),
+                _ => {}
+            }
+        }
+    }
+
+    /// Interprets the body of a serialized macro.
+    fn body(&self, mac: &Map) -> Option<Value> {
+        let name = match mac.get("path")?.fields()?.get("segments")? {
+            Value::Seq(segments) => segments.last()?.fields()?.get("ident")?.as_scalar()?,
+            _ => return None,
+        };
+        let tokens: TokenStream = node::from_value(mac.get("tokens")?.clone()).ok()?;
+        let tokens = proc_macro2::TokenStream::from_adapter(&tokens);
+        let kind = match self.macros.get(name.strip_prefix("r#").unwrap_or(&name)) {
+            Some(kind) => *kind,
+            None if self.exprs => BodyKind::Exprs,
+            None => return None,
+        };
+        let body = (|input: ParseStream<'_>| parse_body(input, kind)).parse2(tokens).ok()?;
+        let mut body = node::to_value(&body);
+        span::set_spans(&mut body, None);
+        Some(body)
+    }
+}
+
+impl Default for MacroBodies {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+value_tree! {
+    /// A syntax tree that is serialized with structured macro bodies.
+    ///
+    /// The bodies are interpreted with the configuration that the tree was
+    /// created by, [`MacroBodies::new`] for [`WithMacroBodies::new`].
+    ///
+    /// See the [module level documentation](self) for more.
+    pub struct WithMacroBodies {
+        bodies: MacroBodies = |_| MacroBodies::new(),
+    };
+    new: "Interprets the macro bodies of",
+    transform: |tree, value| tree.bodies.insert_bodies(value),
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Body {
+    Format(Format),
+    Assert {
+        exprs: Vec<Expr>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        format: Option<Format>,
+    },
+    Exprs(Vec<Expr>),
+    Repeat {
+        expr: Expr,
+        len: Expr,
+    },
+    Matches {
+        expr: Box<Expr>,
+        pat: Box<Pat>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        guard: Option<Box<Expr>>,
+    },
+}
+
+#[derive(Serialize)]
+struct Format {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<Expr>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    string: Option<Expr>,
+    args: Vec<FormatArg>,
+}
+
+#[derive(Serialize)]
+struct FormatArg {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    expr: Expr,
+}
+
+fn parse_body(input: ParseStream<'_>, kind: BodyKind) -> syn::Result<Body> {
+    match kind {
+        BodyKind::Format => Ok(Body::Format(parse_format(input, None)?)),
+        BodyKind::Write => {
+            let target = expr(&input.parse()?);
+            if input.is_empty() {
+                return Ok(Body::Format(Format {
+                    target: Some(target),
+                    string: None,
+                    args: vec![],
+                }));
+            }
+            input.parse::<Token![,]>()?;
+            Ok(Body::Format(parse_format(input, Some(target))?))
+        }
+        BodyKind::Assert(n) => {
+            let mut exprs = vec![];
+            for i in 0..n {
+                if i > 0 {
+                    input.parse::<Token![,]>()?;
+                }
+                exprs.push(expr(&input.parse()?));
+            }
+            let mut format = None;
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+                if !input.is_empty() {
+                    format = Some(parse_format(input, None)?);
+                }
+            }
+            Ok(Body::Assert { exprs, format })
+        }
+        BodyKind::Vec => {
+            if input.is_empty() {
+                return Ok(Body::Exprs(vec![]));
+            }
+            let first: syn::Expr = input.parse()?;
+            if input.peek(Token![;]) {
+                input.parse::<Token![;]>()?;
+                let len = input.parse()?;
+                return Ok(Body::Repeat { expr: expr(&first), len: expr(&len) });
+            }
+            let mut exprs = vec![expr(&first)];
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+                exprs.extend(parse_exprs(input)?);
+            }
+            Ok(Body::Exprs(exprs))
+        }
+        BodyKind::Matches => {
+            let scrutinee = Box::new(expr(&input.parse()?));
+            input.parse::<Token![,]>()?;
+            let pat = Box::new(syn::Pat::parse_multi_with_leading_vert(input)?.to_adapter());
+            let mut guard = None;
+            if input.peek(Token![if]) {
+                input.parse::<Token![if]>()?;
+                guard = Some(Box::new(expr(&input.parse()?)));
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+            Ok(Body::Matches { expr: scrutinee, pat, guard })
+        }
+        BodyKind::Exprs => Ok(Body::Exprs(parse_exprs(input)?)),
+    }
+}
+
+/// Parses a format string and its arguments: `"..", a, name = b`.
+fn parse_format(input: ParseStream<'_>, target: Option<Expr>) -> syn::Result<Format> {
+    if input.is_empty() {
+        return Ok(Format { target, string: None, args: vec![] });
+    }
+    let string = expr(&input.parse()?);
+    let mut args = vec![];
+    while !input.is_empty() {
+        input.parse::<Token![,]>()?;
+        if input.is_empty() {
+            break;
+        }
+        let named = input.peek(syn::Ident::peek_any)
+            && input.peek2(Token![=])
+            && !input.peek2(Token![==])
+            && !input.peek2(Token![=>]);
+        let name = if named {
+            let name = syn::Ident::parse_any(input)?;
+            input.parse::<Token![=]>()?;
+            Some(name.to_string())
+        } else {
+            None
+        };
+        args.push(FormatArg { name, expr: expr(&input.parse()?) });
+    }
+    Ok(Format { target, string: Some(string), args })
+}
+
+fn parse_exprs(input: ParseStream<'_>) -> syn::Result<Vec<Expr>> {
+    let exprs = Punctuated::<syn::Expr, Token![,]>::parse_terminated(input)?;
+    Ok(exprs.iter().map(expr).collect())
+}
+
+fn expr(expr: &syn::Expr) -> Expr {
+    expr.to_adapter()
+}
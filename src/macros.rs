@@ -14,13 +14,290 @@ macro_rules! ast_struct {
 macro_rules! ast_enum {
     (
         $(#[$attrs:meta])*
-        pub enum $name:ident $($rest:tt)*
+        pub enum $name:ident {
+            $(
+                $(#[$variant_attrs:meta])*
+                $variant:ident
+                $(($ty:ty))?
+                $({ $($(#[$field_attrs:meta])* $field:ident: $field_ty:ty,)* })?,
+            )*
+        }
     ) => (
-        #[derive(serde_derive::Serialize, serde_derive::Deserialize)]
+        #[derive(serde_derive::Serialize)]
         #[serde(rename_all = "snake_case")]
         $(#[$attrs])*
-        pub enum $name $($rest)*
+        pub enum $name {
+            $(
+                $(#[$variant_attrs])*
+                $variant
+                $(($ty))?
+                $({ $($(#[$field_attrs])* $field: $field_ty,)* })?,
+            )*
+        }
+
+        tagged_enum!($name, #[serde(rename_all = "snake_case")] {
+            $(
+                $(#[$variant_attrs])*
+                $variant
+                $(($ty))?
+                $({ $($(#[$field_attrs])* $field: $field_ty,)* })?,
+            )*
+        });
+    );
+}
+
+// Implements `Deserialize` for the adapter of an enum in the same way as the
+// code generator does: `Deserialize` is derived for a copy of the enum, which
+// reads the externally tagged form, and `tagged::deserialize` also reads the
+// tagged form with it. The copy has the same name, so that serde reports it.
+macro_rules! tagged_enum {
+    (@pat $name:ident, $variant:ident, $v:ident) => (external::$name::$variant);
+    (@pat $name:ident, $variant:ident, $v:ident, { $($field:ident)* }) => (
+        external::$name::$variant { $($field),* }
+    );
+    (@pat $name:ident, $variant:ident, $v:ident, $ty:ty) => (external::$name::$variant($v));
+    (@expr $name:ident, $variant:ident, $v:ident) => ($name::$variant);
+    (@expr $name:ident, $variant:ident, $v:ident, { $($field:ident)* }) => (
+        $name::$variant { $($field),* }
     );
+    (@expr $name:ident, $variant:ident, $v:ident, $ty:ty) => ($name::$variant($v));
+    (
+        $name:ident,
+        $(#[$attrs:meta])*
+        {
+            $(
+                $(#[$variant_attrs:meta])*
+                $variant:ident
+                $(($ty:ty))?
+                $({ $($(#[$field_attrs:meta])* $field:ident: $field_ty:ty,)* })?,
+            )*
+        }
+    ) => {
+        const _: () = {
+            mod external {
+                #[allow(unused_imports)] // Unused by the enums without fields.
+                use super::*;
+
+                #[derive(serde_derive::Deserialize)]
+                $(#[$attrs])*
+                pub(super) enum $name {
+                    $(
+                        $(#[$variant_attrs])*
+                        $variant
+                        $(($ty))?
+                        $({ $($(#[$field_attrs])* $field: $field_ty,)* })?,
+                    )*
+                }
+            }
+
+            impl From<external::$name> for $name {
+                fn from(external: external::$name) -> Self {
+                    match external {
+                        $(
+                            tagged_enum!(@pat $name, $variant, v $(, $ty)? $(, { $($field)* })?) => {
+                                tagged_enum!(@expr $name, $variant, v $(, $ty)? $(, { $($field)* })?)
+                            }
+                        )*
+                    }
+                }
+            }
+
+            impl<'de> serde::Deserialize<'de> for $name {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: serde::Deserializer<'de>,
+                {
+                    crate::tagged::deserialize::<external::$name, D>(deserializer).map(Self::from)
+                }
+            }
+        };
+    };
+}
+
+// Defines a syntax tree that is transformed when it is serialized, along with
+// its conversions from and to adapters.
+//
+// `options` are the modes that the tree is recorded, serialized, and
+// converted back in, `transform` rewrites a copy of the recorded value when
+// it is serialized, and the additional fields are initialized from the
+// recorded value. A tree declared as `pub struct Name(options);` takes its
+// modes as an argument instead.
+macro_rules! value_tree {
+    (
+        $(#[$attrs:meta])*
+        pub struct $name:ident $({ $($field:ident: $ty:ty = $init:expr,)* })?;
+        new: $new:literal,
+        $(options: $options:expr,)?
+        $(transform: $transform:expr,)?
+    ) => {
+        $(#[$attrs])*
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            value: crate::node::Value,
+            $($($field: $ty,)*)?
+        }
+
+        impl $name {
+            #[doc = concat!(" ", $new, " the given adapter.")]
+            pub fn new<T>(adapter: &T) -> Self
+            where
+                T: ?Sized + serde::Serialize,
+            {
+                $(let _enable = crate::options::Enable::add($options);)?
+                let value = crate::node::to_value(adapter);
+                Self { $($($field: ($init)(&value),)*)? value }
+            }
+
+            #[doc = concat!(" ", $new, " the given [`Syn`](crate::Syn) type.")]
+            ///
+            /// This function is equivalent to the following code:
+            ///
+            /// ```
+            #[doc = concat!(
+                " # fn from_syn(syn_file: &syn::File) -> ",
+                module_path!(), "::", stringify!($name), " {"
+            )]
+            #[doc = concat!(" use ", module_path!(), "::", stringify!($name), ";")]
+            /// use syn_serde::Syn;
+            ///
+            /// let adapter = syn_file.to_adapter();
+            #[doc = concat!(" ", stringify!($name), "::new(&adapter)")]
+            /// # }
+            /// ```
+            pub fn from_syn<S>(syn: &S) -> Self
+            where
+                S: crate::Syn,
+            {
+                Self::new(&syn.to_adapter())
+            }
+        }
+
+        value_tree! {
+            @convert $name,
+            options: |_: &$name| {
+                crate::options::Options::new() $(.union($options))?
+            },
+            $(transform: $transform,)?
+        }
+    };
+    (
+        $(#[$attrs:meta])*
+        pub struct $name:ident(options);
+        new: $new:literal,
+    ) => {
+        $(#[$attrs])*
+        #[derive(Debug, Clone)]
+        pub struct $name {
+            value: crate::node::Value,
+            options: crate::options::Options,
+        }
+
+        impl $name {
+            #[doc = concat!(" ", $new, " the given adapter in the given modes.")]
+            pub fn new<T>(adapter: &T, options: crate::options::Options) -> Self
+            where
+                T: ?Sized + serde::Serialize,
+            {
+                let _enable = crate::options::Enable::add(options);
+                Self { value: crate::node::to_value(adapter), options }
+            }
+
+            #[doc = concat!(" ", $new, " the given [`Syn`](crate::Syn) type in the given modes.")]
+            ///
+            /// This function is equivalent to the following code:
+            ///
+            /// ```
+            /// # use syn_serde::options::Options;
+            #[doc = concat!(
+                " # fn from_syn(syn_file: &syn::File, options: Options) -> ",
+                module_path!(), "::", stringify!($name), " {"
+            )]
+            #[doc = concat!(" use ", module_path!(), "::", stringify!($name), ";")]
+            /// use syn_serde::Syn;
+            ///
+            /// let adapter = syn_file.to_adapter();
+            #[doc = concat!(" ", stringify!($name), "::new(&adapter, options)")]
+            /// # }
+            /// ```
+            pub fn from_syn<S>(syn: &S, options: crate::options::Options) -> Self
+            where
+                S: crate::Syn,
+            {
+                Self::new(&syn.to_adapter(), options)
+            }
+        }
+
+        value_tree! {
+            @convert $name,
+            options: |tree: &$name| tree.options,
+        }
+    };
+    (
+        @convert $name:ident,
+        options: $options:expr,
+        $(transform: $transform:expr,)?
+    ) => {
+        impl $name {
+            /// Converts this tree to an adapter.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if this tree does not encode a `T`.
+            pub fn to_adapter<T>(&self) -> Result<T, crate::Error>
+            where
+                T: serde::de::DeserializeOwned,
+            {
+                let options: fn(&Self) -> crate::options::Options = $options;
+                let _enable = crate::options::Enable::add(options(self));
+                crate::node::from_value(self.value.clone())
+                    .map_err(|e| crate::Error::new(e.to_string()))
+            }
+
+            /// Converts this tree to a [`Syn`](crate::Syn) type.
+            ///
+            /// This function is equivalent to the following code:
+            ///
+            /// ```
+            #[doc = concat!(
+                " # fn to_syn(tree: &", module_path!(), "::", stringify!($name),
+                ") -> Result<syn::File, syn_serde::Error> {"
+            )]
+            /// use syn_serde::Syn;
+            ///
+            /// let adapter = tree.to_adapter()?;
+            /// Ok(syn::File::from_adapter(&adapter))
+            /// # }
+            /// ```
+            ///
+            /// # Errors
+            ///
+            /// See [`to_adapter`](Self::to_adapter).
+            pub fn to_syn<S>(&self) -> Result<S, crate::Error>
+            where
+                S: crate::Syn,
+            {
+                let adapter = self.to_adapter()?;
+                Ok(S::from_adapter(&adapter))
+            }
+        }
+
+        impl serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: serde::Serializer,
+            {
+                let options: fn(&Self) -> crate::options::Options = $options;
+                let _enable = crate::options::Enable::add(options(self));
+                let value = std::borrow::Cow::Borrowed(&self.value);
+                $(
+                    let transform: fn(&Self, &mut crate::node::Value) = $transform;
+                    let mut value = value;
+                    transform(self, value.to_mut());
+                )?
+                value.serialize(serializer)
+            }
+        }
+    };
 }
 
 macro_rules! syn_trait_impl {
@@ -34,6 +311,9 @@ macro_rules! syn_trait_impl {
             fn from_adapter(adapter: &Self::Adapter) -> Self {
                 Self::from(adapter)
             }
+            fn to_adapter_in_file(&self, file: usize) -> Self::Adapter {
+                crate::span::set_file(file, self.to_adapter())
+            }
         }
     };
 }
@@ -0,0 +1,536 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Attribute arguments as nested meta items.
+//!
+//! The arguments of list attributes such as `#[derive(Debug, Clone)]` are
+//! serialized as the tokens between the delimiters, because they can contain
+//! arbitrary tokens. [`WithNestedMeta`] parses these tokens as a
+//! comma-separated list of meta items, which are serialized in the same forms
+//! as the `meta` of attributes, in a `"nested"` field that replaces
+//! `"tokens"`:
+//!
+//! | Meta item       | Form                                                               |
+//! | --------------- | ------------------------------------------------------------------ |
+//! | `path::to::x`   | `{"path": {"segments": [...]}}`                                    |
+//! | `name = "lit"`  | `{"name_value": {"path": {...}, "value": {"lit": {"str": "..."}}}}` |
+//! | `name(...)`     | `{"list": {"path": {...}, "delimiter": "paren", "nested": [...]}}` |
+//!
+//! Lists are parsed recursively. Lists that are not in these forms (e.g.,
+//! `alias("a")` in `#[doc(alias("a"))]`, or `#[derive(Debug,)]` with a
+//! trailing comma) are kept as tokens, so the conversion back to tokens is
+//! lossless.
+//!
+//! The spans of the tokens are kept, so they are restored when the meta items
+//! are turned back into tokens. The spans of identifiers are the spans of the
+//! path segments, and the spans of the other tokens are in these fields:
+//!
+//! | Field                                                 | Tokens                                              |
+//! | ----------------------------------------------------- | --------------------------------------------------- |
+//! | `colon_spans` of paths                                | the `:`s of the leading colon and of the separators |
+//! | `eq_span` and `value_span` of `name_value`            | `=` and the literal                                 |
+//! | `group_span`, `span_open`, and `span_close` of `list` | the delimiters                                      |
+//! | `comma_span` of meta items                            | the `,` after the item                              |
+//!
+//! [`WithNestedMeta`] can be deserialized from trees with or without
+//! `"nested"` fields, which are turned back into tokens, and converted to
+//! adapters and syn types with [`to_adapter`](WithNestedMeta::to_adapter)
+//! and [`to_syn`](WithNestedMeta::to_syn).
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn dox() {
+//! use syn_serde::nested_meta::WithNestedMeta;
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     #[serde(rename = "x", default)]
+//!     struct S;
+//! };
+//! let tree = WithNestedMeta::from_syn(&syn_file);
+//!
+//! let json = serde_json::to_value(&tree).unwrap();
+//! let nested = &json["items"][0]["struct"]["attrs"][0]["meta"]["list"]["nested"];
+//! assert_eq!(nested[0]["name_value"]["path"]["segments"][0]["ident"], "rename");
+//! assert_eq!(nested[1]["path"]["segments"][0]["ident"], "default");
+//!
+//! let tree: WithNestedMeta = serde_json::from_value(json).unwrap();
+//! let syn_file2: syn::File = tree.to_syn().unwrap();
+//! assert_eq!(syn_file, syn_file2);
+//! # }
+//! ```
+
+use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenStream, TokenTree};
+use quote::ToTokens as _;
+use serde::de::{self, Deserialize, Deserializer};
+
+use crate::{
+    Error, Expr, Path, Syn as _, default,
+    node::{self, Map, Value, Variant},
+    span,
+};
+
+value_tree! {
+    /// A syntax tree that is serialized with attribute arguments as nested meta
+    /// items.
+    ///
+    /// See the [module level documentation](self) for more.
+    pub struct WithNestedMeta;
+    new: "Parses the attribute arguments of",
+    transform: |_, value| parse_attrs(value),
+}
+
+impl<'de> Deserialize<'de> for WithNestedMeta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut value = Value::deserialize(deserializer)?;
+        restore_tokens(&mut value).map_err(de::Error::custom)?;
+        Ok(Self { value })
+    }
+}
+
+/// Replaces the tokens of the list attributes in `value` with nested meta
+/// items.
+fn parse_attrs(value: &mut Value) {
+    let mut stack = vec![value];
+    while let Some(value) = stack.pop() {
+        match value {
+            Value::Map(map) => {
+                if map.ty == Some("Attribute") {
+                    let list =
+                        map.fields.iter_mut().find(|(k, _)| k == "meta").and_then(|(_, meta)| {
+                            match meta {
+                                Value::Variant(Variant { name: "list", value: Some(list) }) => {
+                                    list.fields_mut()
+                                }
+                                _ => None,
+                            }
+                        });
+                    if let Some(list) = list {
+                        parse_list(list);
+                    }
+                }
+                stack.extend(map.fields.iter_mut().map(|(_, v)| v));
+            }
+            Value::Seq(elems) => stack.extend(elems),
+            Value::Variant(variant) => stack.extend(variant.value.as_deref_mut()),
+            _ => {}
+        }
+    }
+}
+
+/// Replaces the `"tokens"` of a meta list with `"nested"`, if the tokens can
+/// be parsed as meta items that print the same tokens.
+fn parse_list(list: &mut Map) {
+    let i = match list.fields.iter().position(|(k, _)| k == "tokens") {
+        Some(i) => i,
+        None => return,
+    };
+    let tokens = match &list.fields[i].1 {
+        Value::Seq(tokens) => tokens,
+        _ => return,
+    };
+    let nested = match parse_nested(tokens) {
+        Some(nested) => nested,
+        None => return,
+    };
+    let mut printed = match nested_tokens(&nested) {
+        Ok(printed) => Value::Seq(printed),
+        Err(_) => return,
+    };
+    let mut original = list.fields[i].1.clone();
+    strip_spans(&mut printed);
+    strip_spans(&mut original);
+    if printed == original {
+        list.fields[i] = ("nested".to_owned(), Value::Seq(nested));
+    }
+}
+
+/// Parses `tokens` as comma-separated meta items.
+fn parse_nested(tokens: &[Value]) -> Option<Vec<Value>> {
+    let mut nested = vec![];
+    let mut i = 0;
+    while i < tokens.len() {
+        if !nested.is_empty() {
+            if punct(&tokens[i]) != Some(',') {
+                return None;
+            }
+            // The span of a comma is kept in the item before it.
+            if let Some(prev) = nested.last_mut().and_then(Value::fields_mut) {
+                set_span(prev, "comma_span", token_span(&tokens[i]));
+            }
+            i += 1;
+            // A trailing comma cannot be represented.
+            if i == tokens.len() {
+                return None;
+            }
+        }
+        nested.push(parse_meta(tokens, &mut i)?);
+    }
+    Some(nested)
+}
+
+/// Parses a meta item at `tokens[*i]`: `path`, `path = lit`, or `path(..)`.
+fn parse_meta(tokens: &[Value], i: &mut usize) -> Option<Value> {
+    let (path, path_spans) = parse_path(tokens, i)?;
+    let mut stream = None;
+    // The spans of the tokens after the path, and the fields they are kept in.
+    let mut spans = vec![];
+    let meta = match tokens.get(*i) {
+        Some(token) if punct(token) == Some('=') => {
+            let lit = tokens.get(*i + 1).and_then(|token| match token {
+                Value::Variant(Variant { name: "lit", value: Some(lit) }) => text(lit, "lit"),
+                Value::Variant(Variant { name: "ident", value: Some(ident) }) => {
+                    text(ident, "ident").filter(|ident| *ident == "true" || *ident == "false")
+                }
+                _ => None,
+            })?;
+            spans.push(("eq_span", token_span(&tokens[*i])));
+            spans.push(("value_span", token_span(&tokens[*i + 1])));
+            *i += 2;
+            let lit: syn::Lit = syn::parse_str(lit).ok()?;
+            let value = syn::Expr::Lit(syn::ExprLit { attrs: vec![], lit });
+            syn::Meta::NameValue(syn::MetaNameValue { path, eq_token: default(), value })
+        }
+        Some(Value::Variant(Variant { name: "group", value: Some(group) })) => {
+            let delimiter = match group.fields()?.get("delimiter")?.as_scalar()?.as_str() {
+                "parenthesis" => syn::MacroDelimiter::Paren(default()),
+                "brace" => syn::MacroDelimiter::Brace(default()),
+                "bracket" => syn::MacroDelimiter::Bracket(default()),
+                _ => return None,
+            };
+            stream = Some(group.fields()?.get("stream")?.clone());
+            for (key, field) in
+                [("group_span", "span"), ("span_open", "span_open"), ("span_close", "span_close")]
+            {
+                spans.push((key, group.fields()?.get(field).cloned()));
+            }
+            *i += 1;
+            syn::Meta::List(syn::MetaList { path, delimiter, tokens: TokenStream::new() })
+        }
+        _ => syn::Meta::Path(path),
+    };
+
+    let is_path = matches!(meta, syn::Meta::Path(_));
+    let mut meta = node::to_value(&meta.to_adapter());
+    if is_path {
+        set_path_spans(&mut meta, path_spans);
+    } else {
+        let map = meta.fields_mut()?;
+        let path = map.fields.iter_mut().find(|(k, _)| k == "path")?;
+        set_path_spans(&mut path.1, path_spans);
+        for (key, span) in spans {
+            // The spans of literals that record them (`true` and `false`).
+            if key == "value_span" {
+                if let Some((_, value)) = map.fields.iter_mut().find(|(k, _)| k == "value") {
+                    span::set_spans(value, span.as_ref());
+                }
+            }
+            set_span(map, key, span);
+        }
+    }
+    if let Some(stream) = stream {
+        let list = meta.fields_mut()?;
+        let tokens = list.fields.iter_mut().find(|(k, _)| k == "tokens")?;
+        tokens.1 = stream;
+        parse_list(list);
+    }
+    Some(meta)
+}
+
+/// The spans of a path.
+struct PathSpans {
+    /// The span of the whole path.
+    span: Option<Value>,
+    /// The spans of the identifiers of the segments.
+    segments: Vec<Option<Value>>,
+    /// The spans of the `:` tokens of the leading colon and the separators.
+    colons: Vec<Option<Value>>,
+}
+
+/// Parses a path without generic arguments at `tokens[*i]`.
+fn parse_path(tokens: &[Value], i: &mut usize) -> Option<(syn::Path, PathSpans)> {
+    let start = *i;
+    let colons = |i: usize| {
+        tokens.get(i).map_or(false, |t| punct(t) == Some(':') && spacing(t) == Some("joint"))
+            && tokens.get(i + 1).map_or(false, |t| punct(t) == Some(':'))
+    };
+    let mut colon_spans = vec![];
+    let leading_colon = colons(*i);
+    if leading_colon {
+        colon_spans.extend([token_span(&tokens[*i]), token_span(&tokens[*i + 1])]);
+        *i += 2;
+    }
+    let mut path = syn::Path { leading_colon: None, segments: default() };
+    if leading_colon {
+        path.leading_colon = Some(default());
+    }
+    let mut spans = vec![];
+    loop {
+        let ident = match tokens.get(*i)? {
+            Value::Variant(Variant { name: "ident", value: Some(ident) }) => ident,
+            _ => return None,
+        };
+        let text = text(ident, "ident")?;
+        let ident = match text.strip_prefix("r#") {
+            Some(raw) => proc_macro2::Ident::new_raw(raw, proc_macro2::Span::call_site()),
+            None => proc_macro2::Ident::new(text, proc_macro2::Span::call_site()),
+        };
+        path.segments.push(ident.into());
+        spans.push(token_span(&tokens[*i]));
+        *i += 1;
+        if !colons(*i) {
+            break;
+        }
+        colon_spans.extend([token_span(&tokens[*i]), token_span(&tokens[*i + 1])]);
+        *i += 2;
+    }
+    let first = tokens[start].fields().and_then(|token| token.get("span"));
+    let last = spans.last().and_then(Option::as_ref);
+    let span = join_spans(first, last);
+    Some((path, PathSpans { span, segments: spans, colons: colon_spans }))
+}
+
+/// Returns a span from the start of `first` to the end of `last`.
+fn join_spans(first: Option<&Value>, last: Option<&Value>) -> Option<Value> {
+    let (mut first, last) = (first?.as_map()?.clone(), last?.as_map()?);
+    for (k, v) in &mut first.fields {
+        if k.starts_with("end_") {
+            *v = last.get(k)?.clone();
+        }
+    }
+    Some(Value::Map(first))
+}
+
+/// Sets the spans of a serialized path.
+fn set_path_spans(path: &mut Value, spans: PathSpans) {
+    let map = match path.fields_mut() {
+        Some(map) => map,
+        None => return,
+    };
+    set_span(map, "span", spans.span);
+    if let Some((_, Value::Seq(elems))) = map.fields.iter_mut().find(|(k, _)| k == "segments") {
+        for (segment, span) in elems.iter_mut().zip(spans.segments) {
+            if let Some(segment) = segment.fields_mut() {
+                set_span(segment, "span", span);
+            }
+        }
+    }
+    if !spans.colons.is_empty() {
+        let colons = spans.colons.into_iter().collect::<Option<Vec<_>>>().map(Value::Seq);
+        set_span(map, "colon_spans", colons);
+    }
+}
+
+/// Sets the `key` field of `map` to `span`, or removes it if `span` is `None`.
+fn set_span(map: &mut Map, key: &str, span: Option<Value>) {
+    map.fields.retain(|(k, _)| k != key);
+    map.fields.extend(span.map(|span| (key.to_owned(), span)));
+}
+
+fn token_span(token: &Value) -> Option<Value> {
+    token.fields()?.get("span").cloned()
+}
+
+/// Replaces the `"nested"` fields of the meta lists in `value` with tokens.
+fn restore_tokens(value: &mut Value) -> Result<(), Error> {
+    let mut stack = vec![value];
+    while let Some(value) = stack.pop() {
+        match value {
+            Value::Map(map) => {
+                let i = map.fields.iter().position(|(k, _)| k == "nested");
+                if let Some(i) = i {
+                    if map.get("path").is_some() && map.get("delimiter").is_some() {
+                        let tokens = match &map.fields[i].1 {
+                            Value::Seq(nested) => nested_tokens(nested)?,
+                            _ => return Err(Error::new("`nested` must be a list of meta items")),
+                        };
+                        map.fields[i] = ("tokens".to_owned(), Value::Seq(tokens));
+                    }
+                }
+                stack.extend(map.fields.iter_mut().map(|(_, v)| v));
+            }
+            Value::Seq(elems) => stack.extend(elems),
+            Value::Variant(variant) => stack.extend(variant.value.as_deref_mut()),
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+/// Returns the tokens of comma-separated meta items.
+fn nested_tokens(nested: &[Value]) -> Result<Vec<Value>, Error> {
+    let mut tokens = vec![];
+    for (i, meta) in nested.iter().enumerate() {
+        if i > 0 {
+            let (_, prev) = meta_item(&nested[i - 1])?;
+            let mut comma = token(&Punct::new(',', Spacing::Alone).into());
+            set_token_span(&mut comma, "span", prev.fields().and_then(|m| m.get("comma_span")));
+            tokens.push(comma);
+        }
+        tokens.extend(meta_tokens(meta)?);
+    }
+    Ok(tokens)
+}
+
+/// Returns the kind and the content of a meta item.
+fn meta_item(meta: &Value) -> Result<(&str, &Value), Error> {
+    match meta {
+        Value::Variant(Variant { name, value: Some(value) }) => Ok((*name, &**value)),
+        // `{"name": value}`, as deserialized by `impl Deserialize for Value`.
+        Value::Map(map) if map.fields.len() == 1 => Ok((&*map.fields[0].0, &map.fields[0].1)),
+        _ => Err(Error::new("invalid meta item: expected `path`, `list`, or `name_value`")),
+    }
+}
+
+/// Returns the tokens of a meta item.
+fn meta_tokens(meta: &Value) -> Result<Vec<Value>, Error> {
+    let (kind, value) = meta_item(meta)?;
+    match kind {
+        "path" => return path_tokens(value),
+        "list" | "name_value" => {}
+        _ => {
+            return Err(Error::new(format!(
+                "invalid meta item `{}`: expected `path`, `list`, or `name_value`",
+                kind
+            )));
+        }
+    }
+    let map = value.as_map().ok_or_else(|| Error::new(format!("invalid `{}` meta item", kind)))?;
+    let field = |name| {
+        map.get(name).ok_or_else(|| Error::new(format!("missing field `{}` in `{}`", name, kind)))
+    };
+    let mut tokens = path_tokens(field("path")?)?;
+    if kind == "name_value" {
+        let expr: Expr = node::from_value(field("value")?.clone())
+            .map_err(|e| Error::new(format!("invalid value of `name_value`: {}", e)))?;
+        let mut eq = token(&Punct::new('=', Spacing::Alone).into());
+        set_token_span(&mut eq, "span", map.get("eq_span"));
+        tokens.push(eq);
+        tokens.extend(syn::Expr::from_adapter(&expr).into_token_stream().into_iter().map(|tt| {
+            let mut value = token(&tt);
+            set_token_span(&mut value, "span", map.get("value_span"));
+            value
+        }));
+    } else {
+        let delimiter = match field("delimiter")?.as_scalar().as_deref() {
+            Some("paren") => Delimiter::Parenthesis,
+            Some("brace") => Delimiter::Brace,
+            Some("bracket") => Delimiter::Bracket,
+            _ => return Err(Error::new("invalid delimiter of `list`")),
+        };
+        let stream = match (map.get("nested"), map.get("tokens")) {
+            (Some(Value::Seq(nested)), _) => Value::Seq(nested_tokens(nested)?),
+            (None, Some(tokens)) => tokens.clone(),
+            _ => return Err(Error::new("missing field `nested` in `list`")),
+        };
+        let mut group = token(&Group::new(delimiter, TokenStream::new()).into());
+        if let Some((_, v)) = group
+            .fields_mut()
+            .and_then(|group| group.fields.iter_mut().find(|(k, _)| k == "stream"))
+        {
+            *v = stream;
+        }
+        for (key, field) in
+            [("span", "group_span"), ("span_open", "span_open"), ("span_close", "span_close")]
+        {
+            set_token_span(&mut group, key, map.get(field));
+        }
+        tokens.push(group);
+    }
+    Ok(tokens)
+}
+
+/// Returns the tokens of a path, with the spans of its segments.
+fn path_tokens(path: &Value) -> Result<Vec<Value>, Error> {
+    let adapter: Path = node::from_value(path.clone())
+        .map_err(|e| Error::new(format!("invalid path of meta item: {}", e)))?;
+    let mut tokens: Vec<_> = syn::Path::from_adapter(&adapter)
+        .into_token_stream()
+        .into_iter()
+        .map(|tt| token(&tt))
+        .collect();
+    let segments = match path.fields().and_then(|path| path.get("segments")) {
+        Some(Value::Seq(segments)) => &**segments,
+        _ => &[],
+    };
+    let mut idents: Vec<_> = tokens
+        .iter_mut()
+        .filter(|token| matches!(token, Value::Variant(Variant { name: "ident", .. })))
+        .collect();
+    if idents.len() == segments.len() {
+        for (ident, segment) in idents.iter_mut().zip(segments) {
+            set_token_span(ident, "span", segment.fields().and_then(|s| s.get("span")));
+        }
+    }
+    let colons = match path.fields().and_then(|path| path.get("colon_spans")) {
+        Some(Value::Seq(colons)) => &**colons,
+        _ => &[],
+    };
+    let mut puncts: Vec<_> = tokens
+        .iter_mut()
+        .filter(|token| matches!(token, Value::Variant(Variant { name: "punct", .. })))
+        .collect();
+    if puncts.len() == colons.len() {
+        for (punct, span) in puncts.iter_mut().zip(colons) {
+            set_token_span(punct, "span", Some(span));
+        }
+    }
+    Ok(tokens)
+}
+
+/// Sets the `key` span of a serialized token.
+fn set_token_span(token: &mut Value, key: &str, span: Option<&Value>) {
+    if let Some(token) = token.fields_mut() {
+        set_span(token, key, span.cloned());
+    }
+}
+
+/// Serializes a token without spans.
+fn token(token: &TokenTree) -> Value {
+    let mut value = node::to_value(&token.to_adapter());
+    strip_spans(&mut value);
+    value
+}
+
+/// Removes the spans of tokens, including the spans of the delimiters of
+/// groups.
+fn strip_spans(value: &mut Value) {
+    let mut stack = vec![value];
+    while let Some(value) = stack.pop() {
+        match value {
+            Value::Map(map) => {
+                map.fields.retain(|(k, _)| !matches!(&**k, "span" | "span_open" | "span_close"));
+                stack.extend(map.fields.iter_mut().map(|(_, v)| v));
+            }
+            Value::Seq(elems) => stack.extend(elems),
+            Value::Variant(variant) => stack.extend(variant.value.as_deref_mut()),
+            _ => {}
+        }
+    }
+}
+
+fn punct(token: &Value) -> Option<char> {
+    match token {
+        Value::Variant(Variant { name: "punct", value: Some(punct) }) => {
+            text(punct, "op")?.chars().next()
+        }
+        _ => None,
+    }
+}
+
+fn spacing(token: &Value) -> Option<&'static str> {
+    match token.fields()?.get("spacing")? {
+        Value::Variant(Variant { name, value: None }) => Some(name),
+        _ => None,
+    }
+}
+
+fn text<'a>(value: &'a Value, field: &str) -> Option<&'a str> {
+    match value.fields()?.get(field)? {
+        Value::Str(text) => Some(text),
+        _ => None,
+    }
+}
@@ -0,0 +1,1205 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A generic, schema-aware view of adapter trees.
+//!
+//! Adapters are converted into a [`Value`] through their `Serialize`
+//! implementations, and back through their `Deserialize` implementations.
+//! The resulting tree has the same shape as the JSON representation, but it
+//! also remembers the struct names and enum variant names reported by serde,
+//! which JSON loses. This allows analyses to find the kind of a node (`"fn"`,
+//! `"method_call"`, `"block"`, ...) without maintaining a separate schema.
+//!
+//! A [`Value`] always records the tokens that are only serialized in
+//! [lossless](crate::lossless) mode, so that the syntax trees converted back
+//! from it keep them, but only writes them out in lossless mode. A field `K`
+//! that is only serialized in lossless mode is stored under the key `+K`, and
+//! the trailing punctuation of a list is stored as a metadata element at the
+//! end of the list, so that analyses do not mistake them for syntax.
+
+use core::fmt;
+
+use serde::{
+    de,
+    ser::{self, Serialize},
+};
+
+use crate::options;
+
+/// A generic representation of a serialized adapter.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Value {
+    Null,
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    Str(String),
+    Seq(Vec<Value>),
+    /// A struct or a map.
+    Map(Map),
+    /// An enum variant.
+    Variant(Variant),
+}
+
+/// A struct or a map.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Map {
+    /// The name of the struct. This is `None` for maps and for structs that
+    /// contain `#[serde(flatten)]` fields (serde serializes them as maps),
+    /// unless they are in an enum variant.
+    pub(crate) ty: Option<&'static str>,
+    pub(crate) fields: Vec<(String, Value)>,
+}
+
+/// An enum variant.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Variant {
+    pub(crate) name: &'static str,
+    /// The content of the variant, `None` for unit variants.
+    pub(crate) value: Option<Box<Value>>,
+}
+
+impl Value {
+    pub(crate) fn as_map(&self) -> Option<&Map> {
+        match self {
+            Value::Map(map) => Some(map),
+            _ => None,
+        }
+    }
+
+    /// Returns the string representation of a scalar value.
+    pub(crate) fn as_scalar(&self) -> Option<String> {
+        match self {
+            Value::Bool(b) => Some(b.to_string()),
+            Value::I64(n) => Some(n.to_string()),
+            Value::U64(n) => Some(n.to_string()),
+            Value::F64(n) => Some(n.to_string()),
+            Value::Str(s) => Some(s.clone()),
+            Value::Variant(Variant { name, value: None }) => Some((*name).to_owned()),
+            Value::Null | Value::Seq(_) | Value::Map(_) | Value::Variant(_) => None,
+        }
+    }
+
+    /// Returns the fields of this value if it is a map or a variant that
+    /// contains a map.
+    pub(crate) fn fields(&self) -> Option<&Map> {
+        match self {
+            Value::Map(map) => Some(map),
+            Value::Variant(Variant { value: Some(value), .. }) => value.as_map(),
+            _ => None,
+        }
+    }
+
+    /// Returns the fields of this value if it is a map or a variant that
+    /// contains a map.
+    pub(crate) fn fields_mut(&mut self) -> Option<&mut Map> {
+        match self {
+            Value::Map(map) => Some(map),
+            Value::Variant(Variant { value: Some(value), .. }) => match &mut **value {
+                Value::Map(map) => Some(map),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if this value is span, comment, or trivia metadata,
+    /// which is not part of the syntax itself.
+    pub(crate) fn is_metadata(&self) -> bool {
+        match self {
+            Value::Map(map) => map.is_metadata(),
+            Value::Seq(elems) => elems.first().map_or(false, Value::is_metadata),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if this value is a syntax tree node.
+    ///
+    /// Nodes are enum variants that carry a value, and structs or maps other
+    /// than metadata.
+    pub(crate) fn is_node(&self) -> bool {
+        match self {
+            Value::Variant(v) => v.value.is_some(),
+            Value::Map(map) => !map.is_metadata(),
+            _ => false,
+        }
+    }
+
+    /// Returns the kind of this node: the name of the enum variant, or the
+    /// snake-cased name of the struct.
+    pub(crate) fn kind(&self) -> Option<String> {
+        match self {
+            Value::Variant(v) => Some(v.name.to_owned()),
+            Value::Map(map) => map.ty.map(snake_case),
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the struct that contains the fields of this node.
+    pub(crate) fn ty(&self) -> Option<&'static str> {
+        self.fields().and_then(|map| map.ty)
+    }
+}
+
+impl Map {
+    pub(crate) fn get(&self, key: &str) -> Option<&Value> {
+        self.fields.iter().find(|(k, _)| k == key).map(|(_, v)| v)
+    }
+
+    fn is_metadata(&self) -> bool {
+        // `Punctuated` is the trailing punctuation at the end of a list.
+        matches!(self.ty, Some("SpanInfo" | "Comment" | "Trivia" | "TokenTrivia" | "Punctuated"))
+    }
+
+    /// Returns `true` if this map only contains data that is serialized in
+    /// lossless mode, e.g., the [`Generics`](crate::Generics) of `fn f<>()`.
+    fn is_lossless(&self) -> bool {
+        !self.fields.is_empty() && self.fields.iter().all(|(key, _)| lossless_field(key).is_some())
+    }
+
+    /// Reads the `span` field of this node.
+    pub(crate) fn span(&self) -> Option<crate::SpanInfo> {
+        let span = self.get("span")?.as_map()?;
+        let field = |name| match span.get(name)? {
+            Value::U64(n) => usize::try_from(*n).ok(),
+            _ => None,
+        };
+        Some(crate::SpanInfo {
+            start_offset: field("start_offset")?,
+            end_offset: field("end_offset")?,
+            start_line: field("start_line")?,
+            start_column: field("start_column")?,
+            end_line: field("end_line")?,
+            end_column: field("end_column")?,
+            file: field("file"),
+        })
+    }
+}
+
+/// Converts `ExprMethodCall` into `expr_method_call`.
+pub(crate) fn snake_case(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 4);
+    for (i, ch) in s.char_indices() {
+        if ch.is_ascii_uppercase() {
+            if i != 0 {
+                out.push('_');
+            }
+            out.push(ch.to_ascii_lowercase());
+        } else {
+            out.push(ch);
+        }
+    }
+    out
+}
+
+/// The prefix of the keys of the fields that are only serialized in lossless
+/// mode.
+const LOSSLESS: char = '+';
+
+/// The name of the newtype struct that wraps the fields that are only
+/// serialized in lossless mode, see [`lossless::serialize_field`].
+///
+/// [`lossless::serialize_field`]: crate::lossless::serialize_field
+pub(crate) const LOSSLESS_FIELD: &str = "$syn_serde::lossless";
+
+/// Returns the name of the field that `key` records in lossless mode, or
+/// `None` if `key` is not a lossless key.
+pub(crate) fn lossless_field(key: &str) -> Option<&str> {
+    key.strip_prefix(LOSSLESS)
+}
+
+fn lossless_key(key: &str) -> String {
+    format!("{}{}", LOSSLESS, key)
+}
+
+/// Splits the trailing punctuation recorded by [`to_value`] off the end of a
+/// list.
+pub(crate) fn split_trailing(elems: &[Value]) -> (&[Value], Option<&Map>) {
+    match elems.split_last() {
+        Some((Value::Map(map), elems)) if map.ty == Some("Punctuated") => (elems, Some(map)),
+        _ => (elems, None),
+    }
+}
+
+/// Escapes a reference token of a JSON Pointer (RFC 6901).
+pub(crate) fn escape_pointer_token(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+// -----------------------------------------------------------------------------
+// Index
+
+/// A node found by [`index`].
+pub(crate) struct Entry<'a> {
+    /// The JSON Pointer to the node.
+    pub(crate) path: String,
+    /// The index of the parent node in the list returned by [`index`].
+    pub(crate) parent: Option<usize>,
+    pub(crate) value: &'a Value,
+}
+
+/// Lists all nodes in the tree in pre-order.
+///
+/// This does not recurse, so that it can be used on deeply nested trees.
+pub(crate) fn index(root: &Value) -> Vec<Entry<'_>> {
+    let mut entries = vec![];
+    // The values to visit, with their paths and the indices of their parent
+    // nodes. Children are pushed in reverse, so that they are visited in
+    // order.
+    let mut stack = vec![(root, String::new(), None)];
+    while let Some((value, path, parent)) = stack.pop() {
+        if value.is_node() {
+            let id = entries.len();
+            entries.push(Entry { path: path.clone(), parent, value });
+            match value {
+                Value::Variant(Variant { name, value: Some(inner) }) => {
+                    let path = format!("{}/{}", path, escape_pointer_token(name));
+                    push_children(inner, path, id, &mut stack);
+                }
+                _ => push_children(value, path, id, &mut stack),
+            }
+        } else if let Value::Seq(elems) = value {
+            for (i, value) in elems.iter().enumerate().rev() {
+                stack.push((value, format!("{}/{}", path, i), parent));
+            }
+        }
+        // Other values, including spans and comments, do not contain nodes.
+    }
+    entries
+}
+
+fn push_children<'a>(
+    value: &'a Value,
+    path: String,
+    id: usize,
+    stack: &mut Vec<(&'a Value, String, Option<usize>)>,
+) {
+    match value {
+        Value::Map(map) => {
+            for (key, value) in map.fields.iter().rev() {
+                stack.push((value, format!("{}/{}", path, escape_pointer_token(key)), Some(id)));
+            }
+        }
+        Value::Seq(elems) => {
+            for (i, value) in elems.iter().enumerate().rev() {
+                stack.push((value, format!("{}/{}", path, i), Some(id)));
+            }
+        }
+        _ => stack.push((value, path, Some(id))),
+    }
+}
+
+/// Writes the id of each node into its fields as `"id"`.
+///
+/// The id of a node is its position in the list returned by [`index`]. Nodes
+/// whose content is not a map (e.g., `Lit::Str`) have an id, but it is not
+/// written.
+pub(crate) fn insert_ids(root: &mut Value) {
+    let mut next_id = 0_usize;
+    // The children are pushed in reverse order, so that the nodes are
+    // numbered in the same order as by `index`.
+    let mut stack = vec![root];
+    while let Some(value) = stack.pop() {
+        if value.is_node() {
+            if let Some(map) = value.fields_mut() {
+                map.fields.insert(0, ("id".to_owned(), Value::U64(next_id as u64)));
+            }
+            next_id += 1;
+            let value = match value {
+                Value::Variant(Variant { value: Some(inner), .. }) => &mut **inner,
+                value => value,
+            };
+            match value {
+                Value::Map(map) => {
+                    stack.extend(map.fields.iter_mut().rev().map(|(_, value)| value));
+                }
+                Value::Seq(elems) => stack.extend(elems.iter_mut().rev()),
+                value => stack.push(value),
+            }
+        } else if let Value::Seq(elems) = value {
+            stack.extend(elems.iter_mut().rev());
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Serializer
+
+/// Converts the given adapter into a [`Value`].
+pub(crate) fn to_value<T>(value: &T) -> Value
+where
+    T: ?Sized + Serialize,
+{
+    // Record the tokens that are only serialized in lossless mode; `Value`
+    // omits them when it is serialized in the default mode. Enums are
+    // recorded as variants, whatever form they are written in.
+    let _enable = options::Enable::set(options::current().lossless(true).tagged(false));
+    // All of the data structures in syn-serde can be represented by `Value`.
+    value.serialize(Serializer).unwrap()
+}
+
+#[derive(Debug)]
+pub(crate) struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+struct Serializer;
+
+impl ser::Serializer for Serializer {
+    type Ok = Value;
+    type Error = Error;
+    type SerializeSeq = SerializeSeq;
+    type SerializeTuple = SerializeSeq;
+    type SerializeTupleStruct = SerializeSeq;
+    type SerializeTupleVariant = SerializeSeq;
+    type SerializeMap = SerializeMap;
+    type SerializeStruct = SerializeMap;
+    type SerializeStructVariant = SerializeMap;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        Ok(Value::Bool(v))
+    }
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        Ok(Value::I64(v.into()))
+    }
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        Ok(Value::I64(v.into()))
+    }
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        Ok(Value::I64(v.into()))
+    }
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        Ok(Value::I64(v))
+    }
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        Ok(Value::U64(v.into()))
+    }
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        Ok(Value::U64(v.into()))
+    }
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        Ok(Value::U64(v.into()))
+    }
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        Ok(Value::U64(v))
+    }
+    fn serialize_f32(self, v: f32) -> Result<Value, Error> {
+        Ok(Value::F64(v.into()))
+    }
+    fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+        Ok(Value::F64(v))
+    }
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        Ok(Value::Str(v.to_string()))
+    }
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        Ok(Value::Str(v.to_owned()))
+    }
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Error> {
+        Ok(Value::Seq(v.iter().map(|&b| Value::U64(b.into())).collect()))
+    }
+    fn serialize_none(self) -> Result<Value, Error> {
+        Ok(Value::Null)
+    }
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Ok(Value::Null)
+    }
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, Error> {
+        Ok(Value::Null)
+    }
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Value, Error> {
+        Ok(Value::Variant(Variant { name: variant, value: None }))
+    }
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        let value = value.serialize(self)?;
+        if name == LOSSLESS_FIELD {
+            // Unwrapped by `SerializeMap::push`.
+            Ok(Value::Map(Map { ty: Some(LOSSLESS_FIELD), fields: vec![(String::new(), value)] }))
+        } else {
+            Ok(value)
+        }
+    }
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Value, Error> {
+        let mut value = value.serialize(self)?;
+        if let Value::Map(map) = &mut value {
+            if map.ty.is_none() {
+                map.ty = crate::ast_enum::flattened_struct(name, variant);
+            }
+        }
+        Ok(Value::Variant(Variant { name: variant, value: Some(Box::new(value)) }))
+    }
+    fn serialize_seq(self, len: Option<usize>) -> Result<SerializeSeq, Error> {
+        Ok(SerializeSeq { variant: None, elems: Vec::with_capacity(len.unwrap_or(0)) })
+    }
+    fn serialize_tuple(self, len: usize) -> Result<SerializeSeq, Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SerializeSeq, Error> {
+        self.serialize_seq(Some(len))
+    }
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeSeq, Error> {
+        Ok(SerializeSeq { variant: Some(variant), elems: Vec::with_capacity(len) })
+    }
+    fn serialize_map(self, len: Option<usize>) -> Result<SerializeMap, Error> {
+        Ok(SerializeMap {
+            variant: None,
+            map: Map { ty: None, fields: Vec::with_capacity(len.unwrap_or(0)) },
+            next_key: None,
+        })
+    }
+    fn serialize_struct(self, name: &'static str, len: usize) -> Result<SerializeMap, Error> {
+        Ok(SerializeMap {
+            variant: None,
+            map: Map { ty: Some(name), fields: Vec::with_capacity(len) },
+            next_key: None,
+        })
+    }
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SerializeMap, Error> {
+        Ok(SerializeMap {
+            variant: Some(variant),
+            map: Map { ty: None, fields: Vec::with_capacity(len) },
+            next_key: None,
+        })
+    }
+}
+
+struct SerializeSeq {
+    variant: Option<&'static str>,
+    elems: Vec<Value>,
+}
+
+impl SerializeSeq {
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.elems.push(value.serialize(Serializer)?);
+        Ok(())
+    }
+
+    fn finish(self) -> Value {
+        let seq = Value::Seq(self.elems);
+        match self.variant {
+            Some(name) => Value::Variant(Variant { name, value: Some(Box::new(seq)) }),
+            None => seq,
+        }
+    }
+}
+
+impl ser::SerializeSeq for SerializeSeq {
+    type Ok = Value;
+    type Error = Error;
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTuple for SerializeSeq {
+    type Ok = Value;
+    type Error = Error;
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleStruct for SerializeSeq {
+    type Ok = Value;
+    type Error = Error;
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeTupleVariant for SerializeSeq {
+    type Ok = Value;
+    type Error = Error;
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+struct SerializeMap {
+    variant: Option<&'static str>,
+    map: Map,
+    next_key: Option<String>,
+}
+
+impl SerializeMap {
+    fn push(&mut self, key: String, value: Value) {
+        match value {
+            // A field that is only serialized in lossless mode.
+            Value::Map(Map { ty: Some(LOSSLESS_FIELD), mut fields }) => {
+                self.map.fields.push((lossless_key(&key), fields.pop().unwrap().1));
+            }
+            value => self.map.fields.push((key, value)),
+        }
+    }
+
+    fn finish(mut self) -> Value {
+        // A list with trailing punctuation, `{"elems": [...], "trailing": true}`.
+        if self.map.ty == Some("Punctuated") {
+            if let (_, Value::Seq(mut elems)) = self.map.fields.remove(0) {
+                elems.push(Value::Map(self.map));
+                return Value::Seq(elems);
+            }
+        }
+        let map = Value::Map(self.map);
+        match self.variant {
+            Some(name) => Value::Variant(Variant { name, value: Some(Box::new(map)) }),
+            None => map,
+        }
+    }
+}
+
+impl ser::SerializeMap for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), Error> {
+        match key.serialize(Serializer)? {
+            Value::Str(key) => {
+                self.next_key = Some(key);
+                Ok(())
+            }
+            Value::Variant(Variant { name, value: None }) => {
+                self.next_key = Some(name.to_owned());
+                Ok(())
+            }
+            _ => Err(ser::Error::custom("map key must be a string")),
+        }
+    }
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), Error> {
+        let key = self.next_key.take().expect("serialize_value called before serialize_key");
+        self.push(key, value.serialize(Serializer)?);
+        Ok(())
+    }
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeStruct for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(key.to_owned(), value.serialize(Serializer)?);
+        Ok(())
+    }
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+impl ser::SerializeStructVariant for SerializeMap {
+    type Ok = Value;
+    type Error = Error;
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(key.to_owned(), value.serialize(Serializer)?);
+        Ok(())
+    }
+    fn end(self) -> Result<Value, Error> {
+        Ok(self.finish())
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Serialize
+
+// `Value` serializes into the same representation as the adapter it was
+// created from, e.g., `{"fn": {...}}` for enum variants.
+impl Serialize for Value {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        use serde::ser::{SerializeMap as _, SerializeSeq as _};
+        match self {
+            Value::Null => serializer.serialize_unit(),
+            Value::Bool(v) => serializer.serialize_bool(*v),
+            Value::I64(v) => serializer.serialize_i64(*v),
+            Value::U64(v) => serializer.serialize_u64(*v),
+            Value::F64(v) => serializer.serialize_f64(*v),
+            Value::Str(v) => serializer.serialize_str(v),
+            Value::Seq(elems) => match split_trailing(elems) {
+                (elems, Some(_)) if options::current().lossless => {
+                    Trailing(elems).serialize(serializer)
+                }
+                (elems, _) => {
+                    let mut seq = serializer.serialize_seq(Some(elems.len()))?;
+                    for elem in elems {
+                        seq.serialize_element(elem)?;
+                    }
+                    seq.end()
+                }
+            },
+            Value::Map(map) => {
+                let fields = map.output();
+                let mut s = serializer.serialize_map(Some(fields.clone().count()))?;
+                for (key, value) in fields {
+                    s.serialize_entry(key, &value)?;
+                }
+                s.end()
+            }
+            Value::Variant(Variant { name, value }) if options::current().tagged => {
+                crate::tagged::serialize_variant(name, value.as_deref(), serializer)
+            }
+            Value::Variant(Variant { name, value: None }) => {
+                serializer.serialize_unit_variant("", 0, name)
+            }
+            Value::Variant(Variant { name, value: Some(value) }) => {
+                serializer.serialize_newtype_variant("", 0, name, value)
+            }
+        }
+    }
+}
+
+impl Map {
+    /// Returns the fields of this map that are written out in the current
+    /// mode.
+    pub(crate) fn output(&self) -> impl Iterator<Item = (&str, Field<'_>)> + Clone {
+        let lossless = options::current().lossless;
+        self.fields.iter().filter_map(move |(key, value)| match lossless_field(key) {
+            Some(key) => Some((key, Field { value, lossless: true })).filter(|_| lossless),
+            None if !lossless && value.as_map().map_or(false, Map::is_lossless) => None,
+            None => Some((&**key, Field { value, lossless: false })),
+        })
+    }
+}
+
+/// A field of a map, as written out by [`Map::output`].
+pub(crate) struct Field<'a> {
+    value: &'a Value,
+    /// `true` if the field is only serialized in lossless mode.
+    lossless: bool,
+}
+
+impl Serialize for Field<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        if self.lossless {
+            // Keeps the field recorded as such when it is converted into a
+            // `Value` again.
+            serializer.serialize_newtype_struct(LOSSLESS_FIELD, self.value)
+        } else {
+            self.value.serialize(serializer)
+        }
+    }
+}
+
+/// A list with trailing punctuation, in its lossless form.
+struct Trailing<'a>(&'a [Value]);
+
+impl Serialize for Trailing<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        use serde::ser::SerializeStruct as _;
+        // The same as `lossless::Punctuated`.
+        let mut s = serializer.serialize_struct("Punctuated", 2)?;
+        s.serialize_field("elems", self.0)?;
+        s.serialize_field("trailing", &true)?;
+        s.end()
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Deserializer
+
+/// Converts the given [`Value`] back into an adapter.
+pub(crate) fn from_value<T>(value: Value) -> Result<T, Error>
+where
+    T: de::DeserializeOwned,
+{
+    T::deserialize(Deserializer::new(value, None))
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self(msg.to_string())
+    }
+}
+
+/// Replaces subtrees with placeholders while they are deserialized, so that
+/// they can be deserialized separately.
+pub(crate) trait Split {
+    /// Returns a placeholder for `value`, which is about to be deserialized as
+    /// the enum `name`, or returns `value` back if it should be deserialized
+    /// in place.
+    fn split(&self, name: &'static str, value: Value) -> Result<Value, Value>;
+}
+
+/// Deserializes adapters from a [`Value`].
+pub(crate) struct Deserializer<'a> {
+    value: Value,
+    split: Option<&'a dyn Split>,
+    /// `true` for the root of the tree being deserialized, which is never
+    /// split.
+    root: bool,
+}
+
+impl<'a> Deserializer<'a> {
+    pub(crate) fn new(value: Value, split: Option<&'a dyn Split>) -> Self {
+        Self { value, split, root: true }
+    }
+
+    fn child(value: Value, split: Option<&'a dyn Split>) -> Self {
+        Self { value, split, root: false }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'_> {
+    type Error = Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        let split = self.split;
+        match self.value {
+            Value::Null => visitor.visit_unit(),
+            Value::Bool(v) => visitor.visit_bool(v),
+            Value::I64(v) => visitor.visit_i64(v),
+            Value::U64(v) => visitor.visit_u64(v),
+            Value::F64(v) => visitor.visit_f64(v),
+            Value::Str(v) => visitor.visit_string(v),
+            Value::Seq(mut elems) if split_trailing(&elems).1.is_some() => {
+                let mut fields = match elems.pop() {
+                    Some(Value::Map(map)) => map.fields,
+                    _ => unreachable!(),
+                };
+                fields.insert(0, ("elems".to_owned(), Value::Seq(elems)));
+                Deserializer::child(Value::Map(Map { ty: None, fields }), split)
+                    .deserialize_any(visitor)
+            }
+            Value::Seq(elems) => {
+                let len = elems.len();
+                let mut seq = SeqAccess { iter: elems.into_iter(), split };
+                let value = visitor.visit_seq(&mut seq)?;
+                if seq.iter.len() == 0 {
+                    Ok(value)
+                } else {
+                    Err(de::Error::invalid_length(len, &"fewer elements in sequence"))
+                }
+            }
+            Value::Map(map) => visitor.visit_map(MapAccess {
+                iter: lossless_form(map.fields).into_iter(),
+                value: None,
+                split,
+            }),
+            // Same as the externally tagged representation: `"name"` or
+            // `{"name": value}`.
+            Value::Variant(Variant { name, value: None }) => visitor.visit_str(name),
+            Value::Variant(Variant { name, value: Some(value) }) => visitor.visit_map(MapAccess {
+                iter: vec![(name.to_owned(), *value)].into_iter(),
+                value: None,
+                split,
+            }),
+        }
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        // Enums in the tagged form are read by `deserialize_enum`, without
+        // buffering them (see `tagged::deserialize`).
+        if name == crate::tagged::ENUM {
+            return visitor.visit_some(self);
+        }
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        let split = self.split;
+        // `{"kind": "name", ...}`, as serialized by `Tagged`.
+        let value = match self.value {
+            Value::Map(map) if options::current().tagged => crate::tagged::untag(map, variants),
+            value => value,
+        };
+        let value = match split {
+            Some(s) if !self.root => match s.split(name, value) {
+                Ok(placeholder) => {
+                    return Deserializer::new(placeholder, None)
+                        .deserialize_enum(name, variants, visitor);
+                }
+                Err(value) => value,
+            },
+            _ => value,
+        };
+        match value {
+            Value::Variant(Variant { name, value }) => visitor.visit_enum(EnumAccess {
+                name: name.to_owned(),
+                value: value.map(|v| *v),
+                split,
+            }),
+            Value::Str(name) => visitor.visit_enum(EnumAccess { name, value: None, split }),
+            // `{"name": value}`, as deserialized by `impl Deserialize for Value`.
+            Value::Map(map) if map.fields.len() == 1 => {
+                let (name, value) = map.fields.into_iter().next().unwrap();
+                visitor.visit_enum(EnumAccess { name, value: Some(value), split })
+            }
+            _ => Err(de::Error::invalid_type(de::Unexpected::Other("non-enum value"), &visitor)),
+        }
+    }
+
+    // Ignored values (e.g., `"id"` fields) are dropped without recursion.
+    fn deserialize_ignored_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        drop_deep(self.value);
+        visitor.visit_unit()
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier
+    }
+}
+
+/// Renames the fields recorded by [`to_value`] that are only serialized in
+/// lossless mode back, so that the adapters deserialize them.
+fn lossless_form(mut fields: Vec<(String, Value)>) -> Vec<(String, Value)> {
+    for (key, _) in &mut fields {
+        if let Some(name) = lossless_field(key) {
+            *key = name.to_owned();
+        }
+    }
+    fields
+}
+
+struct SeqAccess<'a> {
+    iter: std::vec::IntoIter<Value>,
+    split: Option<&'a dyn Split>,
+}
+
+impl<'de> de::SeqAccess<'de> for SeqAccess<'_> {
+    type Error = Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        match self.iter.next() {
+            Some(value) => seed.deserialize(Deserializer::child(value, self.split)).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+impl Drop for SeqAccess<'_> {
+    fn drop(&mut self) {
+        self.iter.by_ref().for_each(drop_deep);
+    }
+}
+
+struct MapAccess<'a> {
+    iter: std::vec::IntoIter<(String, Value)>,
+    value: Option<Value>,
+    split: Option<&'a dyn Split>,
+}
+
+impl<'de> de::MapAccess<'de> for MapAccess<'_> {
+    type Error = Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.iter.next() {
+            Some((key, value)) => {
+                self.value = Some(value);
+                let key: de::value::StringDeserializer<Error> =
+                    de::IntoDeserializer::into_deserializer(key);
+                seed.deserialize(key).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        match self.value.take() {
+            Some(value) => seed.deserialize(Deserializer::child(value, self.split)),
+            None => Err(de::Error::custom("value is missing")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.iter.len())
+    }
+}
+
+impl Drop for MapAccess<'_> {
+    fn drop(&mut self) {
+        self.value.take().into_iter().for_each(drop_deep);
+        self.iter.by_ref().for_each(|(_, value)| drop_deep(value));
+    }
+}
+
+struct EnumAccess<'a> {
+    name: String,
+    value: Option<Value>,
+    split: Option<&'a dyn Split>,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for EnumAccess<'a> {
+    type Error = Error;
+    type Variant = VariantAccess<'a>;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, VariantAccess<'a>), Error> {
+        let name: de::value::StringDeserializer<Error> =
+            de::IntoDeserializer::into_deserializer(self.name);
+        Ok((seed.deserialize(name)?, VariantAccess { value: self.value, split: self.split }))
+    }
+}
+
+struct VariantAccess<'a> {
+    value: Option<Value>,
+    split: Option<&'a dyn Split>,
+}
+
+impl<'de> de::VariantAccess<'de> for VariantAccess<'_> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        match self.value {
+            None | Some(Value::Null) => Ok(()),
+            Some(_) => Err(de::Error::invalid_type(de::Unexpected::NewtypeVariant, &"unit variant")),
+        }
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        match self.value {
+            Some(value) => seed.deserialize(Deserializer::child(value, self.split)),
+            None => Err(de::Error::invalid_type(de::Unexpected::UnitVariant, &"newtype variant")),
+        }
+    }
+
+    fn tuple_variant<V: de::Visitor<'de>>(self, _len: usize, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Some(value) => {
+                de::Deserializer::deserialize_seq(Deserializer::child(value, self.split), visitor)
+            }
+            None => Err(de::Error::invalid_type(de::Unexpected::UnitVariant, &"tuple variant")),
+        }
+    }
+
+    fn struct_variant<V: de::Visitor<'de>>(
+        self,
+        _fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.value {
+            Some(value) => {
+                de::Deserializer::deserialize_map(Deserializer::child(value, self.split), visitor)
+            }
+            None => Err(de::Error::invalid_type(de::Unexpected::UnitVariant, &"struct variant")),
+        }
+    }
+}
+
+/// Drops the given value without recursion, so that deeply nested values do
+/// not overflow the stack.
+pub(crate) fn drop_deep(value: Value) {
+    let mut stack = vec![value];
+    while let Some(value) = stack.pop() {
+        match value {
+            Value::Seq(elems) => stack.extend(elems),
+            Value::Map(map) => stack.extend(map.fields.into_iter().map(|(_, v)| v)),
+            Value::Variant(Variant { value: Some(v), .. }) => stack.push(*v),
+            _ => {}
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Deserialize
+
+// Struct names and enum variants cannot be recovered from self-describing
+// formats, so structs are deserialized as maps with `ty: None`, and variants
+// as maps with a single entry or as strings. Both of them can be deserialized
+// into adapters.
+impl<'de> de::Deserialize<'de> for Value {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(ValueVisitor)
+    }
+}
+
+struct ValueVisitor;
+
+impl<'de> de::Visitor<'de> for ValueVisitor {
+    type Value = Value;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("any value")
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Value, E> {
+        Ok(Value::Bool(v))
+    }
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Value, E> {
+        Ok(Value::I64(v))
+    }
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Value, E> {
+        Ok(Value::U64(v))
+    }
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Value, E> {
+        Ok(Value::F64(v))
+    }
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Value, E> {
+        Ok(Value::Str(v.to_owned()))
+    }
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Value, E> {
+        Ok(Value::Str(v))
+    }
+    fn visit_none<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+    fn visit_some<D: de::Deserializer<'de>>(self, deserializer: D) -> Result<Value, D::Error> {
+        de::Deserialize::deserialize(deserializer)
+    }
+    fn visit_unit<E: de::Error>(self) -> Result<Value, E> {
+        Ok(Value::Null)
+    }
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Value, A::Error> {
+        let mut elems = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        loop {
+            match seq.next_element() {
+                Ok(Some(elem)) => elems.push(elem),
+                Ok(None) => return Ok(Value::Seq(elems)),
+                Err(e) => {
+                    elems.into_iter().for_each(drop_deep);
+                    return Err(e);
+                }
+            }
+        }
+    }
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Value, A::Error> {
+        let mut fields = Vec::with_capacity(map.size_hint().unwrap_or(0));
+        loop {
+            match map.next_entry() {
+                Ok(Some(entry)) => fields.push(entry),
+                Ok(None) => return Ok(Value::Map(Map { ty: None, fields })),
+                Err(e) => {
+                    fields.into_iter().map(|(_, v)| v).for_each(drop_deep);
+                    return Err(e);
+                }
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// JSON Pointer
+
+/// Returns the value at the given JSON Pointer (RFC 6901), relative to the
+/// given value.
+pub(crate) fn pointer_mut<'a>(mut value: &'a mut Value, pointer: &str) -> Option<&'a mut Value> {
+    if pointer.is_empty() {
+        return Some(value);
+    }
+    for token in pointer.split('/') {
+        let token = token.replace("~1", "/").replace("~0", "~");
+        // A list with trailing punctuation is addressed like the list itself.
+        let elems = match &*value {
+            Value::Map(map) if map.get(&token).is_none() => {
+                map.fields.iter().position(|(k, _)| k == "elems")
+            }
+            _ => None,
+        };
+        value = match (elems, value) {
+            (Some(i), Value::Map(map)) => &mut map.fields[i].1,
+            (_, value) => value,
+        };
+        value = match value {
+            Value::Map(map) => map.fields.iter_mut().find(|(k, _)| *k == token).map(|(_, v)| v)?,
+            Value::Seq(elems) => elems.get_mut(token.parse::<usize>().ok()?)?,
+            Value::Variant(Variant { name, value: Some(inner) }) if *name == token => inner,
+            _ => return None,
+        };
+    }
+    Some(value)
+}
@@ -0,0 +1,125 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Combinations of serialization modes.
+//!
+//! [`Lossless`], [`Explicit`], and [`Tagged`] each serialize a syntax tree in
+//! one mode. [`Tree`] serializes a syntax tree with any combination of them,
+//! as selected by [`Options`].
+//!
+//! [`Explicit`]: crate::explicit::Explicit
+//! [`Lossless`]: crate::lossless::Lossless
+//! [`Tagged`]: crate::tagged::Tagged
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn dox() {
+//! use syn_serde::options::{Options, Tree};
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     fn f(a: u8,) {}
+//! };
+//! let options = Options::new().lossless(true).explicit(true).tagged(true);
+//! let tree = Tree::from_syn(&syn_file, options);
+//!
+//! let json = serde_json::to_value(&tree).unwrap();
+//! let f = &json["items"][0];
+//! assert_eq!(f["kind"], "fn");
+//! assert_eq!(f["attrs"], serde_json::json!([]));
+//! assert_eq!(f["inputs"]["trailing"], true);
+//! # }
+//! ```
+
+use core::cell::Cell;
+
+/// The serialization modes to enable.
+///
+/// See the [module level documentation](self) for more.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Options {
+    pub(crate) lossless: bool,
+    pub(crate) explicit: bool,
+    pub(crate) tagged: bool,
+}
+
+impl Options {
+    /// Creates options with every mode disabled, which is the default form.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serializes trailing punctuation and optional tokens, as
+    /// [`Lossless`](crate::lossless::Lossless) does.
+    #[must_use]
+    pub fn lossless(mut self, yes: bool) -> Self {
+        self.lossless = yes;
+        self
+    }
+
+    /// Serializes the fields that have their default value, as
+    /// [`Explicit`](crate::explicit::Explicit) does.
+    #[must_use]
+    pub fn explicit(mut self, yes: bool) -> Self {
+        self.explicit = yes;
+        self
+    }
+
+    /// Serializes enums in the internally tagged form, as
+    /// [`Tagged`](crate::tagged::Tagged) does.
+    #[must_use]
+    pub fn tagged(mut self, yes: bool) -> Self {
+        self.tagged = yes;
+        self
+    }
+
+    pub(crate) fn union(self, other: Self) -> Self {
+        Self {
+            lossless: self.lossless || other.lossless,
+            explicit: self.explicit || other.explicit,
+            tagged: self.tagged || other.tagged,
+        }
+    }
+}
+
+thread_local! {
+    static CURRENT: Cell<Options> = Cell::new(Options::new());
+}
+
+/// Returns the modes that the adapters are being serialized in.
+pub(crate) fn current() -> Options {
+    CURRENT.with(Cell::get)
+}
+
+/// Sets the modes that the adapters are serialized in until dropped.
+pub(crate) struct Enable {
+    prev: Options,
+}
+
+impl Enable {
+    /// Enables `options` in addition to the current modes.
+    pub(crate) fn add(options: Options) -> Self {
+        Self::set(current().union(options))
+    }
+
+    /// Replaces the current modes with `options`.
+    pub(crate) fn set(options: Options) -> Self {
+        Self { prev: CURRENT.with(|current| current.replace(options)) }
+    }
+}
+
+impl Drop for Enable {
+    fn drop(&mut self) {
+        let prev = self.prev;
+        CURRENT.with(|current| current.set(prev));
+    }
+}
+
+value_tree! {
+    /// A syntax tree that is serialized with the given [`Options`].
+    ///
+    /// See the [module level documentation](self) for more.
+    pub struct Tree(options);
+    new: "Records",
+}
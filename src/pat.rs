@@ -12,11 +12,13 @@ pub use crate::{
 ast_struct! {
     /// An adapter for [`struct@syn::PatOr`].
     pub struct PatOr {
-        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) attrs: Vec<Attribute>,
         // TODO: can remove
-        #[serde(default, skip_serializing_if = "not")]
+        #[serde(default, skip_serializing_if = "explicit::skip")]
         pub(crate) leading_vert: bool,
         pub(crate) cases: Punctuated<Pat>,
+        #[serde(default, skip_serializing_if = "Vec::is_empty")]
+        pub(crate) trivia: Vec<crate::trivia::TokenTrivia>,
     }
 }
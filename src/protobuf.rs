@@ -0,0 +1,548 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A module to provide functions for Protocol Buffers <-> Rust serialize and
+//! deserialize.
+//!
+//! A file is encoded as the `File` message of [`SCHEMA`], which is generated
+//! from the syntax tree description of syn. The messages mirror the JSON
+//! form of the adapters:
+//!
+//! | Adapter                                       | Message                                                          |
+//! | --------------------------------------------- | ---------------------------------------------------------------- |
+//! | Struct (e.g., `ItemFn`)                       | A message with the same fields                                   |
+//! | Enum with data (e.g., `Expr`)                 | A message with a `oneof kind`, where unit variants are a `Unit`  |
+//! | Enum without data (e.g., `BinOp`)             | An enum                                                          |
+//! | Optional token (e.g., `unsafe`)               | `bool`                                                           |
+//! | Identifier and literal                        | `Ident` and `Literal`, with an optional `span`                   |
+//! | Token stream (e.g., `Verbatim`, macro bodies) | `TokenStream`, with a `TokenTree` for each token                 |
+//! | Span and comment                              | `SpanInfo` and `Comment`                                         |
+//!
+//! The fields that are flattened into their parent in JSON (e.g., the
+//! signature of an `ItemFn`) are nested messages, and the fields are numbered
+//! in the order in which they are declared.
+//!
+//! # Examples
+//!
+//! ```
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     fn main() {}
+//! };
+//! let bytes = syn_serde::protobuf::to_vec(&syn_file);
+//! let syn_file2 = syn_serde::protobuf::from_slice(&bytes).unwrap();
+//! assert_eq!(syn_file, syn_file2);
+//! ```
+
+use crate::{
+    Error, File, Syn as _,
+    node::{self, Map, Value, Variant},
+};
+
+#[path = "gen/protobuf.rs"]
+mod schema;
+
+/// The Protocol Buffers schema of the syntax trees, in the proto3 language.
+pub const SCHEMA: &str = include_str!("gen/syn.proto");
+
+/// The maximum nesting depth of the messages, like the recursion limit of
+/// `serde_json`.
+const RECURSION_LIMIT: usize = 128;
+
+const VARINT: u64 = 0;
+const I64: u64 = 1;
+const LEN: u64 = 2;
+const I32: u64 = 5;
+
+struct Message {
+    name: &'static str,
+    kind: Kind,
+    fields: &'static [Field],
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// A struct, serialized as a map.
+    Struct,
+    /// A struct with `#[serde(transparent)]`, serialized as its only field.
+    Transparent,
+    /// A tuple, serialized as a sequence.
+    Tuple,
+    /// A struct that is serialized as its first field if the other fields
+    /// are absent, e.g., `Ident`.
+    Token,
+    /// An enum with data, encoded as a `oneof`.
+    Oneof,
+    /// An enum with only unit variants, encoded as a protobuf enum.
+    Enum,
+}
+
+struct Field {
+    /// The name that the field or variant is serialized as.
+    key: &'static str,
+    label: Label,
+    ty: Ty,
+    flatten: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Label {
+    Singular,
+    Optional,
+    Repeated,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Ty {
+    Bool,
+    Uint32,
+    Uint64,
+    String,
+    Message(&'static str),
+    Enum(&'static str),
+}
+
+impl Ty {
+    fn is_varint(self) -> bool {
+        !matches!(self, Ty::String | Ty::Message(_))
+    }
+}
+
+fn message(name: &str) -> &'static Message {
+    let i = schema::MESSAGES.binary_search_by_key(&name, |m| m.name).unwrap();
+    &schema::MESSAGES[i]
+}
+
+/// Serialize the given [`syn::File`] as a `File` message.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # fn to_vec(syn_file: &syn::File) -> Vec<u8> {
+/// use syn_serde::Syn;
+///
+/// let adapter = syn_file.to_adapter();
+/// syn_serde::protobuf::encode(&adapter)
+/// # }
+/// ```
+#[must_use]
+pub fn to_vec(syn_file: &syn::File) -> Vec<u8> {
+    encode(&syn_file.to_adapter())
+}
+
+/// Deserialize a [`syn::File`] from a `File` message.
+///
+/// This function is equivalent to the following code:
+///
+/// ```
+/// # fn from_slice(v: &[u8]) -> Result<syn::File, syn_serde::Error> {
+/// use syn_serde::Syn;
+///
+/// let adapter = syn_serde::protobuf::decode(v)?;
+/// Ok(syn::File::from_adapter(&adapter))
+/// # }
+/// ```
+///
+/// # Errors
+///
+/// See [`decode`].
+pub fn from_slice(v: &[u8]) -> Result<syn::File, Error> {
+    decode(v).map(|file| syn::File::from_adapter(&file))
+}
+
+/// Serialize the given [`File`] adapter as a `File` message, along with its
+/// spans, comments, and trivia.
+#[allow(clippy::missing_panics_doc)]
+#[must_use]
+pub fn encode(file: &File) -> Vec<u8> {
+    let mut out = vec![];
+    if let Err(e) = encode_message(message("File"), &node::to_value(file), &mut out) {
+        // The schema is generated from the same description as the adapters.
+        panic!("the adapters do not match the protobuf schema: {}", e)
+    }
+    out
+}
+
+/// Deserialize a [`File`] adapter from a `File` message.
+///
+/// # Errors
+///
+/// Returns an error if `v` is not a valid `File` message, or if the messages
+/// are nested too deeply.
+pub fn decode(v: &[u8]) -> Result<File, Error> {
+    let value = decode_message(message("File"), v, 0)?;
+    node::from_value(value).map_err(|e| Error::new(e.to_string()))
+}
+
+// -----------------------------------------------------------------------------
+// Encoding
+
+fn describe(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::I64(_) | Value::U64(_) | Value::F64(_) => "a number",
+        Value::Str(_) => "a string",
+        Value::Seq(_) => "a sequence",
+        Value::Map(_) => "a map",
+        Value::Variant(_) => "an enum variant",
+    }
+}
+
+fn invalid(msg: &Message, value: &Value) -> Error {
+    Error::new(format!("invalid value for {}: {}", msg.name, describe(value)))
+}
+
+fn encode_message(msg: &Message, value: &Value, out: &mut Vec<u8>) -> Result<(), Error> {
+    match (msg.kind, value) {
+        (Kind::Token, Value::Str(_)) | (Kind::Transparent, _) => encode_field(msg, 0, value, out),
+        (Kind::Struct | Kind::Token, Value::Map(map)) => {
+            let mut used = vec![false; map.fields.len()];
+            encode_fields(msg, map, &mut used, out)?;
+            // Like JSON, messages do not record the tokens that are only
+            // serialized in lossless mode.
+            let unknown = used
+                .iter()
+                .zip(&map.fields)
+                .position(|(&used, (key, _))| !used && node::lossless_field(key).is_none());
+            match unknown {
+                Some(i) => {
+                    Err(Error::new(format!("unknown field `{}` in {}", map.fields[i].0, msg.name)))
+                }
+                None => Ok(()),
+            }
+        }
+        (Kind::Tuple, Value::Seq(elems)) if elems.len() == msg.fields.len() => {
+            for (i, elem) in elems.iter().enumerate() {
+                encode_field(msg, i, elem, out)?;
+            }
+            Ok(())
+        }
+        (Kind::Oneof, Value::Variant(Variant { name, value })) => {
+            encode_variant(msg, name, value.as_deref(), out)
+        }
+        _ => Err(invalid(msg, value)),
+    }
+}
+
+/// Encodes the fields of `msg` that are in `map`, and marks them as used.
+fn encode_fields(
+    msg: &Message,
+    map: &Map,
+    used: &mut [bool],
+    out: &mut Vec<u8>,
+) -> Result<(), Error> {
+    for (i, field) in msg.fields.iter().enumerate() {
+        if field.flatten {
+            let inner = match field.ty {
+                Ty::Message(name) => message(name),
+                _ => unreachable!(),
+            };
+            let mut buf = vec![];
+            if inner.kind == Kind::Oneof {
+                let variant = map.fields.iter().enumerate().find(|&(j, (key, _))| {
+                    !used[j] && inner.fields.iter().any(|field| field.key == key)
+                });
+                match variant {
+                    Some((j, (key, content))) => {
+                        used[j] = true;
+                        encode_variant(inner, key, Some(content), &mut buf)?;
+                    }
+                    None => continue,
+                }
+            } else {
+                encode_fields(inner, map, used, &mut buf)?;
+            }
+            write_len(out, i + 1, &buf);
+        } else if let Some(j) = map.fields.iter().position(|(key, _)| key == field.key) {
+            used[j] = true;
+            encode_field(msg, i, &map.fields[j].1, out)?;
+        }
+    }
+    Ok(())
+}
+
+fn encode_variant(
+    msg: &Message,
+    name: &str,
+    content: Option<&Value>,
+    out: &mut Vec<u8>,
+) -> Result<(), Error> {
+    let i = msg
+        .fields
+        .iter()
+        .position(|field| field.key == name)
+        .ok_or_else(|| Error::new(format!("unknown variant `{}` of {}", name, msg.name)))?;
+    match content {
+        Some(content) => encode_field(msg, i, content, out),
+        None => encode_field(msg, i, &Value::Map(Map { ty: None, fields: vec![] }), out),
+    }
+}
+
+fn encode_field(msg: &Message, i: usize, value: &Value, out: &mut Vec<u8>) -> Result<(), Error> {
+    match (msg.fields[i].label, value) {
+        (Label::Repeated, Value::Seq(elems)) => {
+            let (elems, _) = node::split_trailing(elems);
+            for elem in elems {
+                encode_value(msg, i, elem, out)?;
+            }
+            Ok(())
+        }
+        (Label::Repeated, _) => Err(invalid_field(msg, i, value)),
+        (_, Value::Null) => Ok(()),
+        _ => encode_value(msg, i, value, out),
+    }
+}
+
+fn invalid_field(msg: &Message, i: usize, value: &Value) -> Error {
+    let field = &msg.fields[i];
+    Error::new(format!("invalid value for `{}` in {}: {}", field.key, msg.name, describe(value)))
+}
+
+fn encode_value(msg: &Message, i: usize, value: &Value, out: &mut Vec<u8>) -> Result<(), Error> {
+    let number = i + 1;
+    match (msg.fields[i].ty, value) {
+        (Ty::Bool, &Value::Bool(b)) => {
+            write_tag(out, number, VARINT);
+            write_varint(out, b.into());
+        }
+        (Ty::Uint32 | Ty::Uint64, &Value::U64(n)) => {
+            write_tag(out, number, VARINT);
+            write_varint(out, n);
+        }
+        (Ty::String, Value::Str(s)) => write_len(out, number, s.as_bytes()),
+        (Ty::Enum(name), Value::Variant(Variant { name: variant, value: None })) => {
+            let n =
+                message(name).fields.iter().position(|field| field.key == *variant).ok_or_else(
+                    || Error::new(format!("unknown variant `{}` of {}", variant, name)),
+                )?;
+            write_tag(out, number, VARINT);
+            write_varint(out, n as u64);
+        }
+        (Ty::Message(name), _) => {
+            let mut buf = vec![];
+            encode_message(message(name), value, &mut buf)?;
+            write_len(out, number, &buf);
+        }
+        _ => return Err(invalid_field(msg, i, value)),
+    }
+    Ok(())
+}
+
+#[allow(clippy::cast_possible_truncation)]
+fn write_varint(out: &mut Vec<u8>, mut n: u64) {
+    while n >= 0x80 {
+        out.push(n as u8 | 0x80);
+        n >>= 7;
+    }
+    out.push(n as u8);
+}
+
+fn write_tag(out: &mut Vec<u8>, number: usize, wire_type: u64) {
+    write_varint(out, (number as u64) << 3 | wire_type);
+}
+
+fn write_len(out: &mut Vec<u8>, number: usize, bytes: &[u8]) {
+    write_tag(out, number, LEN);
+    write_varint(out, bytes.len() as u64);
+    out.extend_from_slice(bytes);
+}
+
+// -----------------------------------------------------------------------------
+// Decoding
+
+struct Reader<'a> {
+    buf: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+    fn varint(&mut self) -> Result<u64, Error> {
+        let mut n = 0;
+        for shift in (0..64).step_by(7) {
+            let (&b, rest) = self.buf.split_first().ok_or_else(eof)?;
+            self.buf = rest;
+            n |= u64::from(b & 0x7F) << shift;
+            if b & 0x80 == 0 {
+                return Ok(n);
+            }
+        }
+        Err(Error::new("varint is too long"))
+    }
+
+    fn bytes(&mut self, len: u64) -> Result<&'a [u8], Error> {
+        match usize::try_from(len) {
+            Ok(len) if len <= self.buf.len() => {
+                let (bytes, rest) = self.buf.split_at(len);
+                self.buf = rest;
+                Ok(bytes)
+            }
+            _ => Err(eof()),
+        }
+    }
+
+    fn len_delimited(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.varint()?;
+        self.bytes(len)
+    }
+
+    fn skip(&mut self, wire_type: u64) -> Result<(), Error> {
+        match wire_type {
+            VARINT => self.varint().map(drop),
+            I64 => self.bytes(8).map(drop),
+            LEN => self.len_delimited().map(drop),
+            I32 => self.bytes(4).map(drop),
+            _ => Err(Error::new(format!("unsupported wire type {}", wire_type))),
+        }
+    }
+}
+
+fn eof() -> Error {
+    Error::new("unexpected end of message")
+}
+
+fn decode_message(msg: &Message, bytes: &[u8], depth: usize) -> Result<Value, Error> {
+    if depth > RECURSION_LIMIT {
+        return Err(Error::new("recursion limit exceeded"));
+    }
+
+    let mut slots: Vec<Option<Value>> = vec![None; msg.fields.len()];
+    let mut last = None;
+    let mut reader = Reader { buf: bytes };
+    while !reader.buf.is_empty() {
+        let key = reader.varint()?;
+        let wire_type = key & 7;
+        let i = match usize::try_from(key >> 3) {
+            Ok(number) if number >= 1 && number <= msg.fields.len() => number - 1,
+            // Unknown fields are skipped, as other implementations do.
+            _ => {
+                reader.skip(wire_type)?;
+                continue;
+            }
+        };
+        let field = &msg.fields[i];
+        match (field.label, field.ty.is_varint(), wire_type) {
+            (Label::Repeated, true, LEN) => {
+                // Packed repeated field.
+                let mut packed = Reader { buf: reader.len_delimited()? };
+                while !packed.buf.is_empty() {
+                    let value = decode_varint(msg, i, packed.varint()?)?;
+                    store(&mut slots[i], field.label, value);
+                }
+            }
+            (_, true, VARINT) => {
+                let value = decode_varint(msg, i, reader.varint()?)?;
+                store(&mut slots[i], field.label, value);
+            }
+            (_, false, LEN) => {
+                let bytes = reader.len_delimited()?;
+                let value = match field.ty {
+                    Ty::String => match String::from_utf8(bytes.to_owned()) {
+                        Ok(s) => Value::Str(s),
+                        Err(_) => return Err(invalid_wire(msg, i, "invalid UTF-8")),
+                    },
+                    Ty::Message(name) => decode_message(message(name), bytes, depth + 1)?,
+                    _ => unreachable!(),
+                };
+                store(&mut slots[i], field.label, value);
+            }
+            _ => return Err(invalid_wire(msg, i, &format!("wire type {}", wire_type))),
+        }
+        last = Some(i);
+    }
+
+    finish(msg, slots, last)
+}
+
+fn invalid_wire(msg: &Message, i: usize, what: &str) -> Error {
+    Error::new(format!("invalid `{}` in {}: {}", msg.fields[i].key, msg.name, what))
+}
+
+fn store(slot: &mut Option<Value>, label: Label, value: Value) {
+    match (label, slot) {
+        (Label::Repeated, Some(Value::Seq(elems))) => elems.push(value),
+        (Label::Repeated, slot) => *slot = Some(Value::Seq(vec![value])),
+        // The last value of a non-repeated field wins.
+        (_, slot) => *slot = Some(value),
+    }
+}
+
+fn decode_varint(msg: &Message, i: usize, n: u64) -> Result<Value, Error> {
+    match msg.fields[i].ty {
+        Ty::Bool => Ok(Value::Bool(n != 0)),
+        Ty::Uint32 if n > u32::MAX.into() => Err(invalid_wire(msg, i, "out of range")),
+        Ty::Uint32 | Ty::Uint64 => Ok(Value::U64(n)),
+        Ty::Enum(name) => {
+            let variant = usize::try_from(n).ok().and_then(|n| message(name).fields.get(n));
+            match variant {
+                Some(variant) => Ok(Value::Str(variant.key.to_owned())),
+                None => Err(invalid_wire(msg, i, &format!("unknown enum value {}", n))),
+            }
+        }
+        Ty::String | Ty::Message(_) => unreachable!(),
+    }
+}
+
+/// Returns the value of a field that is absent from the message, i.e., the
+/// default value of non-optional scalars and repeated fields.
+fn default(field: &Field) -> Option<Value> {
+    match (field.label, field.ty) {
+        (Label::Repeated, _) => Some(Value::Seq(vec![])),
+        (Label::Singular, Ty::Bool) => Some(Value::Bool(false)),
+        (Label::Singular, Ty::Uint32 | Ty::Uint64) => Some(Value::U64(0)),
+        (Label::Singular, Ty::String) => Some(Value::Str(String::new())),
+        (Label::Singular, Ty::Enum(name)) => {
+            Some(Value::Str(message(name).fields[0].key.to_owned()))
+        }
+        _ => None,
+    }
+}
+
+/// Converts the decoded fields of `msg` to the form in which the adapter is
+/// serialized.
+fn finish(
+    msg: &Message,
+    mut slots: Vec<Option<Value>>,
+    last: Option<usize>,
+) -> Result<Value, Error> {
+    match msg.kind {
+        Kind::Token if slots[1..].iter().all(Option::is_none) => {
+            Ok(slots.swap_remove(0).unwrap_or_else(|| Value::Str(String::new())))
+        }
+        Kind::Struct | Kind::Token => {
+            let mut fields = vec![];
+            for (field, slot) in msg.fields.iter().zip(slots) {
+                let value = match slot.or_else(|| default(field)) {
+                    Some(value) => value,
+                    None => continue,
+                };
+                if !field.flatten {
+                    fields.push((field.key.to_owned(), value));
+                } else if let Value::Map(map) = value {
+                    fields.extend(map.fields);
+                } else {
+                    return Err(Error::new(format!("invalid `{}` in {}", field.key, msg.name)));
+                }
+            }
+            Ok(Value::Map(Map { ty: None, fields }))
+        }
+        Kind::Transparent => {
+            Ok(slots.swap_remove(0).or_else(|| default(&msg.fields[0])).unwrap_or(Value::Null))
+        }
+        Kind::Tuple => Ok(Value::Seq(
+            msg.fields
+                .iter()
+                .zip(slots)
+                .map(|(field, slot)| slot.or_else(|| default(field)).unwrap_or(Value::Null))
+                .collect(),
+        )),
+        Kind::Oneof => {
+            let i = last.ok_or_else(|| Error::new(format!("missing variant of {}", msg.name)))?;
+            let field = &msg.fields[i];
+            if field.ty == Ty::Message("Unit") {
+                return Ok(Value::Str(field.key.to_owned()));
+            }
+            let content = slots.swap_remove(i).unwrap();
+            Ok(Value::Map(Map { ty: None, fields: vec![(field.key.to_owned(), content)] }))
+        }
+        // Enums are encoded as varints, not as messages.
+        Kind::Enum => unreachable!(),
+    }
+}
@@ -0,0 +1,397 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! A selector language for locating nodes in syntax trees.
+//!
+//! Selectors are similar to CSS selectors. They match nodes by their kind,
+//! which is the name used for the node in the serialized tree: enum variants
+//! use their tag (`fn`, `method_call`, `let`, ...) and other structs use their
+//! snake-cased type name (`block`, `signature`, `file`, ...).
+//!
+//! | Syntax         | Matches                                                   |
+//! | -------------- | --------------------------------------------------------- |
+//! | `kind`         | nodes of the given kind                                   |
+//! | `*`            | any node                                                  |
+//! | `[a]`          | nodes that have a non-null field `a`                      |
+//! | `[a=v]`        | nodes whose field `a` is `v` (`!=`, `^=`, `$=`, `*=` also work) |
+//! | `[a.b=v]`      | nodes whose field `a` contains a field `b` that is `v`    |
+//! | `x y`          | `y` nodes that are descendants of `x` nodes               |
+//! | `x > y`        | `y` nodes that are direct children of `x` nodes           |
+//! | `x, y`         | nodes that match either `x` or `y`                        |
+//!
+//! Field values are compared with the string representation of identifiers,
+//! literals, booleans, numbers, and unit variants (e.g., `[vis=pub]`).
+//! Values that contain characters other than ASCII alphanumerics, `_`, and `:`
+//! need to be quoted (e.g., `[op="+"]`).
+//!
+//! # Examples
+//!
+//! ```
+//! use syn_serde::query::Query;
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     fn main() {
+//!         let x = foo().unwrap();
+//!         bar().unwrap_or_default();
+//!     }
+//! };
+//!
+//! let query = Query::parse("fn > block method_call[method=unwrap]").unwrap();
+//! let matches = query.find_syn(&syn_file);
+//! assert_eq!(matches.len(), 1);
+//! assert_eq!(matches[0].kind.as_deref(), Some("method_call"));
+//! ```
+
+use core::str::FromStr;
+
+use serde::ser::Serialize;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    Error, SpanInfo, Syn,
+    node::{self, Entry, Value},
+};
+
+/// A parsed selector.
+///
+/// See the [module level documentation](self) for the syntax.
+#[derive(Debug, Clone)]
+pub struct Query {
+    selectors: Vec<Selector>,
+}
+
+/// A node that matched a [`Query`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Match {
+    /// The JSON Pointer (RFC 6901) to the node in the serialized tree.
+    pub path: String,
+    /// The kind of the node, e.g., `"method_call"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// The span of the node, if the node records one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<SpanInfo>,
+}
+
+impl Query {
+    /// Parses a selector.
+    pub fn parse(selector: &str) -> Result<Self, Error> {
+        Parser { s: selector, pos: 0 }.parse()
+    }
+
+    /// Finds all nodes in the given adapter that match this query.
+    ///
+    /// Matches are returned in the order in which they appear in the
+    /// serialized tree.
+    pub fn find<T>(&self, adapter: &T) -> Vec<Match>
+    where
+        T: ?Sized + Serialize,
+    {
+        let root = node::to_value(adapter);
+        let entries = node::index(&root);
+        (0..entries.len())
+            .filter(|&i| self.selectors.iter().any(|s| s.matches(&entries, i)))
+            .map(|i| {
+                let entry = &entries[i];
+                Match {
+                    path: entry.path.clone(),
+                    kind: entry.value.kind(),
+                    span: entry.value.fields().and_then(node::Map::span),
+                }
+            })
+            .collect()
+    }
+
+    /// Finds all nodes in the given [`Syn`] type that match this query.
+    ///
+    /// This function is equivalent to the following code:
+    ///
+    /// ```
+    /// # fn find_syn(query: &syn_serde::query::Query, syn_file: &syn::File) -> Vec<syn_serde::query::Match> {
+    /// use syn_serde::Syn;
+    ///
+    /// let adapter = syn_file.to_adapter();
+    /// query.find(&adapter)
+    /// # }
+    /// ```
+    pub fn find_syn<S>(&self, syn: &S) -> Vec<Match>
+    where
+        S: Syn,
+    {
+        self.find(&syn.to_adapter())
+    }
+}
+
+impl FromStr for Query {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Matching
+
+#[derive(Debug, Clone)]
+struct Selector {
+    /// Compound selectors and the combinators that precede them.
+    /// The combinator of the first compound selector is ignored.
+    parts: Vec<(Combinator, Compound)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+#[derive(Debug, Clone)]
+struct Compound {
+    /// `None` for `*`.
+    kind: Option<String>,
+    attrs: Vec<Attr>,
+}
+
+#[derive(Debug, Clone)]
+struct Attr {
+    path: Vec<String>,
+    op: Option<(Op, String)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Eq,
+    Ne,
+    Prefix,
+    Suffix,
+    Contains,
+}
+
+impl Selector {
+    fn matches(&self, entries: &[Entry<'_>], i: usize) -> bool {
+        self.matches_part(entries, self.parts.len() - 1, i)
+    }
+
+    fn matches_part(&self, entries: &[Entry<'_>], part: usize, i: usize) -> bool {
+        let (combinator, compound) = &self.parts[part];
+        if !compound.matches(entries[i].value) {
+            return false;
+        }
+        if part == 0 {
+            return true;
+        }
+        let mut parent = entries[i].parent;
+        match combinator {
+            Combinator::Child => parent.map_or(false, |p| self.matches_part(entries, part - 1, p)),
+            Combinator::Descendant => {
+                while let Some(p) = parent {
+                    if self.matches_part(entries, part - 1, p) {
+                        return true;
+                    }
+                    parent = entries[p].parent;
+                }
+                false
+            }
+        }
+    }
+}
+
+impl Compound {
+    fn matches(&self, value: &Value) -> bool {
+        if let Some(kind) = &self.kind {
+            let ty = value.ty().map(node::snake_case);
+            if value.kind().as_ref() != Some(kind) && ty.as_ref() != Some(kind) {
+                return false;
+            }
+        }
+        self.attrs.iter().all(|attr| attr.matches(value))
+    }
+}
+
+impl Attr {
+    fn matches(&self, value: &Value) -> bool {
+        let mut field = Some(value);
+        for name in &self.path {
+            field = field.and_then(Value::fields).and_then(|map| map.get(name));
+        }
+        let field = field.filter(|v| **v != Value::Null);
+        match &self.op {
+            None => field.is_some(),
+            Some((op, expected)) => {
+                let actual = field.and_then(Value::as_scalar);
+                match (op, actual) {
+                    (Op::Ne, actual) => actual.as_ref() != Some(expected),
+                    (_, None) => false,
+                    (Op::Eq, Some(actual)) => actual == *expected,
+                    (Op::Prefix, Some(actual)) => actual.starts_with(expected.as_str()),
+                    (Op::Suffix, Some(actual)) => actual.ends_with(expected.as_str()),
+                    (Op::Contains, Some(actual)) => actual.contains(expected.as_str()),
+                }
+            }
+        }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Parsing
+
+struct Parser<'a> {
+    s: &'a str,
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn parse(mut self) -> Result<Query, Error> {
+        let mut selectors = vec![self.selector()?];
+        while self.eat(',') {
+            selectors.push(self.selector()?);
+        }
+        self.skip_whitespace();
+        if let Some(ch) = self.peek() {
+            return Err(Error::at(format!("unexpected character `{}`", ch), self.pos));
+        }
+        Ok(Query { selectors })
+    }
+
+    fn selector(&mut self) -> Result<Selector, Error> {
+        self.skip_whitespace();
+        let mut parts = vec![(Combinator::Descendant, self.compound()?)];
+        loop {
+            let had_whitespace = self.skip_whitespace();
+            let combinator = if self.eat('>') {
+                Combinator::Child
+            } else if had_whitespace && self.peek().map_or(false, |ch| ch != ',') {
+                Combinator::Descendant
+            } else {
+                break;
+            };
+            self.skip_whitespace();
+            parts.push((combinator, self.compound()?));
+        }
+        Ok(Selector { parts })
+    }
+
+    fn compound(&mut self) -> Result<Compound, Error> {
+        let kind = if self.eat('*') || self.peek() == Some('[') {
+            None
+        } else {
+            Some(self.ident("node kind")?)
+        };
+        let mut attrs = vec![];
+        while self.eat('[') {
+            self.skip_whitespace();
+            let mut path = vec![self.ident("field name")?];
+            while self.eat('.') {
+                path.push(self.ident("field name")?);
+            }
+            self.skip_whitespace();
+            let op = if self.eat(']') {
+                attrs.push(Attr { path, op: None });
+                continue;
+            } else if self.eat('=') {
+                Op::Eq
+            } else if self.eat_str("!=") {
+                Op::Ne
+            } else if self.eat_str("^=") {
+                Op::Prefix
+            } else if self.eat_str("$=") {
+                Op::Suffix
+            } else if self.eat_str("*=") {
+                Op::Contains
+            } else {
+                return Err(self.expected("`]` or an operator"));
+            };
+            self.skip_whitespace();
+            let value = self.value()?;
+            self.skip_whitespace();
+            if !self.eat(']') {
+                return Err(self.expected("`]`"));
+            }
+            attrs.push(Attr { path, op: Some((op, value)) });
+        }
+        Ok(Compound { kind, attrs })
+    }
+
+    fn ident(&mut self, what: &str) -> Result<String, Error> {
+        let start = self.pos;
+        while self.peek().map_or(false, |ch| ch.is_ascii_alphanumeric() || ch == '_') {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(self.expected(what));
+        }
+        Ok(self.s[start..self.pos].to_owned())
+    }
+
+    fn value(&mut self) -> Result<String, Error> {
+        if let Some(quote @ ('"' | '\'')) = self.peek() {
+            let start = self.pos;
+            self.pos += 1;
+            let mut value = String::new();
+            loop {
+                match self.bump() {
+                    Some('\\') => match self.bump() {
+                        Some(ch) => value.push(ch),
+                        None => break,
+                    },
+                    Some(ch) if ch == quote => return Ok(value),
+                    Some(ch) => value.push(ch),
+                    None => break,
+                }
+            }
+            return Err(Error::at("unterminated string", start));
+        }
+        let start = self.pos;
+        while self.peek().map_or(false, |ch| ch.is_ascii_alphanumeric() || matches!(ch, '_' | ':'))
+        {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Err(self.expected("a value"));
+        }
+        Ok(self.s[start..self.pos].to_owned())
+    }
+
+    fn expected(&self, what: &str) -> Error {
+        match self.peek() {
+            Some(ch) => Error::at(format!("expected {}, found `{}`", what, ch), self.pos),
+            None => Error::at(format!("expected {}, found end of input", what), self.pos),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.s[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn eat(&mut self, ch: char) -> bool {
+        if self.peek() == Some(ch) {
+            self.pos += ch.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn eat_str(&mut self, s: &str) -> bool {
+        if self.s[self.pos..].starts_with(s) {
+            self.pos += s.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn skip_whitespace(&mut self) -> bool {
+        let start = self.pos;
+        while self.peek().map_or(false, char::is_whitespace) {
+            self.bump();
+        }
+        start != self.pos
+    }
+}
@@ -0,0 +1,539 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Structural search-and-replace.
+//!
+//! A [`Rule`] consists of a pattern and a template, both written as Rust code
+//! in which `$name` marks a metavariable. The pattern matches any node with
+//! the same syntax tree, ignoring spans and comments, and each metavariable
+//! matches an arbitrary node in its position. Matched nodes are replaced with
+//! the template, with the metavariables substituted by the nodes they matched.
+//!
+//! - A metavariable that appears more than once in a pattern only matches if
+//!   all of its occurrences match the same syntax.
+//! - `$_` matches any node without binding it.
+//! - Metavariables can be used where an expression, type, pattern, path,
+//!   identifier, or single token is expected.
+//!
+//! Patterns are parsed as an expression, type, item, statement, or pattern,
+//! in that order; the first kind that parses is used, and the template must
+//! parse as the same kind.
+//!
+//! The comments of a replaced node are kept: they are attached to the first
+//! node in the replacement that can hold comments, or to the nearest enclosing
+//! node that can if there is no such node. The replacement inherits the span
+//! of the replaced node.
+//!
+//! # Examples
+//!
+//! ```
+//! use quote::ToTokens;
+//! use syn_serde::rewrite::Rule;
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     fn main() {
+//!         let x = foo().unwrap();
+//!     }
+//! };
+//!
+//! let rule = Rule::new("$x.unwrap()", r#"$x.expect("TODO")"#).unwrap();
+//! let syn_file = rule.rewrite_syn(&syn_file).unwrap();
+//!
+//! let expected: syn::File = syn::parse_quote! {
+//!     fn main() {
+//!         let x = foo().expect("TODO");
+//!     }
+//! };
+//! assert_eq!(syn_file.into_token_stream().to_string(), expected.into_token_stream().to_string(),);
+//! ```
+
+use proc_macro2::{Group, Ident, TokenStream, TokenTree};
+use serde::{de::DeserializeOwned, ser::Serialize};
+use syn::parse::Parser as _;
+
+use crate::{
+    Error, Syn,
+    ast_struct::COMMENTED,
+    node::{self, Value, Variant},
+    query::Match,
+};
+
+/// The prefix of the identifiers that `$name` is replaced with before parsing.
+const METAVAR_PREFIX: &str = "__syn_serde_metavar_";
+
+/// A structural search-and-replace rule.
+///
+/// See the [module level documentation](self) for more.
+#[derive(Debug, Clone)]
+pub struct Rule {
+    pattern: Value,
+    template: Value,
+}
+
+impl Rule {
+    /// Creates a rule that replaces nodes that match `pattern` with
+    /// `template`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `pattern` or `template` cannot be parsed, if the
+    /// pattern consists of a single metavariable, or if the template uses a
+    /// metavariable that the pattern does not bind.
+    pub fn new(pattern: &str, template: &str) -> Result<Self, Error> {
+        let pattern_tokens =
+            tokenize(pattern).map_err(|e| Error::new(format!("failed to parse pattern: {}", e)))?;
+        let template_tokens = tokenize(template)
+            .map_err(|e| Error::new(format!("failed to parse template: {}", e)))?;
+
+        let mut kinds = KINDS.iter();
+        let (kind, pattern) = loop {
+            let kind = match kinds.next() {
+                Some(kind) => kind,
+                None => return Err(Error::new("failed to parse pattern")),
+            };
+            if let Ok(value) = kind.parse(pattern_tokens.clone()) {
+                break (kind, value);
+            }
+        };
+        let template = kind.parse(template_tokens).map_err(|e| {
+            Error::new(format!("failed to parse template as {}: {}", kind.name(), e))
+        })?;
+
+        if metavar(&pattern).is_some() {
+            return Err(Error::new("pattern must not consist of a single metavariable"));
+        }
+        let mut bound = vec![];
+        collect_metavars(&pattern, &mut bound);
+        let mut used = vec![];
+        collect_metavars(&template, &mut used);
+        for name in used {
+            if name == "_" {
+                return Err(Error::new("`$_` cannot be used in a template"));
+            }
+            if !bound.contains(&name) {
+                return Err(Error::new(format!(
+                    "metavariable `${}` is not bound by the pattern",
+                    name
+                )));
+            }
+        }
+
+        Ok(Self { pattern, template })
+    }
+
+    /// Finds all nodes in the given adapter that match the pattern of this
+    /// rule.
+    ///
+    /// Matches are returned in the order in which they appear in the
+    /// serialized tree.
+    pub fn find<T>(&self, adapter: &T) -> Vec<Match>
+    where
+        T: ?Sized + Serialize,
+    {
+        let root = node::to_value(adapter);
+        node::index(&root)
+            .into_iter()
+            .filter(|entry| self.captures(entry.value).is_some())
+            .map(|entry| Match {
+                path: entry.path,
+                kind: entry.value.kind(),
+                span: entry.value.fields().and_then(node::Map::span),
+            })
+            .collect()
+    }
+
+    /// Finds all nodes in the given [`Syn`] type that match the pattern of
+    /// this rule.
+    ///
+    /// This function is equivalent to the following code:
+    ///
+    /// ```
+    /// # fn find_syn(rule: &syn_serde::rewrite::Rule, syn_file: &syn::File) -> Vec<syn_serde::query::Match> {
+    /// use syn_serde::Syn;
+    ///
+    /// let adapter = syn_file.to_adapter();
+    /// rule.find(&adapter)
+    /// # }
+    /// ```
+    pub fn find_syn<S>(&self, syn: &S) -> Vec<Match>
+    where
+        S: Syn,
+    {
+        self.find(&syn.to_adapter())
+    }
+
+    /// Replaces all nodes in the given adapter that match the pattern of this
+    /// rule with the template.
+    ///
+    /// Nodes are rewritten from the inside out, so metavariables bind nodes
+    /// that have already been rewritten.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a replacement is not valid in the position of the
+    /// replaced node, e.g., because a metavariable that matched an expression
+    /// is used where a type is expected.
+    pub fn rewrite<T>(&self, adapter: &T) -> Result<T, Error>
+    where
+        T: Serialize + DeserializeOwned,
+    {
+        let mut root = node::to_value(adapter);
+        // Comments that reach the root have nowhere else to go.
+        let _orphans = self.rewrite_value(&mut root);
+        node::from_value(root)
+            .map_err(|e| Error::new(format!("rewritten syntax tree is invalid: {}", e)))
+    }
+
+    /// Replaces all nodes in the given [`Syn`] type that match the pattern of
+    /// this rule with the template.
+    ///
+    /// This function is equivalent to the following code:
+    ///
+    /// ```
+    /// # fn rewrite_syn(rule: &syn_serde::rewrite::Rule, syn_file: &syn::File) -> Result<syn::File, syn_serde::Error> {
+    /// use syn_serde::Syn;
+    ///
+    /// let adapter = syn_file.to_adapter();
+    /// let adapter = rule.rewrite(&adapter)?;
+    /// Ok(syn::File::from_adapter(&adapter))
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// See [`rewrite`](Self::rewrite).
+    pub fn rewrite_syn<S>(&self, syn: &S) -> Result<S, Error>
+    where
+        S: Syn,
+    {
+        let adapter = self.rewrite(&syn.to_adapter())?;
+        Ok(S::from_adapter(&adapter))
+    }
+
+    /// Rewrites the given value and its descendants, and returns the comments
+    /// that could not be attached to any of them.
+    fn rewrite_value(&self, value: &mut Value) -> Vec<Value> {
+        let mut orphans = vec![];
+        match value {
+            Value::Map(map) => {
+                for (_, value) in &mut map.fields {
+                    orphans.extend(self.rewrite_value(value));
+                }
+            }
+            Value::Seq(elems) => {
+                for value in elems {
+                    orphans.extend(self.rewrite_value(value));
+                }
+            }
+            Value::Variant(Variant { value: Some(value), .. }) => {
+                orphans.extend(self.rewrite_value(value));
+            }
+            _ => {}
+        }
+        if !orphans.is_empty() && accepts_comments(value) {
+            add_comments(value, orphans.drain(..));
+        }
+
+        if let Some(captures) = self.captures(value) {
+            let mut replacement = substitute(&self.template, &captures);
+            let span = value.fields().and_then(|map| map.get("span"));
+            let template_has_span =
+                self.template.fields().map_or(false, |map| map.get("span").is_some());
+            if let (Some(span), true) = (span, template_has_span) {
+                if let Some(map) = replacement.fields_mut() {
+                    map.fields.push(("span".to_owned(), span.clone()));
+                }
+            }
+            if !captures.comments.is_empty() {
+                match comment_holder(&mut replacement) {
+                    Some(holder) => add_comments(holder, captures.comments.into_iter()),
+                    None => orphans.extend(captures.comments),
+                }
+            }
+            *value = replacement;
+        }
+        orphans
+    }
+
+    fn captures(&self, value: &Value) -> Option<Captures> {
+        let mut captures = Captures { bindings: vec![], comments: vec![] };
+        if match_value(&self.pattern, value, &mut captures) { Some(captures) } else { None }
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Parsing
+
+/// The kinds of syntax that patterns are parsed as, in order of preference.
+const KINDS: &[Kind] = &[Kind::Expr, Kind::Type, Kind::Item, Kind::Stmt, Kind::Pat];
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    Expr,
+    Type,
+    Item,
+    Stmt,
+    Pat,
+}
+
+impl Kind {
+    fn name(self) -> &'static str {
+        match self {
+            Kind::Expr => "an expression",
+            Kind::Type => "a type",
+            Kind::Item => "an item",
+            Kind::Stmt => "a statement",
+            Kind::Pat => "a pattern",
+        }
+    }
+
+    fn parse(self, tokens: TokenStream) -> syn::Result<Value> {
+        Ok(match self {
+            Kind::Expr => node::to_value(&syn::parse2::<syn::Expr>(tokens)?.to_adapter()),
+            Kind::Type => node::to_value(&syn::parse2::<syn::Type>(tokens)?.to_adapter()),
+            Kind::Item => node::to_value(&syn::parse2::<syn::Item>(tokens)?.to_adapter()),
+            Kind::Stmt => node::to_value(&syn::parse2::<syn::Stmt>(tokens)?.to_adapter()),
+            Kind::Pat => node::to_value(&syn::Pat::parse_single.parse2(tokens)?.to_adapter()),
+        })
+    }
+}
+
+/// Tokenizes the given code, replacing each `$name` with an identifier that
+/// the matcher recognizes as a metavariable.
+fn tokenize(code: &str) -> Result<TokenStream, String> {
+    let tokens: TokenStream = code.parse().map_err(|e: proc_macro2::LexError| e.to_string())?;
+    replace_metavars(tokens)
+}
+
+fn replace_metavars(tokens: TokenStream) -> Result<TokenStream, String> {
+    let mut out = vec![];
+    let mut iter = tokens.into_iter();
+    while let Some(tt) = iter.next() {
+        match tt {
+            TokenTree::Punct(punct) if punct.as_char() == '$' => match iter.next() {
+                Some(TokenTree::Ident(ident)) => {
+                    let name = format!("{}{}", METAVAR_PREFIX, ident);
+                    out.push(TokenTree::Ident(Ident::new(&name, ident.span())));
+                }
+                _ => return Err("expected metavariable name after `$`".to_owned()),
+            },
+            TokenTree::Group(group) => {
+                let mut new = Group::new(group.delimiter(), replace_metavars(group.stream())?);
+                new.set_span(group.span());
+                out.push(TokenTree::Group(new));
+            }
+            tt => out.push(tt),
+        }
+    }
+    Ok(out.into_iter().collect())
+}
+
+/// Returns the name of the metavariable if the given value consists of a
+/// single metavariable.
+///
+/// A metavariable is parsed as an identifier, which can be wrapped in a path
+/// (`Expr::Path`, `Type::Path`), a binding (`Pat::Ident`), or a token.
+fn metavar(value: &Value) -> Option<&str> {
+    match value {
+        Value::Str(s) => s.strip_prefix(METAVAR_PREFIX),
+        // A variant that directly contains another variant, such as
+        // `GenericArgument::Type(Type::Path(..))`, is not a metavariable
+        // itself; the inner variant is.
+        Value::Variant(Variant { value: Some(value), .. })
+            if !matches!(**value, Value::Variant(_)) =>
+        {
+            metavar(value)
+        }
+        Value::Map(map) => {
+            let mut fields = map.fields.iter().filter(|(_, value)| !value.is_metadata());
+            match (fields.next(), fields.next()) {
+                (Some((key, Value::Seq(elems))), None) if key == "segments" && elems.len() == 1 => {
+                    metavar(&elems[0])
+                }
+                (Some((key, value)), None) if key == "ident" || key == "path" => metavar(value),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+fn collect_metavars(value: &Value, names: &mut Vec<String>) {
+    if let Some(name) = metavar(value) {
+        names.push(name.to_owned());
+        return;
+    }
+    match value {
+        Value::Map(map) => {
+            for (_, value) in &map.fields {
+                collect_metavars(value, names);
+            }
+        }
+        Value::Seq(elems) => {
+            for value in elems {
+                collect_metavars(value, names);
+            }
+        }
+        Value::Variant(Variant { value: Some(value), .. }) => collect_metavars(value, names),
+        _ => {}
+    }
+}
+
+// -----------------------------------------------------------------------------
+// Matching
+
+struct Captures {
+    bindings: Vec<(String, Value)>,
+    /// Comments of the matched node that are not part of any binding.
+    comments: Vec<Value>,
+}
+
+fn match_value(pattern: &Value, value: &Value, captures: &mut Captures) -> bool {
+    if let Some(name) = metavar(pattern) {
+        if name == "_" {
+            return true;
+        }
+        if let Some((_, bound)) = captures.bindings.iter().find(|(n, _)| n == name) {
+            return same_syntax(bound, value);
+        }
+        captures.bindings.push((name.to_owned(), value.clone()));
+        return true;
+    }
+    match (pattern, value) {
+        (Value::Map(pattern), Value::Map(value)) => {
+            for (_, field) in &value.fields {
+                if let Value::Seq(comments) = field {
+                    if field.is_metadata() {
+                        captures.comments.extend(comments.iter().cloned());
+                    }
+                }
+            }
+            fields_match(pattern, value, |p, v| match_value(p, v, captures))
+        }
+        (Value::Seq(pattern), Value::Seq(value)) => {
+            let (pattern, _) = node::split_trailing(pattern);
+            let (value, _) = node::split_trailing(value);
+            pattern.len() == value.len()
+                && pattern.iter().zip(value).all(|(p, v)| match_value(p, v, captures))
+        }
+        (Value::Variant(pattern), Value::Variant(value)) => {
+            pattern.name == value.name
+                && match (&pattern.value, &value.value) {
+                    (None, None) => true,
+                    (Some(p), Some(v)) => match_value(p, v, captures),
+                    _ => false,
+                }
+        }
+        _ => pattern == value,
+    }
+}
+
+/// Returns `true` if the given values represent the same syntax, ignoring
+/// spans and comments.
+fn same_syntax(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Map(a), Value::Map(b)) => fields_match(a, b, same_syntax),
+        (Value::Seq(a), Value::Seq(b)) => {
+            let (a, _) = node::split_trailing(a);
+            let (b, _) = node::split_trailing(b);
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| same_syntax(a, b))
+        }
+        (Value::Variant(a), Value::Variant(b)) => {
+            a.name == b.name
+                && match (&a.value, &b.value) {
+                    (None, None) => true,
+                    (Some(a), Some(b)) => same_syntax(a, b),
+                    _ => false,
+                }
+        }
+        _ => a == b,
+    }
+}
+
+/// Compares the fields of two maps other than spans, comments, and tokens
+/// that are only serialized in lossless mode.
+fn fields_match<F>(a: &node::Map, b: &node::Map, mut f: F) -> bool
+where
+    F: FnMut(&Value, &Value) -> bool,
+{
+    if a.ty.is_some() && b.ty.is_some() && a.ty != b.ty {
+        return false;
+    }
+    let syntax = |(key, value): &&(String, Value)| {
+        !value.is_metadata() && node::lossless_field(key).is_none()
+    };
+    let a_fields = a.fields.iter().filter(syntax);
+    let b_len = b.fields.iter().filter(syntax).count();
+    let mut a_len = 0;
+    for (key, a_value) in a_fields {
+        a_len += 1;
+        match b.get(key) {
+            Some(b_value) if f(a_value, b_value) => {}
+            _ => return false,
+        }
+    }
+    a_len == b_len
+}
+
+// -----------------------------------------------------------------------------
+// Substitution
+
+/// Instantiates the template. Spans and comments of the template are dropped
+/// since they refer to the template string, but its punctuation and optional
+/// tokens are kept.
+fn substitute(template: &Value, captures: &Captures) -> Value {
+    if let Some(name) = metavar(template) {
+        // `Rule::new` checks that all metavariables in the template are bound.
+        return captures.bindings.iter().find(|(n, _)| n == name).unwrap().1.clone();
+    }
+    match template {
+        Value::Map(map) => Value::Map(node::Map {
+            ty: map.ty,
+            fields: map
+                .fields
+                .iter()
+                .filter(|(key, value)| !value.is_metadata() || node::lossless_field(key).is_some())
+                .map(|(key, value)| (key.clone(), substitute(value, captures)))
+                .collect(),
+        }),
+        Value::Seq(elems) => Value::Seq(elems.iter().map(|v| substitute(v, captures)).collect()),
+        Value::Variant(Variant { name, value }) => Value::Variant(Variant {
+            name,
+            value: value.as_ref().map(|v| Box::new(substitute(v, captures))),
+        }),
+        _ => template.clone(),
+    }
+}
+
+fn accepts_comments(value: &Value) -> bool {
+    value.fields().map_or(false, |map| {
+        map.ty.map_or(false, |ty| COMMENTED.contains(&ty)) || map.get("comments").is_some()
+    })
+}
+
+/// Finds the first node in pre-order that can hold comments.
+fn comment_holder(value: &mut Value) -> Option<&mut Value> {
+    if accepts_comments(value) {
+        return Some(value);
+    }
+    match value {
+        Value::Map(map) => map.fields.iter_mut().find_map(|(_, value)| comment_holder(value)),
+        Value::Seq(elems) => elems.iter_mut().find_map(comment_holder),
+        Value::Variant(Variant { value: Some(value), .. }) => comment_holder(value),
+        _ => None,
+    }
+}
+
+fn add_comments<I>(value: &mut Value, comments: I)
+where
+    I: Iterator<Item = Value>,
+{
+    let map = value.fields_mut().unwrap();
+    if let Some((_, Value::Seq(elems))) = map.fields.iter_mut().find(|(key, _)| key == "comments") {
+        elems.extend(comments);
+        return;
+    }
+    let comments: Vec<_> = comments.collect();
+    if !comments.is_empty() {
+        map.fields.push(("comments".to_owned(), Value::Seq(comments)));
+    }
+}
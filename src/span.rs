@@ -27,6 +27,11 @@
 use proc_macro2::Span;
 use serde_derive::{Deserialize, Serialize};
 
+use crate::{
+    node::Value,
+    visit_mut::{Node, VisitMut},
+};
+
 /// Serializable representation of span information.
 /// 
 /// This preserves location information from the original source code,
@@ -39,7 +44,7 @@ use serde_derive::{Deserialize, Serialize};
 /// The `start_offset` and `end_offset` fields are currently set to 0 because
 /// `proc_macro2::Span` doesn't expose byte offset information directly. These
 /// fields are reserved for future use or can be populated by external tools.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct SpanInfo {
     /// Byte offset of the start of the span (currently always 0)
     pub start_offset: usize,
@@ -53,6 +58,11 @@ pub struct SpanInfo {
     pub end_line: usize,
     /// Column number (0-based) of the end of the span
     pub end_column: usize,
+    /// The id of the file that contains the span, in documents that contain
+    /// several files, such as [`Crate`](crate::krate::Crate). See also
+    /// [`Syn::to_adapter_in_file`](crate::Syn::to_adapter_in_file).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file: Option<usize>,
 }
 
 impl SpanInfo {
@@ -72,6 +82,7 @@ impl SpanInfo {
     /// let span_info = SpanInfo::from_span(span);
     /// assert!(span_info.start_line >= 1);
     /// ```
+    #[must_use]
     pub fn from_span(span: Span) -> Self {
         // Try to extract span location information
         // This uses runtime feature detection instead of compile-time cfg
@@ -88,6 +99,7 @@ impl SpanInfo {
                     start_column,
                     end_line,
                     end_column,
+                    file: None,
                 }
             }
             Err(_) => {
@@ -99,11 +111,33 @@ impl SpanInfo {
                     start_column: 0,
                     end_line: 1,
                     end_column: 0,
+                    file: None,
                 }
             }
         }
     }
     
+    /// Create a SpanInfo from a proc_macro2::Span in the file with the given
+    /// id.
+    ///
+    /// `proc_macro2::Span` does not know which file it came from, so the
+    /// caller provides it, e.g., as an index into the
+    /// [`files`](crate::krate::Crate::files) of a crate.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use syn_serde::SpanInfo;
+    /// use proc_macro2::Span;
+    ///
+    /// let span_info = SpanInfo::from_span_in_file(Span::call_site(), 1);
+    /// assert_eq!(span_info.file, Some(1));
+    /// ```
+    #[must_use]
+    pub fn from_span_in_file(span: Span, file: usize) -> Self {
+        Self { file: Some(file), ..Self::from_span(span) }
+    }
+
     /// Convert back to a proc_macro2::Span.
     /// 
     /// Note: This creates a span at `call_site()` since `proc_macro2`
@@ -122,6 +156,9 @@ impl SpanInfo {
     /// 
     /// // The restored span will be call_site(), but span_info retains the location data
     /// ```
+    #[must_use]
+    // Kept as a method taking `&self` for API compatibility and symmetry with `from_span`.
+    #[allow(clippy::unused_self, clippy::wrong_self_convention)]
     pub fn to_span(&self) -> Span {
         Span::call_site()
     }
@@ -129,11 +166,13 @@ impl SpanInfo {
     /// Create a default SpanInfo (used when span information is not available).
     ///
     /// This is equivalent to `SpanInfo::from_span(Span::call_site())`.
+    #[must_use]
     pub fn call_site() -> Self {
         Self::from_span(Span::call_site())
     }
 
     /// Check if this span represents a single point (start == end).
+    #[must_use]
     pub fn is_point(&self) -> bool {
         self.start_line == self.end_line && self.start_column == self.end_column
     }
@@ -141,6 +180,7 @@ impl SpanInfo {
     /// Get the length in columns (for single-line spans).
     /// 
     /// Returns `None` if the span crosses multiple lines.
+    #[must_use]
     pub fn column_length(&self) -> Option<usize> {
         if self.start_line == self.end_line {
             Some(self.end_column.saturating_sub(self.start_column))
@@ -167,4 +207,42 @@ impl From<&SpanInfo> for Span {
     fn from(_span_info: &SpanInfo) -> Self {
         Span::call_site()
     }
-}
\ No newline at end of file
+}
+
+/// Records `file` as the file of all spans in an adapter.
+pub(crate) fn set_file<T: Node>(file: usize, mut adapter: T) -> T {
+    adapter.visit_mut(&mut SetFile(file));
+    adapter
+}
+
+/// Records the given file as the file of all spans in an adapter.
+pub(crate) struct SetFile(pub(crate) usize);
+
+impl VisitMut for SetFile {
+    fn visit_span_info_mut(&mut self, span: &mut SpanInfo) {
+        span.file = Some(self.0);
+    }
+}
+
+/// Replaces the spans in `value` with `span`, or removes them if `span` is
+/// `None`.
+pub(crate) fn set_spans(value: &mut Value, span: Option<&Value>) {
+    let mut stack = vec![value];
+    while let Some(value) = stack.pop() {
+        match value {
+            Value::Map(map) => {
+                map.fields.retain(|(k, _)| k != "span" || span.is_some());
+                for (k, v) in &mut map.fields {
+                    if k == "span" {
+                        *v = span.cloned().unwrap_or(Value::Null);
+                    } else {
+                        stack.push(v);
+                    }
+                }
+            }
+            Value::Seq(elems) => stack.extend(elems),
+            Value::Variant(variant) => stack.extend(variant.value.as_deref_mut()),
+            _ => {}
+        }
+    }
+}
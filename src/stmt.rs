@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use serde::de::Deserializer;
+
 use super::*;
 pub use crate::ast_struct::{Block, Local, LocalInit, StmtMacro};
 
@@ -9,8 +11,28 @@ ast_enum! {
         #[serde(rename = "let")]
         Local(Local),
         Item(Item),
-        // TODO: Should be Expr { expr: Exor, semi: bool }?
-        Expr(Expr, bool),
+        // Also accepts the `[expr, semi]` form of older versions.
+        #[serde(deserialize_with = "stmt_expr")]
+        Expr {
+            expr: Expr,
+            #[serde(default, skip_serializing_if = "explicit::skip")]
+            semi: bool,
+        },
         Macro(StmtMacro),
     }
 }
+
+fn stmt_expr<'de, D>(deserializer: D) -> Result<(Expr, bool), D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // Derived `Deserialize` of structs accepts both maps and sequences.
+    #[derive(Deserialize)]
+    struct StmtExpr {
+        expr: Expr,
+        #[serde(default)]
+        semi: bool,
+    }
+
+    StmtExpr::deserialize(deserializer).map(|StmtExpr { expr, semi }| (expr, semi))
+}
@@ -0,0 +1,581 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Symbol indexes of syntax trees.
+//!
+//! [`index`] lists the named items of a file: modules, functions, structs,
+//! enums, unions, traits, trait aliases, constants, statics, type aliases,
+//! and `macro_rules!` macros, as well as the functions, constants, and types
+//! of impls, traits, and `extern` blocks. Items inside function bodies are
+//! not listed, since they cannot be named from outside.
+//!
+//! Each [`Symbol`] records the fully qualified path of the item, its
+//! visibility, its generics, its signature as Rust code, and its span, so it
+//! can serve as the index of code search or ctags-like jump-to-definition.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn dox() {
+//! use syn_serde::{
+//!     Syn as _,
+//!     symbols::{self, SymbolKind},
+//! };
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     pub mod shapes {
+//!         pub struct Square<T> {
+//!             side: T,
+//!         }
+//!
+//!         impl<T: Copy> Square<T> {
+//!             pub fn new(side: T) -> Self {
+//!                 Self { side }
+//!             }
+//!         }
+//!     }
+//! };
+//!
+//! let symbols = symbols::index(&syn_file.to_adapter());
+//! let new = &symbols[2];
+//! assert_eq!(new.kind, SymbolKind::Method);
+//! assert_eq!(new.path, "crate::shapes::Square::new");
+//! assert_eq!(new.parent.as_deref(), Some("impl<T: Copy> Square<T>"));
+//! assert_eq!(new.signature, "pub fn new(side: T) -> Self");
+//! # }
+//! # fn main() {} // rustdoc bug: https://github.com/rust-lang/rust/issues/131893
+//! ```
+
+use core::mem;
+
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+use quote::quote;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    File, ForeignItem, ImplItem, Item, ItemMod, ItemTrait, MapInto as _, SpanInfo, TraitItem,
+    TypeParamBound, default, lossless::Punctuated,
+};
+
+/// A named item in a syntax tree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Symbol {
+    /// The name of the item.
+    pub name: String,
+    /// The kind of the item.
+    pub kind: SymbolKind,
+    /// The fully qualified path of the item, e.g., `crate::a::Square::new`.
+    /// Items of impls are qualified with the name of the self type, and items
+    /// of traits with the name of the trait.
+    pub path: String,
+    /// The path of the module that contains the item, e.g., `crate::a`.
+    pub module: String,
+    /// The header of the impl or the trait that contains the item, e.g.,
+    /// `impl<T> Display for Square<T>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+    /// The visibility of the item, e.g., `pub(crate)`, or an empty string if
+    /// the item is private. Items of traits have the visibility of the
+    /// trait, and `#[macro_export]` macros are `pub`.
+    pub visibility: String,
+    /// The generic parameters of the item, e.g., `<'a, T: Clone>`, or an empty
+    /// string if the item has none.
+    pub generics: String,
+    /// The signature of the item as Rust code, e.g.,
+    /// `pub fn new(side: T) -> Self`.
+    pub signature: String,
+    /// The span of the name of the item.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<SpanInfo>,
+}
+
+/// The kind of a [`Symbol`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SymbolKind {
+    /// `mod`
+    Mod,
+    /// `fn` outside of impls and traits
+    Fn,
+    /// `fn` in impls and traits
+    Method,
+    /// `struct`
+    Struct,
+    /// `enum`
+    Enum,
+    /// `union`
+    Union,
+    /// `trait`
+    Trait,
+    /// `trait Alias = ...`
+    TraitAlias,
+    /// `const`
+    Const,
+    /// `static`
+    Static,
+    /// `type`
+    Type,
+    /// `macro_rules!`
+    Macro,
+}
+
+/// Returns the named items of a file, in the order in which they appear.
+///
+/// See the [module level documentation](self) for more.
+#[must_use]
+pub fn index(file: &File) -> Vec<Symbol> {
+    let mut indexer = Indexer { module: "crate".to_owned(), symbols: vec![] };
+    indexer.items(&file.items);
+    indexer.symbols
+}
+
+struct Indexer {
+    /// The path of the current module.
+    module: String,
+    symbols: Vec<Symbol>,
+}
+
+/// The impl or the trait that contains associated items.
+struct Parent {
+    /// The path that the items are qualified with.
+    path: String,
+    header: String,
+    /// The visibility of the items of traits.
+    vis: Option<String>,
+}
+
+/// A named item, before it is qualified with its module and parent.
+struct Def {
+    kind: SymbolKind,
+    name: String,
+    vis: syn::Visibility,
+    generics: syn::Generics,
+    signature: TokenStream,
+    span: Option<SpanInfo>,
+}
+
+impl Def {
+    fn new(
+        kind: SymbolKind,
+        ident: &proc_macro2::Ident,
+        vis: syn::Visibility,
+        generics: syn::Generics,
+        signature: TokenStream,
+        span: Option<SpanInfo>,
+    ) -> Self {
+        Self { kind, name: ident.to_string(), vis, generics, signature, span }
+    }
+}
+
+impl Indexer {
+    fn push(&mut self, def: Def, parent: Option<&Parent>) {
+        let Def { kind, name, vis, generics, signature, span } = def;
+        let path = format!("{}::{}", parent.map_or(&self.module, |parent| &parent.path), name);
+        let visibility = match parent.and_then(|parent| parent.vis.as_ref()) {
+            Some(vis) => vis.clone(),
+            None => print(&quote!(#vis)),
+        };
+        self.symbols.push(Symbol {
+            name,
+            kind,
+            path,
+            module: self.module.clone(),
+            parent: parent.map(|parent| parent.header.clone()),
+            visibility,
+            generics: print(&quote!(#generics)),
+            signature: print(&signature),
+            span,
+        });
+    }
+
+    fn items(&mut self, items: &[Item]) {
+        for item in items {
+            self.item(item);
+        }
+    }
+
+    fn item(&mut self, item: &Item) {
+        match item {
+            Item::Mod(item) => {
+                let def = item_def_mod(item);
+                let name = def.name.clone();
+                self.push(def, None);
+                if let Some(items) = &item.content {
+                    let len = self.module.len();
+                    self.module.push_str("::");
+                    self.module.push_str(&name);
+                    self.items(items);
+                    self.module.truncate(len);
+                }
+            }
+            Item::Trait(item) => {
+                let vis = syn::Visibility::from(&item.vis);
+                let ident = proc_macro2::Ident::from(&item.ident);
+                let generics = syn::Generics::from(&item.generics);
+                let parent = Parent {
+                    path: format!("{}::{}", self.module, ident),
+                    header: print(&quote!(trait #ident #generics)),
+                    vis: Some(print(&quote!(#vis))),
+                };
+                self.push(item_def_trait(item), None);
+                for item in &item.items {
+                    if let Some(def) = trait_item_def(item) {
+                        self.push(def, Some(&parent));
+                    }
+                }
+            }
+            Item::Impl(item) => {
+                let unsafety = if item.unsafety { Some(quote!(unsafe)) } else { None };
+                let generics = syn::Generics::from(&item.generics);
+                let self_ty = syn::Type::from(&*item.self_ty);
+                let trait_ = item.trait_.as_ref().map(|(negative, path)| {
+                    let negative = if *negative { Some(quote!(!)) } else { None };
+                    let path = syn::Path::from(path);
+                    quote!(#negative #path for)
+                });
+                let name = match &self_ty {
+                    syn::Type::Path(ty) if ty.qself.is_none() => match ty.path.segments.last() {
+                        Some(segment) => segment.ident.to_string(),
+                        None => print(&quote!(#self_ty)),
+                    },
+                    _ => print(&quote!(#self_ty)),
+                };
+                let parent = Parent {
+                    path: format!("{}::{}", self.module, name),
+                    header: print(&quote!(#unsafety impl #generics #trait_ #self_ty)),
+                    vis: None,
+                };
+                for item in &item.items {
+                    if let Some(def) = impl_item_def(item) {
+                        self.push(def, Some(&parent));
+                    }
+                }
+            }
+            Item::ForeignMod(item) => {
+                for item in &item.items {
+                    if let Some(def) = foreign_item_def(item) {
+                        self.push(def, None);
+                    }
+                }
+            }
+            Item::Macro(item) => {
+                let ident = match &item.ident {
+                    Some(ident) if syn::Path::from(&item.mac.path).is_ident("macro_rules") => {
+                        proc_macro2::Ident::from(ident)
+                    }
+                    _ => return,
+                };
+                let exported = item
+                    .attrs
+                    .iter()
+                    .any(|attr| syn::Attribute::from(attr).path().is_ident("macro_export"));
+                let vis =
+                    if exported { syn::parse_quote!(pub) } else { syn::Visibility::Inherited };
+                let signature = quote!(macro_rules! #ident);
+                let def = Def::new(SymbolKind::Macro, &ident, vis, default(), signature, item.span);
+                if exported {
+                    // Exported macros are in the root module, wherever they are defined.
+                    let module = mem::replace(&mut self.module, "crate".to_owned());
+                    self.push(def, None);
+                    self.module = module;
+                } else {
+                    self.push(def, None);
+                }
+            }
+            item => {
+                if let Some(def) = item_def(item) {
+                    self.push(def, None);
+                }
+            }
+        }
+    }
+}
+
+fn item_def_mod(item: &ItemMod) -> Def {
+    let vis = syn::Visibility::from(&item.vis);
+    let unsafety = if item.unsafety { Some(quote!(unsafe)) } else { None };
+    let ident = proc_macro2::Ident::from(&item.ident);
+    let signature = quote!(#vis #unsafety mod #ident);
+    Def::new(SymbolKind::Mod, &ident, vis, default(), signature, item.span)
+}
+
+fn item_def_trait(item: &ItemTrait) -> Def {
+    let vis = syn::Visibility::from(&item.vis);
+    let unsafety = if item.unsafety { Some(quote!(unsafe)) } else { None };
+    let auto = if item.auto_token { Some(quote!(auto)) } else { None };
+    let ident = proc_macro2::Ident::from(&item.ident);
+    let generics = syn::Generics::from(&item.generics);
+    let where_clause = &generics.where_clause;
+    let supertraits = bounds(&item.supertraits);
+    let colon = if supertraits.is_empty() { None } else { Some(quote!(:)) };
+    let signature =
+        quote!(#vis #unsafety #auto trait #ident #generics #colon #supertraits #where_clause);
+    Def::new(SymbolKind::Trait, &ident, vis, generics, signature, item.span)
+}
+
+/// Returns the definitions of the items that do not contain other items.
+fn item_def(item: &Item) -> Option<Def> {
+    Some(match item {
+        Item::Fn(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let sig = syn::Signature::from(&item.sig);
+            let signature = quote!(#vis #sig);
+            Def::new(SymbolKind::Fn, &sig.ident, vis, sig.generics, signature, item.span)
+        }
+        Item::Struct(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let generics = syn::Generics::from(&item.generics);
+            let where_clause = &generics.where_clause;
+            let signature = quote!(#vis struct #ident #generics #where_clause);
+            Def::new(SymbolKind::Struct, &ident, vis, generics, signature, item.span)
+        }
+        Item::Enum(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let generics = syn::Generics::from(&item.generics);
+            let where_clause = &generics.where_clause;
+            let signature = quote!(#vis enum #ident #generics #where_clause);
+            Def::new(SymbolKind::Enum, &ident, vis, generics, signature, item.span)
+        }
+        Item::Union(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let generics = syn::Generics::from(&item.generics);
+            let where_clause = &generics.where_clause;
+            let signature = quote!(#vis union #ident #generics #where_clause);
+            Def::new(SymbolKind::Union, &ident, vis, generics, signature, item.span)
+        }
+        Item::TraitAlias(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let generics = syn::Generics::from(&item.generics);
+            let where_clause = &generics.where_clause;
+            let bounds = bounds(&item.bounds);
+            let signature = quote!(#vis trait #ident #generics = #bounds #where_clause);
+            Def::new(SymbolKind::TraitAlias, &ident, vis, generics, signature, item.span)
+        }
+        Item::Const(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let generics = syn::Generics::from(&item.generics);
+            let ty = syn::Type::from(&*item.ty);
+            let signature = quote!(#vis const #ident #generics: #ty);
+            Def::new(SymbolKind::Const, &ident, vis, generics, signature, item.span)
+        }
+        Item::Static(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let mutability = syn::StaticMutability::from(&item.mutability);
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let ty = syn::Type::from(&*item.ty);
+            let signature = quote!(#vis static #mutability #ident: #ty);
+            Def::new(SymbolKind::Static, &ident, vis, default(), signature, item.span)
+        }
+        Item::Type(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let generics = syn::Generics::from(&item.generics);
+            let where_clause = &generics.where_clause;
+            let ty = syn::Type::from(&*item.ty);
+            let signature = quote!(#vis type #ident #generics = #ty #where_clause);
+            Def::new(SymbolKind::Type, &ident, vis, generics, signature, item.span)
+        }
+        _ => return None,
+    })
+}
+
+fn impl_item_def(item: &ImplItem) -> Option<Def> {
+    Some(match item {
+        ImplItem::Fn(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let defaultness = if item.defaultness { Some(quote!(default)) } else { None };
+            let sig = syn::Signature::from(&item.sig);
+            let signature = quote!(#vis #defaultness #sig);
+            Def::new(SymbolKind::Method, &sig.ident, vis, sig.generics, signature, item.span)
+        }
+        ImplItem::Const(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let defaultness = if item.defaultness { Some(quote!(default)) } else { None };
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let generics = syn::Generics::from(&item.generics);
+            let ty = syn::Type::from(&item.ty);
+            let signature = quote!(#vis #defaultness const #ident #generics: #ty);
+            Def::new(SymbolKind::Const, &ident, vis, generics, signature, item.span)
+        }
+        ImplItem::Type(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let defaultness = if item.defaultness { Some(quote!(default)) } else { None };
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let generics = syn::Generics::from(&item.generics);
+            let where_clause = &generics.where_clause;
+            let ty = syn::Type::from(&item.ty);
+            let signature = quote!(#vis #defaultness type #ident #generics = #ty #where_clause);
+            Def::new(SymbolKind::Type, &ident, vis, generics, signature, item.span)
+        }
+        ImplItem::Macro(_) | ImplItem::Verbatim(_) => return None,
+    })
+}
+
+fn trait_item_def(item: &TraitItem) -> Option<Def> {
+    let vis = syn::Visibility::Inherited;
+    Some(match item {
+        TraitItem::Fn(item) => {
+            let sig = syn::Signature::from(&item.sig);
+            let signature = quote!(#sig);
+            Def::new(SymbolKind::Method, &sig.ident, vis, sig.generics, signature, item.span)
+        }
+        TraitItem::Const(item) => {
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let generics = syn::Generics::from(&item.generics);
+            let ty = syn::Type::from(&item.ty);
+            let signature = quote!(const #ident #generics: #ty);
+            Def::new(SymbolKind::Const, &ident, vis, generics, signature, item.span)
+        }
+        TraitItem::Type(item) => {
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let generics = syn::Generics::from(&item.generics);
+            let where_clause = &generics.where_clause;
+            let bounds = bounds(&item.bounds);
+            let colon = if bounds.is_empty() { None } else { Some(quote!(:)) };
+            let default = item.default.as_ref().map(|ty| {
+                let ty = syn::Type::from(ty);
+                quote!(= #ty)
+            });
+            let signature = quote!(type #ident #generics #colon #bounds #default #where_clause);
+            Def::new(SymbolKind::Type, &ident, vis, generics, signature, item.span)
+        }
+        TraitItem::Macro(_) | TraitItem::Verbatim(_) => return None,
+    })
+}
+
+fn foreign_item_def(item: &ForeignItem) -> Option<Def> {
+    Some(match item {
+        ForeignItem::Fn(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let sig = syn::Signature::from(&item.sig);
+            let signature = quote!(#vis #sig);
+            Def::new(SymbolKind::Fn, &sig.ident, vis, sig.generics, signature, item.span)
+        }
+        ForeignItem::Static(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let mutability = syn::StaticMutability::from(&item.mutability);
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let ty = syn::Type::from(&*item.ty);
+            let signature = quote!(#vis static #mutability #ident: #ty);
+            Def::new(SymbolKind::Static, &ident, vis, default(), signature, item.span)
+        }
+        ForeignItem::Type(item) => {
+            let vis = syn::Visibility::from(&item.vis);
+            let ident = proc_macro2::Ident::from(&item.ident);
+            let generics = syn::Generics::from(&item.generics);
+            let signature = quote!(#vis type #ident #generics);
+            Def::new(SymbolKind::Type, &ident, vis, generics, signature, item.span)
+        }
+        ForeignItem::Macro(_) | ForeignItem::Verbatim(_) => return None,
+    })
+}
+
+fn bounds(
+    bounds: &Punctuated<TypeParamBound>,
+) -> syn::punctuated::Punctuated<syn::TypeParamBound, syn::Token![+]> {
+    bounds.map_into()
+}
+
+/// A token of printed code. Consecutive joint punctuation, e.g., `::`, and
+/// lifetimes are a single token.
+enum Token {
+    Word(String),
+    Op(String),
+    Open(char),
+    Close(char),
+}
+
+/// Prints tokens as Rust code, with the spacing of rustfmt for the tokens
+/// that appear in signatures.
+pub(crate) fn print(tokens: &TokenStream) -> String {
+    fn flatten(tokens: TokenStream, out: &mut Vec<Token>) {
+        let mut joint = false;
+        for tt in tokens {
+            match tt {
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Parenthesis => ('(', ')'),
+                        Delimiter::Brace => ('{', '}'),
+                        Delimiter::Bracket => ('[', ']'),
+                        Delimiter::None => {
+                            flatten(group.stream(), out);
+                            continue;
+                        }
+                    };
+                    out.push(Token::Open(open));
+                    flatten(group.stream(), out);
+                    out.push(Token::Close(close));
+                }
+                TokenTree::Ident(ident) => {
+                    if joint && matches!(out.last(), Some(Token::Op(op)) if op == "'") {
+                        // Lifetimes.
+                        out.pop();
+                        out.push(Token::Word(format!("'{}", ident)));
+                    } else {
+                        out.push(Token::Word(ident.to_string()));
+                    }
+                }
+                TokenTree::Literal(lit) => out.push(Token::Word(lit.to_string())),
+                TokenTree::Punct(punct) => {
+                    match out.last_mut() {
+                        Some(Token::Op(op)) if joint => op.push(punct.as_char()),
+                        // Macros, e.g., `macro_rules!`.
+                        Some(Token::Word(word))
+                            if punct.as_char() == '!' && punct.spacing() == Spacing::Alone =>
+                        {
+                            word.push('!');
+                        }
+                        _ => out.push(Token::Op(punct.as_char().to_string())),
+                    }
+                    joint = punct.spacing() == Spacing::Joint;
+                    continue;
+                }
+            }
+            joint = false;
+        }
+    }
+
+    fn is_angle_close(op: &str) -> bool {
+        op.bytes().all(|b| b == b'>')
+    }
+
+    let mut flat = vec![];
+    flatten(tokens.clone(), &mut flat);
+    let mut out = String::new();
+    let mut prev: Option<&Token> = None;
+    for token in &flat {
+        let space = match (prev, token) {
+            // Inside delimiters, and before the arguments of function calls,
+            // tuple structs, and `Fn(..)`.
+            (None | Some(Token::Open(_)), _)
+            | (_, Token::Close(_))
+            | (Some(Token::Word(_)), Token::Open('(')) => false,
+            (_, Token::Op(op)) if matches!(&**op, "," | ";" | ":" | "::" | ".") => false,
+            (_, Token::Op(op)) if is_angle_close(op) => false,
+            (Some(Token::Op(op)), _)
+                if matches!(&**op, "::" | "." | "<" | "&" | "*" | "!" | "?" | "#") =>
+            {
+                false
+            }
+            // Generic arguments.
+            (Some(Token::Word(_)), Token::Op(op)) if op == "<" => false,
+            // Parameters after generics, e.g., `fn f<T>(x: T)`.
+            (Some(Token::Op(op)), Token::Open('(')) if is_angle_close(op) => false,
+            _ => true,
+        };
+        if space {
+            out.push(' ');
+        }
+        match token {
+            Token::Word(s) | Token::Op(s) => out.push_str(s),
+            Token::Open(c) | Token::Close(c) => out.push(*c),
+        }
+        prev = Some(token);
+    }
+    out
+}
@@ -0,0 +1,203 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Internally tagged encoding of enums.
+//!
+//! By default, enums are serialized in the externally tagged form of serde,
+//! e.g., `{"fn": {...}}`. [`Tagged`] serializes a syntax tree with every enum
+//! variant as a map with a `"kind"` field instead, which is the form that
+//! discriminated unions of most schema languages expect:
+//!
+//! | Variant                    | Externally tagged       | Tagged                                  |
+//! | -------------------------- | ----------------------- | --------------------------------------- |
+//! | Variant with a struct      | `{"fn": {"ident": ..}}` | `{"kind": "fn", "ident": ..}`           |
+//! | Variant with another value | `{"str": "\"a\""}`      | `{"kind": "str", "value": "\"a\""}`     |
+//! | Unit variant               | `"inherited"`           | `{"kind": "inherited"}`                 |
+//!
+//! The content of a variant is also stored in `"value"` if it is a map that
+//! is empty, has a `"kind"` field itself, or only has a `"value"` field, so
+//! that it can be told apart from the other forms. Enums that are flattened
+//! into their parent struct (e.g., the literal of an `ExprLit`) are
+//! serialized as fields of the parent as usual.
+//!
+//! [`Tagged`] can be deserialized from both the tagged and the externally
+//! tagged form, and converted to adapters and syn types with
+//! [`to_adapter`](Tagged::to_adapter) and [`to_syn`](Tagged::to_syn).
+//! [`json::from_str_deep`](crate::json::from_str_deep) also accepts both
+//! forms. The `"kind"` of a map is only read as a tag where an enum is
+//! expected, so the structs that have a `"kind"` field of their own (e.g., a
+//! comment) are read as is.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "json")]
+//! # fn dox() {
+//! use syn_serde::tagged::Tagged;
+//!
+//! let syn_file: syn::File = syn::parse_quote! {
+//!     fn main() {}
+//! };
+//! let tree = Tagged::from_syn(&syn_file);
+//!
+//! let json = serde_json::to_value(&tree).unwrap();
+//! assert_eq!(json["items"][0]["kind"], "fn");
+//! assert_eq!(json["items"][0]["ident"], "main");
+//!
+//! let tree: Tagged = serde_json::from_value(json).unwrap();
+//! let syn_file2: syn::File = tree.to_syn().unwrap();
+//! assert_eq!(syn_file, syn_file2);
+//! # }
+//! ```
+
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, Deserialize, Deserializer},
+    ser::{SerializeMap as _, Serializer},
+};
+
+use crate::{
+    node::{self, Map, Value},
+    options::{self, Options},
+};
+
+value_tree! {
+    /// A syntax tree that is serialized with internally tagged enums.
+    ///
+    /// See the [module level documentation](self) for more.
+    pub struct Tagged;
+    new: "Encodes",
+    options: Options::new().tagged(true),
+}
+
+impl<'de> Deserialize<'de> for Tagged {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Value::deserialize(deserializer).map(|value| Self { value })
+    }
+}
+
+/// Serializes an enum variant in the tagged form.
+pub(crate) fn serialize_variant<S>(
+    name: &str,
+    content: Option<&Value>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match content {
+        None => {
+            let mut s = serializer.serialize_map(Some(1))?;
+            s.serialize_entry("kind", name)?;
+            s.end()
+        }
+        Some(Value::Map(map)) if can_merge(map) => {
+            let fields = map.output();
+            let mut s = serializer.serialize_map(Some(fields.clone().count() + 1))?;
+            s.serialize_entry("kind", name)?;
+            for (key, value) in fields {
+                s.serialize_entry(key, &value)?;
+            }
+            s.end()
+        }
+        Some(content) => {
+            let mut s = serializer.serialize_map(Some(2))?;
+            s.serialize_entry("kind", name)?;
+            s.serialize_entry("value", content)?;
+            s.end()
+        }
+    }
+}
+
+/// Returns `true` if the fields of `map` can be merged with the `"kind"` of a
+/// variant without being mistaken for another form.
+fn can_merge(map: &Map) -> bool {
+    let keys: Vec<_> = map.output().map(|(key, _)| key).collect();
+    !keys.is_empty() && !keys.contains(&"kind") && keys != ["value"]
+}
+
+/// The name of the newtype struct through which [`deserialize`] asks a
+/// [`node::Deserializer`] to read the tagged form itself.
+pub(crate) const ENUM: &str = "$syn_serde::tagged::Enum";
+
+/// Deserializes the adapter of an enum with the `Deserialize` implementation
+/// of `T`, which reads the externally tagged form. In tagged mode, the tagged
+/// form is also read.
+///
+/// The code generator and `ast_enum!` implement `Deserialize` for the
+/// adapters of enums with this. The tags are therefore only read where an
+/// enum is expected, so other maps with a `"kind"` field (e.g., a
+/// [`Comment`](crate::Comment)) are left as is.
+pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    if options::current().tagged {
+        deserializer.deserialize_newtype_struct(ENUM, EnumVisitor(PhantomData))
+    } else {
+        T::deserialize(deserializer)
+    }
+}
+
+struct EnumVisitor<T>(PhantomData<T>);
+
+impl<'de, T> de::Visitor<'de> for EnumVisitor<T>
+where
+    T: Deserialize<'de>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an enum variant")
+    }
+
+    // A `node::Deserializer`, which reads the tagged form itself.
+    fn visit_some<D>(self, deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        T::deserialize(deserializer)
+    }
+
+    // Other deserializers, e.g., the ones that serde buffers the fields of
+    // structs with flattened fields into.
+    fn visit_newtype_struct<D>(self, deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = Value::deserialize(deserializer)?;
+        T::deserialize(node::Deserializer::new(value, None)).map_err(de::Error::custom)
+    }
+}
+
+/// Converts a variant in the tagged form to the externally tagged form, as
+/// deserialized from self-describing formats: `"name"` or `{"name": value}`.
+///
+/// `map` is in the position of an enum with the given `variants`. Maps that
+/// are not in the tagged form are returned unchanged.
+pub(crate) fn untag(mut map: Map, variants: &[&str]) -> Value {
+    let i = map.fields.iter().position(|(k, v)| k == "kind" && matches!(v, Value::Str(_)));
+    let i = match i {
+        Some(i) if !variants.contains(&"kind") => i,
+        _ => return Value::Map(map),
+    };
+    let name = match map.fields.remove(i).1 {
+        Value::Str(name) => name,
+        _ => unreachable!(),
+    };
+    match map.fields.len() {
+        0 => Value::Str(name),
+        1 if map.fields[0].0 == "value" => {
+            let content = map.fields.pop().unwrap().1;
+            Value::Map(Map { ty: None, fields: vec![(name, content)] })
+        }
+        _ => {
+            let content = Value::Map(Map { ty: None, fields: map.fields });
+            Value::Map(Map { ty: None, fields: vec![(name, content)] })
+        }
+    }
+}
@@ -9,7 +9,7 @@ ast_struct! {
     #[derive(Clone, Default)]
     #[serde(transparent)]
     pub struct TokenStream {
-        inner: Vec<TokenTree>,
+        pub(crate) inner: Vec<TokenTree>,
     }
 }
 
@@ -35,8 +35,14 @@ ast_struct! {
     /// An adapter for [`struct@proc_macro2::Group`].
     #[derive(Clone)]
     pub struct Group {
-        delimiter: Delimiter,
-        stream: TokenStream,
+        pub(crate) delimiter: Delimiter,
+        pub(crate) stream: TokenStream,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub(crate) span: Option<SpanInfo>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub(crate) span_open: Option<SpanInfo>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub(crate) span_close: Option<SpanInfo>,
     }
 }
 
@@ -55,8 +61,24 @@ ast_struct! {
     /// An adapter for [`struct@proc_macro2::Punct`].
     #[derive(Clone, Copy)]
     pub struct Punct {
+        #[serde(deserialize_with = "deserialize_op")]
         op: char,
-        spacing: Spacing,
+        pub(crate) spacing: Spacing,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        pub(crate) span: Option<SpanInfo>,
+    }
+}
+
+/// Rejects the characters that `proc_macro2::Punct::new` panics on.
+fn deserialize_op<'de, D>(deserializer: D) -> Result<char, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let op = char::deserialize(deserializer)?;
+    if "!#$%&'*+,-./:;<=>?@^|~".contains(op) {
+        Ok(op)
+    } else {
+        Err(serde::de::Error::custom(format!("`{}` is not a punctuation character", op)))
     }
 }
 
@@ -69,31 +91,149 @@ ast_enum! {
     }
 }
 
-ast_struct! {
-    /// An adapter for [`struct@proc_macro2::Ident`].
-    #[derive(Clone, Eq, PartialEq)]
-    #[serde(transparent)]
-    pub struct Ident {
-        inner: String,
+/// An adapter for [`struct@proc_macro2::Ident`].
+///
+/// This is serialized as a string, or as `{"ident": "...", "span": {...}}` if
+/// the span is recorded. Only identifiers in token trees record their spans;
+/// the spans of other identifiers are recorded by the nodes that contain them.
+#[derive(Clone, Eq, PartialEq, Deserialize)]
+#[serde(try_from = "IdentRepr")]
+pub struct Ident {
+    inner: String,
+    pub(crate) span: Option<SpanInfo>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum IdentRepr {
+    Text(String),
+    Spanned {
+        ident: String,
+        #[serde(default)]
+        span: Option<SpanInfo>,
+    },
+}
+
+impl TryFrom<IdentRepr> for Ident {
+    type Error = String;
+
+    fn try_from(repr: IdentRepr) -> Result<Self, String> {
+        let (inner, span) = match repr {
+            IdentRepr::Text(inner) => (inner, None),
+            IdentRepr::Spanned { ident, span } => (ident, span),
+        };
+        // Identifiers are validated here, so that converting them to
+        // `proc_macro2::Ident` cannot panic.
+        let mut iter = inner.parse::<proc_macro2::TokenStream>().into_iter().flatten();
+        match (iter.next(), iter.next()) {
+            (Some(proc_macro2::TokenTree::Ident(ident)), None) if ident == inner => {
+                Ok(Self { inner, span })
+            }
+            _ => Err(format!("`{}` is not a valid identifier", inner)),
+        }
     }
 }
 
-ast_struct! {
-    /// An adapter for [`struct@proc_macro2::Literal`].
-    #[derive(Clone)]
-    #[serde(transparent)]
-    pub struct Literal {
-        pub(crate) text: String,
+impl Serialize for Ident {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_spanned(serializer, "Ident", "ident", &self.inner, self.span.as_ref())
+    }
+}
+
+/// An adapter for [`struct@proc_macro2::Literal`].
+///
+/// This is serialized as a string, or as `{"lit": "...", "span": {...}}` if
+/// the span is recorded. Only literals in token trees record their spans; the
+/// spans of other literals are recorded by the nodes that contain them.
+#[derive(Clone, Deserialize)]
+#[serde(try_from = "LiteralRepr")]
+pub struct Literal {
+    pub(crate) text: String,
+    pub(crate) span: Option<SpanInfo>,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum LiteralRepr {
+    Text(String),
+    Spanned {
+        lit: String,
+        #[serde(default)]
+        span: Option<SpanInfo>,
+    },
+}
+
+impl TryFrom<LiteralRepr> for Literal {
+    type Error = String;
+
+    fn try_from(repr: LiteralRepr) -> Result<Self, String> {
+        let (text, span) = match repr {
+            LiteralRepr::Text(text) => (text, None),
+            LiteralRepr::Spanned { lit, span } => (lit, span),
+        };
+        // Literals are validated here, so that converting them to
+        // `proc_macro2::Literal` cannot panic.
+        let mut iter = text.parse::<proc_macro2::TokenStream>().into_iter().flatten();
+        match (iter.next(), iter.next()) {
+            (Some(proc_macro2::TokenTree::Literal(lit)), None) if lit.to_string() == text => {
+                Ok(Self { text, span })
+            }
+            _ => Err(format!("`{}` is not a valid literal", text)),
+        }
+    }
+}
+
+impl Serialize for Literal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serialize_spanned(serializer, "Literal", "lit", &self.text, self.span.as_ref())
+    }
+}
+
+fn serialize_spanned<S>(
+    serializer: S,
+    name: &'static str,
+    key: &'static str,
+    text: &str,
+    span: Option<&SpanInfo>,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    use serde::ser::SerializeStruct as _;
+    match span {
+        None => serializer.serialize_str(text),
+        Some(span) => {
+            let mut s = serializer.serialize_struct(name, 2)?;
+            s.serialize_field(key, text)?;
+            s.serialize_field("span", span)?;
+            s.end()
+        }
     }
 }
 
 impl Literal {
     fn _new(text: String) -> Self {
-        Self { text }
+        Self { text, span: None }
     }
 
-    pub(crate) fn u8_suffixed(n: u8) -> Self {
-        Self::_new(format!(concat!("{}", stringify!(u8)), n))
+    pub(crate) fn byte_character(b: u8) -> Self {
+        let escaped = match b {
+            b'\0' => r"\0".to_owned(),
+            b'\t' => r"\t".to_owned(),
+            b'\n' => r"\n".to_owned(),
+            b'\r' => r"\r".to_owned(),
+            b'\'' => r"\'".to_owned(),
+            b'\\' => r"\\".to_owned(),
+            b'\x20'..=b'\x7E' => (b as char).to_string(),
+            _ => format!("\\x{:02X}", b),
+        };
+        Self::_new(format!("b'{}'", escaped))
     }
 
     pub(crate) fn string(t: &str) -> Self {
@@ -127,6 +267,14 @@ impl Literal {
         escaped.push('"');
         Self::_new(escaped)
     }
+
+    pub(crate) fn c_string(bytes: &[u8]) -> Self {
+        // The escapes of byte strings are also valid in C strings. Nul bytes
+        // are rejected when the literal is validated.
+        let mut text = Self::byte_string(bytes).text;
+        text.replace_range(..1, "c");
+        Self::_new(text)
+    }
 }
 
 // TODO: when release the next minor version, remove this.
@@ -159,9 +307,15 @@ mod convert {
             use super::TokenTree::*;
             match other {
                 proc_macro2::TokenTree::Group(t) => Group(t.into()),
-                proc_macro2::TokenTree::Ident(t) => Ident(t.into()),
+                // Unlike elsewhere, idents and literals in token trees record
+                // their spans.
+                proc_macro2::TokenTree::Ident(t) => {
+                    Ident(super::Ident { span: Some(SpanInfo::from_span(t.span())), ..t.into() })
+                }
                 proc_macro2::TokenTree::Punct(t) => Punct(t.into()),
-                proc_macro2::TokenTree::Literal(t) => Literal(t.into()),
+                proc_macro2::TokenTree::Literal(t) => {
+                    Literal(super::Literal { span: Some(SpanInfo::from_span(t.span())), ..t.into() })
+                }
             }
         }
     }
@@ -181,7 +335,13 @@ mod convert {
     syn_trait_impl!(proc_macro2::Group);
     impl From<&proc_macro2::Group> for Group {
         fn from(other: &proc_macro2::Group) -> Self {
-            Self { delimiter: other.delimiter().ref_into(), stream: other.stream().ref_into() }
+            Self {
+                delimiter: other.delimiter().ref_into(),
+                stream: other.stream().ref_into(),
+                span: Some(SpanInfo::from_span(other.span())),
+                span_open: Some(SpanInfo::from_span(other.span_open())),
+                span_close: Some(SpanInfo::from_span(other.span_close())),
+            }
         }
     }
     impl From<&Group> for proc_macro2::Group {
@@ -219,12 +379,15 @@ mod convert {
     syn_trait_impl!(proc_macro2::Ident);
     impl From<&proc_macro2::Ident> for Ident {
         fn from(other: &proc_macro2::Ident) -> Self {
-            Self { inner: other.to_string() }
+            Self { inner: other.to_string(), span: None }
         }
     }
     impl From<&Ident> for proc_macro2::Ident {
         fn from(other: &Ident) -> Self {
-            Self::new(&other.inner, Span::call_site())
+            match other.inner.strip_prefix("r#") {
+                Some(raw) => Self::new_raw(raw, Span::call_site()),
+                None => Self::new(&other.inner, Span::call_site()),
+            }
         }
     }
 
@@ -232,7 +395,11 @@ mod convert {
     syn_trait_impl!(proc_macro2::Punct);
     impl From<&proc_macro2::Punct> for Punct {
         fn from(other: &proc_macro2::Punct) -> Self {
-            Self { op: other.as_char(), spacing: other.spacing().ref_into() }
+            Self {
+                op: other.as_char(),
+                spacing: other.spacing().ref_into(),
+                span: Some(SpanInfo::from_span(other.span())),
+            }
         }
     }
     impl From<&Punct> for proc_macro2::Punct {
@@ -266,7 +433,7 @@ mod convert {
     syn_trait_impl!(proc_macro2::Literal);
     impl From<&proc_macro2::Literal> for Literal {
         fn from(other: &proc_macro2::Literal) -> Self {
-            Self { text: other.to_string() }
+            Self { text: other.to_string(), span: None }
         }
     }
     impl From<&Literal> for proc_macro2::Literal {
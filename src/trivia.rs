@@ -0,0 +1,662 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+//! Whitespace and comments of the source code.
+//!
+//! [`parse_file`] records the trivia (whitespace and comments) around each
+//! token of the source code in the nodes of the [`File`], and [`print_file`]
+//! prints the file back with them. An unmodified file prints back
+//! byte-for-byte:
+//!
+//! ```
+//! let source = "fn main()  {\n    // Hello.\n    let x = 1 ;\n}\n";
+//! let file = syn_serde::trivia::parse_file(source).unwrap();
+//! assert_eq!(syn_serde::trivia::print_file(&file), source);
+//! ```
+//!
+//! Each token is recorded in the `"trivia"` field of the innermost node that
+//! prints it, including the keywords, punctuation, and delimiters that are
+//! implied by the kind of the node and the separators of its lists:
+//!
+//! ```json
+//! {
+//!   "fn": {
+//!     "ident": "main",
+//!     "stmts": {
+//!       "stmts": [
+//!         {
+//!           "let": {
+//!             ...
+//!             "trivia": [
+//!               { "token": "let", "leading": "    // Hello.\n    ", "trailing": " ", "index": 5 },
+//!               { "token": "=", "trailing": " ", "index": 7 },
+//!               { "token": ";", "trailing": "\n", "index": 9 }
+//!             ]
+//!           }
+//!         }
+//!       ],
+//!       "trivia": [
+//!         { "token": "{", "trailing": "\n", "index": 4 },
+//!         { "token": "}", "trailing": "\n", "index": 10 }
+//!       ]
+//!     },
+//!     "trivia": [
+//!       { "token": "fn", "trailing": " ", "index": 0 },
+//!       { "token": "main", "index": 1 },
+//!       { "token": "(", "index": 2 },
+//!       { "token": ")", "trailing": "  ", "index": 3 }
+//!     ]
+//!   }
+//! }
+//! ```
+//!
+//! Each token records its `leading` trivia and its `trailing` trivia, which
+//! is the rest of the line after the token, and its `index` among the tokens
+//! of the file, which orders the tokens of different nodes. `source` records
+//! the source text of the token if it differs from `token`, e.g., for doc
+//! comments, which are tokenized as `#[doc = "..."]`. The adapters that are
+//! serialized as part of another value (e.g., [`Path`](crate::Path) and
+//! [`Macro`](crate::Macro), whose fields are flattened into the nodes
+//! containing them) have no `"trivia"` field, so their tokens are recorded by
+//! the nodes containing them. The tokens that no node records and the text
+//! after the last line of the file are recorded in the `"trivia"` field of
+//! the file.
+//!
+//! When printing a file that has been modified, the tokens of the file are
+//! matched against the recorded tokens in the order of their indices, so the
+//! unmodified parts keep their formatting. Inserted nodes have no trivia, so
+//! their tokens are separated by single spaces, and removed nodes take their
+//! trivia with them. The trivia of the other removed tokens (e.g., the
+//! separator of a removed element of a list) are dropped, except for the
+//! line break after them. Moved nodes are not matched at their new place, so
+//! they lose their trivia.
+//!
+//! Trailing punctuation and optional tokens are not serialized by default, so
+//! serialize files with [`Lossless`](crate::lossless::Lossless) to print them
+//! back byte-for-byte after deserialization.
+
+use core::{cell::RefCell, mem};
+use std::borrow::Cow;
+
+use proc_macro2::{Delimiter, LineColumn, Spacing, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    File,
+    node::{self, Value},
+};
+
+/// The trivia of the tokens of a file that are not recorded by its nodes.
+///
+/// See the [module level documentation](self) for more.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Trivia {
+    /// The tokens that are not recorded by the nodes of the file.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tokens: Vec<TokenTrivia>,
+    /// The text after the trailing trivia of the last token.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub eof: String,
+}
+
+/// A token and its trivia.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenTrivia {
+    /// The text of the token, as printed by `proc_macro2`.
+    pub token: String,
+    /// Whitespace and comments before the token.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub leading: String,
+    /// Whitespace and comments after the token, up to the end of the line.
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub trailing: String,
+    /// The source text of the token, if it differs from `token`.
+    ///
+    /// For a doc comment, this is the comment for its first token, and an
+    /// empty string for the rest of its tokens.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+    /// The index of the token among the tokens of the file.
+    pub index: usize,
+}
+
+/// Parses a file and records the trivia of its tokens.
+///
+/// # Errors
+///
+/// Returns an error if `source` is not a valid Rust file.
+pub fn parse_file(source: &str) -> syn::Result<File> {
+    let syn_file = syn::parse_file(source)?;
+    let (tokens, eof) = from_source(source)?;
+    let _recording = Recording::start(tokens);
+    let mut file = File::from(&syn_file);
+    file.trivia = Some(Trivia { tokens: Recording::rest(), eof });
+    Ok(file)
+}
+
+/// Returns `true` if `file` records the trivia of its tokens, that is, if it
+/// was parsed by [`parse_file`].
+#[must_use]
+pub fn has_trivia(file: &File) -> bool {
+    file.trivia.is_some()
+}
+
+/// Prints a file with the trivia of its tokens.
+///
+/// If the file has no trivia, the tokens are separated by single spaces.
+#[must_use]
+pub fn print_file(file: &File) -> String {
+    let syn_file = syn::File::from(file);
+    let mut tokens = vec![];
+    printed_tokens(syn_file.into_token_stream(), &mut tokens);
+
+    let mut out = String::new();
+    match &file.trivia {
+        Some(trivia) => {
+            let mut recorded = node_trivia(file);
+            recorded.extend(trivia.tokens.iter().cloned());
+            recorded.sort_by_key(|t| t.index);
+            let trivia = Trivia { tokens: recorded, eof: trivia.eof.clone() };
+            Printer::new(&trivia, &tokens).print(&mut out);
+        }
+        None => {
+            if let Some(shebang) = &file.shebang {
+                out.push_str(shebang);
+                out.push('\n');
+            }
+            Printer::new(&Trivia::default(), &tokens).print(&mut out);
+        }
+    }
+    out
+}
+
+/// Takes the trivia of the tokens of `node` that are not taken by the nodes
+/// in it yet, while [`parse_file`] converts a file.
+///
+/// The adapters that record trivia call this after converting their fields.
+pub(crate) fn take<T>(node: &T) -> Vec<TokenTrivia>
+where
+    T: ?Sized + ToTokens,
+{
+    RECORDING.with(|recording| {
+        let mut recording = recording.borrow_mut();
+        let recording = match &mut *recording {
+            Some(recording) => recording,
+            None => return vec![],
+        };
+        let mut spans = vec![];
+        token_spans(node.to_token_stream(), &mut spans);
+        spans.iter().filter_map(|(token, span)| recording.take(token, span.start())).collect()
+    })
+}
+
+thread_local! {
+    static RECORDING: RefCell<Option<Recording>> = RefCell::new(None);
+}
+
+/// The tokens of the file that [`parse_file`] is converting.
+struct Recording {
+    tokens: Vec<TokenTrivia>,
+    /// The start of each token in the source code.
+    starts: Vec<(usize, usize)>,
+    taken: Vec<bool>,
+}
+
+/// Stops recording when dropped.
+struct Stop(Option<Recording>);
+
+impl Recording {
+    fn start(tokens: Vec<(TokenTrivia, LineColumn)>) -> Stop {
+        let (tokens, starts): (Vec<_>, Vec<_>) =
+            tokens.into_iter().map(|(t, start)| (t, (start.line, start.column))).unzip();
+        let taken = vec![false; tokens.len()];
+        let recording = Self { tokens, starts, taken };
+        Stop(RECORDING.with(|current| current.replace(Some(recording))))
+    }
+
+    /// Takes the tokens that no node has taken.
+    fn rest() -> Vec<TokenTrivia> {
+        RECORDING.with(|recording| match &mut *recording.borrow_mut() {
+            Some(recording) => recording
+                .tokens
+                .iter_mut()
+                .zip(&recording.taken)
+                .filter(|(_, taken)| !**taken)
+                .map(|(t, _)| mem::take(t))
+                .collect(),
+            None => vec![],
+        })
+    }
+
+    /// Takes the recorded token `token` that starts at `start`. Tokens that
+    /// are not in the source code (e.g., the ones printed for the default
+    /// values of syn) are not found.
+    fn take(&mut self, token: &str, start: LineColumn) -> Option<TokenTrivia> {
+        let start = (start.line, start.column);
+        let first = self.starts.partition_point(|s| *s < start);
+        // The tokens of a doc comment start at the same position.
+        let i = (first..self.tokens.len())
+            .take_while(|&i| self.starts[i] == start)
+            .find(|&i| !self.taken[i] && self.tokens[i].token == token)?;
+        self.taken[i] = true;
+        Some(mem::take(&mut self.tokens[i]))
+    }
+}
+
+impl Drop for Stop {
+    fn drop(&mut self) {
+        let prev = self.0.take();
+        RECORDING.with(|current| *current.borrow_mut() = prev);
+    }
+}
+
+/// Collects the trivia recorded by the nodes of `file`.
+fn node_trivia(file: &File) -> Vec<TokenTrivia> {
+    let mut out = vec![];
+    let mut stack = vec![node::to_value(file)];
+    while let Some(value) = stack.pop() {
+        match value {
+            Value::Map(map) => {
+                for (key, value) in map.fields {
+                    match value {
+                        Value::Seq(tokens) if key == "trivia" => {
+                            out.extend(tokens.into_iter().filter_map(|t| node::from_value(t).ok()));
+                        }
+                        value => stack.push(value),
+                    }
+                }
+            }
+            Value::Seq(elems) => stack.extend(elems),
+            Value::Variant(variant) => stack.extend(variant.value.map(|v| *v)),
+            _ => {}
+        }
+    }
+    out
+}
+
+/// Lexes `source` and records the trivia of its tokens, with the start of
+/// each token, and the text after the last token.
+fn from_source(
+    source: &str,
+) -> Result<(Vec<(TokenTrivia, LineColumn)>, String), proc_macro2::LexError> {
+    // `syn::parse_file` skips BOM and shebang, which are not tokens.
+    let mut start = if source.starts_with('\u{feff}') { '\u{feff}'.len_utf8() } else { 0 };
+    let rest = &source[start..];
+    if rest.starts_with("#!") && !rest[2..].trim_start().starts_with('[') {
+        start += rest.find('\n').unwrap_or(rest.len());
+    }
+
+    let text = &source[start..];
+    let lines = LineIndex::new(text);
+    let mut lexed = vec![];
+    token_spans(text.parse()?, &mut lexed);
+
+    let mut tokens: Vec<(TokenTrivia, LineColumn)> = vec![];
+    let mut pos = 0;
+    let mut comment_end = 0;
+    for (token, span) in lexed {
+        let index = tokens.len();
+        let token_start = lines.offset(span.start()) + start;
+        let mut token_end = lines.offset(span.end()) + start;
+        if token_start < comment_end {
+            // The rest of the tokens of a doc comment.
+            let source = Some(String::new());
+            tokens.push((
+                TokenTrivia { token, source, index, ..TokenTrivia::default() },
+                span.start(),
+            ));
+            continue;
+        }
+        let mut text = &source[token_start..token_end];
+        if text.starts_with("//") || text.starts_with("/*") {
+            token_end = token_start + comment_len(&source[token_start..]);
+            comment_end = token_end;
+            text = &source[token_start..token_end];
+        }
+
+        let leading = push_trailing(&mut tokens, &source[pos..token_start]);
+        let t = TokenTrivia {
+            source: if text == token { None } else { Some(text.to_owned()) },
+            token,
+            leading: leading.to_owned(),
+            trailing: String::new(),
+            index,
+        };
+        tokens.push((t, span.start()));
+        pos = token_end;
+    }
+    let eof = push_trailing(&mut tokens, &source[pos..]).to_owned();
+    Ok((tokens, eof))
+}
+
+/// Records the trailing trivia of the last token from `gap`, and returns the
+/// rest of `gap`.
+fn push_trailing<'a>(tokens: &mut [(TokenTrivia, LineColumn)], gap: &'a str) -> &'a str {
+    match tokens.last_mut() {
+        Some((last, _)) => {
+            let (trailing, rest) = gap.split_at(trailing_len(gap));
+            last.trailing = trailing.to_owned();
+            rest
+        }
+        None => gap,
+    }
+}
+
+/// Returns the length of the trailing trivia at the start of `gap`: up to and
+/// including the first line break outside comments.
+fn trailing_len(gap: &str) -> usize {
+    let mut i = 0;
+    while i < gap.len() {
+        let rest = &gap[i..];
+        if rest.starts_with('\n') {
+            return i + 1;
+        }
+        if rest.starts_with("//") || rest.starts_with("/*") {
+            i += comment_len(rest);
+        } else {
+            i += rest.chars().next().map_or(1, char::len_utf8);
+        }
+    }
+    gap.len()
+}
+
+/// Returns the length of the comment at the start of `s`, excluding the line
+/// break after a line comment.
+fn comment_len(s: &str) -> usize {
+    if s.starts_with("//") {
+        return match s.find('\n') {
+            Some(i) if s[..i].ends_with('\r') => i - 1,
+            Some(i) => i,
+            None => s.len(),
+        };
+    }
+    let bytes = s.as_bytes();
+    let mut depth = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i..].starts_with(b"/*") {
+            depth += 1;
+            i += 2;
+        } else if bytes[i..].starts_with(b"*/") {
+            depth -= 1;
+            i += 2;
+            if depth == 0 {
+                return i;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    s.len()
+}
+
+/// Converts line-column positions of `proc_macro2` to byte offsets.
+struct LineIndex<'a> {
+    text: &'a str,
+    starts: Vec<usize>,
+}
+
+impl<'a> LineIndex<'a> {
+    fn new(text: &'a str) -> Self {
+        let mut starts = vec![0];
+        starts.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+        Self { text, starts }
+    }
+
+    fn offset(&self, pos: LineColumn) -> usize {
+        let start = self.starts[pos.line - 1];
+        let line = &self.text[start..];
+        start + line.char_indices().nth(pos.column).map_or(line.len(), |(i, _)| i)
+    }
+}
+
+/// Collects the tokens of a token stream with their spans.
+fn token_spans(tokens: TokenStream, out: &mut Vec<(String, Span)>) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(g) => {
+                let (open, close) = delimiters(g.delimiter());
+                if !open.is_empty() {
+                    out.push((open.to_owned(), g.span_open()));
+                }
+                token_spans(g.stream(), out);
+                if !close.is_empty() {
+                    out.push((close.to_owned(), g.span_close()));
+                }
+            }
+            tt => out.push((tt.to_string(), tt.span())),
+        }
+    }
+}
+
+/// A token printed by syn, and whether it is joint to the next token.
+struct Printed {
+    text: Cow<'static, str>,
+    joint: bool,
+}
+
+/// Collects the tokens printed by syn.
+fn printed_tokens(tokens: TokenStream, out: &mut Vec<Printed>) {
+    for tt in tokens {
+        match tt {
+            TokenTree::Group(g) => {
+                let (open, close) = delimiters(g.delimiter());
+                if !open.is_empty() {
+                    out.push(Printed { text: open.into(), joint: false });
+                }
+                printed_tokens(g.stream(), out);
+                if !close.is_empty() {
+                    out.push(Printed { text: close.into(), joint: false });
+                }
+            }
+            TokenTree::Punct(p) => out.push(Printed {
+                text: p.as_char().to_string().into(),
+                joint: p.spacing() == Spacing::Joint,
+            }),
+            tt => out.push(Printed { text: tt.to_string().into(), joint: false }),
+        }
+    }
+}
+
+fn delimiters(delimiter: Delimiter) -> (&'static str, &'static str) {
+    match delimiter {
+        Delimiter::Parenthesis => ("(", ")"),
+        Delimiter::Brace => ("{", "}"),
+        Delimiter::Bracket => ("[", "]"),
+        Delimiter::None => ("", ""),
+    }
+}
+
+struct Printer<'a> {
+    trivia: &'a Trivia,
+    tokens: &'a [Printed],
+    /// The index of the recorded token that matches each printed token.
+    matches: Vec<Option<usize>>,
+    /// Whether to print the `source` of each recorded token.
+    use_source: Vec<bool>,
+}
+
+impl<'a> Printer<'a> {
+    fn new(trivia: &'a Trivia, tokens: &'a [Printed]) -> Self {
+        let old: Vec<_> = trivia.tokens.iter().map(|t| &*t.token).collect();
+        let new: Vec<_> = tokens.iter().map(|t| &*t.text).collect();
+        let matches = diff(&old, &new);
+
+        // The source of a doc comment can only be printed if all of its
+        // tokens are unmodified.
+        let mut old_matches = vec![None; old.len()];
+        for (j, i) in matches.iter().enumerate() {
+            if let Some(i) = *i {
+                old_matches[i] = Some(j);
+            }
+        }
+        let mut use_source = vec![true; old.len()];
+        let mut i = 0;
+        while i < old.len() {
+            let len = 1 + trivia.tokens[i + 1..]
+                .iter()
+                .take_while(|t| t.source.as_deref() == Some(""))
+                .count();
+            let unmodified = old_matches[i]
+                .map_or(false, |j| (1..len).all(|k| old_matches[i + k] == Some(j + k)));
+            if !unmodified {
+                use_source[i..i + len].iter_mut().for_each(|x| *x = false);
+            }
+            i += len;
+        }
+
+        Self { trivia, tokens, matches, use_source }
+    }
+
+    fn print(&self, out: &mut String) {
+        let recorded = &self.trivia.tokens;
+        let mut last: Option<usize> = None;
+        let mut joint = false;
+        for (token, matched) in self.tokens.iter().zip(&self.matches) {
+            match *matched {
+                Some(i) => {
+                    let next = last.map_or(0, |last| last + 1);
+                    if i > next && !out.ends_with('\n') {
+                        // Keep the line break after the removed tokens.
+                        out.push_str(&recorded[i - 1].trailing);
+                    }
+                    let t = &recorded[i];
+                    // The tokens of removed nodes are not recorded, so the
+                    // tokens are adjacent if their indices are.
+                    let adjacent = last.map_or(0, |last| recorded[last].index + 1) == t.index;
+                    // A doc comment printed as an attribute has no trivia
+                    // between its tokens.
+                    let attr = t.source.is_some() && !self.use_source[i];
+                    if t.leading.is_empty()
+                        && (!adjacent || attr)
+                        && needs_space(out, joint, &token.text)
+                    {
+                        out.push(' ');
+                    }
+                    out.push_str(&t.leading);
+                    match &t.source {
+                        Some(source) if self.use_source[i] => out.push_str(source),
+                        _ => out.push_str(&t.token),
+                    }
+                    out.push_str(&t.trailing);
+                    last = Some(i);
+                }
+                None => {
+                    if needs_space(out, joint, &token.text) {
+                        out.push(' ');
+                    }
+                    out.push_str(&token.text);
+                }
+            }
+            joint = token.joint;
+        }
+        if let Some(t) = recorded.last() {
+            if last != Some(recorded.len() - 1) && !out.ends_with('\n') {
+                out.push_str(&t.trailing);
+            }
+        }
+        out.push_str(&self.trivia.eof);
+    }
+}
+
+/// Returns whether a space is needed between `out` and an inserted token.
+fn needs_space(out: &str, joint: bool, token: &str) -> bool {
+    match out.chars().next_back() {
+        Some(last) => {
+            !joint
+                && !last.is_whitespace()
+                && !matches!(last, '(' | '[')
+                && !matches!(token, ")" | "]" | "," | ";")
+        }
+        None => false,
+    }
+}
+
+/// The maximum number of edits that [`diff`] searches for.
+const MAX_EDITS: usize = 4096;
+
+/// Matches the tokens of `new` against `old`, returning the index in `old`
+/// that each token of `new` matches.
+///
+/// This uses Myers' diff algorithm after trimming the common prefix. The
+/// common suffix is not trimmed: the separators of the removed elements of a
+/// list are still recorded by the node of the list, and matching from the
+/// start pairs the kept elements with the separators right after them. If
+/// there are too many edits, the rest are treated as replaced.
+fn diff(old: &[&str], new: &[&str]) -> Vec<Option<usize>> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+
+    let mut matches = vec![None; new.len()];
+    for (j, m) in matches.iter_mut().enumerate().take(prefix) {
+        *m = Some(j);
+    }
+    for (i, j) in myers(&old[prefix..], &new[prefix..]) {
+        matches[prefix + j] = Some(prefix + i);
+    }
+    matches
+}
+
+/// Returns the matching pairs of indices of the shortest edit script.
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss, clippy::many_single_char_names)]
+fn myers(a: &[&str], b: &[&str]) -> Vec<(usize, usize)> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    // `trace[d][k + d]` is the furthest x reached on diagonal k with d edits.
+    let mut trace: Vec<Vec<isize>> = vec![];
+    let mut found = false;
+    for d in 0..=(n + m).min(MAX_EDITS as isize) {
+        let prev = trace.last();
+        let mut v = vec![0; 2 * d as usize + 1];
+        for k in (-d..=d).step_by(2) {
+            let mut x = match prev {
+                None => 0,
+                Some(prev) => {
+                    let at = |k: isize| prev[(k + d - 1) as usize];
+                    if k == -d || k != d && at(k - 1) < at(k + 1) {
+                        at(k + 1)
+                    } else {
+                        at(k - 1) + 1
+                    }
+                }
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[(k + d) as usize] = x;
+            if x >= n && y >= m {
+                found = true;
+            }
+        }
+        trace.push(v);
+        if found {
+            break;
+        }
+    }
+    if !found {
+        return vec![];
+    }
+
+    let mut pairs = vec![];
+    let (mut x, mut y) = (n, m);
+    for d in (0..trace.len() as isize).rev() {
+        let (prev_x, prev_y) = if d == 0 {
+            (0, 0)
+        } else {
+            let prev = &trace[d as usize - 1];
+            let at = |k: isize| prev[(k + d - 1) as usize];
+            let k = x - y;
+            let prev_k = if k == -d || k != d && at(k - 1) < at(k + 1) { k + 1 } else { k - 1 };
+            let prev_x = at(prev_k);
+            (prev_x, prev_x - prev_k)
+        };
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            pairs.push((x as usize, y as usize));
+        }
+        x = prev_x;
+        y = prev_y;
+    }
+    pairs
+}
@@ -14,7 +14,7 @@ ast_struct! {
     #[derive(Default)]
     #[serde(transparent)]
     pub struct ReturnType {
-        ty: Option<Box<Type>>,
+        pub(crate) ty: Option<Box<Type>>,
     }
 }
 
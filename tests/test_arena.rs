@@ -0,0 +1,124 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use syn_serde::{Syn as _, arena::Arena};
+
+#[test]
+fn test_round_trip() {
+    let syn_file: syn::File = syn::parse_quote! {
+        #[derive(Debug)]
+        pub struct S<T: Clone> {
+            a: Vec<T>,
+        }
+
+        impl<T: Clone> S<T> {
+            fn f(&self, x: Option<u8>) -> u8 {
+                match x {
+                    Some(1..=9) | None => 0,
+                    Some(n) if n > 10 => n * 2,
+                    _ => {
+                        println!("{}", x.unwrap());
+                        'a: loop { break 'a 1 }
+                    }
+                }
+            }
+        }
+    };
+
+    let arena = Arena::from_syn(&syn_file);
+    let json = serde_json::to_string(&arena).unwrap();
+    let arena: Arena = serde_json::from_str(&json).unwrap();
+    // Same as the result of the round trip through the adapter.
+    let expected = syn::File::from_adapter(&syn_file.to_adapter());
+    assert_eq!(arena.to_syn::<syn::File>().unwrap(), expected);
+}
+
+#[test]
+fn test_round_trip_with_comments() {
+    let code = "\
+// Main function.
+fn main() {
+    // Body.
+    foo();
+}
+";
+    let syn_file = syn::parse_file(code).unwrap();
+    let file = syn_serde::File::from_syn_with_comments(&syn_file, code);
+
+    let arena = Arena::new(&file);
+    let json = serde_json::to_string(&arena).unwrap();
+    let arena: Arena = serde_json::from_str(&json).unwrap();
+    let file2: syn_serde::File = arena.to_adapter().unwrap();
+    assert_eq!(serde_json::to_value(&file2).unwrap(), serde_json::to_value(&file).unwrap());
+}
+
+#[test]
+fn test_rows() {
+    let syn_file: syn::File = syn::parse_quote! {
+        fn main() {
+            foo.unwrap();
+        }
+    };
+    let arena = Arena::from_syn(&syn_file);
+    let json = serde_json::to_value(&arena).unwrap();
+    let nodes = json["nodes"].as_array().unwrap();
+    assert_eq!(nodes.len(), arena.len());
+
+    assert_eq!(nodes[0]["id"], 0);
+    assert_eq!(nodes[0]["kind"], "file");
+    assert!(nodes[0].get("parent").is_none());
+    assert_eq!(nodes[0]["fields"]["items"], serde_json::json!([null]));
+    assert_eq!(nodes[0]["children"], serde_json::json!([1]));
+
+    assert_eq!(nodes[1]["parent"], 0);
+    assert_eq!(nodes[1]["role"], "items/0");
+    assert_eq!(nodes[1]["kind"], "fn");
+    assert_eq!(nodes[1]["variant"], "fn");
+    assert_eq!(nodes[1]["fields"]["ident"], "main");
+
+    let method_call = nodes.iter().find(|n| n["kind"] == "method_call").unwrap();
+    assert_eq!(method_call["fields"]["method"], "unwrap");
+    assert_eq!(method_call["fields"]["receiver"], serde_json::Value::Null);
+    let receiver = &nodes[usize::try_from(method_call["children"][0].as_u64().unwrap()).unwrap()];
+    assert_eq!(receiver["role"], "receiver");
+    assert_eq!(receiver["kind"], "path");
+}
+
+#[test]
+fn test_invalid() {
+    let syn_file: syn::File = syn::parse_quote! {
+        fn main() {}
+    };
+    let json = serde_json::to_value(Arena::from_syn(&syn_file)).unwrap();
+
+    let mut swapped = json.clone();
+    swapped["nodes"].as_array_mut().unwrap().swap(0, 1);
+    let arena: Arena = serde_json::from_value(swapped).unwrap();
+    assert!(arena.to_syn::<syn::File>().is_err());
+
+    let mut bad_role = json;
+    bad_role["nodes"][1]["role"] = "items/5".into();
+    let arena: Arena = serde_json::from_value(bad_role).unwrap();
+    assert_eq!(
+        arena.to_syn::<syn::File>().unwrap_err().to_string(),
+        "node 1 refers to a nonexistent position `items/5` in node 0"
+    );
+
+    let syn_file: syn::File = syn::parse_quote! {
+        fn f() {}
+        fn g() {}
+    };
+    let mut json = serde_json::to_value(Arena::from_syn(&syn_file)).unwrap();
+    let nodes = json["nodes"].as_array_mut().unwrap();
+    let g = nodes.iter_mut().find(|node| node["role"] == "items/1").unwrap();
+    let id = g["id"].clone();
+    g["role"] = "items/0".into();
+    let arena: Arena = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        arena.to_syn::<syn::File>().unwrap_err().to_string(),
+        format!("nodes 1 and {} have the same position `items/0` in node 0", id)
+    );
+
+    let arena: Arena = serde_json::from_str(r#"{"nodes": []}"#).unwrap();
+    assert!(arena.is_empty());
+    assert!(arena.to_syn::<syn::File>().is_err());
+}
@@ -0,0 +1,128 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "json")]
+
+mod helper;
+
+use std::{fs, path::Path};
+
+use syn_serde::batch::{self, Failure, Options};
+
+use crate::helper::write_dir;
+
+#[test]
+fn test_files() {
+    let dir = write_dir("files", &[
+        (".gitignore", "/target\n*.gen.rs\n!keep.gen.rs\nbuild/\n# comment\n"),
+        ("src/lib.rs", ""),
+        ("src/a.gen.rs", ""),
+        ("src/keep.gen.rs", ""),
+        ("src/build/b.rs", ""),
+        ("src/build.rs", ""),
+        ("src/sub/.gitignore", "c*.rs\n"),
+        ("src/sub/c.rs", ""),
+        ("src/sub/d.rs", ""),
+        ("src/sub/e/c.rs", ""),
+        ("src/x/y/z.rs", ""),
+        ("src/notes.txt", ""),
+        ("target/t.rs", ""),
+        ("tests/target/t.rs", ""),
+        (".git/hooks/h.rs", ""),
+    ]);
+
+    let files = batch::files(&dir, &Options::default()).unwrap();
+    let files: Vec<_> = files.iter().map(|p| p.to_str().unwrap().replace('\\', "/")).collect();
+    assert_eq!(files, [
+        "src/build.rs",
+        "src/keep.gen.rs",
+        "src/lib.rs",
+        "src/sub/d.rs",
+        "src/x/y/z.rs",
+        "tests/target/t.rs"
+    ]);
+
+    let options = Options {
+        excludes: vec!["src/**/z.rs".to_owned(), "[t]ests".to_owned(), "!/src/build/".to_owned()],
+        ..Options::default()
+    };
+    let files = batch::files(&dir, &options).unwrap();
+    let files: Vec<_> = files.iter().map(|p| p.to_str().unwrap().replace('\\', "/")).collect();
+    assert_eq!(files, [
+        "src/build/b.rs",
+        "src/build.rs",
+        "src/keep.gen.rs",
+        "src/lib.rs",
+        "src/sub/d.rs"
+    ]);
+}
+
+#[test]
+fn test_json_lines() {
+    let mut files = vec![];
+    for i in 0..20 {
+        files.push((format!("src/m{:02}.rs", i), format!("fn f{}() {{}}\n", i)));
+    }
+    files.push(("src/m05.rs".to_owned(), "fn f(\n".to_owned()));
+    files.push(("src/m10.rs".to_owned(), "// Comment.\nfn g() { 1 + }\n".to_owned()));
+    files.push(("src/m15.rs".to_owned(), "// Comment.\nfn h() {}\n".to_owned()));
+    let files: Vec<_> = files.iter().map(|(p, c)| (p.as_str(), c.as_str())).collect();
+    let dir = write_dir("json_lines", &files);
+
+    for jobs in [1, 4] {
+        let mut out = vec![];
+        let options = Options { jobs, comments: true, ..Options::default() };
+        let report = batch::to_json_lines(&dir, &mut out, &options).unwrap();
+        assert_eq!(report.converted, 18);
+        assert_eq!(report.failures, [
+            Failure {
+                path: "src/m05.rs".to_owned(),
+                line: Some(1),
+                column: Some(5),
+                message: "cannot parse string into token stream".to_owned(),
+            },
+            Failure {
+                path: "src/m10.rs".to_owned(),
+                line: Some(2),
+                column: Some(14),
+                message: "unexpected end of input, expected an expression".to_owned(),
+            },
+        ]);
+        assert_eq!(
+            report.failures[1].to_string(),
+            "src/m10.rs:2:14: unexpected end of input, expected an expression"
+        );
+
+        let out = String::from_utf8(out).unwrap();
+        let lines: Vec<serde_json::Value> =
+            out.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(lines.len(), 18);
+        assert_eq!(lines[0]["path"], "src/m00.rs");
+        assert_eq!(lines[0]["file"]["items"][0]["fn"]["ident"], "f0");
+        assert_eq!(lines[17]["path"], "src/m19.rs");
+        let h = &lines[13];
+        assert_eq!(h["path"], "src/m15.rs");
+        assert_eq!(h["file"]["items"][0]["fn"]["comments"][0]["text"], "Comment.");
+        for (i, line) in lines.iter().enumerate() {
+            let path = line["path"].as_str().unwrap();
+            assert!(i == 0 || path > lines[i - 1]["path"].as_str().unwrap());
+        }
+    }
+}
+
+#[test]
+fn test_dir() {
+    let dir = write_dir("dir", &[("in/a.rs", "struct A;\n"), ("in/b/c.rs", "struct C;\n")]);
+    let out = dir.join("out");
+    let options = Options { pretty: true, ..Options::default() };
+    let report = batch::to_dir(&dir.join("in"), &out, &options).unwrap();
+    assert_eq!(report.converted, 2);
+    assert!(report.failures.is_empty());
+
+    let json = fs::read_to_string(out.join("b/c.json")).unwrap();
+    assert!(json.starts_with("{\n"));
+    let file: syn::File = syn_serde::json::from_str(&json).unwrap();
+    assert_eq!(file, syn::parse_str("struct C;").unwrap());
+    assert!(Path::new(&out.join("a.json")).is_file());
+
+    assert!(batch::to_dir(&dir.join("nonexistent"), &out, &options).is_err());
+}
@@ -0,0 +1,179 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use quote::ToTokens as _;
+use syn_serde::{Syn as _, cfg::Cfg};
+
+fn cfg(options: &[&str]) -> Cfg {
+    let mut cfg = Cfg::new();
+    for option in options {
+        cfg.enable(option).unwrap();
+    }
+    cfg
+}
+
+fn strip(cfg: &Cfg, code: &str) -> String {
+    let syn_file: syn::File = syn::parse_str(code).unwrap();
+    cfg.strip_syn(&syn_file).unwrap().into_token_stream().to_string()
+}
+
+fn tokens(code: &str) -> String {
+    syn::parse_str::<syn::File>(code).unwrap().into_token_stream().to_string()
+}
+
+#[test]
+fn test_eval() {
+    let cfg = cfg(&["unix", "feature = \"a\"", "r#type", "target_os=\"linux\""]);
+    for (predicate, expected) in [
+        ("unix", true),
+        ("windows", false),
+        ("r#type", true),
+        ("feature = \"a\"", true),
+        ("feature = \"b\"", false),
+        ("feature", false),
+        ("target_os = \"linux\"", true),
+        ("all()", true),
+        ("any()", false),
+        ("all(unix, feature = \"a\")", true),
+        ("all(unix, windows)", false),
+        ("any(windows, not(windows))", true),
+        ("not(any(windows, feature = \"b\"))", true),
+        ("all(true)", true),
+        ("any(false)", false),
+        ("not(false)", true),
+    ] {
+        let predicate: syn::Meta = syn::parse_str(predicate).unwrap();
+        assert_eq!(cfg.eval(&predicate).unwrap(), expected, "{:?}", predicate);
+    }
+
+    for (predicate, msg) in [
+        ("not(unix, windows)", "expected 1 cfg predicate in `not(..)`"),
+        ("one(unix)", "invalid cfg predicate `one`"),
+        ("feature = 1", "cfg predicate value must be a string"),
+        ("a::b", "cfg predicate key must be an identifier"),
+    ] {
+        let predicate: syn::Meta = syn::parse_str(predicate).unwrap();
+        assert_eq!(cfg.eval(&predicate).unwrap_err().to_string(), msg);
+    }
+
+    let syn_file: syn::File =
+        syn::parse_str("#[cfg(true)] fn a() {} #[cfg_attr(false, cfg(false))] fn b() {}").unwrap();
+    assert_eq!(
+        cfg.strip_syn(&syn_file).unwrap().into_token_stream().to_string(),
+        tokens("fn a() {} fn b() {}")
+    );
+
+    let mut cfg = Cfg::new();
+    assert_eq!(cfg.enable("all(unix)").unwrap_err().to_string(), "invalid cfg option `all(unix)`");
+    assert!(cfg.enable("feature = 1").is_err());
+    assert!(cfg.enable("a b").is_err());
+}
+
+#[test]
+fn test_strip() {
+    let cfg = cfg(&["unix", "feature = \"serde\""]);
+    let code = r#"
+        #[cfg(unix)]
+        use std::os::unix;
+        #[cfg(windows)]
+        use std::os::windows;
+
+        #[cfg_attr(feature = "serde", derive(Serialize), serde(rename_all = "kebab-case"))]
+        #[cfg_attr(windows, repr(C))]
+        pub struct S {
+            #[cfg(unix)]
+            a: u8,
+            #[cfg(not(unix))]
+            b: u16,
+        }
+
+        enum E {
+            #[cfg(windows)]
+            A,
+            B,
+        }
+
+        #[cfg_attr(unix, cfg_attr(feature = "serde", cfg(windows)))]
+        fn removed() {}
+
+        mod m {
+            #![cfg(windows)]
+        }
+
+        fn f(x: u8) -> [u8; 1] {
+            #[cfg(unix)]
+            let y = 1;
+            #[cfg(windows)]
+            let y = 2;
+            #[cfg(windows)]
+            println!();
+            match x {
+                #[cfg(windows)]
+                0 => {}
+                _ => {}
+            }
+            g(#[cfg(windows)] 1, 2);
+            [#[cfg(unix)] y, #[cfg(windows)] 0]
+        }
+    "#;
+    let expected = r#"
+        use std::os::unix;
+
+        #[derive(Serialize)]
+        #[serde(rename_all = "kebab-case")]
+        pub struct S {
+            a: u8,
+        }
+
+        enum E {
+            B,
+        }
+
+        fn f(x: u8) -> [u8; 1] {
+            let y = 1;
+            match x {
+                _ => {}
+            }
+            g(2);
+            [y]
+        }
+    "#;
+    assert_eq!(strip(&cfg, code), tokens(expected));
+}
+
+#[test]
+fn test_root() {
+    let cfg = Cfg::new();
+    assert_eq!(
+        strip(&cfg, "#![cfg(unix)]\n#![allow(dead_code)]\nfn f() {}"),
+        tokens("#![allow(dead_code)]")
+    );
+
+    let syn_file: syn::File = syn::parse_str("fn f() { let x = #[cfg(unix)] 1; }").unwrap();
+    assert_eq!(
+        cfg.strip_syn(&syn_file).unwrap_err().to_string(),
+        "removing a node with `#[cfg]` is not supported in this position"
+    );
+    let syn_file: syn::File = syn::parse_str("#[cfg = \"unix\"] fn f() {}").unwrap();
+    assert_eq!(
+        cfg.strip_syn(&syn_file).unwrap_err().to_string(),
+        "malformed `cfg` attribute: expected `#[cfg(..)]`"
+    );
+}
+
+#[test]
+fn test_spans() {
+    let syn_file: syn::File = syn::parse_str("\n#[cfg_attr(unix, inline)]\nfn f() {}").unwrap();
+    let file = cfg(&["unix"]).strip(&syn_file.to_adapter()).unwrap();
+    let json = serde_json::to_value(&file).unwrap();
+    let path = &json["items"][0]["fn"]["attrs"][0]["meta"]["path"];
+    assert_eq!(path["segments"][0]["ident"], "inline");
+    assert_eq!(path["span"]["start_line"], 2);
+}
+
+#[test]
+fn test_lossless() {
+    let cfg = Cfg::new();
+    let code = "fn f(a: u8, #[cfg(unix)] b: u8,) { match a { 0 => {}, #[cfg(unix)] _ => {} } }";
+    let expected = "fn f(a: u8,) { match a { 0 => {}, } }";
+    assert_eq!(strip(&cfg, code), tokens(expected));
+}
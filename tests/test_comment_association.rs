@@ -1,6 +1,8 @@
 //! Test for comment association with AST nodes
 //! This test verifies that comments are properly attached to their corresponding AST nodes
 
+#![allow(unused_imports, unused_variables, clippy::needless_raw_string_hashes)]
+
 use std::collections::HashMap;
 
 #[cfg(test)]
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "json")]
+
+use syn_serde::json;
+
+const DEPTH: usize = 100_000;
+
+/// Builds `const X: i32 = <expr>;`, where `<expr>` is `DEPTH` levels of
+/// `level` around `1`.
+fn deep_file(level: (&str, &str)) -> String {
+    let mut s = String::from(
+        r#"{"items":[{"const":{"ident":"X","ty":{"path":{"segments":[{"ident":"i32"}]}},"expr":"#,
+    );
+    for _ in 0..DEPTH {
+        s.push_str(level.0);
+    }
+    s.push_str(r#"{"lit":{"int":"1"}}"#);
+    for _ in 0..DEPTH {
+        s.push_str(level.1);
+    }
+    s.push_str("}}]}");
+    s
+}
+
+/// Returns the number of nested binary expressions or blocks, dropping the
+/// expression without recursion.
+fn depth(mut expr: syn::Expr) -> usize {
+    let mut depth = 0;
+    loop {
+        expr = match expr {
+            syn::Expr::Binary(e) => *e.left,
+            syn::Expr::Block(e) => match e.block.stmts.into_iter().next() {
+                Some(syn::Stmt::Expr(e, None)) => e,
+                _ => return depth,
+            },
+            _ => return depth,
+        };
+        depth += 1;
+    }
+}
+
+fn const_expr(file: syn::File) -> syn::Expr {
+    match file.items.into_iter().next() {
+        Some(syn::Item::Const(item)) => *item.expr,
+        _ => panic!(),
+    }
+}
+
+#[test]
+fn test_same_as_from_str() {
+    let syn_file: syn::File = syn::parse_quote! {
+        fn f(x: Option<u8>) -> u8 {
+            let v = vec![1, 2, 3];
+            match x {
+                Some(n) if n > 1 => loop { break n * 2 },
+                Some(n) => { n }
+                None => if v.is_empty() { 0 } else { v[0] + v.len() as u8 },
+            }
+        }
+    };
+    let s = json::to_string(&syn_file);
+    let expected: syn::File = json::from_str(&s).unwrap();
+    assert_eq!(json::from_str_deep(&s).unwrap(), expected);
+    assert_eq!(json::from_slice_deep(s.as_bytes()).unwrap(), expected);
+}
+
+#[test]
+fn test_deep_binary() {
+    let s = deep_file((r#"{"binary":{"left":"#, r#","op":"+","right":{"lit":{"int":"1"}}}}"#));
+    let file = json::from_str_deep(&s).unwrap();
+    assert_eq!(depth(const_expr(file)), DEPTH);
+}
+
+#[test]
+fn test_deep_block() {
+    let s = deep_file((r#"{"block":{"stmts":{"stmts":[{"expr":["#, r",false]}]}}}"));
+    let file = json::from_str_deep(&s).unwrap();
+    assert_eq!(depth(const_expr(file)), DEPTH);
+}
+
+#[test]
+fn test_errors() {
+    let mut s = "[".repeat(DEPTH);
+    s.push_str("1,");
+    let e = json::from_str_deep(&s).unwrap_err();
+    assert_eq!(e.to_string(), format!("EOF while parsing a value at line 1 column {}", DEPTH + 2));
+
+    let s = deep_file((r#"{"binary":{"left":"#, r#","op":"?","right":{"lit":{"int":"1"}}}}"#));
+    assert!(json::from_str_deep(&s).is_err());
+
+    assert!(json::from_str_deep(r#"{"items":[]} x"#).is_err());
+    assert!(json::from_str_deep(r#"{"items":[}"#).is_err());
+    assert!(json::from_str_deep(r#"{"items":[{"const":"\ud800"}]}"#).is_err());
+}
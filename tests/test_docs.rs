@@ -0,0 +1,116 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "json")]
+
+use syn_serde::docs::WithDocs;
+
+fn round_trip(code: &str) -> serde_json::Value {
+    let syn_file: syn::File = syn::parse_str(code).unwrap();
+    let json = serde_json::to_value(WithDocs::from_syn(&syn_file)).unwrap();
+    let tree: WithDocs = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(tree.to_syn::<syn::File>().unwrap(), syn_file);
+    json
+}
+
+#[test]
+fn test_docs() {
+    let json = round_trip(
+        r#"//! Crate docs.
+//!
+//! More "docs".
+#![allow(dead_code)]
+
+/// An enum.
+///
+///     indented
+#[derive(Debug)]
+/// Not collected.
+pub enum E {
+    /// A variant.
+    A {
+        /// A field.
+        x: u8
+    }
+}
+
+impl E {
+    /// A method.
+    fn f() {}
+}
+
+mod m {
+    //! Module docs.
+}
+"#,
+    );
+
+    assert_eq!(json["inner_docs"]["text"], "Crate docs.\n\nMore \"docs\".");
+    assert_eq!(json["inner_docs"]["span"]["start_line"], 1);
+    assert_eq!(json["inner_docs"]["span"]["end_line"], 3);
+    assert_eq!(json["attrs"].as_array().unwrap().len(), 1);
+    assert!(json.get("docs").is_none());
+
+    let e = &json["items"][0]["enum"];
+    assert_eq!(e["docs"]["text"], "An enum.\n\n    indented");
+    let attrs = e["attrs"].as_array().unwrap();
+    assert_eq!(attrs.len(), 2);
+    assert_eq!(attrs[1]["meta"]["name_value"]["value"]["lit"]["str"], "\" Not collected.\"");
+    let variant = &e["variants"][0];
+    assert_eq!(variant["docs"]["text"], "A variant.");
+    assert!(variant.get("attrs").is_none());
+    assert_eq!(variant["fields"]["named"][0]["docs"]["text"], "A field.");
+
+    let f = &json["items"][1]["impl"]["items"][0]["fn"];
+    assert_eq!(f["docs"]["text"], "A method.");
+    assert_eq!(f["docs"]["span"]["start_line"], 20);
+
+    let m = &json["items"][2]["mod"];
+    assert_eq!(m["inner_docs"]["text"], "Module docs.");
+    assert!(m.get("docs").is_none());
+}
+
+#[test]
+fn test_raw_attrs() {
+    let json = round_trip(concat!(
+        "/**\n * Block.\n */\n",
+        "struct A;\n",
+        "///No space.\n",
+        "struct B;\n",
+        "#[doc = r\"raw\"]\n",
+        "struct C;\n",
+        "/// Space only:\n",
+        "/// \n",
+        "struct D;\n",
+        "#[doc = \"x\\u{41}\"]\n",
+        "struct E;\n",
+    ));
+    for item in json["items"].as_array().unwrap() {
+        let item = &item["struct"];
+        assert!(item.get("docs").is_none(), "{}", item);
+        assert!(item["attrs"].is_array());
+    }
+}
+
+#[test]
+fn test_hand_written() {
+    // Trees without spans, or with docs but no attributes, can be deserialized.
+    let json = serde_json::json!({
+        "items": [{
+            "fn": {
+                "inner_docs": { "text": "Inner." },
+                "docs": { "text": "Line 1.\n\nLine 2." },
+                "attrs": [{ "style": "outer", "meta": { "path": { "segments": [{ "ident": "inline" }] } } }],
+                "ident": "f",
+                "inputs": [],
+                "output": null,
+                "stmts": { "stmts": [] },
+            }
+        }]
+    });
+    let tree: WithDocs = serde_json::from_value(json).unwrap();
+    let syn_file: syn::File = tree.to_syn().unwrap();
+    let expected: syn::File =
+        syn::parse_str("/// Line 1.\n///\n/// Line 2.\n#[inline]\nfn f() {\n    //! Inner.\n}\n")
+            .unwrap();
+    assert_eq!(syn_file, expected);
+}
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use syn_serde::{Syn as _, explicit::Explicit};
+
+fn keys(value: &serde_json::Value) -> Vec<&str> {
+    value.as_object().unwrap().keys().map(String::as_str).collect()
+}
+
+#[test]
+fn test_explicit() {
+    let syn_file: syn::File = syn::parse_quote! {
+        #[inline]
+        pub const unsafe fn f<T>(x: &'static mut T) -> u8 where T: Copy { 0 }
+        fn g(x: &T) {}
+        struct S { a: u8 }
+        static mut X: u8 = 0;
+        static Y: u8 = 0;
+    };
+    let json = serde_json::to_value(Explicit::from_syn(&syn_file)).unwrap();
+    let items = &json["items"];
+
+    // Nodes of the same kind have the same fields.
+    let (f, g) = (&items[0]["fn"], &items[1]["fn"]);
+    assert_eq!(keys(f), keys(g));
+    assert_eq!(g["attrs"], serde_json::json!([]));
+    assert_eq!(g["vis"], "inherited");
+    assert_eq!(g["const"], false);
+    assert_eq!(g["unsafe"], false);
+    assert_eq!(g["abi"], serde_json::Value::Null);
+    assert_eq!(g["generics"]["params"], serde_json::json!([]));
+    assert_eq!(g["generics"]["where_clause"], serde_json::Value::Null);
+    let (x, y) =
+        (&f["inputs"][0]["typed"]["ty"]["reference"], &g["inputs"][0]["typed"]["ty"]["reference"]);
+    assert_eq!(keys(x), keys(y));
+    assert_eq!(y["lifetime"], serde_json::Value::Null);
+    assert_eq!(y["mut"], false);
+    let field = &items[2]["struct"]["fields"]["named"][0];
+    assert_eq!(field["mut"], "none");
+    assert_eq!(field["colon_token"], true);
+    assert_eq!(keys(&items[3]["static"]), keys(&items[4]["static"]));
+    assert_eq!(items[4]["static"]["mut"], "none");
+
+    // Both forms can be deserialized.
+    let file: syn_serde::File = serde_json::from_value(json).unwrap();
+    assert_eq!(syn::File::from_adapter(&file), syn_file);
+
+    // The default mode omits the defaults.
+    let json = serde_json::to_value(syn_file.to_adapter()).unwrap();
+    let g = &json["items"][1]["fn"];
+    assert!(g.get("attrs").is_none());
+    assert!(g.get("vis").is_none());
+    assert!(g.get("unsafe").is_none());
+}
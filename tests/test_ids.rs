@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use syn_serde::ids::WithIds;
+
+#[test]
+fn test_ids() {
+    let syn_file: syn::File = syn::parse_quote! {
+        fn main() {
+            foo.unwrap();
+        }
+    };
+    let tree = WithIds::from_syn(&syn_file);
+    let json = serde_json::to_value(&tree).unwrap();
+
+    let nodes = tree.nodes();
+    for (id, node) in nodes.iter().enumerate() {
+        assert_eq!(node.id, id);
+        if let Some(parent) = node.parent {
+            assert!(parent < id);
+        }
+    }
+
+    let item_fn = &json["items"][0]["fn"];
+    let block = &item_fn["stmts"];
+    assert_eq!(json["id"], 0);
+    assert_eq!(item_fn["id"], 1);
+    let block_id = usize::try_from(block["id"].as_u64().unwrap()).unwrap();
+    assert_eq!(nodes[block_id].parent, Some(1));
+    assert_eq!(nodes[block_id].role.as_deref(), Some("stmts"));
+    assert_eq!(nodes[block_id].kind.as_deref(), Some("block"));
+    assert_eq!(nodes[block_id].path, "/items/0/fn/stmts");
+
+    let stmt = nodes.iter().find(|n| n.path == "/items/0/fn/stmts/stmts/0").unwrap();
+    assert_eq!(stmt.parent, Some(block_id));
+    assert_eq!(stmt.role.as_deref(), Some("stmts/0"));
+    assert_eq!(stmt.kind.as_deref(), Some("expr"));
+    assert_eq!(block["stmts"][0]["expr"]["id"].as_u64(), Some(stmt.id as u64));
+    let method_call = &block["stmts"][0]["expr"]["expr"]["method_call"];
+    let method_call_id = usize::try_from(method_call["id"].as_u64().unwrap()).unwrap();
+    assert_eq!(nodes[method_call_id].parent, Some(stmt.id));
+    assert_eq!(nodes[method_call_id].role.as_deref(), Some("expr"));
+
+    // Ids are ignored when deserializing.
+    let file: syn_serde::File = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        serde_json::to_value(&file).unwrap(),
+        serde_json::to_value(syn_serde::File::from(&syn_file)).unwrap()
+    );
+}
+
+#[test]
+fn test_comment_ids() {
+    let code = "\
+// Main function.
+fn main() {
+    // Body.
+    foo();
+}
+";
+    let syn_file = syn::parse_file(code).unwrap();
+    let file = syn_serde::File::from_syn_with_comments(&syn_file, code);
+
+    // Ids do not depend on comments.
+    let tree = WithIds::new(&file);
+    assert_eq!(tree.nodes(), WithIds::from_syn(&syn_file).nodes());
+
+    let json = serde_json::to_value(&tree).unwrap();
+    let item_fn = &json["items"][0]["fn"];
+    assert_eq!(item_fn["comments"][0]["text"], "Main function.");
+    assert_eq!(item_fn["stmts"]["comments"][0]["text"], "Body.");
+}
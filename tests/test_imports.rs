@@ -0,0 +1,152 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod helper;
+
+use syn_serde::{
+    Syn as _,
+    imports::{self, Import},
+    krate::Crate,
+};
+
+use crate::helper::write_dir;
+
+fn flatten(code: &str) -> Vec<Import> {
+    let syn_file: syn::File = syn::parse_str(code).unwrap();
+    imports::flatten(&syn_file.to_adapter())
+}
+
+#[test]
+fn test_flatten() {
+    let imports = flatten(
+        "
+        use std::{collections::{HashMap, hash_map::{self, Entry as E}}, *};
+        pub(crate) use ::serde::Serialize as _;
+        mod a {
+            use super::b::C;
+        }
+        fn f() {
+            use core::mem;
+        }
+        const _: fn() = || {
+            use self::a::D;
+        };
+        ",
+    );
+    let paths: Vec<_> = imports.iter().map(|i| (&*i.path, i.alias.as_deref())).collect();
+    assert_eq!(paths, [
+        ("std::collections::HashMap", None),
+        ("std::collections::hash_map", None),
+        ("std::collections::hash_map::Entry", Some("E")),
+        ("std::*", None),
+        ("::serde::Serialize", Some("_")),
+        ("crate::b::C", None),
+        ("core::mem", None),
+        ("crate::a::D", None),
+    ]);
+    let names: Vec<_> = imports.iter().map(Import::name).collect();
+    assert_eq!(names, [
+        Some("HashMap"),
+        Some("hash_map"),
+        Some("E"),
+        None,
+        None,
+        Some("C"),
+        Some("mem"),
+        Some("D")
+    ]);
+    assert!(imports[3].is_glob());
+    assert!(!imports[0].is_glob());
+
+    assert_eq!(imports[0].visibility, "");
+    assert_eq!(imports[4].visibility, "pub(crate)");
+    assert_eq!(imports[5].module, "crate::a");
+    assert!(!imports[5].local);
+    assert_eq!(imports[6].module, "crate");
+    assert!(imports[6].local);
+    assert_eq!(imports[7].module, "crate");
+    assert!(imports[7].local);
+
+    let span = imports[4].span.unwrap();
+    assert_eq!((span.start_line, span.start_column), (3, 19));
+    assert!(imports.iter().all(|i| i.resolved.is_none()));
+}
+
+#[test]
+fn test_absolute_paths() {
+    let imports = flatten(
+        "
+        use super::a;
+        mod a {
+            mod b {
+                use self::super::super::c::{self, D as E};
+                use super::{*, super::f};
+            }
+        }
+        ",
+    );
+    let paths: Vec<_> = imports.iter().map(|i| (&*i.module, &*i.path)).collect();
+    assert_eq!(paths, [
+        // The root of the crate has no parent.
+        ("crate", "super::a"),
+        ("crate::a::b", "crate::c"),
+        ("crate::a::b", "crate::c::D"),
+        ("crate::a::b", "crate::a::*"),
+        ("crate::a::b", "crate::f"),
+    ]);
+}
+
+#[test]
+fn test_resolve() {
+    let dir = write_dir("resolve", &[
+        (
+            "lib.rs",
+            "
+mod shapes;
+pub mod prelude {
+    pub use crate::shapes::{Square as Quad, Kind::*};
+    pub use super::shapes::circle::*;
+}
+use prelude::{Quad, Circle, Round};
+use self::prelude::Triangle;
+use std::fmt;
+use shapes::circle;
+use shapes::Kind::Flat;
+use crate::missing::X;
+use crate::a::*;
+mod a {
+    pub use crate::b::*;
+}
+mod b {
+    pub use crate::a::*;
+}
+use a::Y;
+",
+        ),
+        ("shapes.rs", "pub mod circle;\npub struct Square;\npub enum Kind { Round, Flat }\n"),
+        ("shapes/circle.rs", "pub struct Circle;\nfn area() { use super::Square; }\n"),
+    ]);
+    let krate = Crate::load(dir.join("lib.rs")).unwrap();
+    let imports = imports::resolve(&krate);
+    let resolved: Vec<_> =
+        imports.iter().map(|i| (&*i.module, &*i.path, i.resolved.as_deref())).collect();
+    assert_eq!(resolved, [
+        ("crate::shapes::circle", "crate::shapes::Square", Some("crate::shapes::Square")),
+        ("crate::prelude", "crate::shapes::Square", Some("crate::shapes::Square")),
+        ("crate::prelude", "crate::shapes::Kind::*", Some("crate::shapes::Kind")),
+        ("crate::prelude", "crate::shapes::circle::*", Some("crate::shapes::circle")),
+        ("crate", "prelude::Quad", Some("crate::shapes::Square")),
+        ("crate", "prelude::Circle", Some("crate::shapes::circle::Circle")),
+        ("crate", "prelude::Round", Some("crate::shapes::Kind::Round")),
+        ("crate", "crate::prelude::Triangle", None),
+        ("crate", "std::fmt", None),
+        ("crate", "shapes::circle", Some("crate::shapes::circle")),
+        ("crate", "shapes::Kind::Flat", Some("crate::shapes::Kind::Flat")),
+        ("crate", "crate::missing::X", None),
+        ("crate", "crate::a::*", Some("crate::a")),
+        ("crate::a", "crate::b::*", Some("crate::b")),
+        ("crate::b", "crate::a::*", Some("crate::a")),
+        ("crate", "a::Y", None),
+    ]);
+    assert!(imports[0].local);
+    assert_eq!(imports[0].span.unwrap().file, Some(2));
+}
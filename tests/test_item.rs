@@ -8,6 +8,21 @@ fn print_actual(actual: &impl syn_serde::Syn) {
     println!("actual:\n```\n{}\n", syn_serde::json::to_string_pretty(actual));
 }
 
+/// Checks that the item survives a round trip through the protobuf encoding,
+/// along with its spans.
+#[cfg(feature = "protobuf")]
+fn assert_protobuf_round_trip(raw: &str) {
+    use syn_serde::{Syn as _, protobuf};
+
+    let file = syn::parse_file(raw).unwrap();
+    let adapter = file.to_adapter();
+    let decoded = protobuf::decode(&protobuf::encode(&adapter)).unwrap();
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), serde_json::to_value(&adapter).unwrap());
+    assert_eq!(File::from_adapter(&decoded), file);
+}
+#[cfg(not(feature = "protobuf"))]
+fn assert_protobuf_round_trip(_raw: &str) {}
+
 #[test]
 fn test_unit() {
     let raw = "struct Unit;";
@@ -23,6 +38,7 @@ fn test_unit() {
 
     let actual = syn::parse_str(raw).unwrap();
     print_actual(&actual);
+    assert_protobuf_round_trip(raw);
     let ser: syn_serde::Item = serde_json::from_str(json).unwrap();
     let ser = Item::from(&ser);
     assert_eq!(ser, actual);
@@ -129,6 +145,7 @@ fn test_struct() {
 
     let actual = syn::parse_str(raw).unwrap();
     print_actual(&actual);
+    assert_protobuf_round_trip(raw);
     let json: syn_serde::Item = serde_json::from_str(json).unwrap();
     let json = Item::from(&json);
     assert_eq!(json, actual);
@@ -186,6 +203,7 @@ fn test_union() {
 
     let actual = syn::parse_str(raw).unwrap();
     print_actual(&actual);
+    assert_protobuf_round_trip(raw);
     let json: syn_serde::Item = serde_json::from_str(json).unwrap();
     let json = Item::from(&json);
     assert_eq!(json, actual);
@@ -347,6 +365,7 @@ fn test_enum() {
 
     let actual = syn::parse_str(raw).unwrap();
     print_actual(&actual);
+    assert_protobuf_round_trip(raw);
     let json: syn_serde::Item = serde_json::from_str(json).unwrap();
     let json = Item::from(&json);
     assert_eq!(json, actual);
@@ -411,6 +430,7 @@ fn test_pub_restricted() {
 
     let actual = syn::parse_str(raw).unwrap();
     print_actual(&actual);
+    assert_protobuf_round_trip(raw);
     let json: syn_serde::Item = serde_json::from_str(json).unwrap();
     let json = Item::from(&json);
     assert_eq!(json, actual);
@@ -444,6 +464,7 @@ fn test_pub_restricted_crate() {
 
     let actual = syn::parse_str(raw).unwrap();
     print_actual(&actual);
+    assert_protobuf_round_trip(raw);
     let json: syn_serde::Item = serde_json::from_str(json).unwrap();
     let json = Item::from(&json);
     assert_eq!(json, actual);
@@ -477,6 +498,7 @@ fn test_pub_restricted_super() {
 
     let actual = syn::parse_str(raw).unwrap();
     print_actual(&actual);
+    assert_protobuf_round_trip(raw);
     let json: syn_serde::Item = serde_json::from_str(json).unwrap();
     let json = Item::from(&json);
     assert_eq!(json, actual);
@@ -511,6 +533,7 @@ fn test_pub_restricted_in_super() {
 
     let actual = syn::parse_str(raw).unwrap();
     print_actual(&actual);
+    assert_protobuf_round_trip(raw);
     let json: syn_serde::Item = serde_json::from_str(json).unwrap();
     let json = Item::from(&json);
     assert_eq!(json, actual);
@@ -549,6 +572,7 @@ fn test_ambiguous_crate() {
 
     let actual = syn::parse_str(raw).unwrap();
     print_actual(&actual);
+    assert_protobuf_round_trip(raw);
     let json: syn_serde::Item = serde_json::from_str(json).unwrap();
     let json = Item::from(&json);
     assert_eq!(json, actual);
@@ -585,10 +609,74 @@ fn test_static_mut() {
 
     let actual = syn::parse_str(raw).unwrap();
     print_actual(&actual);
+    assert_protobuf_round_trip(raw);
     let json: syn_serde::Item = serde_json::from_str(json).unwrap();
     let json = Item::from(&json);
     assert_eq!(json, actual);
 }
+
+#[test]
+fn test_c_str() {
+    let raw = r##"const C: T = [c"a\x7F\xFF\u{e9}\n", cr#"\0"b"#];"##;
+
+    let json = r##"
+    {
+      "const": {
+        "ident": "C",
+        "ty": {
+          "path": {
+            "segments": [
+              {
+                "ident": "T"
+              }
+            ]
+          }
+        },
+        "expr": {
+          "array": {
+            "elems": [
+              {
+                "lit": {
+                  "c_str": "c\"a\\x7F\\xFF\\u{e9}\\n\""
+                }
+              },
+              {
+                "lit": {
+                  "c_str": "cr#\"\\0\"b\"#"
+                }
+              }
+            ]
+          }
+        }
+      }
+    }
+    "##;
+
+    let actual = syn::parse_str(raw).unwrap();
+    print_actual(&actual);
+    assert_protobuf_round_trip(raw);
+    let json: syn_serde::Item = serde_json::from_str(json).unwrap();
+    let json = Item::from(&json);
+    assert_eq!(json, actual);
+
+    let elems = match json {
+        Item::Const(ItemConst { expr, .. }) => match *expr {
+            Expr::Array(array) => array.elems,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    let values: Vec<_> = elems
+        .iter()
+        .map(|elem| match elem {
+            Expr::Lit(ExprLit { lit: Lit::CStr(lit), .. }) => lit.value(),
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(values[0].as_bytes(), b"a\x7F\xFF\xC3\xA9\n");
+    assert_eq!(values[1].as_bytes(), br#"\0"b"#);
+}
+
 #[test]
 fn test_backward_compatibility() {
     // Test that JSON without spans still deserializes correctly
@@ -604,3 +692,68 @@ fn test_backward_compatibility() {
     let _parsed: syn_serde::Item = serde_json::from_str(json_without_spans).unwrap();
     println!("Backward compatibility test passed - JSON without spans can still be parsed!");
 }
+
+#[test]
+fn test_raw_ident() {
+    let raw = "struct r#type { r#match: u8 }";
+
+    let json = r#"
+    {
+      "struct": {
+        "ident": "r#type",
+        "fields": {
+          "named": [
+            {
+              "ident": "r#match",
+              "colon_token": true,
+              "ty": {
+                "path": {
+                  "segments": [
+                    {
+                      "ident": "u8"
+                    }
+                  ]
+                }
+              }
+            }
+          ]
+        }
+      }
+    }
+    "#;
+
+    let actual = syn::parse_str(raw).unwrap();
+    print_actual(&actual);
+    assert_protobuf_round_trip(raw);
+    let ser: syn_serde::Item = serde_json::from_str(json).unwrap();
+    let ser = Item::from(&ser);
+    assert_eq!(ser, actual);
+}
+
+#[test]
+fn test_invalid_tokens() {
+    let item = |ident: &str, tokens: serde_json::Value| {
+        serde_json::from_value::<syn_serde::Item>(serde_json::json!({
+            "macro": {
+                "ident": ident,
+                "path": {"segments": [{"ident": "m"}]},
+                "delimiter": "paren",
+                "tokens": tokens,
+            }
+        }))
+    };
+    let tokens = serde_json::json!([
+        {"punct": {"op": "+", "spacing": "alone"}},
+        {"lit": "1u8"},
+        {"ident": "r#a"},
+    ]);
+    assert!(item("m", tokens).is_ok());
+
+    // The conversion to syn types would panic on these.
+    assert!(item("1x", serde_json::json!([])).is_err());
+    assert!(item("m", serde_json::json!([{"ident": "a b"}])).is_err());
+    assert!(item("m", serde_json::json!([{"ident": "r#self"}])).is_err());
+    assert!(item("m", serde_json::json!([{"lit": "x"}])).is_err());
+    assert!(item("m", serde_json::json!([{"lit": "1 2"}])).is_err());
+    assert!(item("m", serde_json::json!([{"punct": {"op": "a", "spacing": "alone"}}])).is_err());
+}
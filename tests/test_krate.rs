@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+mod helper;
+
+use syn_serde::{Syn as _, krate::Crate, lossless::Lossless};
+
+use crate::helper::write_dir;
+
+#[test]
+fn test_modules() {
+    let dir = write_dir("modules", &[
+        (
+            "src/lib.rs",
+            "mod a;\nmod b;\n#[path = \"other/c.rs\"]\nmod c;\nmod inline {\n    mod d;\n}\n#[cfg(windows)]\nmod missing;\n",
+        ),
+        ("src/a.rs", "//! Module a.\n\nmod r#nested;\npub fn a() {}\n"),
+        ("src/a/nested.rs", "fn nested() {}\n"),
+        ("src/b/mod.rs", "#[path = \"../x.rs\"]\nmod x;\n"),
+        ("src/x.rs", "struct X;\n"),
+        ("src/other/c.rs", "mod e;\n"),
+        ("src/other/e.rs", "fn e() {}\n"),
+        ("src/inline/d.rs", "fn d() {}\n"),
+    ]);
+    let krate = Crate::load(dir.join("src/lib.rs")).unwrap();
+
+    assert_eq!(krate.files(), [
+        "lib.rs",
+        "a.rs",
+        "a/nested.rs",
+        "b/mod.rs",
+        "x.rs",
+        "other/c.rs",
+        "other/e.rs",
+        "inline/d.rs"
+    ]);
+    let expected: syn::File = syn::parse_quote! {
+        mod a {
+            #![doc = " Module a."]
+            mod r#nested {
+                fn nested() {}
+            }
+            pub fn a() {}
+        }
+        mod b {
+            #[path = "../x.rs"]
+            mod x {
+                struct X;
+            }
+        }
+        #[path = "other/c.rs"]
+        mod c {
+            mod e {
+                fn e() {}
+            }
+        }
+        mod inline {
+            mod d {
+                fn d() {}
+            }
+        }
+        #[cfg(windows)]
+        mod missing;
+    };
+    assert_eq!(syn::File::from_adapter(krate.root()), expected);
+
+    // Spans record the files they came from.
+    let json = serde_json::to_value(&krate).unwrap();
+    let items = &json["root"]["items"];
+    assert_eq!(json["root"]["span"]["file"], 0);
+    let a = &items[0]["mod"]["content"];
+    assert_eq!(a[0]["mod"]["content"][0]["fn"]["span"]["file"], 2);
+    assert_eq!(a[1]["fn"]["span"]["file"], 1);
+    assert_eq!(a[1]["fn"]["span"]["start_line"], 4);
+    let x = &items[1]["mod"]["content"][0]["mod"]["content"][0]["struct"];
+    assert_eq!(x["ident"], "X");
+    let d = &items[3]["mod"]["content"][0]["mod"]["content"][0]["fn"];
+    assert_eq!(d["span"]["file"], 7);
+
+    let krate: Crate = serde_json::from_value(json).unwrap();
+    assert_eq!(syn::File::from_adapter(krate.root()), expected);
+}
+
+#[test]
+fn test_comments() {
+    let dir = write_dir("comments", &[
+        ("main.rs", "mod a;\nfn main() {}\n"),
+        ("a.rs", "// Comment.\nfn a() {}\n"),
+    ]);
+    let krate = Crate::load_with_comments(dir.join("main.rs")).unwrap();
+    let json = serde_json::to_value(krate.root()).unwrap();
+    let comment = &json["items"][0]["mod"]["content"][0]["fn"]["comments"][0];
+    assert_eq!(comment["text"], "Comment.");
+    assert_eq!(comment["span"]["file"], 1);
+}
+
+#[test]
+fn test_lossless() {
+    let dir = write_dir("lossless", &[
+        ("main.rs", "mod a;
+fn main<>() {}
+"),
+        ("a.rs", "// Comment.
+fn a(x: u8,) {}
+"),
+    ]);
+    for krate in [Crate::load(dir.join("main.rs")), Crate::load_with_comments(dir.join("main.rs"))]
+    {
+        let json = serde_json::to_value(Lossless::new(krate.unwrap().root())).unwrap();
+        assert_eq!(json["items"][1]["fn"]["generics"]["lt_token"], true);
+        let a = &json["items"][0]["mod"]["content"][0]["fn"];
+        assert_eq!(a["inputs"]["trailing"], true);
+        assert_eq!(a["span"]["file"], 1);
+    }
+}
+
+#[test]
+fn test_errors() {
+    let dir = write_dir("errors", &[
+        ("missing.rs", "mod a;\n"),
+        ("ambiguous.rs", "mod b;\n"),
+        ("b.rs", ""),
+        ("b/mod.rs", ""),
+        ("circular.rs", "#[path = \"circular.rs\"]\nmod c;\n"),
+        ("invalid.rs", "mod d;\n"),
+        ("d.rs", "fn f() {\n    let\n}\n"),
+        ("path.rs", "#[path(e)]\nmod e;\n"),
+    ]);
+    for (root, msg) in [
+        ("missing.rs", "file not found for module `a`: expected a.rs or a/mod.rs".to_owned()),
+        ("ambiguous.rs", "file for module `b` found at both b.rs and b/mod.rs".to_owned()),
+        ("circular.rs", "circular.rs: circular modules".to_owned()),
+        ("invalid.rs", "d.rs:3:1: unexpected end of input, expected one of: ".to_owned()),
+        ("path.rs", "malformed `path` attribute: expected `#[path = \"file\"]`".to_owned()),
+    ] {
+        let e = Crate::load(dir.join(root)).err().unwrap();
+        assert!(e.to_string().starts_with(&msg), "{}", e);
+    }
+    assert!(Crate::load(dir.join("nonexistent.rs")).is_err());
+}
+
+#[test]
+fn test_self() {
+    let krate = Crate::load(concat!(env!("CARGO_MANIFEST_DIR"), "/src/lib.rs")).unwrap();
+    let files = krate.files();
+    assert_eq!(files[0], "lib.rs");
+    assert!(files.iter().any(|f| f == "krate.rs"));
+    assert!(files.iter().any(|f| f == "gen/ast_struct.rs"));
+}
@@ -0,0 +1,217 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use syn_serde::{Syn as _, lit_values::WithLitValues};
+
+#[test]
+fn test_lit_values() {
+    let syn_file: syn::File = syn::parse_quote! {
+        fn f() {
+            let _ = ("Hello\n\u{1F600}", b"a\n\xFF", b'\'', '\u{e9}', 0x1F_u8, 1_000, 0b101, 1_000.5e3_f32, 2.);
+        }
+    };
+    let tree = WithLitValues::from_syn(&syn_file);
+    let json = serde_json::to_value(&tree).unwrap();
+    let elems =
+        &json["items"][0]["fn"]["stmts"]["stmts"][0]["let"]["init"]["expr"]["tuple"]["elems"];
+    let lit = |i: usize| &elems[i]["lit"];
+
+    assert_eq!(lit(0)["str"]["value"], "Hello\n\u{1F600}");
+    assert!(lit(0)["str"].get("suffix").is_none());
+    assert_eq!(lit(1)["byte_str"]["token"], "b\"a\\n\\xFF\"");
+    assert_eq!(lit(1)["byte_str"]["value"], serde_json::json!([97, 10, 255]));
+    assert_eq!(lit(2)["byte"]["token"], "b'\\''");
+    assert_eq!(lit(2)["byte"]["value"], 39);
+    assert_eq!(lit(3)["char"]["value"], "\u{e9}");
+    assert_eq!(
+        lit(4)["int"],
+        serde_json::json!({"token": "0x1F_u8", "value": "31", "base": 16, "suffix": "u8"})
+    );
+    assert_eq!(lit(5)["int"], serde_json::json!({"token": "1_000", "value": "1000", "base": 10}));
+    assert_eq!(lit(6)["int"]["value"], "5");
+    assert_eq!(lit(6)["int"]["base"], 2);
+    assert_eq!(
+        lit(7)["float"],
+        serde_json::json!({"token": "1_000.5e3_f32", "value": "1000.5e3", "suffix": "f32"})
+    );
+    assert_eq!(lit(8)["float"]["value"], "2.");
+
+    // Both forms can be deserialized.
+    let file: syn_serde::File = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        serde_json::to_value(&file).unwrap(),
+        serde_json::to_value(syn_serde::File::from(&syn_file)).unwrap()
+    );
+
+    let syn_file: syn::File = syn::parse_quote!(
+        const C: &CStr = c"a\xFF";
+    );
+    let json = serde_json::to_value(WithLitValues::from_syn(&syn_file)).unwrap();
+    assert_eq!(
+        json["items"][0]["const"]["expr"]["lit"]["c_str"],
+        serde_json::json!({"token": "c\"a\\xFF\"", "value": [97, 255]})
+    );
+}
+
+#[test]
+fn test_from_value() {
+    let lit = |json: serde_json::Value| {
+        let lit: syn_serde::Lit = serde_json::from_value(json).unwrap();
+        let lit = syn::Lit::from(&lit);
+        quote::quote!(#lit).to_string()
+    };
+    assert_eq!(lit(serde_json::json!({"str": {"value": "a\"\n"}})), r#""a\"\n""#);
+    assert_eq!(lit(serde_json::json!({"byte_str": {"value": [0, 97]}})), r#"b"\0a""#);
+    assert_eq!(lit(serde_json::json!({"c_str": {"value": [97, 255, 10]}})), r#"c"a\xFF\n""#);
+    assert_eq!(lit(serde_json::json!({"byte": {"value": 10}})), r"b'\n'");
+    assert_eq!(lit(serde_json::json!({"char": {"value": "'"}})), r"'\''");
+    assert_eq!(
+        lit(serde_json::json!({"int": {"value": "31", "base": 16, "suffix": "u8"}})),
+        "0x1fu8"
+    );
+    assert_eq!(lit(serde_json::json!({"int": {"value": "31"}})), "31");
+    assert_eq!(lit(serde_json::json!({"float": {"value": "1.5", "suffix": "f64"}})), "1.5f64");
+    // `token` takes precedence over `value`.
+    assert_eq!(lit(serde_json::json!({"int": {"token": "7", "value": "8"}})), "7");
+
+    let err = |json: serde_json::Value| serde_json::from_value::<syn_serde::Lit>(json).is_err();
+    assert!(err(serde_json::json!({"int": {}})));
+    assert!(err(serde_json::json!({"int": {"value": "x"}})));
+    assert!(err(serde_json::json!({"int": {"value": "1", "base": 3}})));
+    assert!(err(serde_json::json!({"float": {"value": "1.5f32"}})));
+    assert!(err(serde_json::json!({"c_str": {"value": [97, 0]}})));
+}
+
+#[test]
+fn test_suffixes() {
+    let syn_file: syn::File = syn::parse_quote! {
+        fn f() {
+            let _ = ("abc"sfx, r"x"sfx, r#"a"b"#sfx, b"x"sfx, br"x"sfx, b'x'sfx, 'x'sfx, 1sfx, 1.5sfx);
+        }
+    };
+    let tree = WithLitValues::from_syn(&syn_file);
+    let json = serde_json::to_value(&tree).unwrap();
+    let elems =
+        &json["items"][0]["fn"]["stmts"]["stmts"][0]["let"]["init"]["expr"]["tuple"]["elems"];
+    let lit = |i: usize| &elems[i]["lit"];
+
+    assert_eq!(
+        lit(0)["str"],
+        serde_json::json!({"token": "\"abc\"sfx", "value": "abc", "suffix": "sfx"})
+    );
+    assert_eq!(
+        lit(1)["str"],
+        serde_json::json!({"token": "r\"x\"sfx", "value": "x", "suffix": "sfx"})
+    );
+    assert_eq!(lit(2)["str"]["value"], "a\"b");
+    assert_eq!(
+        lit(3)["byte_str"],
+        serde_json::json!({"token": "b\"x\"sfx", "value": [120], "suffix": "sfx"})
+    );
+    assert_eq!(lit(4)["byte_str"]["suffix"], "sfx");
+    assert_eq!(lit(5)["byte"]["suffix"], "sfx");
+    assert_eq!(lit(6)["char"]["suffix"], "sfx");
+    assert_eq!(lit(7)["int"]["suffix"], "sfx");
+    assert_eq!(lit(8)["float"]["suffix"], "sfx");
+
+    // The tokens, including raw strings and suffixes, survive a round trip.
+    let s = serde_json::to_string(&syn_file.to_adapter()).unwrap();
+    let file: syn_serde::File = serde_json::from_str(&s).unwrap();
+    assert_eq!(syn::File::from_adapter(&file), syn_file);
+    let file: syn_serde::File = serde_json::from_value(json).unwrap();
+    assert_eq!(syn::File::from_adapter(&file), syn_file);
+}
+
+#[test]
+fn test_escapes() {
+    let value = |token: &str| {
+        let lit: syn_serde::Lit =
+            serde_json::from_value(serde_json::json!({ "str": token })).unwrap();
+        match syn::Lit::from(&lit) {
+            syn::Lit::Str(lit) => lit.value(),
+            _ => unreachable!(),
+        }
+    };
+    assert_eq!(value(r#""\u{10FFFF}""#), "\u{10FFFF}");
+    assert_eq!(value(r#""\u{1_F6_00_}""#), "\u{1F600}");
+    assert_eq!(value(r#""\u{000041}""#), "A");
+    assert_eq!(value(r#""\x7F\0""#), "\x7F\0");
+    assert_eq!(value("\"a\\\n    b\""), "ab");
+    assert_eq!(value("\"a\r\nb\""), "a\nb");
+
+    let err = |kind: &str, token: &str| {
+        serde_json::from_value::<syn_serde::Lit>(serde_json::json!({ kind: token })).is_err()
+    };
+    assert!(err("str", r#""\u{110000}""#));
+    assert!(err("str", r#""\u{D800}""#));
+    assert!(err("str", r#""\u{}""#));
+    assert!(err("str", r#""\u{_1}""#));
+    assert!(err("str", r#""\u{1234567}""#));
+    assert!(err("str", r#""\u41""#));
+    assert!(err("str", r#""\u{41""#));
+    assert!(err("str", r#""\x80""#));
+    assert!(err("str", r#""\x4""#));
+    assert!(err("str", r#""\q""#));
+    assert!(err("str", "\"a\rb\""));
+    assert!(err("str", r#""abc"#));
+    assert!(err("str", r#""abc"1"#));
+    assert!(err("str", r#""a" "b""#));
+    assert!(err("str", "b\"a\""));
+    assert!(err("byte_str", r#"b"\u{41}""#));
+    assert!(err("byte_str", "b\"\u{e9}\""));
+    assert!(err("byte_str", "br\"\u{e9}\""));
+    assert!(err("c_str", r#"c"\0""#));
+    assert!(err("c_str", r#"c"\x00""#));
+    assert!(err("c_str", r#"c"\u{0}""#));
+    assert!(err("c_str", "cr\"\0\""));
+    assert!(err("byte", "b'ab'"));
+    assert!(err("byte", "b'''"));
+    assert!(err("char", "'\n'"));
+    assert!(err("char", "''"));
+    assert!(err("char", r"'\u{D800}'"));
+    assert!(err("int", "0b102"));
+    assert!(err("int", "1e5"));
+    assert!(err("int", "1.0"));
+    assert!(err("float", "1e"));
+    assert!(err("float", "_1.0"));
+}
+
+#[test]
+fn test_large_int() {
+    let syn_file: syn::File =
+        syn::parse_str("const X: u128 = 340282366920938463463374607431768211456;").unwrap();
+
+    // Integers that syn accepts round-trip, even if their value is too large.
+    let json = syn_serde::json::to_string(&syn_file);
+    let syn_file2: syn::File = syn_serde::json::from_str(&json).unwrap();
+    assert_eq!(syn_file2, syn_file);
+
+    let json = serde_json::to_value(WithLitValues::from_syn(&syn_file)).unwrap();
+    assert_eq!(
+        json["items"][0]["const"]["expr"]["lit"]["int"],
+        serde_json::json!({"token": "340282366920938463463374607431768211456", "base": 10})
+    );
+    let syn_file2: syn::File = syn_serde::json::from_str(&json.to_string()).unwrap();
+    assert_eq!(syn_file2, syn_file);
+}
+
+#[test]
+fn test_token_kinds() {
+    let err = |kind: &str, token: &str| {
+        serde_json::from_value::<syn_serde::Lit>(serde_json::json!({ kind: token })).is_err()
+    };
+    // Tokens of another kind of literal are rejected.
+    assert!(err("str", "1u8"));
+    assert!(err("str", "'a'"));
+    assert!(err("byte_str", r#""a""#));
+    assert!(err("byte_str", r#"c"a""#));
+    assert!(err("c_str", r#"b"a""#));
+    assert!(err("byte", "'a'"));
+    assert!(err("char", "b'a'"));
+    assert!(err("int", "1.0f32"));
+    assert!(err("int", "'a'"));
+    assert!(err("float", "1"));
+    assert!(err("float", "1u8"));
+    // syn parses `1f32` as an integer literal with a `f32` suffix.
+    assert!(err("float", "1f32"));
+    assert!(!err("int", "1f32"));
+}
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use quote::ToTokens as _;
+use syn_serde::{Syn as _, lossless::Lossless};
+
+fn round_trip(json: &serde_json::Value) -> syn::File {
+    let file: syn_serde::File = serde_json::from_value(json.clone()).unwrap();
+    syn::File::from_adapter(&file)
+}
+
+#[test]
+fn test_round_trip() {
+    let syn_file: syn::File = syn::parse_quote! {
+        struct S<>(u8,);
+        struct T { a: u8, b: u8, }
+        trait Tr { fn f(); fn g() {} }
+        fn f<T,>(x: u8,) -> (u8,) {
+            let v = [1, 2,];
+            match x {
+                0 => {},
+                1 => { 1 }
+                _ => (v[0],)
+            }
+        }
+        use a::{b, c,};
+    };
+    let tree = Lossless::from_syn(&syn_file);
+    let json = serde_json::to_value(&tree).unwrap();
+
+    let items = &json["items"];
+    assert_eq!(
+        items[0]["struct"]["generics"],
+        serde_json::json!({"lt_token": true, "gt_token": true})
+    );
+    assert_eq!(items[0]["struct"]["fields"]["unnamed"]["trailing"], true);
+    assert_eq!(items[0]["struct"]["semi_token"], true);
+    assert_eq!(items[1]["struct"]["fields"]["named"]["trailing"], true);
+    assert!(items[1]["struct"].get("semi_token").is_none());
+    assert_eq!(items[2]["trait"]["items"][0]["fn"]["semi_token"], true);
+    assert!(items[2]["trait"]["items"][1]["fn"].get("semi_token").is_none());
+    let f = &items[3]["fn"];
+    assert_eq!(f["generics"]["params"]["trailing"], true);
+    assert_eq!(f["inputs"]["trailing"], true);
+    let arms = &f["stmts"]["stmts"][1]["expr"]["expr"]["match"]["arms"];
+    assert_eq!(arms[0]["comma"], true);
+    assert!(arms[1].get("comma").is_none());
+    assert_eq!(arms[2]["comma"], false);
+
+    let syn_file2 = round_trip(&json);
+    assert_eq!(syn_file2, syn_file);
+    assert_eq!(syn_file2.into_token_stream().to_string(), syn_file.to_token_stream().to_string());
+}
+
+#[test]
+fn test_default_mode() {
+    let syn_file: syn::File = syn::parse_quote! {
+        fn f<>(x: u8,) {
+            match x {
+                0 => {},
+                _ => 1
+            }
+        }
+    };
+
+    // The default serialization does not record the tokens.
+    let json = serde_json::to_value(syn_file.to_adapter()).unwrap();
+    assert!(json["items"][0]["fn"].get("generics").is_none());
+    assert!(json["items"][0]["fn"]["inputs"].is_array());
+    let expected: syn::File = syn::parse_quote! {
+        fn f(x: u8) {
+            match x {
+                0 => {}
+                _ => 1,
+            }
+        }
+    };
+    assert_eq!(round_trip(&json), expected);
+
+    // The lossless form can be deserialized, and the adapters keep the tokens
+    // without serialization.
+    let json = serde_json::to_value(Lossless::from_syn(&syn_file)).unwrap();
+    assert_eq!(round_trip(&json), syn_file);
+    assert_eq!(syn::File::from_adapter(&syn_file.to_adapter()), syn_file);
+}
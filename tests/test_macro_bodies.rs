@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "json")]
+
+use serde_json::json;
+use syn_serde::macro_bodies::{BodyKind, MacroBodies, WithMacroBodies};
+
+/// Returns the bodies of the macro statements in the body of `fn f`.
+fn interpret(bodies: &MacroBodies, stmts: &str) -> Vec<serde_json::Value> {
+    let syn_file: syn::File = syn::parse_str(&format!("fn f() {{ {} }}", stmts)).unwrap();
+    let json = serde_json::to_value(bodies.interpret_syn(&syn_file)).unwrap();
+
+    // The tokens are kept, so the tree is read back losslessly.
+    let syn_file2: syn::File = syn_serde::json::from_str(&json.to_string()).unwrap();
+    assert_eq!(syn_file2, syn_file);
+
+    let stmts = json["items"][0]["fn"]["stmts"]["stmts"].as_array().unwrap();
+    stmts
+        .iter()
+        .map(|stmt| {
+            let mac = &stmt["macro"];
+            assert!(mac["tokens"].is_array());
+            mac.get("body").cloned().unwrap_or(serde_json::Value::Null)
+        })
+        .collect()
+}
+
+fn ident(expr: &serde_json::Value) -> &serde_json::Value {
+    &expr["path"]["segments"][0]["ident"]
+}
+
+#[test]
+fn test_format() {
+    let bodies = interpret(
+        &MacroBodies::new(),
+        r#"
+        println!("{} {x}", a, x = b,);
+        println!();
+        std::format!("{y}");
+        write!(f, "{}", a == b);
+        writeln!(f);
+        panic!("{}", c => d);
+        "#,
+    );
+
+    let format = &bodies[0]["format"];
+    assert_eq!(format["string"]["lit"]["str"], "\"{} {x}\"");
+    assert!(format.get("target").is_none());
+    let args = format["args"].as_array().unwrap();
+    assert_eq!(args.len(), 2);
+    assert!(args[0].get("name").is_none());
+    assert_eq!(ident(&args[0]["expr"]), "a");
+    assert_eq!(args[1]["name"], "x");
+    assert_eq!(ident(&args[1]["expr"]), "b");
+    // Nodes in bodies do not record spans.
+    assert!(args[0]["expr"]["path"]["segments"][0].get("span").is_none());
+
+    assert_eq!(bodies[1], json!({ "format": { "args": [] } }));
+    assert_eq!(bodies[2]["format"]["string"]["lit"]["str"], "\"{y}\"");
+
+    let write = &bodies[3]["format"];
+    assert_eq!(ident(&write["target"]), "f");
+    assert_eq!(write["args"][0]["expr"]["binary"]["op"], "==");
+    assert!(write["args"][0].get("name").is_none());
+    assert_eq!(ident(&bodies[4]["format"]["target"]), "f");
+    assert!(bodies[4]["format"].get("string").is_none());
+
+    assert_eq!(bodies[5], serde_json::Value::Null);
+}
+
+#[test]
+fn test_other() {
+    let bodies = interpret(
+        &MacroBodies::new(),
+        r#"
+        assert!(a);
+        assert_eq!(a, b, "{}", c);
+        debug_assert_ne!(a, b,);
+        vec![1, 2];
+        vec![0; n];
+        vec![];
+        matches!(x, Some(1) | None if y);
+        my_macro!(a, b + 1);
+        my_macro! { struct S; }
+        "#,
+    );
+
+    assert_eq!(ident(&bodies[0]["assert"]["exprs"][0]), "a");
+    assert!(bodies[0]["assert"].get("format").is_none());
+    let assert = &bodies[1]["assert"];
+    assert_eq!(assert["exprs"].as_array().unwrap().len(), 2);
+    assert_eq!(assert["format"]["string"]["lit"]["str"], "\"{}\"");
+    assert_eq!(ident(&assert["format"]["args"][0]["expr"]), "c");
+    assert!(bodies[2]["assert"].get("format").is_none());
+
+    assert_eq!(bodies[3]["exprs"][1]["lit"]["int"], "2");
+    assert_eq!(bodies[4]["repeat"]["expr"]["lit"]["int"], "0");
+    assert_eq!(ident(&bodies[4]["repeat"]["len"]), "n");
+    assert_eq!(bodies[5], json!({ "exprs": [] }));
+
+    let matches = &bodies[6]["matches"];
+    assert_eq!(ident(&matches["expr"]), "x");
+    assert_eq!(matches["pat"]["or"]["cases"].as_array().unwrap().len(), 2);
+    assert_eq!(ident(&matches["guard"]), "y");
+
+    assert_eq!(bodies[7]["exprs"][1]["binary"]["op"], "+");
+    assert_eq!(bodies[8], serde_json::Value::Null);
+}
+
+#[test]
+fn test_config() {
+    let mut config = MacroBodies::empty();
+    config.insert("my_vec", BodyKind::Vec);
+    let bodies = interpret(&config, "my_vec![a; 2]; println!(\"{}\", a); other!(a);");
+    assert_eq!(ident(&bodies[0]["repeat"]["expr"]), "a");
+    assert_eq!(bodies[1], serde_json::Value::Null);
+    assert_eq!(bodies[2], serde_json::Value::Null);
+
+    let mut config = MacroBodies::new();
+    config.remove("println");
+    config.set_exprs(false);
+    let bodies = interpret(&config, "println!(\"{}\", a); other!(a); vec![a];");
+    assert_eq!(bodies[0], serde_json::Value::Null);
+    assert_eq!(bodies[1], serde_json::Value::Null);
+    assert_eq!(ident(&bodies[2]["exprs"][0]), "a");
+}
+
+#[test]
+fn test_nested() {
+    let syn_file: syn::File =
+        syn::parse_str("const A: Vec<String> = vec![format!(\"{}\", 1)];").unwrap();
+    let json = serde_json::to_value(WithMacroBodies::from_syn(&syn_file)).unwrap();
+    let vec = &json["items"][0]["const"]["expr"]["macro"];
+    assert_eq!(vec["path"]["segments"][0]["ident"], "vec");
+    let format = &vec["body"]["exprs"][0]["macro"];
+    assert_eq!(format["body"]["format"]["args"][0]["expr"]["lit"]["int"], "1");
+}
@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "json")]
+
+use syn_serde::{Syn as _, nested_meta::WithNestedMeta};
+
+fn round_trip(code: &str) -> serde_json::Value {
+    let syn_file: syn::File = syn::parse_str(code).unwrap();
+    let json = serde_json::to_value(WithNestedMeta::from_syn(&syn_file)).unwrap();
+    let tree: WithNestedMeta = serde_json::from_value(json.clone()).unwrap();
+    let syn_file2: syn::File = tree.to_syn().unwrap();
+    assert_eq!(syn_file2, syn_file);
+    let tokens = |file: &syn::File| quote::quote!(#file).to_string();
+    assert_eq!(tokens(&syn_file2), tokens(&syn_file));
+    // The spans of the tokens are kept.
+    let adapter: syn_serde::File = tree.to_adapter().unwrap();
+    assert_eq!(
+        serde_json::to_value(adapter).unwrap(),
+        serde_json::to_value(syn_file.to_adapter()).unwrap()
+    );
+    json
+}
+
+fn list(attr: &serde_json::Value) -> &serde_json::Value {
+    &attr["meta"]["list"]
+}
+
+#[test]
+fn test_nested() {
+    let json = round_trip(
+        r#"
+#[serde(rename = "x", default)]
+#[derive(Debug, Clone)]
+#[a(b(c, r#type = 1), ::d::e[f], g = true, h{})]
+#[i()]
+struct S;
+"#,
+    );
+    let attrs = &json["items"][0]["struct"]["attrs"];
+
+    let serde = list(&attrs[0]);
+    assert!(serde.get("tokens").is_none());
+    let rename = &serde["nested"][0]["name_value"];
+    assert_eq!(rename["path"]["segments"][0]["ident"], "rename");
+    assert_eq!(rename["path"]["span"]["start_column"], 8);
+    assert_eq!(rename["value"]["lit"]["str"], "\"x\"");
+    assert_eq!(rename["eq_span"]["start_column"], 15);
+    assert_eq!(rename["value_span"]["start_column"], 17);
+    assert_eq!(rename["comma_span"]["start_column"], 20);
+    assert_eq!(serde["nested"][1]["path"]["segments"][0]["ident"], "default");
+
+    let derive = &list(&attrs[1])["nested"];
+    assert_eq!(derive[0]["path"]["segments"][0]["ident"], "Debug");
+    assert_eq!(derive[1]["path"]["segments"][0]["ident"], "Clone");
+    assert_eq!(derive[1]["path"]["segments"][0]["span"]["start_column"], 16);
+
+    let a = &list(&attrs[2])["nested"];
+    let b = &a[0]["list"];
+    assert_eq!(b["delimiter"], "paren");
+    assert_eq!(b["nested"][0]["path"]["segments"][0]["ident"], "c");
+    assert_eq!(b["nested"][1]["name_value"]["path"]["segments"][0]["ident"], "r#type");
+    assert_eq!(b["nested"][1]["name_value"]["value"]["lit"]["int"], "1");
+    let e = &a[1]["list"];
+    assert_eq!(e["delimiter"], "bracket");
+    assert_eq!(e["path"]["leading_colon"], true);
+    assert_eq!(e["path"]["segments"][1]["ident"], "e");
+    assert_eq!(e["path"]["span"]["start_column"], 22);
+    assert_eq!(e["path"]["span"]["end_column"], 28);
+    assert_eq!(e["path"]["colon_spans"].as_array().unwrap().len(), 4);
+    assert_eq!(e["path"]["colon_spans"][2]["start_column"], 25);
+    assert_eq!(e["span_open"]["start_column"], 28);
+    assert_eq!(e["span_close"]["start_column"], 30);
+    let g = &a[2]["name_value"]["value"]["lit"]["bool"];
+    assert_eq!(g["value"], true);
+    assert_eq!(g["span"]["start_column"], 37);
+    assert_eq!(a[3]["list"]["delimiter"], "brace");
+    assert_eq!(a[3]["list"]["nested"], serde_json::json!([]));
+
+    assert_eq!(list(&attrs[3])["nested"], serde_json::json!([]));
+}
+
+#[test]
+fn test_fallback() {
+    let json = round_trip(
+        r#"
+#[derive(Debug,)]
+#[a("b")]
+#[a(b = -1)]
+#[a(b = c)]
+#[a(b: c)]
+#[a(b::<c>)]
+#[a(b(c d), e)]
+#[a(b::c)]
+#[doc(alias("a"))]
+macro_rules! m { (a, b) => {} }
+fn f() {
+    #[a(b)]
+    let _ = 1;
+}
+"#,
+    );
+    let m = &json["items"][0]["macro"];
+    let attrs = m["attrs"].as_array().unwrap();
+    for attr in &attrs[..6] {
+        assert!(list(attr).get("nested").is_none(), "{}", attr);
+        assert!(list(attr)["tokens"].is_array());
+    }
+    // Lists that cannot be parsed are kept as tokens in parsed lists.
+    let nested = &list(&attrs[6])["nested"];
+    assert!(nested[0]["list"]["tokens"].is_array());
+    assert_eq!(nested[1]["path"]["segments"][0]["ident"], "e");
+    let nested = &list(&attrs[8])["nested"];
+    assert_eq!(nested[0]["list"]["path"]["segments"][0]["ident"], "alias");
+    assert_eq!(nested[0]["list"]["tokens"][0]["lit"]["lit"], "\"a\"");
+    assert_eq!(list(&attrs[7])["nested"][0]["path"]["segments"][1]["ident"], "c");
+
+    // The tokens of macros are not attribute arguments.
+    assert!(m["tokens"].is_array());
+
+    let local = &json["items"][1]["fn"]["stmts"]["stmts"][0]["let"];
+    assert_eq!(list(&local["attrs"][0])["nested"][0]["path"]["segments"][0]["ident"], "b");
+}
+
+#[test]
+fn test_hand_written() {
+    let json = serde_json::json!({
+        "items": [{
+            "struct": {
+                "attrs": [{
+                    "style": "outer",
+                    "meta": {
+                        "list": {
+                            "path": { "segments": [{ "ident": "serde" }] },
+                            "delimiter": "paren",
+                            "nested": [
+                                { "name_value": {
+                                    "path": { "segments": [{ "ident": "rename" }] },
+                                    "value": { "lit": { "str": "\"x\"" } }
+                                } },
+                                { "list": {
+                                    "path": { "segments": [{ "ident": "bound" }] },
+                                    "delimiter": "paren",
+                                    "tokens": [{ "ident": "T" }, { "punct": { "op": ":", "spacing": "alone" } }, { "ident": "Clone" }]
+                                } }
+                            ]
+                        }
+                    }
+                }],
+                "ident": "S",
+                "fields": "unit"
+            }
+        }]
+    });
+    let tree: WithNestedMeta = serde_json::from_value(json).unwrap();
+    let syn_file: syn::File = tree.to_syn().unwrap();
+    let expected: syn::File =
+        syn::parse_str("#[serde(rename = \"x\", bound(T: Clone))] struct S;").unwrap();
+    assert_eq!(syn_file, expected);
+
+    let json = serde_json::json!({
+        "attrs": [{
+            "style": "outer",
+            "meta": { "list": {
+                "path": { "segments": [{ "ident": "a" }] },
+                "delimiter": "paren",
+                "nested": [{ "b": {} }]
+            } }
+        }],
+        "items": []
+    });
+    let err = serde_json::from_value::<WithNestedMeta>(json).unwrap_err();
+    assert_eq!(err.to_string(), "invalid meta item `b`: expected `path`, `list`, or `name_value`");
+}
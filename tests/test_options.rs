@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use syn_serde::{
+    explicit::Explicit,
+    ids::WithIds,
+    lit_values::WithLitValues,
+    lossless::Lossless,
+    macro_bodies::WithMacroBodies,
+    options::{Options, Tree},
+    tagged::Tagged,
+};
+
+#[test]
+fn test_combined() {
+    let syn_file: syn::File = syn::parse_quote! {
+        struct S<>(u8,);
+        fn f(x: u8,) {}
+    };
+
+    for options in [
+        Options::new().lossless(true).explicit(true),
+        Options::new().lossless(true).tagged(true),
+        Options::new().explicit(true).tagged(true),
+        Options::new().lossless(true).explicit(true).tagged(true),
+    ] {
+        let json = serde_json::to_value(Tree::from_syn(&syn_file, options)).unwrap();
+        let (s, f) = if options == options.tagged(true) {
+            assert_eq!(json["items"][0]["kind"], "struct");
+            assert_eq!(json["items"][1]["kind"], "fn");
+            (&json["items"][0], &json["items"][1])
+        } else {
+            (&json["items"][0]["struct"], &json["items"][1]["fn"])
+        };
+        if options == options.lossless(true) {
+            assert_eq!(s["generics"]["lt_token"], true);
+            assert_eq!(f["inputs"]["trailing"], true);
+        } else {
+            assert!(s["generics"].get("lt_token").is_none());
+            assert!(f["inputs"].is_array());
+        }
+        if options == options.explicit(true) {
+            assert_eq!(f["attrs"], serde_json::json!([]));
+            assert_eq!(f["unsafe"], false);
+        } else {
+            assert!(f.get("attrs").is_none());
+        }
+
+        let tree: Tagged = serde_json::from_value(json).unwrap();
+        let syn_file2: syn::File = tree.to_syn().unwrap();
+        if options == options.lossless(true) {
+            assert_eq!(syn_file2, syn_file);
+        }
+    }
+}
+
+#[test]
+fn test_to_syn() {
+    let syn_file: syn::File = syn::parse_quote! {
+        struct S<>(u8,);
+        fn f(x: u8,) -> Vec<u8> {
+            vec![x, b'a']
+        }
+    };
+
+    // Every tree converts back to the tree it recorded, whatever it is
+    // serialized with.
+    let options = Options::new().lossless(true).explicit(true).tagged(true);
+    assert_eq!(Tree::from_syn(&syn_file, options).to_syn::<syn::File>().unwrap(), syn_file);
+    assert_eq!(Lossless::from_syn(&syn_file).to_syn::<syn::File>().unwrap(), syn_file);
+    assert_eq!(Explicit::from_syn(&syn_file).to_syn::<syn::File>().unwrap(), syn_file);
+    assert_eq!(WithIds::from_syn(&syn_file).to_syn::<syn::File>().unwrap(), syn_file);
+    assert_eq!(WithLitValues::from_syn(&syn_file).to_syn::<syn::File>().unwrap(), syn_file);
+    assert_eq!(WithMacroBodies::from_syn(&syn_file).to_syn::<syn::File>().unwrap(), syn_file);
+}
@@ -0,0 +1,120 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+#![cfg(feature = "protobuf")]
+
+use std::{fs, path::Path};
+
+use syn_serde::{Syn as _, protobuf, trivia};
+
+#[track_caller]
+fn assert_round_trip(adapter: &syn_serde::File) -> syn_serde::File {
+    let decoded = protobuf::decode(&protobuf::encode(adapter)).unwrap();
+    assert_eq!(serde_json::to_value(&decoded).unwrap(), serde_json::to_value(adapter).unwrap());
+    decoded
+}
+
+#[test]
+fn test_source_files() {
+    for dir in ["src", "tests"] {
+        for entry in fs::read_dir(Path::new(env!("CARGO_MANIFEST_DIR")).join(dir)).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().map_or(true, |e| e != "rs") {
+                continue;
+            }
+            let syn_file = syn::parse_file(&fs::read_to_string(&path).unwrap()).unwrap();
+            assert_round_trip(&syn_file.to_adapter());
+            // The default form is lossy in the same way as JSON.
+            let expected: syn::File =
+                syn_serde::json::from_str(&syn_serde::json::to_string(&syn_file)).unwrap();
+            assert_eq!(protobuf::from_slice(&protobuf::to_vec(&syn_file)).unwrap(), expected);
+        }
+    }
+}
+
+#[test]
+fn test_comments() {
+    let source = "// Leading.\nfn f() {\n    // Inside.\n    g();\n}\n";
+    let syn_file = syn::parse_file(source).unwrap();
+    let adapter = syn_serde::File::from_syn_with_comments(&syn_file, source);
+
+    let json = serde_json::to_value(assert_round_trip(&adapter)).unwrap();
+    assert_eq!(json["items"][0]["fn"]["comments"][0]["text"], "Leading.");
+    assert_eq!(json["items"][0]["fn"]["stmts"]["comments"][0]["text"], "Inside.");
+    assert_eq!(json["items"][0]["fn"]["stmts"]["comments"][0]["kind"], "Line");
+}
+
+#[test]
+fn test_verbatim() {
+    let tokens: proc_macro2::TokenStream = "struct S(#[a] 'b', [1.0; 2]) => $x".parse().unwrap();
+    let syn_file = syn::File {
+        shebang: Some("#!/bin/rust".to_owned()),
+        attrs: vec![],
+        items: vec![syn::Item::Verbatim(tokens)],
+    };
+    let adapter = syn_file.to_adapter();
+
+    let json = serde_json::to_value(assert_round_trip(&adapter)).unwrap();
+    let verbatim = &json["items"][0]["verbatim"];
+    assert_eq!(verbatim[0]["ident"]["ident"], "struct");
+    assert!(verbatim[0]["ident"]["span"].is_object());
+    assert_eq!(verbatim[2]["group"]["delimiter"], "parenthesis");
+    assert_eq!(verbatim[3]["punct"]["op"], "=");
+    assert_eq!(verbatim[3]["punct"]["spacing"], "joint");
+    assert_eq!(protobuf::from_slice(&protobuf::to_vec(&syn_file)).unwrap(), syn_file);
+}
+
+#[test]
+fn test_trivia() {
+    let source = "fn main()  {\n    // Hello.\n    let x = 1 ;\n}\n";
+    let file = trivia::parse_file(source).unwrap();
+    let decoded = assert_round_trip(&file);
+    assert_eq!(trivia::print_file(&decoded), source);
+}
+
+#[test]
+fn test_wire_format() {
+    // `fn f() {}`: File.items[0] = Item.fn = ItemFn { sig: Signature {
+    // ident: Ident { ident: "f" } }, stmts: Block {} }
+    let bytes = [0x1A, 0x0B, 0x22, 0x09, 0x1A, 0x05, 0x2A, 0x03, 0x0A, 0x01, b'f', 0x22, 0x00];
+    let expected: syn::File = syn::parse_quote!(
+        fn f() {}
+    );
+    assert_eq!(protobuf::from_slice(&bytes).unwrap(), expected);
+
+    let json = r#"{"items":[{"fn":{"ident":"f","inputs":[],"output":null,"stmts":{"stmts":[]}}}]}"#;
+    let adapter: syn_serde::File = serde_json::from_str(json).unwrap();
+    assert_eq!(protobuf::encode(&adapter), bytes);
+
+    // Unknown fields are skipped.
+    let mut unknown = bytes.to_vec();
+    unknown.extend_from_slice(&[0x78, 0x01, 0x82, 0x01, 0x01, 0x00]);
+    assert_eq!(protobuf::from_slice(&unknown).unwrap(), expected);
+
+    assert!(protobuf::SCHEMA.contains("syntax = \"proto3\";"));
+    assert!(protobuf::SCHEMA.contains("message File {\n  optional string shebang = 1;\n"));
+    assert!(protobuf::SCHEMA.contains("    ItemFn fn = 4;\n"));
+}
+
+#[test]
+fn test_errors() {
+    // Truncated message: a length prefix that exceeds the remaining input.
+    let bytes = protobuf::to_vec(&syn::parse_quote!(
+        fn f() {}
+    ));
+    assert!(protobuf::decode(&bytes[..1]).is_err());
+    assert!(protobuf::decode(&bytes[..bytes.len() - 1]).is_err());
+    // Invalid wire type for `File.items`.
+    assert!(protobuf::decode(&[0x18, 0x01]).is_err());
+    // An item without a variant.
+    assert!(protobuf::decode(&[0x1A, 0x00]).is_err());
+    // Invalid UTF-8 in `File.shebang`.
+    assert!(protobuf::decode(&[0x0A, 0x01, 0xFF]).is_err());
+
+    let mut expr = "x".to_owned();
+    for _ in 0..100 {
+        expr = format!("({})", expr);
+    }
+    let syn_file = syn::parse_file(&format!("const C: () = {};", expr)).unwrap();
+    let e = protobuf::from_slice(&protobuf::to_vec(&syn_file)).unwrap_err();
+    assert_eq!(e.to_string(), "recursion limit exceeded");
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use syn_serde::query::Query;
+
+fn find(selector: &str, code: &str) -> Vec<syn_serde::query::Match> {
+    let syn_file: syn::File = syn::parse_str(code).unwrap();
+    Query::parse(selector).unwrap().find_syn(&syn_file)
+}
+
+#[test]
+fn test_kind() {
+    let code = "
+        fn main() {
+            let x = foo().unwrap();
+            x.unwrap_or_default();
+        }
+        fn bar() {}
+    ";
+
+    let matches = find("fn", code);
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0].path, "/items/0");
+    assert_eq!(matches[1].path, "/items/1");
+
+    let matches = find("method_call", code);
+    assert_eq!(matches.len(), 2);
+    assert!(matches.iter().all(|m| m.kind.as_deref() == Some("method_call")));
+
+    // Struct types can be matched by their snake-cased names.
+    assert_eq!(find("expr_method_call", code), matches);
+    assert_eq!(find("block", code).len(), 2);
+}
+
+#[test]
+fn test_attribute() {
+    let code = "
+        fn main() {
+            let x = foo().unwrap();
+            x.unwrap_or_default();
+            pub_fn();
+        }
+        pub fn pub_fn() {}
+    ";
+
+    let matches = find("fn > block method_call[method=unwrap]", code);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].path, "/items/0/fn/stmts/stmts/0/let/init/expr");
+    let span = matches[0].span.as_ref().unwrap();
+    assert_eq!((span.start_line, span.start_column), (3, 26));
+
+    assert_eq!(find("method_call[method^=unwrap]", code).len(), 2);
+    assert_eq!(find("method_call[method$=default]", code).len(), 1);
+    assert_eq!(find("method_call[method*=wrap_or]", code).len(), 1);
+    assert_eq!(find("method_call[method!=unwrap]", code).len(), 1);
+    assert_eq!(find("fn[vis=pub]", code).len(), 1);
+    assert_eq!(find("fn[vis]", code).len(), 1);
+    assert_eq!(find("fn[ident='main']", code).len(), 1);
+    assert_eq!(find("let[pat.ident=x]", code).len(), 1);
+}
+
+#[test]
+fn test_combinators() {
+    let code = "
+        fn f() {
+            if a {
+                g(1 + 2);
+            }
+        }
+        const C: i32 = 1 + 2;
+    ";
+
+    assert_eq!(find("binary", code).len(), 2);
+    assert_eq!(find("fn binary", code).len(), 1);
+    assert_eq!(find("fn > binary", code).len(), 0);
+    assert_eq!(find("call > binary", code).len(), 1);
+    assert_eq!(find("if call, const", code).len(), 2);
+    assert_eq!(find("binary[op=\"+\"]", code).len(), 2);
+    assert_eq!(find("*[op]", code).len(), 2);
+}
+
+#[test]
+fn test_with_comments() {
+    let code = "
+// A function.
+fn f() {
+    foo.unwrap();
+}
+";
+    let syn_file = syn::parse_file(code).unwrap();
+    let file = syn_serde::File::from_syn_with_comments(&syn_file, code);
+
+    let matches = Query::parse("method_call").unwrap().find(&file);
+    assert_eq!(matches.len(), 1);
+    assert_eq!(matches[0].path, "/items/0/fn/stmts/stmts/0/expr/expr");
+}
+
+#[test]
+fn test_parse_error() {
+    for selector in ["", "fn >", "fn[", "fn[ident", "fn[ident=]", "fn[ident='x]", "fn)"] {
+        assert!(Query::parse(selector).is_err(), "{selector}");
+    }
+    let err = Query::parse("fn > ?").unwrap_err();
+    assert_eq!(err.position(), Some(5));
+    assert_eq!(err.to_string(), "expected node kind, found `?` at position 5");
+}
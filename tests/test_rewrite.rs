@@ -0,0 +1,173 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use quote::ToTokens as _;
+use syn_serde::{Syn as _, rewrite::Rule};
+
+fn rewrite(pattern: &str, template: &str, code: &str) -> String {
+    let syn_file: syn::File = syn::parse_str(code).unwrap();
+    let rule = Rule::new(pattern, template).unwrap();
+    rule.rewrite_syn(&syn_file).unwrap().into_token_stream().to_string()
+}
+
+fn tokens(code: &str) -> String {
+    syn::parse_str::<syn::File>(code).unwrap().into_token_stream().to_string()
+}
+
+#[test]
+fn test_expr() {
+    let code = "
+        fn main() {
+            let x = foo().unwrap();
+            x.unwrap_or_default();
+            a.unwrap().unwrap();
+        }
+    ";
+    let expected = r#"
+        fn main() {
+            let x = foo().expect("TODO");
+            x.unwrap_or_default();
+            a.expect("TODO").expect("TODO");
+        }
+    "#;
+    assert_eq!(rewrite("$x.unwrap()", r#"$x.expect("TODO")"#, code), tokens(expected));
+}
+
+#[test]
+fn test_find() {
+    let code = "
+        fn main() {
+            let x = foo().unwrap();
+            bar(x.unwrap(), y.unwrap_or(1));
+        }
+    ";
+    let syn_file: syn::File = syn::parse_str(code).unwrap();
+    let rule = Rule::new("$x.unwrap()", "$x").unwrap();
+    let matches = rule.find_syn(&syn_file);
+    assert_eq!(matches.len(), 2);
+    assert_eq!(matches[0].path, "/items/0/fn/stmts/stmts/0/let/init/expr");
+    assert_eq!(matches[0].kind.as_deref(), Some("method_call"));
+    let span = matches[0].span.as_ref().unwrap();
+    assert_eq!((span.start_line, span.start_column), (3, 26));
+}
+
+#[test]
+fn test_repeated_metavar() {
+    let code = "const A: i32 = x + x; const B: i32 = x + y;";
+    let expected = "const A: i32 = 2 * x; const B: i32 = x + y;";
+    assert_eq!(rewrite("$a + $a", "2 * $a", code), tokens(expected));
+
+    // `$_` does not bind, so its occurrences match independently.
+    let expected = "const A: i32 = 0; const B: i32 = 0;";
+    assert_eq!(rewrite("$_ + $_", "0", code), tokens(expected));
+}
+
+#[test]
+fn test_ident_and_type() {
+    let code = "fn f(v: Vec<u8>) -> Vec<Vec<u8>> { v.len() }";
+    let expected = "fn f(v: Box<[u8]>) -> Box<[Box<[u8]>]> { v.len() }";
+    assert_eq!(rewrite("Vec<$T>", "Box<[$T]>", code), tokens(expected));
+
+    let code = "fn f() { a.push(1); b.push(a.pop()); }";
+    let expected = "fn f() { a.push_back(1); b.push_back(a.pop()); }";
+    assert_eq!(rewrite("$x.push($y)", "$x.push_back($y)", code), tokens(expected));
+    assert_eq!(
+        rewrite("$x.$m()", "$x.$m().into()", "fn f() { a.b(); }"),
+        tokens("fn f() { a.b().into(); }")
+    );
+}
+
+#[test]
+fn test_punctuation() {
+    // Trailing punctuation and optional tokens outside of the matches are
+    // kept, and are ignored when matching.
+    let code = "fn f<>(a: u8, b: u8,) { g(a, b,); g(b, a); }";
+    let expected = "fn f<>(a: u8, b: u8,) { h(a, b); h(b, a); }";
+    assert_eq!(rewrite("g($x, $y)", "h($x, $y)", code), tokens(expected));
+
+    // The template keeps its own.
+    let expected = "fn f<>(a: u8, b: u8,) { h(a, b,); h(b, a,); }";
+    assert_eq!(rewrite("g($x, $y)", "h($x, $y,)", code), tokens(expected));
+}
+
+#[test]
+fn test_macro_tokens() {
+    let code = r#"fn f() { let s = format!("{}", x); }"#;
+    let expected = r#"fn f() { let s = format!("{:?}", x); }"#;
+    assert_eq!(rewrite(r#"format!("{}", $x)"#, r#"format!("{:?}", $x)"#, code), tokens(expected));
+
+    // Metavariables in macro bodies match single tokens, which cannot be used
+    // as expressions.
+    let syn_file: syn::File = syn::parse_str(code).unwrap();
+    let rule = Rule::new(r#"format!("{}", $x)"#, "$x.to_string()").unwrap();
+    assert!(rule.rewrite_syn(&syn_file).is_err());
+}
+
+#[test]
+fn test_item_with_comments() {
+    let code = "\
+// The answer.
+const ANSWER: u32 = 42;
+
+fn f() {
+    // Unsafe for no reason.
+    unsafe { g() }
+}
+";
+    let syn_file = syn::parse_file(code).unwrap();
+    let file = syn_serde::File::from_syn_with_comments(&syn_file, code);
+    let rule = Rule::new("const $N: $T = $v;", "static $N: $T = $v;").unwrap();
+    let file = rule.rewrite(&file).unwrap();
+
+    let json = serde_json::to_value(&file).unwrap();
+    let item = &json["items"][0]["static"];
+    assert_eq!(item["ident"], "ANSWER");
+    assert_eq!(item["comments"][0]["text"], "The answer.");
+    assert_eq!(item["span"]["start_line"], 2);
+
+    let rule = Rule::new("fn $f() { unsafe { $e } }", "fn $f() { $e }").unwrap();
+    let file = rule.rewrite(&file).unwrap();
+    let json = serde_json::to_value(&file).unwrap();
+    let item = &json["items"][1]["fn"];
+    assert_eq!(item["comments"][0]["text"], "Unsafe for no reason.");
+
+    let expected = "static ANSWER: u32 = 42; fn f() { g() }";
+    assert_eq!(syn::File::from_adapter(&file).into_token_stream().to_string(), tokens(expected));
+}
+
+#[test]
+fn test_fn_with_comments() {
+    let code = "// Keep me.\nfn old() {}\n";
+    let syn_file = syn::parse_file(code).unwrap();
+    let file = syn_serde::File::from_syn_with_comments(&syn_file, code);
+    let rule = Rule::new("fn old() {}", "fn new() {}").unwrap();
+    let file = rule.rewrite(&file).unwrap();
+
+    let json = serde_json::to_value(&file).unwrap();
+    let item = &json["items"][0]["fn"];
+    assert_eq!(item["ident"], "new");
+    assert_eq!(item["comments"][0]["text"], "Keep me.");
+    assert!(item["stmts"].get("comments").is_none());
+}
+
+#[test]
+fn test_error() {
+    for (pattern, template) in [
+        ("$x", "$x"),
+        ("$x.unwrap()", "$y"),
+        ("$x.unwrap()", "$_"),
+        ("$", "x"),
+        ("(", "x"),
+        ("$x.unwrap()", "fn f() {}"),
+    ] {
+        assert!(Rule::new(pattern, template).is_err(), "{pattern} -> {template}");
+    }
+    assert_eq!(
+        Rule::new("$x.unwrap()", "$y").unwrap_err().to_string(),
+        "metavariable `$y` is not bound by the pattern"
+    );
+
+    // `$x` matches an expression, which is not valid in a type position.
+    let syn_file: syn::File = syn::parse_str("fn f() { a().unwrap(); }").unwrap();
+    let rule = Rule::new("$x.unwrap()", "{ let _: $x = 1; }").unwrap();
+    assert!(rule.rewrite_syn(&syn_file).is_err());
+}
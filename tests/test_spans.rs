@@ -1,3 +1,5 @@
+#![allow(clippy::needless_raw_string_hashes)]
+
 use syn_serde::json;
 
 #[test]
@@ -92,6 +94,7 @@ fn test_span_info_methods() {
         start_column: 10,
         end_line: 2,
         end_column: 15,
+        file: None,
     };
     
     // Test column length calculation
@@ -106,6 +109,7 @@ fn test_span_info_methods() {
         start_column: 5,
         end_line: 1,
         end_column: 5,
+        file: None,
     };
     
     assert!(point_span.is_point());
@@ -119,6 +123,7 @@ fn test_span_info_methods() {
         start_column: 10,
         end_line: 3,
         end_column: 5,
+        file: None,
     };
     
     assert!(!multiline_span.is_point());
@@ -197,4 +202,71 @@ fn foo() // Line 2
     // Verify that JSON round-trips correctly
     let restored_file: syn::File = json::from_str(&json_str).unwrap();
     assert_eq!(syn_file.items.len(), restored_file.items.len());
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_token_spans() {
+    let code = "fn main() {\n    println!(\"{}\", (x));\n}\n";
+    let syn_file: syn::File = syn::parse_str(code).unwrap();
+    let json_value = serde_json::to_value(syn_serde::File::from(&syn_file)).unwrap();
+
+    // Idents outside of token trees are still serialized as strings.
+    let fn_item = &json_value["items"][0]["fn"];
+    assert_eq!(fn_item["ident"], "main");
+
+    let tokens = &fn_item["stmts"]["stmts"][0]["macro"]["tokens"];
+    let lit = &tokens[0]["lit"];
+    assert_eq!(lit["lit"], "\"{}\"");
+    assert_eq!(lit["span"]["start_line"], 2);
+    assert_eq!(lit["span"]["start_column"], 13);
+    assert_eq!(lit["span"]["end_column"], 17);
+    assert_eq!(tokens[1]["punct"]["span"]["start_column"], 17);
+
+    let group = &tokens[2]["group"];
+    assert_eq!(group["span"]["start_column"], 19);
+    assert_eq!(group["span"]["end_column"], 22);
+    assert_eq!(group["span_open"]["end_column"], 20);
+    assert_eq!(group["span_close"]["start_column"], 21);
+    let ident = &group["stream"][0]["ident"];
+    assert_eq!(ident["ident"], "x");
+    assert_eq!(ident["span"]["start_column"], 20);
+    assert_eq!(ident["span"]["end_column"], 21);
+
+    // Both the spanned form and the plain form can be deserialized.
+    let restored: syn::File = json::from_str(&json_value.to_string()).unwrap();
+    assert_eq!(restored, syn_file);
+    let plain = r#"{"items":[{"macro":{"path":{"segments":[{"ident":"m"}]},"delimiter":"paren","tokens":[{"ident":"x"},{"lit":"1"}],"semi_token":true}}]}"#;
+    let restored: syn::File = json::from_str(plain).unwrap();
+    assert_eq!(restored, syn::parse_str("m!(x 1);").unwrap());
+}
+
+#[test]
+fn test_span_file() {
+    use syn_serde::{SpanInfo, Syn as _};
+
+    let span_info = SpanInfo::from_span_in_file(proc_macro2::Span::call_site(), 3);
+    assert_eq!(span_info.file, Some(3));
+    let json_str = serde_json::to_string(&span_info).unwrap();
+    assert!(json_str.ends_with(r#","file":3}"#));
+    assert_eq!(serde_json::from_str::<SpanInfo>(&json_str).unwrap(), span_info);
+
+    // Spans without a file omit the field.
+    let json_str = serde_json::to_string(&SpanInfo::call_site()).unwrap();
+    assert!(!json_str.contains("file"));
+
+    let code = "fn main() {\n    println!(\"{}\", x);\n}\n";
+    let syn_file: syn::File = syn::parse_str(code).unwrap();
+    let json_value = serde_json::to_value(syn_file.to_adapter_in_file(1)).unwrap();
+
+    // All spans, including the ones in token streams, record the file.
+    let fn_item = &json_value["items"][0]["fn"];
+    assert_eq!(json_value["span"]["file"], 1);
+    assert_eq!(fn_item["span"]["file"], 1);
+    let tokens = &fn_item["stmts"]["stmts"][0]["macro"]["tokens"];
+    assert_eq!(tokens[0]["lit"]["span"]["file"], 1);
+    assert_eq!(tokens[2]["ident"]["span"]["file"], 1);
+    assert_eq!(tokens[2]["ident"]["span"]["start_column"], 19);
+
+    let restored: syn::File = json::from_str(&json_value.to_string()).unwrap();
+    assert_eq!(restored, syn_file);
+}
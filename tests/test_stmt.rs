@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use syn_serde::Syn as _;
+
+#[test]
+fn test_expr() {
+    let syn_block: syn::Block = syn::parse_quote!({
+        f();
+        g()
+    });
+    let json = serde_json::to_value(syn_block.to_adapter()).unwrap();
+    let stmts = &json["stmts"];
+    assert!(stmts[0]["expr"]["expr"]["call"].is_object());
+    assert_eq!(stmts[0]["expr"]["semi"], true);
+    assert!(stmts[1]["expr"]["expr"]["call"].is_object());
+    assert!(stmts[1]["expr"].get("semi").is_none());
+    let block: syn_serde::Block = serde_json::from_value(json).unwrap();
+    assert_eq!(syn::Block::from_adapter(&block), syn_block);
+
+    // The `[expr, semi]` form of older versions is also accepted.
+    let json = serde_json::json!({
+        "stmts": [
+            {"expr": [{"call": {"func": {"path": {"segments": [{"ident": "f"}]}}, "args": []}}, true]},
+            {"expr": [{"call": {"func": {"path": {"segments": [{"ident": "g"}]}}, "args": []}}, false]}
+        ]
+    });
+    let block: syn_serde::Block = serde_json::from_value(json).unwrap();
+    assert_eq!(syn::Block::from_adapter(&block), syn_block);
+}
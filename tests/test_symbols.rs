@@ -0,0 +1,174 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use syn_serde::{
+    Syn as _,
+    symbols::{self, Symbol, SymbolKind},
+};
+
+fn index(code: &str) -> Vec<Symbol> {
+    let syn_file: syn::File = syn::parse_str(code).unwrap();
+    symbols::index(&syn_file.to_adapter())
+}
+
+fn paths(symbols: &[Symbol]) -> Vec<(&str, SymbolKind)> {
+    symbols.iter().map(|s| (&*s.path, s.kind)).collect()
+}
+
+#[test]
+fn test_paths() {
+    let symbols = index(
+        "
+        use std::fmt;
+        extern crate alloc;
+        pub mod a {
+            pub(crate) struct S;
+            mod b {
+                enum E { A }
+                union U { x: u8 }
+            }
+            const C: u8 = 0;
+            static mut M: u8 = 0;
+            type T = u8;
+            trait Alias = Clone;
+            mod out_of_line;
+        }
+        fn f() {
+            fn inner() {}
+        }
+        macro_rules! m { () => {} }
+        println!();
+        ",
+    );
+    assert_eq!(paths(&symbols), [
+        ("crate::a", SymbolKind::Mod),
+        ("crate::a::S", SymbolKind::Struct),
+        ("crate::a::b", SymbolKind::Mod),
+        ("crate::a::b::E", SymbolKind::Enum),
+        ("crate::a::b::U", SymbolKind::Union),
+        ("crate::a::C", SymbolKind::Const),
+        ("crate::a::M", SymbolKind::Static),
+        ("crate::a::T", SymbolKind::Type),
+        ("crate::a::Alias", SymbolKind::TraitAlias),
+        ("crate::a::out_of_line", SymbolKind::Mod),
+        ("crate::f", SymbolKind::Fn),
+        ("crate::m", SymbolKind::Macro),
+    ]);
+    assert_eq!(symbols[1].module, "crate::a");
+    assert_eq!(symbols[1].visibility, "pub(crate)");
+    assert_eq!(symbols[3].module, "crate::a::b");
+    assert_eq!(symbols[3].visibility, "");
+    assert_eq!(symbols[6].signature, "static mut M: u8");
+    assert_eq!(symbols[8].signature, "trait Alias = Clone");
+}
+
+#[test]
+fn test_associated_items() {
+    let symbols = index(
+        "
+        pub trait Shape: Clone + 'static {
+            const SIDES: usize;
+            type Output: Copy = u8;
+            fn area(&self) -> f64;
+        }
+        impl<T: Copy> Shape for Square<T> {
+            const SIDES: usize = 4;
+            type Output = T;
+            fn area(&self) -> f64 { 0.0 }
+        }
+        unsafe impl<'a> Send for &'a Square<u8> {}
+        extern \"C\" {
+            pub fn abs(x: i32) -> i32;
+            static errno: i32;
+            type Opaque;
+        }
+        ",
+    );
+    assert_eq!(paths(&symbols), [
+        ("crate::Shape", SymbolKind::Trait),
+        ("crate::Shape::SIDES", SymbolKind::Const),
+        ("crate::Shape::Output", SymbolKind::Type),
+        ("crate::Shape::area", SymbolKind::Method),
+        ("crate::Square::SIDES", SymbolKind::Const),
+        ("crate::Square::Output", SymbolKind::Type),
+        ("crate::Square::area", SymbolKind::Method),
+        ("crate::abs", SymbolKind::Fn),
+        ("crate::errno", SymbolKind::Static),
+        ("crate::Opaque", SymbolKind::Type),
+    ]);
+
+    let shape = &symbols[0];
+    assert_eq!(shape.parent, None);
+    assert_eq!(shape.signature, "pub trait Shape: Clone + 'static");
+    let output = &symbols[2];
+    assert_eq!(output.parent.as_deref(), Some("trait Shape"));
+    assert_eq!(output.visibility, "pub");
+    assert_eq!(output.signature, "type Output: Copy = u8");
+    let area = &symbols[6];
+    assert_eq!(area.parent.as_deref(), Some("impl<T: Copy> Shape for Square<T>"));
+    assert_eq!(area.visibility, "");
+    assert_eq!(area.signature, "fn area(&self) -> f64");
+    assert_eq!(symbols[7].signature, "pub fn abs(x: i32) -> i32");
+}
+
+#[test]
+fn test_macro_export() {
+    let symbols = index(
+        "
+        mod a {
+            #[macro_export]
+            macro_rules! exported { () => {} }
+            macro_rules! local { () => {} }
+        }
+        ",
+    );
+    assert_eq!(paths(&symbols), [
+        ("crate::a", SymbolKind::Mod),
+        ("crate::exported", SymbolKind::Macro),
+        ("crate::a::local", SymbolKind::Macro),
+    ]);
+    assert_eq!(symbols[1].module, "crate");
+    assert_eq!(symbols[1].visibility, "pub");
+    assert_eq!(symbols[1].signature, "macro_rules! exported");
+    assert_eq!(symbols[2].visibility, "");
+}
+
+#[test]
+fn test_signature() {
+    let symbols = index(
+        "
+        pub async unsafe fn f<'a, T: Clone, const N: usize>(x: &'a [T; N], y: *const u8, f: impl Fn(&T) -> Option<Vec<T>>) -> Result<(), E> where T: Send + ?Sized {}
+        pub(in crate::a) const fn g(mut self: Box<Self>, (a, b): (u8, u8), ..: u8) -> ! {}
+        ",
+    );
+    assert_eq!(symbols[0].generics, "<'a, T: Clone, const N: usize>");
+    assert_eq!(
+        symbols[0].signature,
+        "pub async unsafe fn f<'a, T: Clone, const N: usize>(x: &'a [T; N], y: *const u8, f: impl Fn(&T) -> Option<Vec<T>>) -> Result<(), E> where T: Send + ?Sized"
+    );
+    assert_eq!(symbols[1].visibility, "pub(in crate::a)");
+    assert_eq!(symbols[1].generics, "");
+    assert_eq!(
+        symbols[1].signature,
+        "pub(in crate::a) const fn g(mut self: Box<Self>, (a, b): (u8, u8), ..: u8) -> !"
+    );
+}
+
+#[test]
+fn test_span() {
+    let symbols = index("struct A;\nimpl A {\n    pub fn new() -> Self { A }\n}\n");
+    let span = symbols[1].span.unwrap();
+    assert_eq!((span.start_line, span.start_column), (3, 11));
+    assert_eq!((span.end_line, span.end_column), (3, 14));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn test_json() {
+    let symbols = index("pub trait T {}");
+    let json = serde_json::to_value(&symbols).unwrap();
+    assert_eq!(json[0]["kind"], "trait");
+    assert_eq!(json[0]["path"], "crate::T");
+    assert!(json[0].get("parent").is_none());
+    let back: Vec<Symbol> = serde_json::from_value(json).unwrap();
+    assert_eq!(back, symbols);
+}
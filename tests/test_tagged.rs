@@ -0,0 +1,114 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use syn_serde::{Syn as _, tagged::Tagged};
+
+#[test]
+fn test_tagged() {
+    let syn_file: syn::File = syn::parse_quote! {
+        pub(crate) fn f(x: u8) -> u8 {
+            let s = "a";
+            match x {
+                0 => x + 1,
+                _ => { -x }
+            }
+        }
+        struct S;
+    };
+    let tree = Tagged::from_syn(&syn_file);
+    let json = serde_json::to_value(&tree).unwrap();
+
+    let f = &json["items"][0];
+    assert_eq!(f["kind"], "fn");
+    assert_eq!(f["vis"]["kind"], "restricted");
+    assert_eq!(f["inputs"][0]["kind"], "typed");
+    assert_eq!(f["inputs"][0]["pat"]["kind"], "ident");
+    assert_eq!(f["output"]["kind"], "path");
+    let stmts = &f["stmts"]["stmts"];
+    assert_eq!(stmts[0]["kind"], "let");
+    let lit = &stmts[0]["init"]["expr"];
+    assert_eq!(lit["kind"], "lit");
+    // The literal is flattened into `ExprLit`.
+    assert_eq!(lit["str"], "\"a\"");
+    let arms = &stmts[1]["expr"]["arms"];
+    assert_eq!(stmts[1]["expr"]["kind"], "match");
+    assert_eq!(arms[0]["pat"]["kind"], "lit");
+    assert_eq!(arms[0]["body"]["kind"], "binary");
+    assert_eq!(arms[0]["body"]["op"], serde_json::json!({"kind": "+"}));
+    assert_eq!(arms[1]["pat"], serde_json::json!({"kind": "_", "value": {}}));
+    let s = &json["items"][1];
+    assert_eq!(s["kind"], "struct");
+    assert_eq!(s["fields"], serde_json::json!({"kind": "unit"}));
+
+    // Both the tagged and the externally tagged forms can be deserialized.
+    let tree: Tagged = serde_json::from_value(json.clone()).unwrap();
+    assert_eq!(tree.to_syn::<syn::File>().unwrap(), syn_file);
+    let external = serde_json::to_value(syn_file.to_adapter()).unwrap();
+    let tree: Tagged = serde_json::from_value(external).unwrap();
+    assert_eq!(tree.to_syn::<syn::File>().unwrap(), syn_file);
+    assert_eq!(syn_serde::json::from_str_deep(&json.to_string()).unwrap(), syn_file);
+}
+
+#[test]
+fn test_value() {
+    let syn_file: syn::File = syn::parse_quote! {
+        type T = [u8; 1];
+        fn f() { let _: T = []; }
+    };
+    let json = serde_json::to_value(Tagged::from_syn(&syn_file)).unwrap();
+    let ty = &json["items"][0]["ty"];
+    assert_eq!(ty["kind"], "array");
+    assert_eq!(ty["len"], serde_json::json!({"kind": "lit", "int": "1"}));
+    // Empty contents are not merged, so that they are not mistaken for unit
+    // variants.
+    assert_eq!(
+        json["items"][1]["stmts"]["stmts"][0]["pat"]["pat"],
+        serde_json::json!({"kind": "_", "value": {}})
+    );
+
+    let tree: Tagged = serde_json::from_value(json).unwrap();
+    assert_eq!(tree.to_syn::<syn::File>().unwrap(), syn_file);
+
+    // A content that only has `value` is stored in `value`.
+    let lit: syn_serde::Lit =
+        serde_json::from_value(serde_json::json!({"kind": "str", "value": {"value": "a"}}))
+            .map(|tree: Tagged| tree.to_adapter().unwrap())
+            .unwrap();
+    assert_eq!(syn::Lit::from(&lit), syn::parse_quote!("a"));
+}
+
+#[test]
+fn test_comments() {
+    let source = "fn f() {\n    // Comment.\n    g();\n}\n";
+    let syn_file = syn::parse_file(source).unwrap();
+    let adapter = syn_serde::File::from_syn_with_comments(&syn_file, source);
+
+    let json = serde_json::to_value(Tagged::new(&adapter)).unwrap();
+    let comment = &json["items"][0]["stmts"]["comments"][0];
+    assert_eq!(comment["kind"], serde_json::json!({"kind": "Line"}));
+
+    // The `kind` of a comment in the externally tagged form is not a tag.
+    let external = serde_json::to_value(&adapter).unwrap();
+    for json in [json, external] {
+        let tree: Tagged = serde_json::from_value(json).unwrap();
+        let file: syn_serde::File = tree.to_adapter().unwrap();
+        assert_eq!(serde_json::to_value(file).unwrap(), serde_json::to_value(&adapter).unwrap());
+    }
+}
+
+#[test]
+fn test_enum_positions() {
+    // Tags are only read where an enum is expected.
+    let json = serde_json::json!({
+        "items": [{"kind": "fn", "ident": "f", "inputs": [], "stmts": {"stmts": []}}],
+    });
+    let tree: Tagged = serde_json::from_value(json.clone()).unwrap();
+    let file: syn::File = tree.to_syn().unwrap();
+    assert_eq!(file, syn::parse_quote!(fn f() {}));
+    // The adapters themselves only read the externally tagged form.
+    assert!(serde_json::from_value::<syn_serde::File>(json).is_err());
+
+    let json = serde_json::json!({"items": [{"kind": "function", "ident": "f"}]});
+    let tree: Tagged = serde_json::from_value(json).unwrap();
+    let e = tree.to_syn::<syn::File>().unwrap_err();
+    assert!(e.to_string().contains("unknown variant `function`"), "{}", e);
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+use syn_serde::{
+    Syn as _,
+    lossless::Lossless,
+    trivia::{parse_file, print_file},
+};
+
+const SOURCES: &[&str] = &[
+    "fn main()  {\n    // Hello.\n    let x = 1 ;\n}\n",
+    "#!/usr/bin/env rust\n//! Inner doc.\n\n/// Doc\n/// comment.\n#[derive( Debug )]\nstruct S<T,>  {\n    a: T, // trailing\n\n    /* block /* nested */ */ b: u8,\n}\n\n\n",
+    "\u{feff}/** Block\n doc. */\nenum E { A = 1, B }\r\n\r\nfn f<'a>(x: &'a str) -> &'a str { match x { \"a\" => x, _ => { x } , } }",
+    "// Only a comment.\n",
+    "",
+];
+
+#[test]
+fn test_print() {
+    for &source in SOURCES {
+        let file = parse_file(source).unwrap();
+        assert_eq!(print_file(&file), source);
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    for &source in SOURCES {
+        let file = parse_file(source).unwrap();
+        let json = serde_json::to_string(&Lossless::new(&file)).unwrap();
+        let file: syn_serde::File = serde_json::from_str(&json).unwrap();
+        assert_eq!(print_file(&file), source);
+    }
+}
+
+#[test]
+fn test_nodes() {
+    // Keywords, delimiters, and separators are recorded by the innermost
+    // node that prints them.
+    let file = parse_file("fn f(a: u8, b: u8) {\n    g();\n}\n").unwrap();
+    let json = serde_json::to_value(&file).unwrap();
+    let tokens = |node: &serde_json::Value| -> Vec<String> {
+        let trivia = node["trivia"].as_array().unwrap();
+        trivia.iter().map(|t| t["token"].as_str().unwrap().to_owned()).collect()
+    };
+    let f = &json["items"][0]["fn"];
+    assert_eq!(tokens(f), ["fn", "f", "(", ",", ")"]);
+    assert_eq!(tokens(&f["inputs"][1]["typed"]), [":"]);
+    assert_eq!(tokens(&f["stmts"]), ["{", ";", "}"]);
+    assert_eq!(f["stmts"]["trivia"][0]["trailing"], "\n");
+    assert_eq!(f["stmts"]["trivia"][1]["index"], 15);
+
+    // Without trivia, the form of the nodes is unchanged.
+    let json = serde_json::to_value(syn::parse_file("fn f() {}").unwrap().to_adapter()).unwrap();
+    assert!(json["items"][0]["fn"].get("trivia").is_none());
+}
+
+#[test]
+fn test_edit() {
+    let source = "/// Doc.\nfn f(a: u8) {\n    // Comment.\n    g(a);\n\n    h();\n}\n";
+    let file = parse_file(source).unwrap();
+    let mut json = serde_json::to_value(&file).unwrap();
+    let f = &mut json["items"][0]["fn"];
+    let arg: syn::FnArg = syn::parse_quote!(b: u16);
+    f["inputs"].as_array_mut().unwrap().push(serde_json::to_value(arg.to_adapter()).unwrap());
+    f["stmts"]["stmts"].as_array_mut().unwrap().remove(1);
+    let attr: syn::Attribute = syn::parse_quote!(#[doc = " Edited."]);
+    f["attrs"][0] = serde_json::to_value(attr.to_adapter()).unwrap();
+
+    let edited: syn_serde::File = serde_json::from_value(json).unwrap();
+    assert_eq!(
+        print_file(&edited),
+        "# [doc = \" Edited.\"] fn f(a: u8, b : u16) {\n    // Comment.\n    g(a);\n}\n"
+    );
+
+    // Without trivia, the tokens are separated by spaces.
+    let syn_file = syn::File::from_adapter(&edited);
+    assert_eq!(
+        print_file(&syn_file.to_adapter()),
+        "# [doc = \" Edited.\"] fn f (a : u8, b : u16) { g (a); }"
+    );
+}
+
+#[test]
+fn test_sources() {
+    for entry in std::fs::read_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/src")).unwrap() {
+        let path = entry.unwrap().path();
+        if path.extension().map_or(false, |ext| ext == "rs") {
+            let source = std::fs::read_to_string(&path).unwrap();
+            let file = parse_file(&source).unwrap();
+            assert_eq!(print_file(&file), source, "{}", path.display());
+        }
+    }
+}
+
+#[test]
+fn test_ids() {
+    // Trivia are not nodes.
+    let source = "fn f() {}\n";
+    let file = parse_file(source).unwrap();
+    let syn_file = syn::parse_file(source).unwrap();
+    assert_eq!(
+        syn_serde::ids::WithIds::new(&file).nodes().len(),
+        syn_serde::ids::WithIds::from_syn(&syn_file).nodes().len()
+    );
+}
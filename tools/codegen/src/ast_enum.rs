@@ -1,22 +1,27 @@
 // SPDX-License-Identifier: Apache-2.0 OR MIT
 
+use std::collections::BTreeMap;
+
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn_codegen::{Data, Definitions, Node, Type};
 
 use crate::{
-    convert::{EMPTY_STRUCTS, IGNORED_TYPES},
-    file, traverse,
+    ast_struct::{base_ty, flatten},
+    convert::{EMPTY_STRUCTS, IGNORED_TYPES, variant_fields},
+    file,
+    protobuf::snake_case,
+    traverse,
 };
 
 const AST_ENUM_SRC: &str = "src/gen/ast_enum.rs";
 
-const SKIPPED: &[&str] = &[
+pub(crate) const SKIPPED: &[&str] = &[
     // stmt.rs
-    "Stmt", // TODO
+    "Stmt", // manually implemented to accept the array form of `Stmt::Expr`
 ];
 
-fn rename(ident: &str, variant: &str) -> Option<&'static str> {
+pub(crate) fn rename(ident: &str, variant: &str) -> Option<&'static str> {
     match (ident, variant) {
         ("Pat", "Wild") | ("Type", "Infer") => Some("_"),
         ("Type", "Never") => Some("!"),
@@ -32,6 +37,19 @@ fn rename(ident: &str, variant: &str) -> Option<&'static str> {
     }
 }
 
+/// Returns `true` if `ident` is flattened into the structs that contain it.
+///
+/// serde reads flattened enums from the fields of their parent, so they are
+/// only read in the externally tagged form.
+fn is_flattened(ident: &str, defs: &Definitions) -> bool {
+    defs.types.iter().any(|node| match &node.data {
+        Data::Struct(fields) => fields
+            .iter()
+            .any(|(field, ty)| base_ty(ty) == Some(ident) && flatten(&node.ident, field, ty)),
+        _ => false,
+    })
+}
+
 fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
     if SKIPPED.contains(&&*node.ident) || IGNORED_TYPES.contains(&&*node.ident) {
         return;
@@ -39,6 +57,9 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
 
     if let Data::Enum(variants) = &node.data {
         let mut body = TokenStream::new();
+        // The arms of the conversion from the copy of the enum that
+        // `Deserialize` is derived for.
+        let mut arms = TokenStream::new();
 
         for (variant, fields) in variants {
             body.extend(rename(&node.ident, variant).map(|s| quote!(#[serde(rename = #s)])));
@@ -47,15 +68,18 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
 
             if fields.is_empty() {
                 body.extend(quote!(#variant,));
+                arms.extend(quote!(External::#variant => Self::#variant,));
             } else {
-                assert!(fields.len() == 1 || node.ident == "Stmt");
+                assert!(fields.len() == 1 || variant_fields(&node.ident, &variant.to_string()).is_some());
                 match &fields[0] {
                     Type::Syn(s) if EMPTY_STRUCTS.contains(&&**s) => {
                         body.extend(quote!(#variant,));
+                        arms.extend(quote!(External::#variant => Self::#variant,));
                     }
                     Type::Syn(s) | Type::Ext(s) => {
                         let ty = format_ident!("{s}");
                         body.extend(quote!(#variant(#ty),));
+                        arms.extend(quote!(External::#variant(v) => Self::#variant(v),));
                     }
                     Type::Token(t) | Type::Group(t) => {
                         if matches!(&*node.ident, "BinOp" | "UnOp") {
@@ -63,6 +87,7 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
                             body.extend(quote!(#[serde(rename = #s)]));
                         }
                         body.extend(quote!(#variant,));
+                        arms.extend(quote!(External::#variant => Self::#variant,));
                     }
                     _ => unreachable!("Data::Enum: {}", node.ident),
                 }
@@ -77,24 +102,117 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
 
         let ident = format_ident!("{}", node.ident);
         let doc = format!(" An adapter for [`enum@syn::{}`].", node.ident);
+        if is_flattened(&node.ident, defs) {
+            impls.extend(quote! {
+                #[doc = #doc]
+                #[derive(Serialize, Deserialize)]
+                #[serde(rename_all = "snake_case")]
+                #non_exhaustive
+                pub enum #ident {
+                    #body
+                }
+            });
+            return;
+        }
+
+        // `Deserialize` is derived for a copy of the enum, which reads the
+        // externally tagged form, and `tagged::deserialize` also reads the
+        // tagged form with it.
+        let name = ident.to_string();
         impls.extend(quote! {
             #[doc = #doc]
-            #[derive(Serialize, Deserialize)]
+            #[derive(Serialize)]
             #[serde(rename_all = "snake_case")]
             #non_exhaustive
             pub enum #ident {
                 #body
             }
+
+            const _: () = {
+                #[derive(Deserialize)]
+                #[serde(rename = #name, rename_all = "snake_case")]
+                enum External {
+                    #body
+                }
+
+                impl From<External> for #ident {
+                    fn from(external: External) -> Self {
+                        match external {
+                            #arms
+                        }
+                    }
+                }
+
+                impl<'de> Deserialize<'de> for #ident {
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: serde::Deserializer<'de>,
+                    {
+                        tagged::deserialize::<External, D>(deserializer).map(Self::from)
+                    }
+                }
+            };
         });
     }
 }
 
+/// Returns `true` if `ident` is a struct that contains flattened fields.
+fn has_flattened_fields(ident: &str, defs: &Definitions) -> bool {
+    defs.types.iter().any(|node| match &node.data {
+        Data::Struct(fields) => {
+            node.ident == ident && fields.iter().any(|(field, ty)| flatten(ident, field, ty))
+        }
+        _ => false,
+    })
+}
+
+/// Generates the arms of `flattened_struct`, which names the structs in enum
+/// variants that serde serializes as maps without their name.
+fn flattened_struct_arms(defs: &Definitions) -> TokenStream {
+    let mut enums: BTreeMap<(&str, String), Vec<&str>> = BTreeMap::new();
+    for node in &defs.types {
+        if IGNORED_TYPES.contains(&&*node.ident) {
+            continue;
+        }
+        if let Data::Enum(variants) = &node.data {
+            for (variant, fields) in variants {
+                match fields.as_slice() {
+                    [Type::Syn(s)] if has_flattened_fields(s, defs) => {
+                        let variant = match rename(&node.ident, variant) {
+                            Some(name) => name.to_owned(),
+                            None => snake_case(variant),
+                        };
+                        enums.entry((s, variant)).or_default().push(&node.ident);
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    enums
+        .into_iter()
+        .map(|((s, variant), idents)| quote!((#(#idents)|*, #variant) => Some(#s),))
+        .collect()
+}
+
 pub(crate) fn generate(defs: &Definitions) {
     let impls = traverse::traverse(defs, node);
+    let flattened_struct_arms = flattened_struct_arms(defs);
     let path = &file::workspace_root().join(AST_ENUM_SRC);
     file::write(function_name!(), path, quote! {
+        #![allow(clippy::large_enum_variant)]
+
         use crate::*;
 
+        /// Returns the name of the struct in the given variant of the given
+        /// enum if the struct contains `#[serde(flatten)]` fields.
+        pub(crate) fn flattened_struct(ident: &str, variant: &str) -> Option<&'static str> {
+            match (ident, variant) {
+                #flattened_struct_arms
+                _ => None,
+            }
+        }
+
         #impls
     })
     .unwrap();
@@ -2,13 +2,13 @@
 
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn_codegen::{Data, Definitions, Node, Punctuated, Type};
+use syn_codegen::{Data, Definitions, Fields, Node, Punctuated, Type};
 
 use crate::{convert::{EMPTY_STRUCTS, should_have_span}, file, traverse};
 
 const AST_ENUM_SRC: &str = "src/gen/ast_struct.rs";
 
-const SKIPPED: &[&str] = &[
+pub(crate) const SKIPPED: &[&str] = &[
     // data.rs
     "Field", // TODO
     // expr.rs
@@ -35,29 +35,42 @@ fn struct_attrs(ident: &str) -> TokenStream {
     }
 }
 
-// Some fields always have the same attributes.
-fn field_attrs(field: &str, ty: &Type, defs: &Definitions) -> TokenStream {
-    fn is_keyword(token: &str) -> bool {
-        matches!(
-            token,
-            "Mut"
-                | "Ref"
-                | "Const"
-                | "Dyn"
-                | "Unsafe"
-                | "Default"
-                | "Async"
-                | "Static"
-                | "Move"
-                | "Auto"
-        )
+fn is_keyword(token: &str) -> bool {
+    matches!(
+        token,
+        "Mut" | "Ref" | "Const" | "Dyn" | "Unsafe" | "Default" | "Async" | "Static" | "Move" | "Auto"
+    )
+}
+
+/// Returns the name that the field is serialized as, i.e., the name with the
+/// renames of `field_attrs` and `rename` applied.
+pub(crate) fn field_key(ident: &str, field: &str, ty: &Type, defs: &Definitions) -> String {
+    let ty = match ty {
+        Type::Box(ty) => &**ty,
+        _ => ty,
+    };
+    match ty {
+        Type::Option(ty) => match &**ty {
+            Type::Token(ty) | Type::Group(ty) if is_keyword(ty) => return defs.tokens[ty].clone(),
+            _ => {}
+        },
+        Type::Syn(ty) => match &**ty {
+            "StaticMutability" | "FieldMutability" => return "mut".to_owned(),
+            "Block" if field == "block" => return "stmts".to_owned(),
+            _ => {}
+        },
+        _ => {}
     }
+    rename(ident, field).unwrap_or(field).to_owned()
+}
 
+// Some fields always have the same attributes.
+fn field_attrs(field: &str, ty: &Type, defs: &Definitions) -> TokenStream {
     match ty {
         Type::Box(ty) => return field_attrs(field, ty, defs),
         Type::Option(ty) => match &**ty {
             Type::Token(ty) | Type::Group(ty) => {
-                let attr = quote!(#[serde(default, skip_serializing_if = "not")]);
+                let attr = quote!(#[serde(default, skip_serializing_if = "explicit::skip")]);
                 return if is_keyword(ty) {
                     assert!(matches!(
                         field,
@@ -82,32 +95,31 @@ fn field_attrs(field: &str, ty: &Type, defs: &Definitions) -> TokenStream {
                     attr
                 };
             }
-            _ => return quote!(#[serde(default, skip_serializing_if = "Option::is_none")]),
+            _ => return quote!(#[serde(default, skip_serializing_if = "explicit::skip")]),
         },
         Type::Syn(ty) => match &**ty {
             "Visibility" => {
                 assert_eq!(field, "vis");
-                return quote!(#[serde(default, skip_serializing_if = "Visibility::is_inherited")]);
+                return quote!(#[serde(default, skip_serializing_if = "explicit::skip")]);
             }
             "StaticMutability" | "FieldMutability" => {
                 assert_eq!(field, "mutability");
-                let is_none = format!("{ty}::is_none");
                 return quote! {
                     #[serde(rename = "mut")]
-                    #[serde(default, skip_serializing_if = #is_none)]
+                    #[serde(default, skip_serializing_if = "explicit::skip")]
                 };
             }
             "Generics" => {
                 assert_eq!(field, "generics");
-                return quote!(#[serde(default, skip_serializing_if = "Generics::is_none")]);
+                return quote!(#[serde(default, skip_serializing_if = "explicit::skip")]);
             }
             "PathArguments" => {
                 assert_eq!(field, "arguments");
-                return quote!(#[serde(default, skip_serializing_if = "PathArguments::is_none")]);
+                return quote!(#[serde(default, skip_serializing_if = "explicit::skip")]);
             }
             "TraitBoundModifier" => {
                 assert_eq!(field, "modifier");
-                return quote!(#[serde(default, skip_serializing_if = "TraitBoundModifier::is_none")]);
+                return quote!(#[serde(default, skip_serializing_if = "explicit::skip")]);
             }
             "ReturnType" => {
                 assert_eq!(field, "output");
@@ -127,17 +139,17 @@ fn field_attrs(field: &str, ty: &Type, defs: &Definitions) -> TokenStream {
     quote!()
 }
 
-fn skip_serializing_if(ident: &str, field: &str, ty: &Type) -> Option<String> {
+fn skip_serializing_if(ident: &str, field: &str) -> Option<&'static str> {
     match (ident, field) {
         (_, "attrs")
         | ("Attribute", "tokens")
         | ("TypeParam" | "LifetimeDef" | "TraitItemType", "bounds")
-        | ("ItemTrait", "supertraits") => Some(format!("{}::is_empty", outer_ty(ty))),
+        | ("ItemTrait", "supertraits") => Some("explicit::skip"),
         _ => None,
     }
 }
 
-fn allow_transparent(ident: &str, field: &str, ty: &Type) -> bool {
+pub(crate) fn allow_transparent(ident: &str, field: &str, ty: &Type) -> bool {
     const DISALLOWED: &[&str] = &[
         // TODO: revisit
         "MethodTurbofish",
@@ -149,7 +161,38 @@ fn allow_transparent(ident: &str, field: &str, ty: &Type) -> bool {
         && !ident.starts_with("Type")
 }
 
-fn flatten(ident: &str, field: &str, ty: &Type) -> bool {
+/// Returns `true` if the adapter is serialized as its only field.
+pub(crate) fn is_transparent(ident: &str, fields: &Fields) -> bool {
+    let mut fields = fields.iter().filter(|(_, ty)| format_ty(ty).is_some());
+    match (fields.next(), fields.next()) {
+        (Some((field, ty)), None) => {
+            !should_have_span(ident)
+                && !should_have_comments(ident)
+                && ident != "File"
+                && allow_transparent(ident, field, ty)
+        }
+        _ => false,
+    }
+}
+
+/// Returns `true` if the adapter, hand-written or not, has a `trivia` field.
+/// The fields of the transparent and flattened adapters are serialized into
+/// another value, so the trivia of their tokens are recorded by the nodes
+/// containing them, as are the ones of the types that syn cannot print by
+/// themselves.
+pub(crate) fn has_trivia(ident: &str, fields: &Fields) -> bool {
+    !EMPTY_STRUCTS.contains(&ident)
+        && ident != "File"
+        && !matches!(ident, "Macro" | "Path" | "Signature")
+        // These are printed by the nodes containing them.
+        && !matches!(ident, "LocalInit" | "QSelf")
+        // The fields of `Generics` are only serialized if it is not empty or
+        // in lossless mode.
+        && ident != "Generics"
+        && !is_transparent(ident, fields)
+}
+
+pub(crate) fn flatten(ident: &str, field: &str, ty: &Type) -> bool {
     match (field, base_ty(ty)) {
         ("member", Some("Member")) | ("mac", Some("Macro")) | ("sig", Some("Signature")) => true,
         ("lit", Some("Lit")) => ident.ends_with("Lit"),
@@ -158,11 +201,11 @@ fn flatten(ident: &str, field: &str, ty: &Type) -> bool {
     }
 }
 
-fn rename<'a>(_ident: &str, field: &'a str) -> Option<&'a str> {
+pub(crate) fn rename<'a>(_ident: &str, field: &'a str) -> Option<&'a str> {
     field.strip_suffix('_')
 }
 
-fn base_ty(ty: &Type) -> Option<&str> {
+pub(crate) fn base_ty(ty: &Type) -> Option<&str> {
     match ty {
         Type::Syn(ty) | Type::Ext(ty) | Type::Std(ty) => Some(ty),
         _ => None,
@@ -180,7 +223,7 @@ fn outer_ty(ty: &Type) -> &str {
     }
 }
 
-fn format_ty(ty: &Type) -> Option<TokenStream> {
+pub(crate) fn format_ty(ty: &Type) -> Option<TokenStream> {
     match ty {
         Type::Box(t)
         | Type::Vec(t)
@@ -216,7 +259,7 @@ fn format_ty(ty: &Type) -> Option<TokenStream> {
 
 
 // Determine if a type should have comments attached to it
-fn should_have_comments(ident: &str) -> bool {
+pub(crate) fn should_have_comments(ident: &str) -> bool {
     // Add comments to major structural elements that users typically comment
     match ident {
         // Item types that commonly have comments
@@ -232,6 +275,12 @@ fn should_have_comments(ident: &str) -> bool {
     }
 }
 
+/// Returns `true` if the adapter has a `comments` field. The hand-written
+/// adapters do not have one.
+pub(crate) fn has_comments(ident: &str) -> bool {
+    !SKIPPED.contains(&ident) && should_have_comments(ident)
+}
+
 fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
     if SKIPPED.contains(&&*node.ident) || EMPTY_STRUCTS.contains(&&*node.ident) {
         return;
@@ -239,7 +288,6 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
 
     if let Data::Struct(fields) = &node.data {
         let mut body = vec![];
-        let mut last = "";
         let mut has_existing_span = false;
         
         // Process existing fields
@@ -250,7 +298,7 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
             if let Some(t) = format_ty(ty) {
                 let attrs = field_attrs(field, ty, defs);
                 let rename = rename(&node.ident, field).map(|s| quote!(#[serde(rename = #s)]));
-                let skip_serializing_if = skip_serializing_if(&node.ident, field, ty)
+                let skip_serializing_if = skip_serializing_if(&node.ident, field)
                     .map(|s| quote!(#[serde(default, skip_serializing_if = #s)]));
                 let flatten = if flatten(&node.ident, field, ty) {
                     quote!(#[serde(flatten)])
@@ -266,7 +314,6 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
                     #flatten
                     pub(crate) #f: #t,
                 });
-                last = &**field;
             }
         }
         
@@ -274,11 +321,9 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
         if should_have_span(&node.ident) && !has_existing_span {
             // Check if this type has any flattened fields that might already have spans
             let has_flattened_spannable = fields.iter().any(|(field, ty)| {
-                flatten(&node.ident, field, ty) && match (field.as_str(), base_ty(ty)) {
-                    ("path", Some("Path")) => true, // Path has spans
-                    ("lit", Some("Lit")) => true,   // Lit can have spans 
-                    _ => false,
-                }
+                // Path has spans, Lit can have spans
+                flatten(&node.ident, field, ty)
+                    && matches!((field.as_str(), base_ty(ty)), ("path", Some("Path")) | ("lit", Some("Lit")))
             });
             
             if !has_flattened_spannable {
@@ -297,8 +342,23 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
             });
         }
 
-        let transparent = if body.len() == 1 && !should_have_span(&node.ident) && allow_transparent(&node.ident, last, &fields[last])
-        {
+        // Add trivia field to the root file
+        if node.ident == "File" {
+            body.push(quote! {
+                #[serde(default, skip_serializing_if = "Option::is_none")]
+                pub(crate) trivia: Option<crate::trivia::Trivia>,
+            });
+        }
+
+        // Add trivia field to the nodes that own tokens
+        if has_trivia(&node.ident, fields) {
+            body.push(quote! {
+                #[serde(default, skip_serializing_if = "Vec::is_empty")]
+                pub(crate) trivia: Vec<crate::trivia::TokenTrivia>,
+            });
+        }
+
+        let transparent = if is_transparent(&node.ident, fields) {
             Some(quote!(#[serde(transparent)]))
         } else {
             None
@@ -321,10 +381,19 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
 
 pub(crate) fn generate(defs: &Definitions) {
     let impls = traverse::traverse(defs, node);
+    let commented = traverse::traverse(defs, |commented, node, _| {
+        if matches!(node.data, Data::Struct(_)) && has_comments(&node.ident) {
+            let ident = &node.ident;
+            commented.extend(quote!(#ident,));
+        }
+    });
     let path = &file::workspace_root().join(AST_ENUM_SRC);
     file::write(function_name!(), path, quote! {
         use crate::*;
 
+        /// The names of the adapters that have a `comments` field.
+        pub(crate) const COMMENTED: &[&str] = &[#commented];
+
         #impls
     })
     .unwrap();
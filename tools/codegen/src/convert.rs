@@ -4,7 +4,7 @@ use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 use syn_codegen::{Data, Definitions, Node, Type};
 
-use crate::{file, traverse};
+use crate::{ast_struct::has_trivia, file, traverse};
 
 const CONVERT_SRC: &str = "src/gen/convert.rs";
 
@@ -15,6 +15,15 @@ pub(crate) const IGNORED_TYPES: &[&str] =
 pub(crate) const EMPTY_STRUCTS: &[&str] =
     &["TypeInfer", "TypeNever", "UseGlob", "VisCrate", "VisPublic"];
 
+/// The field names of the variants that are struct variants in the adapters,
+/// instead of tuple variants like in syn.
+pub(crate) fn variant_fields(ident: &str, variant: &str) -> Option<&'static [&'static str]> {
+    match (ident, variant) {
+        ("Stmt", "Expr") => Some(&["expr", "semi"]),
+        _ => None,
+    }
+}
+
 fn visit(ty: &Type, var: &TokenStream, defs: &Definitions) -> (Option<TokenStream>, TokenStream) {
     match ty {
         Type::Box(_) | Type::Vec(_) | Type::Punctuated(_) => {
@@ -142,6 +151,11 @@ pub(crate) fn should_have_span(ident: &str) -> bool {
         // Most important Item types that definitely implement Spanned
         "ItemFn" | "ItemEnum" | "ItemImpl" | "ItemUse" | 
         "ItemConst" | "ItemStatic" | "ItemTrait" | "ItemType" |
+        // Other named items, for symbol indexes
+        "ItemStruct" | "ItemUnion" | "ItemMod" | "ItemMacro" | "ItemTraitAlias" |
+        "ImplItemFn" | "ImplItemConst" | "ImplItemType" |
+        "TraitItemFn" | "TraitItemConst" | "TraitItemType" |
+        "ForeignItemFn" | "ForeignItemStatic" | "ForeignItemType" |
         // Most important Expression types that implement Spanned  
         "ExprCall" | "ExprPath" | "ExprField" | "ExprMethodCall" | "ExprLit" |
         "ExprBlock" | "ExprIf" | "ExprArray" | "ExprTuple" |
@@ -212,7 +226,16 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
                     }
                 }
 
-                if from_expr.is_empty() {
+                if let Some(names) = variant_fields(&node.ident, &variant.to_string()) {
+                    assert_eq!(names.len(), from_expr.len());
+                    let names: Vec<_> = names.iter().map(|name| format_ident!("{name}")).collect();
+                    from_variants.extend(quote! {
+                        syn::#ident::#variant(#(#from_pat),*) => #ident::#variant { #(#names: #from_expr),* },
+                    });
+                    into_variants.extend(quote! {
+                        #ident::#variant { #(#names: #into_pat),* } => syn::#ident::#variant(#(#into_expr),*),
+                    });
+                } else if from_expr.is_empty() {
                     from_variants.extend(quote! {
                         syn::#ident::#variant(..) => #ident::#variant,
                     });
@@ -281,22 +304,24 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
                         _ => false,
                     };
                     
-                    is_flattened && match (field.as_str(), base_ty_str(ty)) {
-                        ("path", Some("Path")) => true, // Path has spans
-                        ("lit", Some("Lit")) => true,   // Lit can have spans 
-                        _ => false,
-                    }
+                    // Path has spans, Lit can have spans
+                    is_flattened
+                        && matches!((field.as_str(), base_ty_str(ty)), ("path", Some("Path")) | ("lit", Some("Lit")))
                 });
                 
                 if !has_flattened_spannable {
                     // For from conversion (syn -> syn-serde), extract span intelligently
                     let span_expr = match node.ident.as_str() {
-                        // Types that implement Spanned directly
-                        "ExprLit" | "ExprPath" | "File" => quote!(node.span()),
+                        // Types that implement Spanned directly, and core types
+                        "ExprLit" | "ExprPath" | "File" | "Ident" => quote!(node.span()),
                         // Item types - need to be more specific about their structure
-                        "ItemFn" => quote!(node.sig.ident.span()),
-                        "ItemEnum" | "ItemConst" | "ItemStatic" | 
-                        "ItemTrait" | "ItemType" => quote!(node.ident.span()),
+                        "ItemFn" | "ImplItemFn" | "TraitItemFn" | "ForeignItemFn" => quote!(node.sig.ident.span()),
+                        "ItemEnum" | "ItemConst" | "ItemStatic" |
+                        "ItemTrait" | "ItemType" | "PatIdent" | "PathSegment" |
+                        "ItemStruct" | "ItemUnion" | "ItemMod" | "ItemTraitAlias" |
+                        "ImplItemConst" | "ImplItemType" | "TraitItemConst" | "TraitItemType" |
+                        "ForeignItemStatic" | "ForeignItemType" => quote!(node.ident.span()),
+                        "ItemMacro" => quote!(match &node.ident { Some(ident) => ident.span(), None => node.mac.path.span() }),
                         "ItemImpl" => quote!(node.impl_token.span()),
                         "ItemUse" => quote!(node.use_token.span()),
                         // Expression types - use various strategies
@@ -306,23 +331,16 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
                         "ExprStruct" => quote!(node.path.span()),
                         "ExprArray" => quote!(node.bracket_token.span.join()),
                         "ExprTuple" => quote!(node.paren_token.span.join()),
-                        "ExprBinary" => quote!(node.op.span()),
-                        "ExprUnary" => quote!(node.op.span()),
+                        "ExprBinary" | "ExprUnary" => quote!(node.op.span()),
                         "ExprAssign" => quote!(node.left.span()),
                         "ExprBlock" => quote!(node.block.brace_token.span.join()),
                         "ExprIf" => quote!(node.if_token.span()),
                         // Block type - extract span from brace tokens
                         "Block" => quote!(node.brace_token.span.join()),
-                        // Pattern types
-                        "PatIdent" => quote!(node.ident.span()),
-                        "PatPath" | "PatStruct" | "PatTuple" => quote!(proc_macro2::Span::call_site()),
-                        // Path types  
+                        // Path types
                         "Path" => quote!(if node.segments.is_empty() { proc_macro2::Span::call_site() } else { node.segments.first().unwrap().ident.span() }),
-                        "PathSegment" => quote!(node.ident.span()),
-                        // Core types
-                        "Ident" => quote!(node.span()),
                         "Local" => quote!(node.pat.span()),
-                        // Fallback for any other types
+                        // Fallback for any other types (including PatPath, PatStruct and PatTuple)
                         _ => quote!(proc_macro2::Span::call_site()),
                     };
                     from_fields.extend(quote!(span: Some(crate::SpanInfo::from_span(#span_expr)),));
@@ -334,6 +352,17 @@ fn node(impls: &mut TokenStream, node: &Node, defs: &Definitions) {
                 from_fields.extend(quote!(comments: vec![],));
             }
 
+            // The trivia of the source code are only recorded by `trivia::parse_file`
+            if node.ident == "File" {
+                from_fields.extend(quote!(trivia: None,));
+            }
+
+            // The trivia are taken after the fields are converted, so that the
+            // nodes in the fields take the trivia of their tokens first
+            if has_trivia(&node.ident, fields) {
+                from_fields.extend(quote!(trivia: crate::trivia::take(node),));
+            }
+
             assert!(!fields.is_empty(), "fields.is_empty: {ident}");
             assert!(!from_fields.is_empty(), "from_fields.is_empty(): {ident}");
 
@@ -374,7 +403,7 @@ pub(crate) fn generate(defs: &Definitions) {
         )]
 
         use crate::*;
-        use syn::spanned::Spanned;
+        use syn::spanned::Spanned as _;
 
         #impls
     })
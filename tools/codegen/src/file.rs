@@ -35,13 +35,16 @@ pub(crate) fn header(function_name: &str) -> String {
     // a module without a body and unstable ignore option in .rustfmt.toml.
     // https://github.com/rust-lang/rust-analyzer/issues/10826
     // So use inner attribute under cfg(rustfmt).
+    format!("{}\n#![cfg_attr(rustfmt, rustfmt::skip)]\n", comment_header(function_name))
+}
+
+#[track_caller]
+fn comment_header(function_name: &str) -> String {
     format!(
         "// SPDX-License-Identifier: Apache-2.0 OR MIT
 // This file is @generated by {bin_name}
 // ({function_name} function at {file}).
-// It is not intended for manual editing.\n
-#![cfg_attr(rustfmt, rustfmt::skip)]
-",
+// It is not intended for manual editing.\n",
         bin_name = env!("CARGO_BIN_NAME"),
         file = std::panic::Location::caller().file()
     )
@@ -130,6 +133,21 @@ pub(crate) fn write_raw(
     path: &Path,
     contents: impl AsRef<[u8]>,
 ) -> io::Result<()> {
+    let mut out = header(function_name).into_bytes();
+    out.extend_from_slice(contents.as_ref());
+    write_if_changed(path, &out)
+}
+
+/// Writes a file that is not Rust code, e.g., a Protocol Buffers schema.
+#[track_caller]
+pub(crate) fn write_text(function_name: &str, path: &Path, contents: &str) -> io::Result<()> {
+    let mut out = comment_header(function_name);
+    out.push('\n');
+    out.push_str(contents);
+    write_if_changed(path, out.as_bytes())
+}
+
+fn write_if_changed(path: &Path, out: &[u8]) -> io::Result<()> {
     static LINGUIST_GENERATED: LazyLock<Vec<globset::GlobMatcher>> = LazyLock::new(|| {
         let gitattributes = fs::read_to_string(workspace_root().join(".gitattributes")).unwrap();
         let mut linguist_generated = vec![];
@@ -147,8 +165,6 @@ pub(crate) fn write_raw(
         eprintln!("warning: you may want to mark {} linguist-generated", p.display());
     }
 
-    let mut out = header(function_name).into_bytes();
-    out.extend_from_slice(contents.as_ref());
     if path.is_file() && fs::read(path)? == out {
         return Ok(());
     }
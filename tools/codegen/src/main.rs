@@ -13,7 +13,9 @@ mod file;
 mod ast_enum;
 mod ast_struct;
 mod convert;
+mod protobuf;
 mod traverse;
+mod visit;
 
 use std::path::Path;
 
@@ -28,4 +30,6 @@ fn main() {
     ast_struct::generate(&defs);
     ast_enum::generate(&defs);
     convert::generate(&defs);
+    protobuf::generate(&defs);
+    visit::generate(&defs);
 }
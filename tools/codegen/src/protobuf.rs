@@ -0,0 +1,580 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Generates the Protocol Buffers schema of the adapters (src/gen/syn.proto),
+// and the table that src/protobuf.rs encodes and decodes them with
+// (src/gen/protobuf.rs).
+//
+// The messages describe the serialized form of the adapters, so the rules
+// here follow ast_struct.rs and ast_enum.rs, and the hand-written adapters
+// that do not follow syn.json are listed in `override_fields`.
+
+use std::fmt::Write as _;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn_codegen::{Data, Definitions, Node, Punctuated, Type};
+
+use crate::{
+    ast_enum, ast_struct,
+    convert::{EMPTY_STRUCTS, should_have_span, variant_fields},
+    file,
+};
+
+const PROTOBUF_SRC: &str = "src/gen/protobuf.rs";
+const PROTO_SRC: &str = "src/gen/syn.proto";
+
+/// The message of unit variants.
+const UNIT: &str = "Unit";
+
+/// The types of syn.json that are serialized as their tokens.
+const LIT_TYPES: &[&str] =
+    &["LitByte", "LitByteStr", "LitCStr", "LitChar", "LitFloat", "LitInt", "LitStr"];
+
+struct Message {
+    name: String,
+    doc: String,
+    kind: Kind,
+    fields: Vec<Field>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    /// A struct, serialized as a map.
+    Struct,
+    /// A struct with `#[serde(transparent)]`, serialized as its only field.
+    Transparent,
+    /// A tuple, serialized as a sequence.
+    Tuple,
+    /// A struct that is serialized as its first field if the other fields
+    /// are absent, e.g., `Ident`.
+    Token,
+    /// An enum with data, encoded as a `oneof`.
+    Oneof,
+    /// An enum with only unit variants, encoded as a protobuf enum.
+    Enum,
+}
+
+struct Field {
+    /// The name that the field or variant is serialized as.
+    key: String,
+    /// The name of the field or variant in the schema.
+    name: String,
+    label: Label,
+    ty: Ty,
+    flatten: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Label {
+    Singular,
+    Optional,
+    Repeated,
+}
+
+#[derive(Clone, PartialEq, Eq)]
+enum Ty {
+    Bool,
+    Uint32,
+    Uint64,
+    String,
+    Message(String),
+    Enum(String),
+}
+
+impl Field {
+    fn new(key: &str, label: Label, ty: Ty) -> Self {
+        Self { key: key.to_owned(), name: key.to_owned(), label, ty, flatten: false }
+    }
+}
+
+fn message(name: &str, doc: &str, kind: Kind, fields: Vec<Field>) -> Message {
+    Message { name: name.to_owned(), doc: doc.to_owned(), kind, fields }
+}
+
+fn msg(name: &str) -> Ty {
+    Ty::Message(name.to_owned())
+}
+
+/// The messages of the adapters that are not in syn.json.
+fn extra_messages() -> Vec<Message> {
+    use self::{
+        Label::{Optional, Repeated, Singular},
+        Ty::{Bool, Enum, String, Uint64},
+    };
+    let delimiters = ["parenthesis", "brace", "bracket", "none"];
+    vec![
+        message(UNIT, "The content of unit variants.", Kind::Struct, vec![]),
+        message("SpanInfo", "syn_serde::SpanInfo", Kind::Struct, vec![
+            Field::new("start_offset", Singular, Uint64),
+            Field::new("end_offset", Singular, Uint64),
+            Field::new("start_line", Singular, Uint64),
+            Field::new("start_column", Singular, Uint64),
+            Field::new("end_line", Singular, Uint64),
+            Field::new("end_column", Singular, Uint64),
+            Field::new("file", Optional, Uint64),
+        ]),
+        message("Comment", "syn_serde::Comment", Kind::Struct, vec![
+            Field::new("text", Singular, String),
+            Field::new("span", Singular, msg("SpanInfo")),
+            Field::new("kind", Singular, Enum("CommentKind".to_owned())),
+        ]),
+        message("CommentKind", "syn_serde::CommentKind", Kind::Enum, vec![
+            Field::new("Line", Singular, Bool),
+            Field::new("Block", Singular, Bool),
+        ]),
+        message("Trivia", "syn_serde::trivia::Trivia", Kind::Struct, vec![
+            Field::new("tokens", Repeated, msg("TokenTrivia")),
+            Field::new("eof", Singular, String),
+        ]),
+        message("TokenTrivia", "syn_serde::trivia::TokenTrivia", Kind::Struct, vec![
+            Field::new("token", Singular, String),
+            Field::new("leading", Singular, String),
+            Field::new("trailing", Singular, String),
+            Field::new("source", Optional, String),
+            Field::new("index", Singular, Uint64),
+        ]),
+        message("Ident", "proc_macro2::Ident", Kind::Token, vec![
+            Field::new("ident", Singular, String),
+            Field::new("span", Optional, msg("SpanInfo")),
+        ]),
+        message(
+            "Literal",
+            "proc_macro2::Literal, and the literals of syn (e.g., syn::LitStr)",
+            Kind::Token,
+            vec![
+                Field::new("lit", Singular, String),
+                Field::new("span", Optional, msg("SpanInfo")),
+            ],
+        ),
+        message("TokenStream", "proc_macro2::TokenStream", Kind::Transparent, vec![Field::new(
+            "trees",
+            Repeated,
+            msg("TokenTree"),
+        )]),
+        message("TokenTree", "proc_macro2::TokenTree", Kind::Oneof, vec![
+            Field::new("group", Singular, msg("Group")),
+            Field::new("ident", Singular, msg("Ident")),
+            Field::new("punct", Singular, msg("Punct")),
+            Field::new("lit", Singular, msg("Literal")),
+        ]),
+        message("Group", "proc_macro2::Group", Kind::Struct, vec![
+            Field::new("delimiter", Singular, Enum("Delimiter".to_owned())),
+            Field::new("stream", Singular, msg("TokenStream")),
+            Field::new("span", Optional, msg("SpanInfo")),
+            Field::new("span_open", Optional, msg("SpanInfo")),
+            Field::new("span_close", Optional, msg("SpanInfo")),
+        ]),
+        message(
+            "Delimiter",
+            "proc_macro2::Delimiter",
+            Kind::Enum,
+            delimiters.iter().map(|d| Field::new(d, Singular, Bool)).collect(),
+        ),
+        message("Punct", "proc_macro2::Punct", Kind::Struct, vec![
+            Field::new("op", Singular, String),
+            Field::new("spacing", Singular, Enum("Spacing".to_owned())),
+            Field::new("span", Optional, msg("SpanInfo")),
+        ]),
+        message("Spacing", "proc_macro2::Spacing", Kind::Enum, vec![
+            Field::new("alone", Singular, Bool),
+            Field::new("joint", Singular, Bool),
+        ]),
+    ]
+}
+
+/// The fields of the hand-written adapters that do not follow syn.json.
+fn override_fields(ident: &str) -> Option<Vec<(&'static str, Type)>> {
+    let syn = |t: &str| Type::Syn(t.to_owned());
+    let bool = || Type::Std("bool".to_owned());
+    let vec = |t| Type::Vec(Box::new(t));
+    let option = |t| Type::Option(Box::new(t));
+    match ident {
+        "Arm" => Some(vec![
+            ("attrs", vec(syn("Attribute"))),
+            ("pat", syn("Pat")),
+            ("guard", option(syn("Expr"))),
+            ("body", syn("Expr")),
+            ("comma", option(bool())),
+        ]),
+        "Receiver" => Some(vec![
+            ("attrs", vec(syn("Attribute"))),
+            ("ref", bool()),
+            ("lifetime", option(syn("Lifetime"))),
+            ("mut", bool()),
+            ("colon_token", bool()),
+            ("ty", syn("Type")),
+        ]),
+        "ReturnType" => Some(vec![("ty", option(syn("Type")))]),
+        _ => None,
+    }
+}
+
+fn is_unit_variant(fields: &[Type]) -> bool {
+    match fields {
+        [] | [Type::Token(_) | Type::Group(_)] => true,
+        [Type::Syn(t)] => EMPTY_STRUCTS.contains(&&**t),
+        _ => false,
+    }
+}
+
+fn is_unit_enum(defs: &Definitions, ident: &str) -> bool {
+    defs.types.iter().any(|node| {
+        node.ident == ident
+            && matches!(&node.data, Data::Enum(variants)
+                if !variants.is_empty() && variants.values().all(|v| is_unit_variant(v)))
+    })
+}
+
+fn base_ty(t: &str, defs: &Definitions) -> Ty {
+    match t {
+        "bool" => Ty::Bool,
+        "u32" => Ty::Uint32,
+        "usize" => Ty::Uint64,
+        "String" => Ty::String,
+        "Span" => msg("SpanInfo"),
+        _ if LIT_TYPES.contains(&t) => msg("Literal"),
+        _ if is_unit_enum(defs, t) => Ty::Enum(t.to_owned()),
+        _ => msg(t),
+    }
+}
+
+fn camel_case(s: &str) -> String {
+    s.split('_')
+        .map(|w| {
+            let mut c = w.chars();
+            c.next().map(|f| f.to_ascii_uppercase().to_string() + c.as_str()).unwrap_or_default()
+        })
+        .collect()
+}
+
+pub(crate) fn snake_case(s: &str) -> String {
+    let mut out = String::new();
+    for (i, c) in s.char_indices() {
+        if c.is_ascii_uppercase() {
+            if i != 0 {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Returns the label and type of a field, or `None` if the field is not
+/// serialized. Tuples and optional lists get messages of their own, named
+/// after the field.
+fn field_ty(
+    ty: &Type,
+    name: &str,
+    defs: &Definitions,
+    messages: &mut Vec<Message>,
+) -> Option<(Label, Ty)> {
+    match ty {
+        Type::Box(t) => field_ty(t, name, defs, messages),
+        Type::Vec(t) | Type::Punctuated(Punctuated { element: t, .. }) => {
+            let (_, ty) = field_ty(t, name, defs, messages)?;
+            Some((Label::Repeated, ty))
+        }
+        Type::Option(t) => match &**t {
+            Type::Token(_) | Type::Group(_) => Some((Label::Singular, Ty::Bool)),
+            Type::Tuple(elems) => {
+                let elems: Vec<_> =
+                    elems.iter().filter(|t| ast_struct::format_ty(t).is_some()).collect();
+                if elems.len() == 1 {
+                    return field_ty(
+                        &Type::Option(Box::new(elems[0].clone())),
+                        name,
+                        defs,
+                        messages,
+                    );
+                }
+                let fields = elems
+                    .iter()
+                    .enumerate()
+                    .map(|(i, t)| {
+                        let (label, ty) = field_ty(t, name, defs, messages).unwrap();
+                        Field::new(&format!("elem{i}"), label, ty)
+                    })
+                    .collect();
+                messages.push(message(name, "", Kind::Tuple, fields));
+                Some((Label::Optional, msg(name)))
+            }
+            t => {
+                let (label, ty) = field_ty(t, name, defs, messages)?;
+                if label == Label::Repeated {
+                    let field = Field::new("elems", label, ty);
+                    messages.push(message(name, "", Kind::Transparent, vec![field]));
+                    return Some((Label::Optional, msg(name)));
+                }
+                Some((Label::Optional, ty))
+            }
+        },
+        Type::Token(_) | Type::Group(_) | Type::Tuple(_) => None,
+        Type::Syn(t) if t == "Reserved" || EMPTY_STRUCTS.contains(&&**t) => None,
+        Type::Syn(t) | Type::Ext(t) | Type::Std(t) => Some((Label::Singular, base_ty(t, defs))),
+    }
+}
+
+fn override_message(node: &Node, overrides: Vec<(&str, Type)>, defs: &Definitions) -> Message {
+    let ident = &*node.ident;
+    let fields = overrides
+        .into_iter()
+        .map(|(key, ty)| {
+            let (label, ty) = field_ty(&ty, "", defs, &mut vec![]).unwrap();
+            Field::new(key, label, ty)
+        })
+        .collect();
+    let kind = if ident == "ReturnType" { Kind::Transparent } else { Kind::Struct };
+    message(ident, &format!("syn::{ident}"), kind, fields)
+}
+
+fn struct_message(node: &Node, fields: &syn_codegen::Fields, defs: &Definitions) -> Vec<Message> {
+    let ident = &*node.ident;
+    let mut messages = vec![];
+    let mut body = vec![];
+    let doc = format!("syn::{ident}");
+
+    let mut has_existing_span = false;
+    for (field, ty) in fields {
+        if field == "span" {
+            has_existing_span = true;
+        }
+        let name = format!("{ident}{}", camel_case(field.trim_end_matches('_')));
+        let len = messages.len();
+        let field_ty = field_ty(ty, &name, defs, &mut messages);
+        for message in &mut messages[len..] {
+            message.doc = format!("The `{field}` field of syn::{ident}");
+        }
+        if let Some((label, t)) = field_ty {
+            let key = ast_struct::field_key(ident, field, ty, defs);
+            body.push(Field {
+                flatten: ast_struct::flatten(ident, field, ty),
+                ..Field::new(&key, label, t)
+            });
+        }
+    }
+
+    // The fields that ast_struct.rs adds to the generated adapters. The
+    // handwritten adapters have spans too if they should have one.
+    if should_have_span(ident)
+        && !has_existing_span
+        && !body.iter().any(|f| f.flatten && matches!(&*f.key, "path" | "lit"))
+    {
+        body.push(Field::new("span", Label::Optional, msg("SpanInfo")));
+    }
+    if !ast_struct::SKIPPED.contains(&ident) {
+        if ast_struct::should_have_comments(ident) {
+            body.push(Field::new("comments", Label::Repeated, msg("Comment")));
+        }
+        if ident == "File" {
+            body.push(Field::new("trivia", Label::Optional, msg("Trivia")));
+        }
+    }
+
+    let transparent = !ast_struct::SKIPPED.contains(&ident)
+        && body.len() == 1
+        && !should_have_span(ident)
+        && fields
+            .iter()
+            .rfind(|(_, ty)| ast_struct::format_ty(ty).is_some())
+            .is_some_and(|(field, ty)| ast_struct::allow_transparent(ident, field, ty));
+    if ast_struct::has_trivia(ident, fields) {
+        body.push(Field::new("trivia", Label::Repeated, msg("TokenTrivia")));
+    }
+    let kind = if transparent { Kind::Transparent } else { Kind::Struct };
+    messages.push(Message { name: ident.to_owned(), doc, kind, fields: body });
+    messages
+}
+
+fn enum_message(node: &Node, variants: &syn_codegen::Variants, defs: &Definitions) -> Vec<Message> {
+    let ident = &*node.ident;
+    let mut messages = vec![];
+    let mut body = vec![];
+    let unit_enum = is_unit_enum(defs, ident);
+
+    for (variant, fields) in variants {
+        let key = match ast_enum::rename(ident, variant) {
+            Some(key) => key.to_owned(),
+            None => match (ident, &fields[..]) {
+                ("BinOp" | "UnOp", [Type::Token(t)]) => defs.tokens[t].clone(),
+                _ => snake_case(variant),
+            },
+        };
+        let (label, ty) = if is_unit_variant(fields) {
+            (Label::Singular, msg(UNIT))
+        } else if let Some(names) = variant_fields(ident, variant) {
+            let name = format!("{ident}{variant}");
+            let mut struct_fields = vec![];
+            for (field, ty) in names.iter().zip(fields) {
+                let (label, ty) = field_ty(ty, &name, defs, &mut messages).unwrap();
+                struct_fields.push(Field::new(field, label, ty));
+            }
+            let doc = format!("syn::{ident}::{variant}");
+            messages.push(message(&name, &doc, Kind::Struct, struct_fields));
+            (Label::Singular, msg(&name))
+        } else {
+            assert_eq!(fields.len(), 1, "{ident}::{variant}");
+            field_ty(&fields[0], "", defs, &mut messages).unwrap()
+        };
+        let mut field = Field::new(&key, label, ty);
+        if !is_proto_ident(&key) {
+            field.name = snake_case(variant);
+        }
+        body.push(field);
+    }
+
+    let kind = if unit_enum { Kind::Enum } else { Kind::Oneof };
+    messages.push(message(ident, &format!("syn::{ident}"), kind, body));
+    messages
+}
+
+fn is_proto_ident(s: &str) -> bool {
+    s.starts_with(|c: char| c.is_ascii_alphabetic())
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+fn messages(defs: &Definitions) -> Vec<Message> {
+    let mut messages = extra_messages();
+    for node in defs.types.iter().filter(|node| {
+        (node.features.any.is_empty() || node.features.any.contains("full"))
+            && node.ident != "Reserved"
+            && !EMPTY_STRUCTS.contains(&&*node.ident)
+    }) {
+        if let Some(overrides) = override_fields(&node.ident) {
+            messages.push(override_message(node, overrides, defs));
+            continue;
+        }
+        match &node.data {
+            Data::Struct(fields) => messages.extend(struct_message(node, fields, defs)),
+            Data::Enum(variants) => messages.extend(enum_message(node, variants, defs)),
+            Data::Private => assert!(LIT_TYPES.contains(&&*node.ident), "{}", node.ident),
+        }
+    }
+    messages.sort_by(|a, b| a.name.cmp(&b.name));
+    for w in messages.windows(2) {
+        assert_ne!(w[0].name, w[1].name);
+    }
+    messages
+}
+
+fn proto_ty(ty: &Ty) -> &str {
+    match ty {
+        Ty::Bool => "bool",
+        Ty::Uint32 => "uint32",
+        Ty::Uint64 => "uint64",
+        Ty::String => "string",
+        Ty::Message(name) | Ty::Enum(name) => name,
+    }
+}
+
+fn proto(messages: &[Message]) -> String {
+    let mut out = String::new();
+    out.push_str(
+        "// The serialized form of the adapters of syn-serde, as encoded by the
+// `protobuf` module. See the documentation of the module for how the
+// messages correspond to the adapters.
+
+syntax = \"proto3\";
+
+package syn_serde;
+",
+    );
+    for message in messages {
+        out.push('\n');
+        if !message.doc.is_empty() {
+            writeln!(out, "// {}", message.doc).unwrap();
+        }
+        if message.kind == Kind::Enum {
+            let prefix = snake_case(&message.name).to_ascii_uppercase();
+            writeln!(out, "enum {} {{", message.name).unwrap();
+            for (i, field) in message.fields.iter().enumerate() {
+                let name = snake_case(&camel_case(&field.name)).to_ascii_uppercase();
+                writeln!(out, "  {prefix}_{name} = {i};").unwrap();
+            }
+            out.push_str("}\n");
+            continue;
+        }
+        writeln!(out, "message {} {{", message.name).unwrap();
+        let oneof = message.kind == Kind::Oneof && !message.fields.is_empty();
+        let indent = if oneof {
+            out.push_str("  oneof kind {\n");
+            "    "
+        } else {
+            "  "
+        };
+        for (i, field) in message.fields.iter().enumerate() {
+            let label = match field.label {
+                Label::Repeated => "repeated ",
+                Label::Optional if !matches!(field.ty, Ty::Message(_)) => "optional ",
+                _ => "",
+            };
+            let comment = if field.flatten { " // flattened" } else { "" };
+            writeln!(
+                out,
+                "{indent}{label}{} {} = {};{comment}",
+                proto_ty(&field.ty),
+                field.name,
+                i + 1
+            )
+            .unwrap();
+        }
+        if oneof {
+            out.push_str("  }\n");
+        }
+        out.push_str("}\n");
+    }
+    out
+}
+
+fn table(messages: &[Message]) -> TokenStream {
+    let messages = messages.iter().map(|message| {
+        let name = &message.name;
+        let kind = format_ident!("{}", match message.kind {
+            Kind::Struct => "Struct",
+            Kind::Transparent => "Transparent",
+            Kind::Tuple => "Tuple",
+            Kind::Token => "Token",
+            Kind::Oneof => "Oneof",
+            Kind::Enum => "Enum",
+        });
+        let fields = message.fields.iter().map(|field| {
+            let key = &field.key;
+            let label = format_ident!("{}", match field.label {
+                Label::Singular => "Singular",
+                Label::Optional => "Optional",
+                Label::Repeated => "Repeated",
+            });
+            let ty = match &field.ty {
+                Ty::Bool => quote!(Ty::Bool),
+                Ty::Uint32 => quote!(Ty::Uint32),
+                Ty::Uint64 => quote!(Ty::Uint64),
+                Ty::String => quote!(Ty::String),
+                Ty::Message(name) => quote!(Ty::Message(#name)),
+                Ty::Enum(name) => quote!(Ty::Enum(#name)),
+            };
+            let flatten = field.flatten;
+            quote! {
+                Field { key: #key, label: Label::#label, ty: #ty, flatten: #flatten },
+            }
+        });
+        quote! {
+            Message { name: #name, kind: Kind::#kind, fields: &[#(#fields)*] },
+        }
+    });
+    quote! {
+        use super::{Field, Kind, Label, Message, Ty};
+
+        /// The messages of the adapters, sorted by name. The field numbers
+        /// are the indices of the fields plus one.
+        pub(super) static MESSAGES: &[Message] = &[#(#messages)*];
+    }
+}
+
+pub(crate) fn generate(defs: &Definitions) {
+    let messages = messages(defs);
+    let root = file::workspace_root();
+    file::write_text(function_name!(), &root.join(PROTO_SRC), &proto(&messages)).unwrap();
+    file::write(function_name!(), root.join(PROTOBUF_SRC), table(&messages)).unwrap();
+}
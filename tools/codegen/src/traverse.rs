@@ -5,7 +5,7 @@ use syn_codegen::{Definitions, Node};
 
 pub(crate) fn traverse(
     defs: &Definitions,
-    node: fn(&mut TokenStream, &Node, &Definitions),
+    mut node: impl FnMut(&mut TokenStream, &Node, &Definitions),
 ) -> TokenStream {
     let mut types = defs.types.clone();
     types.sort_by(|a, b| a.ident.cmp(&b.ident));
@@ -0,0 +1,435 @@
+// SPDX-License-Identifier: Apache-2.0 OR MIT
+
+// Generates the traversals of the adapters (src/gen/visit.rs and
+// src/gen/visit_mut.rs), in the same shape as syn's `Visit` and `VisitMut`.
+//
+// The fields follow ast_struct.rs and ast_enum.rs, and the hand-written
+// adapters that do not follow syn.json are listed in `override_node`.
+
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+use syn_codegen::{Data, Definitions, Node, Type};
+
+use crate::{
+    ast_struct::{self, format_ty},
+    convert::{EMPTY_STRUCTS, should_have_span, variant_fields},
+    file, traverse,
+};
+
+const VISIT_SRC: &str = "src/gen/visit.rs";
+const VISIT_MUT_SRC: &str = "src/gen/visit_mut.rs";
+
+#[derive(Clone, Copy)]
+struct Mode {
+    mutable: bool,
+}
+
+impl Mode {
+    /// Returns the name of the visit method of a type, e.g., `visit_item_fn`
+    /// or `visit_item_fn_mut`.
+    fn method(self, ty: &str) -> Ident {
+        let mut name = "visit".to_owned();
+        for c in ty.chars() {
+            if c.is_ascii_uppercase() {
+                name.push('_');
+            }
+            name.push(c.to_ascii_lowercase());
+        }
+        if self.mutable {
+            name.push_str("_mut");
+        }
+        format_ident!("{name}")
+    }
+
+    fn reference(self) -> TokenStream {
+        if self.mutable { quote!(&mut) } else { quote!(&) }
+    }
+
+    fn iter_method(self) -> Ident {
+        if self.mutable { format_ident!("iter_mut") } else { format_ident!("iter") }
+    }
+}
+
+/// An expression that refers to a part of an adapter.
+enum Operand {
+    /// A reference to the part.
+    Borrowed(TokenStream),
+    /// A place of the part.
+    Owned(TokenStream),
+}
+
+impl Operand {
+    fn ref_tokens(&self, mode: Mode) -> TokenStream {
+        match self {
+            Operand::Borrowed(var) => var.clone(),
+            Operand::Owned(var) => {
+                let reference = mode.reference();
+                quote!(#reference #var)
+            }
+        }
+    }
+
+    fn deref(&self) -> Operand {
+        match self {
+            Operand::Borrowed(var) => Operand::Owned(quote!(**#var)),
+            Operand::Owned(var) => Operand::Owned(quote!(*#var)),
+        }
+    }
+}
+
+/// Returns the statements that visit `var` of type `ty`, or `None` if the
+/// adapter does not contain anything to visit there.
+fn visit(ty: &Type, var: &Operand, mode: Mode) -> Option<TokenStream> {
+    match ty {
+        Type::Box(t) => visit(t, &var.deref(), mode),
+        Type::Vec(t) | Type::Punctuated(syn_codegen::Punctuated { element: t, .. }) => {
+            let body = visit(t, &Operand::Borrowed(quote!(it)), mode)?;
+            let (Operand::Borrowed(var) | Operand::Owned(var)) = var;
+            let iter = mode.iter_method();
+            Some(quote! {
+                for it in #var.#iter() {
+                    #body
+                }
+            })
+        }
+        Type::Option(t) => match &**t {
+            Type::Token(_) | Type::Group(_) => None,
+            Type::Tuple(t) => {
+                let elems: Vec<_> = t.iter().filter(|t| format_ty(t).is_some()).collect();
+                let ids: Vec<_> = (0..elems.len()).map(|i| format_ident!("_{i}")).collect();
+                let body: Vec<_> = elems
+                    .iter()
+                    .zip(&ids)
+                    .filter_map(|(t, id)| visit(t, &Operand::Borrowed(quote!(#id)), mode))
+                    .collect();
+                if body.is_empty() {
+                    return None;
+                }
+                let pat = if ids.len() == 1 { quote!(#(#ids)*) } else { quote!((#(#ids),*)) };
+                let var = var.ref_tokens(mode);
+                Some(quote! {
+                    if let Some(#pat) = #var {
+                        #(#body)*
+                    }
+                })
+            }
+            t => {
+                let body = visit(t, &Operand::Borrowed(quote!(it)), mode)?;
+                let var = var.ref_tokens(mode);
+                Some(quote! {
+                    if let Some(it) = #var {
+                        #body
+                    }
+                })
+            }
+        },
+        Type::Ext(t) if t == "Span" => {
+            let method = mode.method("SpanInfo");
+            let var = var.ref_tokens(mode);
+            Some(quote!(v.#method(#var);))
+        }
+        Type::Syn(t) if t == "Reserved" || EMPTY_STRUCTS.contains(&&**t) => None,
+        Type::Syn(t) | Type::Ext(t) => {
+            let method = mode.method(t);
+            let var = var.ref_tokens(mode);
+            Some(quote!(v.#method(#var);))
+        }
+        Type::Token(_) | Type::Group(_) | Type::Std(_) => None,
+        Type::Tuple(t) => unreachable!("Type::Tuple: {:?}", t),
+    }
+}
+
+/// Returns the statements that visit the given fields of `node`.
+fn visit_fields(fields: &[(&str, Type)], mode: Mode) -> TokenStream {
+    fields
+        .iter()
+        .filter_map(|(field, ty)| {
+            let field = format_ident!("{field}");
+            visit(ty, &Operand::Owned(quote!(node.#field)), mode)
+        })
+        .collect()
+}
+
+fn syn(ty: &str) -> Type {
+    Type::Syn(ty.to_owned())
+}
+
+fn ext(ty: &str) -> Type {
+    Type::Ext(ty.to_owned())
+}
+
+fn option(ty: Type) -> Type {
+    Type::Option(Box::new(ty))
+}
+
+fn vec(ty: Type) -> Type {
+    Type::Vec(Box::new(ty))
+}
+
+/// Returns the body of the visit function of the hand-written adapters that
+/// do not follow syn.json.
+fn override_node(ident: &str, mode: Mode) -> Option<TokenStream> {
+    let fields = match ident {
+        "Receiver" => vec![
+            ("attrs", vec(syn("Attribute"))),
+            ("lifetime", option(syn("Lifetime"))),
+            ("ty", Type::Box(Box::new(syn("Type")))),
+        ],
+        "ReturnType" => vec![("ty", option(Type::Box(Box::new(syn("Type")))))],
+        _ => return None,
+    };
+    Some(visit_fields(&fields, mode))
+}
+
+/// The adapters that are not in syn.json, and their visit functions.
+fn extra_nodes(mode: Mode) -> Vec<(&'static str, TokenStream)> {
+    let span = || ("span", option(ext("Span")));
+    let mut nodes: Vec<_> = [
+        ("Comment", vec![("span", ext("Span"))]),
+        ("Delimiter", vec![]),
+        ("Group", vec![
+            ("delimiter", ext("Delimiter")),
+            ("stream", ext("TokenStream")),
+            span(),
+            ("span_open", option(ext("Span"))),
+            ("span_close", option(ext("Span"))),
+        ]),
+        ("Ident", vec![span()]),
+        ("Literal", vec![span()]),
+        ("Punct", vec![("spacing", ext("Spacing")), span()]),
+        ("Spacing", vec![]),
+        ("SpanInfo", vec![]),
+        ("TokenStream", vec![("inner", vec(syn("TokenTree")))]),
+    ]
+    .into_iter()
+    .map(|(ident, fields)| (ident, visit_fields(&fields, mode)))
+    .collect();
+
+    let arms = ["Group", "Ident", "Punct", "Literal"].iter().map(|variant| {
+        let method = mode.method(variant);
+        let variant = format_ident!("{variant}");
+        quote!(TokenTree::#variant(it) => v.#method(it),)
+    });
+    nodes.push(("TokenTree", quote! {
+        match node {
+            #(#arms)*
+        }
+    }));
+    nodes
+}
+
+fn node_body(node: &Node, mode: Mode) -> TokenStream {
+    if let Some(body) = override_node(&node.ident, mode) {
+        return body;
+    }
+    let ident = format_ident!("{}", node.ident);
+    match &node.data {
+        Data::Struct(fields) => {
+            let fields: Vec<_> = fields.iter().map(|(f, ty)| (&**f, ty.clone())).collect();
+            let mut body = visit_fields(&fields, mode);
+            let has_flattened_spannable = fields.iter().any(|(field, ty)| {
+                ast_struct::flatten(&node.ident, field, ty)
+                    && matches!(
+                        (*field, ast_struct::base_ty(ty)),
+                        ("path", Some("Path")) | ("lit", Some("Lit"))
+                    )
+            });
+            if should_have_span(&node.ident)
+                && !fields.iter().any(|(field, _)| *field == "span")
+                && !has_flattened_spannable
+            {
+                body.extend(visit_fields(&[("span", option(ext("Span")))], mode));
+            }
+            if ast_struct::has_comments(&node.ident) {
+                body.extend(visit_fields(&[("comments", vec(ext("Comment")))], mode));
+            }
+            body
+        }
+        Data::Enum(variants) => {
+            let mut arms = TokenStream::new();
+            let mut has_children = false;
+            for (variant, fields) in variants {
+                let variant_ident = format_ident!("{variant}");
+                if let Some(names) = variant_fields(&node.ident, variant) {
+                    let (names, body): (Vec<_>, Vec<_>) = names
+                        .iter()
+                        .zip(fields)
+                        .filter_map(|(name, ty)| {
+                            let name = format_ident!("{name}");
+                            visit(ty, &Operand::Borrowed(quote!(#name)), mode)
+                                .map(|body| (name, body))
+                        })
+                        .unzip();
+                    has_children |= !body.is_empty();
+                    arms.extend(quote! {
+                        #ident::#variant_ident { #(#names,)* .. } => {
+                            #(#body)*
+                        }
+                    });
+                    continue;
+                }
+                match fields.first().and_then(|ty| visit(ty, &Operand::Borrowed(quote!(it)), mode))
+                {
+                    Some(body) => {
+                        has_children = true;
+                        arms.extend(quote! {
+                            #ident::#variant_ident(it) => {
+                                #body
+                            }
+                        });
+                    }
+                    None => arms.extend(quote!(#ident::#variant_ident => {})),
+                }
+            }
+            if !has_children {
+                return TokenStream::new();
+            }
+            quote! {
+                match node {
+                    #arms
+                }
+            }
+        }
+        // The literals of syn (e.g., `LitStr`) are serialized as their tokens.
+        Data::Private => visit_fields(&[("token", ext("Literal"))], mode),
+    }
+}
+
+fn method_decl(ty: &str, mode: Mode) -> TokenStream {
+    let ident = format_ident!("{ty}");
+    let method = mode.method(ty);
+    if mode.mutable {
+        quote! {
+            fn #method(&mut self, node: &mut #ident) {
+                #method(self, node);
+            }
+        }
+    } else {
+        quote! {
+            fn #method(&mut self, node: &'ast #ident) {
+                #method(self, node);
+            }
+        }
+    }
+}
+
+fn function_decl(ty: &str, body: TokenStream, mode: Mode) -> TokenStream {
+    let ident = format_ident!("{ty}");
+    let method = mode.method(ty);
+    let (v, node) = if body.is_empty() {
+        (format_ident!("_v"), format_ident!("_node"))
+    } else {
+        (format_ident!("v"), format_ident!("node"))
+    };
+    if mode.mutable {
+        quote! {
+            pub(crate) fn #method<V>(#v: &mut V, #node: &mut #ident)
+            where
+                V: VisitMut + ?Sized,
+            {
+                #body
+            }
+        }
+    } else {
+        quote! {
+            pub(crate) fn #method<'ast, V>(#v: &mut V, #node: &'ast #ident)
+            where
+                V: Visit<'ast> + ?Sized,
+            {
+                #body
+            }
+        }
+    }
+}
+
+/// Returns the impl of `Node` for a type, for the traversals of the adapters
+/// of the `Syn` trait.
+fn node_impl(ty: &str) -> TokenStream {
+    let ident = format_ident!("{ty}");
+    let method = Mode { mutable: true }.method(ty);
+    quote! {
+        impl Node for #ident {
+            fn visit_mut<V>(&mut self, v: &mut V)
+            where
+                V: VisitMut + ?Sized,
+            {
+                v.#method(self);
+            }
+        }
+    }
+}
+
+fn generate_mode(defs: &Definitions, mode: Mode) -> TokenStream {
+    let mut methods = TokenStream::new();
+    let mut functions = TokenStream::new();
+    let mut impls = TokenStream::new();
+    let mut add = |ty: &str, body: TokenStream| {
+        methods.extend(method_decl(ty, mode));
+        functions.extend(function_decl(ty, body, mode));
+        if mode.mutable {
+            impls.extend(node_impl(ty));
+        }
+    };
+    traverse::traverse(defs, |_, node, _| {
+        if !EMPTY_STRUCTS.contains(&&*node.ident) {
+            add(&node.ident, node_body(node, mode));
+        }
+    });
+    for (ty, body) in extra_nodes(mode) {
+        add(ty, body);
+    }
+
+    let allow = quote! {
+        #![allow(
+            clippy::explicit_auto_deref,
+            clippy::explicit_iter_loop,
+            clippy::match_same_arms,
+        )]
+    };
+    if mode.mutable {
+        quote! {
+            #allow
+
+            use crate::*;
+
+            /// A traversal of the adapters that can modify them. Each method
+            /// visits the children of the node by default, like
+            /// `syn::visit_mut::VisitMut`.
+            pub(crate) trait VisitMut {
+                #methods
+            }
+
+            /// An adapter that can be traversed with a [`VisitMut`].
+            pub(crate) trait Node {
+                fn visit_mut<V>(&mut self, v: &mut V)
+                where
+                    V: VisitMut + ?Sized;
+            }
+
+            #impls
+
+            #functions
+        }
+    } else {
+        quote! {
+            #allow
+
+            use crate::*;
+
+            /// A traversal of the adapters. Each method visits the children of
+            /// the node by default, like `syn::visit::Visit`.
+            pub(crate) trait Visit<'ast> {
+                #methods
+            }
+
+            #functions
+        }
+    }
+}
+
+pub(crate) fn generate(defs: &Definitions) {
+    for (path, mutable) in [(VISIT_SRC, false), (VISIT_MUT_SRC, true)] {
+        let path = &file::workspace_root().join(path);
+        file::write(function_name!(), path, generate_mode(defs, Mode { mutable })).unwrap();
+    }
+}